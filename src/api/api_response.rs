@@ -3,5 +3,96 @@ use serde::{Deserialize, Serialize};
 #[derive(Serialize, Deserialize)]
 pub struct ApiResponse<T> {
     pub data: Option<T>,
+    /// Left out of successful responses
+    #[serde(flatten)]
+    pub error: Option<ApiError>,
+}
+
+/// Stable error codes for clients, unlike the localized messages
+#[derive(Serialize, Deserialize, Copy, Clone, PartialEq, Debug)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ErrorCode {
+    NotFound,
+    ValidationFailed,
+    Unauthorized,
+    Forbidden,
+    Conflict,
+    InternalError,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct FieldError {
+    /// Path of the field in the request body, like "ingredients[0].name"
+    pub field: String,
+    pub message: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiError {
     pub error: String,
+    pub code: ErrorCode,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub details: Vec<FieldError>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+}
+
+#[cfg(test)]
+mod unit_tests_api_response {
+    use serde_json::json;
+
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn serializes_api_response() {
+        struct TestCase {
+            title: String,
+            api_response: ApiResponse<String>,
+            expected_json: serde_json::Value,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Leaves out error fields on success".into(),
+                api_response: ApiResponse {
+                    data: Some("42".into()),
+                    error: None,
+                },
+                expected_json: json!({"data": "42"}),
+            },
+            TestCase {
+                title: "Flattens the error into the envelope".into(),
+                api_response: ApiResponse {
+                    data: None,
+                    error: Some(ApiError {
+                        error: "Invalid recipe".into(),
+                        code: ErrorCode::ValidationFailed,
+                        details: vec![FieldError {
+                            field: "title".into(),
+                            message: "Title must not be empty".into(),
+                        }],
+                        request_id: Some("abc".into()),
+                    }),
+                },
+                expected_json: json!({
+                    "data": null,
+                    "error": "Invalid recipe",
+                    "code": "VALIDATION_FAILED",
+                    "details": [{"field": "title", "message": "Title must not be empty"}],
+                    "requestId": "abc",
+                }),
+            },
+        ];
+
+        for t in test_cases {
+            assert_eq!(
+                serde_json::to_value(&t.api_response).expect("Serializable response"),
+                t.expected_json,
+                "{}",
+                t.title
+            );
+        }
+    }
 }
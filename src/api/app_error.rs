@@ -0,0 +1,258 @@
+use std::fmt::{self, Display};
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use mongodb::error::{ErrorKind, WriteFailure};
+use tracing::info;
+
+use super::{
+    api_response::{ApiError, ApiResponse, ErrorCode, FieldError},
+    messages::Message,
+    request_context::get_current_request_id,
+};
+
+const DUPLICATE_KEY_ERROR_CODE: i32 = 11000;
+
+/// Errors of the API, each holding the message shown to the client
+#[derive(Debug)]
+pub enum AppError {
+    NotFound(String),
+    Validation(String),
+    /// Validation failures of single fields of the request body
+    InvalidFields(Vec<FieldError>),
+    Unauthorized(String),
+    Forbidden(String),
+    Conflict(String),
+    /// Failure on the server, only the outermost context is shown to the client
+    Internal(anyhow::Error),
+}
+
+impl AppError {
+    /// For requests without a valid access token, the reason is only logged
+    pub fn unauthenticated(err: anyhow::Error) -> Self {
+        let err_msg = Message::AuthenticationFailed.to_string();
+        info!("{err_msg}: {err}");
+
+        Self::Unauthorized(err_msg)
+    }
+
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Validation(_) | Self::InvalidFields(_) => StatusCode::BAD_REQUEST,
+            Self::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            Self::Forbidden(_) => StatusCode::FORBIDDEN,
+            Self::Conflict(_) => StatusCode::CONFLICT,
+            Self::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::NotFound(_) => ErrorCode::NotFound,
+            Self::Validation(_) | Self::InvalidFields(_) => ErrorCode::ValidationFailed,
+            Self::Unauthorized(_) => ErrorCode::Unauthorized,
+            Self::Forbidden(_) => ErrorCode::Forbidden,
+            Self::Conflict(_) => ErrorCode::Conflict,
+            Self::Internal(_) => ErrorCode::InternalError,
+        }
+    }
+
+    /// Keeps the kind of error but shows another message to the client
+    pub fn replace_message(self, message: String) -> Self {
+        match self {
+            Self::NotFound(_) => Self::NotFound(message),
+            Self::Validation(_) => Self::Validation(message),
+            // The details say more than any message
+            Self::InvalidFields(details) => Self::InvalidFields(details),
+            Self::Unauthorized(_) => Self::Unauthorized(message),
+            Self::Forbidden(_) => Self::Forbidden(message),
+            Self::Conflict(_) => Self::Conflict(message),
+            Self::Internal(err) => Self::Internal(err.context(message)),
+        }
+    }
+}
+
+impl Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NotFound(message)
+            | Self::Validation(message)
+            | Self::Unauthorized(message)
+            | Self::Forbidden(message)
+            | Self::Conflict(message) => f.write_str(message),
+            Self::InvalidFields(_) => f.write_str(&Message::InvalidFields.to_string()),
+            Self::Internal(err) => Display::fmt(err, f),
+        }
+    }
+}
+
+impl std::error::Error for AppError {}
+
+impl From<anyhow::Error> for AppError {
+    fn from(err: anyhow::Error) -> Self {
+        // Handlers still returning anyhow errors may pass on typed ones
+        match err.downcast::<AppError>() {
+            Ok(app_error) => app_error,
+            Err(err) => Self::Internal(err.context(Message::InternalError.to_string())),
+        }
+    }
+}
+
+/// Whether the error comes from a duplicate key on the unique index with the given name
+pub fn is_duplicate_key_on(err: &mongodb::error::Error, index_name: &str) -> bool {
+    match err.kind.as_ref() {
+        ErrorKind::Write(WriteFailure::WriteError(write_error)) => {
+            write_error.code == DUPLICATE_KEY_ERROR_CODE
+                && write_error
+                    .message
+                    .contains(&format!("index: {index_name} dup key"))
+        }
+        _ => false,
+    }
+}
+
+impl From<mongodb::error::Error> for AppError {
+    fn from(err: mongodb::error::Error) -> Self {
+        match err.kind.as_ref() {
+            ErrorKind::Write(WriteFailure::WriteError(write_error))
+                if write_error.code == DUPLICATE_KEY_ERROR_CODE =>
+            {
+                info!("{err}");
+                Self::Conflict(Message::DuplicateDocument.to_string())
+            }
+            _ => anyhow::Error::from(err).into(),
+        }
+    }
+}
+
+impl From<bson::ser::Error> for AppError {
+    fn from(err: bson::ser::Error) -> Self {
+        anyhow::Error::from(err).into()
+    }
+}
+
+impl From<bson::de::Error> for AppError {
+    fn from(err: bson::de::Error) -> Self {
+        anyhow::Error::from(err).into()
+    }
+}
+
+impl From<AppError> for ApiError {
+    fn from(app_error: AppError) -> Self {
+        let code = app_error.code();
+        let error = app_error.to_string();
+        let details = match app_error {
+            AppError::InvalidFields(details) => details,
+            _ => vec![],
+        };
+
+        Self {
+            error,
+            code,
+            details,
+            request_id: get_current_request_id(),
+        }
+    }
+}
+
+/// Lets handlers that build their responses themselves answer with an AppError
+impl<T> From<AppError> for (StatusCode, Json<ApiResponse<T>>) {
+    fn from(app_error: AppError) -> Self {
+        (
+            app_error.status_code(),
+            Json(ApiResponse {
+                data: None,
+                error: Some(app_error.into()),
+            }),
+        )
+    }
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        if let Self::Internal(err) = &self {
+            info!("{err:#}");
+        }
+
+        <(StatusCode, Json<ApiResponse<()>>)>::from(self).into_response()
+    }
+}
+
+pub trait WithMessage<T> {
+    /// Shows the message to the client if the result is an error, keeping the kind of error
+    fn with_message(self, message: Message) -> Result<T, AppError>;
+}
+
+impl<T, E: Into<AppError>> WithMessage<T> for Result<T, E> {
+    fn with_message(self, message: Message) -> Result<T, AppError> {
+        self.map_err(|err| err.into().replace_message(message.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod unit_tests_app_error {
+    use anyhow::anyhow;
+
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn converts_errors() {
+        struct TestCase {
+            title: String,
+            app_error: AppError,
+            expected_status_code: StatusCode,
+            expected_message: String,
+        }
+
+        let recipe_id = "42";
+        let test_cases = vec![
+            TestCase {
+                title: "Keeps typed errors passed on as anyhow errors".into(),
+                app_error: anyhow!(AppError::Forbidden("Not yours".into())).into(),
+                expected_status_code: StatusCode::FORBIDDEN,
+                expected_message: "Not yours".into(),
+            },
+            TestCase {
+                title: "Hides the cause of internal errors".into(),
+                app_error: anyhow!("Connection refused").into(),
+                expected_status_code: StatusCode::INTERNAL_SERVER_ERROR,
+                expected_message: Message::InternalError.to_string(),
+            },
+            TestCase {
+                title: "Replaces the message of internal errors".into(),
+                app_error: Err::<(), _>(anyhow!("Connection refused"))
+                    .with_message(Message::GetRecipeFailed {
+                        recipe_id: &recipe_id,
+                    })
+                    .unwrap_err(),
+                expected_status_code: StatusCode::INTERNAL_SERVER_ERROR,
+                expected_message: "Failed to get recipe with id '42'".into(),
+            },
+            TestCase {
+                title: "Keeps the kind when replacing the message".into(),
+                app_error: Err::<(), _>(AppError::NotFound("Missing document".into()))
+                    .with_message(Message::GetRecipeFailed {
+                        recipe_id: &recipe_id,
+                    })
+                    .unwrap_err(),
+                expected_status_code: StatusCode::NOT_FOUND,
+                expected_message: "Failed to get recipe with id '42'".into(),
+            },
+        ];
+
+        for t in test_cases {
+            assert_eq!(
+                t.app_error.status_code(),
+                t.expected_status_code,
+                "{}",
+                t.title
+            );
+            assert_eq!(t.app_error.to_string(), t.expected_message, "{}", t.title);
+        }
+    }
+}
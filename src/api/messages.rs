@@ -0,0 +1,931 @@
+use std::fmt::{self, Display};
+
+use axum::{extract::Request, http::HeaderMap, middleware::Next, response::Response};
+
+use super::services::locale_service::{get_requested_locales, negotiate_locale};
+
+tokio::task_local! {
+    static LOCALE: Locale;
+}
+
+/// Languages of the message catalog
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum Locale {
+    #[default]
+    English,
+    German,
+}
+
+impl Locale {
+    pub fn tag(self) -> &'static str {
+        match self {
+            Self::English => "en",
+            Self::German => "de",
+        }
+    }
+
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        Self::negotiate(&get_requested_locales(headers))
+    }
+
+    /// Locale of a stored preference like "de-AT", English if it is not in the catalog
+    pub fn from_preference(locale: Option<&str>) -> Self {
+        Self::negotiate(&locale.map(String::from).into_iter().collect::<Vec<_>>())
+    }
+
+    fn negotiate(requested_locales: &[String]) -> Self {
+        let tags = [Self::English, Self::German].map(Self::tag);
+
+        match negotiate_locale(requested_locales, &tags).as_deref() {
+            Some("de") => Self::German,
+            _ => Self::English,
+        }
+    }
+
+    /// Locale of the request being handled, English outside of requests
+    pub fn current() -> Self {
+        LOCALE.try_with(|locale| *locale).unwrap_or_default()
+    }
+}
+
+/// Handles the request with messages in the locale negotiated from its Accept-Language header
+pub async fn localize_messages(request: Request, next: Next) -> Response {
+    let locale = Locale::from_headers(request.headers());
+
+    LOCALE.scope(locale, next.run(request)).await
+}
+
+/// Message catalog of everything the API tells its users, displayed in the locale of the request
+pub enum Message<'a> {
+    AuthenticationFailed,
+    InvalidUserData,
+    UserWithEmailNotFound {
+        email: &'a dyn Display,
+    },
+    IncorrectPassword {
+        email: &'a dyn Display,
+    },
+    UserInactive {
+        email: &'a dyn Display,
+    },
+    GenerateTokenFailed,
+    GetCommentsFailed {
+        recipe_id: &'a dyn Display,
+    },
+    GetRecipeFailed {
+        recipe_id: &'a dyn Display,
+    },
+    ParentCommentNotFound {
+        parent_id: &'a dyn Display,
+    },
+    CreateCommentFailed,
+    CommentNotFound {
+        comment_id: &'a dyn Display,
+    },
+    EditCommentForbidden {
+        comment_id: &'a dyn Display,
+    },
+    PatchCommentFailed {
+        comment_id: &'a dyn Display,
+    },
+    DeleteCommentForbidden {
+        comment_id: &'a dyn Display,
+    },
+    DeleteCommentFailed {
+        comment_id: &'a dyn Display,
+    },
+    GetFavoritesFailed,
+    AddFavoriteFailed {
+        recipe_id: &'a dyn Display,
+    },
+    RemoveFavoriteFailed {
+        recipe_id: &'a dyn Display,
+    },
+    GetCollectionsFailed,
+    CreateCollectionFailed,
+    CollectionNotFound {
+        collection_id: &'a dyn Display,
+    },
+    PatchCollectionFailed {
+        collection_id: &'a dyn Display,
+    },
+    DeleteCollectionFailed {
+        collection_id: &'a dyn Display,
+    },
+    AddToCollectionFailed {
+        recipe_id: &'a dyn Display,
+        collection_id: &'a dyn Display,
+    },
+    RemoveFromCollectionFailed {
+        recipe_id: &'a dyn Display,
+        collection_id: &'a dyn Display,
+    },
+    SharedCollectionNotFound,
+    GetSharedCollectionFailed,
+    GetProfileFailed {
+        user_id: &'a dyn Display,
+    },
+    FollowSelf,
+    GetUserFailed {
+        user_id: &'a dyn Display,
+    },
+    FollowUserFailed {
+        user_id: &'a dyn Display,
+    },
+    UnfollowUserFailed {
+        user_id: &'a dyn Display,
+    },
+    GetFeedFailed,
+    GetHouseholdFailed {
+        household_id: &'a dyn Display,
+    },
+    ManageHouseholdForbidden,
+    AlreadyInHousehold,
+    GetHouseholdOfUserFailed,
+    CreateHouseholdFailed,
+    NoHousehold,
+    InviteCodeNotFound,
+    GetHouseholdByInviteCodeFailed,
+    JoinHouseholdFailed {
+        household_id: &'a dyn Display,
+    },
+    PatchHouseholdFailed {
+        household_id: &'a dyn Display,
+    },
+    DeleteHouseholdFailed {
+        household_id: &'a dyn Display,
+    },
+    RegenerateInviteCodeFailed {
+        household_id: &'a dyn Display,
+    },
+    HouseholdOwnerRequired,
+    HouseholdMemberNotFound {
+        user_id: &'a dyn Display,
+    },
+    PatchHouseholdMemberFailed {
+        user_id: &'a dyn Display,
+    },
+    HandOverOwnershipFirst,
+    RemoveHouseholdMemberFailed {
+        user_id: &'a dyn Display,
+    },
+    MaintainPricesForbidden,
+    GetIngredientPricesFailed,
+    CreateIngredientPriceFailed,
+    NegativePrice,
+    IngredientPriceNotFound {
+        id: &'a dyn Display,
+    },
+    PatchIngredientPriceFailed {
+        id: &'a dyn Display,
+    },
+    DeleteIngredientPriceFailed {
+        id: &'a dyn Display,
+    },
+    GetMealPlanFailed {
+        week: &'a dyn Display,
+    },
+    AddMealPlanEntryFailed {
+        week: &'a dyn Display,
+    },
+    MealPlanEntryNotFound {
+        entry_id: &'a dyn Display,
+    },
+    PatchMealPlanEntryFailed {
+        entry_id: &'a dyn Display,
+    },
+    DeleteMealPlanEntryFailed {
+        entry_id: &'a dyn Display,
+    },
+    CopyMealPlanFailed {
+        from_week: &'a dyn Display,
+        week: &'a dyn Display,
+    },
+    InvalidWeekFormat {
+        week: &'a dyn Display,
+    },
+    WeekDoesNotExist {
+        week: &'a dyn Display,
+    },
+    GetNotificationsFailed,
+    NotificationNotFound {
+        notification_id: &'a dyn Display,
+    },
+    MarkNotificationReadFailed {
+        notification_id: &'a dyn Display,
+    },
+    MarkNotificationsReadFailed,
+    GetPantryItemsFailed,
+    InvalidExpiryDate {
+        err: &'a dyn Display,
+    },
+    AddPantryItemFailed,
+    PantryItemNotFound {
+        item_id: &'a dyn Display,
+    },
+    PatchPantryItemFailed {
+        item_id: &'a dyn Display,
+    },
+    DeletePantryItemFailed {
+        item_id: &'a dyn Display,
+    },
+    GetRecipesFailed,
+    UserNotFound,
+    GetPreferencesFailed,
+    PatchPreferencesFailed,
+    AccessRevisionsForbidden {
+        recipe_id: &'a dyn Display,
+    },
+    GetRevisionsFailed {
+        recipe_id: &'a dyn Display,
+    },
+    RevisionNotFound {
+        revision: &'a dyn Display,
+        recipe_id: &'a dyn Display,
+    },
+    GetRevisionFailed {
+        revision: &'a dyn Display,
+        recipe_id: &'a dyn Display,
+    },
+    SaveRevisionFailed {
+        recipe_id: &'a dyn Display,
+    },
+    RecipeNotFound {
+        recipe_id: &'a dyn Display,
+    },
+    RestoreRevisionFailed {
+        revision: &'a dyn Display,
+        recipe_id: &'a dyn Display,
+    },
+    EstimateRecipeCostsFailed,
+    ForkRecipeFailed {
+        recipe_id: &'a dyn Display,
+    },
+    GetForksFailed {
+        recipe_id: &'a dyn Display,
+    },
+    EstimateRecipeCostFailed,
+    GetSuggestedSubstitutionsFailed,
+    CreateRecipeFailed,
+    ImportRecipeFailed {
+        err: &'a dyn Display,
+    },
+    SaveImportedRecipeFailed,
+    ModifyRecipeForbidden {
+        recipe_id: &'a dyn Display,
+    },
+    RecipeRemovedByModerator {
+        recipe_id: &'a dyn Display,
+    },
+    RemoveThroughReports,
+    PublishThroughEndpoint,
+    PatchRecipeFailed {
+        recipe_id: &'a dyn Display,
+    },
+    FindRecipeFailed {
+        recipe_id: &'a dyn Display,
+    },
+    UpdatePublicationFailed {
+        recipe_id: &'a dyn Display,
+    },
+    DeleteRecipeForbidden {
+        recipe_id: &'a dyn Display,
+    },
+    DeleteRecipeFailed {
+        recipe_id: &'a dyn Display,
+    },
+    ModerateReportsForbidden,
+    GetReportedTargetFailed {
+        target_type: &'a dyn Display,
+        target_id: &'a dyn Display,
+    },
+    CreateReportFailed,
+    GetReportsFailed,
+    GetReportFailed {
+        report_id: &'a dyn Display,
+    },
+    ReportAlreadyResolved {
+        report_id: &'a dyn Display,
+    },
+    DeactivateReportedUsers,
+    ApplyModerationFailed {
+        action: &'a dyn Display,
+        report_id: &'a dyn Display,
+    },
+    ResolveReportFailed {
+        report_id: &'a dyn Display,
+    },
+    GetModerationLogFailed,
+    GetReviewsFailed {
+        recipe_id: &'a dyn Display,
+    },
+    ReviewOwnRecipe,
+    RecipeAlreadyReviewed {
+        recipe_id: &'a dyn Display,
+    },
+    CheckExistingReviewFailed,
+    CreateReviewFailed,
+    ReviewNotFound {
+        recipe_id: &'a dyn Display,
+    },
+    PatchReviewFailed {
+        recipe_id: &'a dyn Display,
+    },
+    DeleteReviewFailed {
+        recipe_id: &'a dyn Display,
+    },
+    UnknownSeasonRegion {
+        region: &'a dyn Display,
+        regions: &'a dyn Display,
+    },
+    InvalidMonth,
+    GetShoppingListsFailed,
+    ShoppingListSourceRequired,
+    GetShoppingListRecipesFailed,
+    CreateShoppingListFailed,
+    DefaultShoppingListName,
+    WeekShoppingListName {
+        week: &'a dyn Display,
+    },
+    ShoppingListNotFound {
+        list_id: &'a dyn Display,
+    },
+    GetShoppingListFailed {
+        list_id: &'a dyn Display,
+    },
+    ShoppingListItemNotFound {
+        item_id: &'a dyn Display,
+    },
+    PatchShoppingListItemFailed {
+        item_id: &'a dyn Display,
+    },
+    DeleteShoppingListFailed {
+        list_id: &'a dyn Display,
+    },
+    CurateSubstitutionsForbidden,
+    GetSubstitutionsFailed,
+    CreateSubstitutionFailed,
+    RatioNotPositive,
+    SubstitutionNotFound {
+        id: &'a dyn Display,
+    },
+    PatchSubstitutionFailed {
+        id: &'a dyn Display,
+    },
+    DeleteSubstitutionFailed {
+        id: &'a dyn Display,
+    },
+    GetUsersFailed,
+    GetUserByEmailFailed {
+        email: &'a dyn Display,
+    },
+    DeleteUserFailed {
+        user_id: &'a dyn Display,
+    },
+    ActivateUserFailed {
+        user_id: &'a dyn Display,
+    },
+    DeactivateUserFailed {
+        user_id: &'a dyn Display,
+    },
+    InvalidFeedCursor {
+        cursor: &'a dyn Display,
+    },
+    InvalidLocale {
+        locale: &'a dyn Display,
+    },
+    TranslateOriginalLocale {
+        recipe_id: &'a dyn Display,
+        locale: &'a dyn Display,
+    },
+    TranslationNotFound {
+        locale: &'a dyn Display,
+        recipe_id: &'a dyn Display,
+    },
+    SaveTranslationFailed {
+        locale: &'a dyn Display,
+        recipe_id: &'a dyn Display,
+    },
+    DeleteTranslationFailed {
+        locale: &'a dyn Display,
+        recipe_id: &'a dyn Display,
+    },
+    InternalError,
+    InvalidId {
+        id: &'a dyn Display,
+    },
+    DocumentNotFound {
+        collection: &'a dyn Display,
+        id: &'a dyn Display,
+    },
+    DuplicateDocument,
+    EmailTaken {
+        email: &'a dyn Display,
+    },
+    InvalidFields,
+    FieldEmpty,
+    FieldTooShort {
+        min_length: &'a dyn Display,
+    },
+    FieldTooLong {
+        max_length: &'a dyn Display,
+    },
+    TooFewItems {
+        min_count: &'a dyn Display,
+    },
+    TooManyItems {
+        max_count: &'a dyn Display,
+    },
+    OutOfRange {
+        min: &'a dyn Display,
+        max: &'a dyn Display,
+    },
+    InvalidFormat,
+    InvalidEmail,
+    InvalidFieldValue {
+        err: &'a dyn Display,
+    },
+    InvalidJsonBody {
+        err: &'a dyn Display,
+    },
+    JsonContentTypeRequired,
+    ReadBodyFailed,
+    InvalidPathParams {
+        err: &'a dyn Display,
+    },
+    InvalidQueryParams {
+        err: &'a dyn Display,
+    },
+    ForkOwnRecipe,
+    ForkUnpublishedRecipe,
+    ReviewCreatedNotification,
+    NewFollowerNotification,
+    RecipeForkedNotification,
+    ReportResolvedNotification,
+    DigestSubjectSingle,
+    DigestSubject {
+        count: &'a dyn Display,
+    },
+}
+
+impl Message<'_> {
+    pub fn localize(&self, locale: Locale) -> String {
+        match locale {
+            Locale::English => self.english(),
+            Locale::German => self.german(),
+        }
+    }
+
+    fn english(&self) -> String {
+        match self {
+            Self::AuthenticationFailed => "Failed to authenticate request".into(),
+            Self::InvalidUserData => "Failed to process provided user data".into(),
+            Self::UserWithEmailNotFound { email } => format!("Failed to find user with the provided email: {email}"),
+            Self::IncorrectPassword { email } => format!("Incorrect password: {email}"),
+            Self::UserInactive { email } => format!("User is inactive: {email}"),
+            Self::GenerateTokenFailed => "Failed to generate JWT token".into(),
+            Self::GetCommentsFailed { recipe_id } => format!("Failed to get comments for recipe with id '{recipe_id}'"),
+            Self::GetRecipeFailed { recipe_id } => format!("Failed to get recipe with id '{recipe_id}'"),
+            Self::ParentCommentNotFound { parent_id } => format!("Failed to find parent comment with id '{parent_id}' on this recipe"),
+            Self::CreateCommentFailed => "Failed to create new comment".into(),
+            Self::CommentNotFound { comment_id } => format!("Failed to find comment with id '{comment_id}'"),
+            Self::EditCommentForbidden { comment_id } => format!("Not allowed to edit comment with id '{comment_id}'"),
+            Self::PatchCommentFailed { comment_id } => format!("Failed to patch comment with id '{comment_id}'"),
+            Self::DeleteCommentForbidden { comment_id } => format!("Not allowed to delete comment with id '{comment_id}'"),
+            Self::DeleteCommentFailed { comment_id } => format!("Failed to delete comment with id '{comment_id}'"),
+            Self::GetFavoritesFailed => "Failed to get favorite recipes".into(),
+            Self::AddFavoriteFailed { recipe_id } => format!("Failed to add recipe with id '{recipe_id}' to favorites"),
+            Self::RemoveFavoriteFailed { recipe_id } => format!("Failed to remove recipe with id '{recipe_id}' from favorites"),
+            Self::GetCollectionsFailed => "Failed to get recipe collections".into(),
+            Self::CreateCollectionFailed => "Failed to create new recipe collection".into(),
+            Self::CollectionNotFound { collection_id } => format!("Failed to find recipe collection with id '{collection_id}'"),
+            Self::PatchCollectionFailed { collection_id } => format!("Failed to patch recipe collection with id '{collection_id}'"),
+            Self::DeleteCollectionFailed { collection_id } => format!("Failed to delete recipe collection with id '{collection_id}'"),
+            Self::AddToCollectionFailed { recipe_id, collection_id } => format!("Failed to add recipe with id '{recipe_id}' to collection with id '{collection_id}'"),
+            Self::RemoveFromCollectionFailed { recipe_id, collection_id } => format!("Failed to remove recipe with id '{recipe_id}' from collection with id '{collection_id}'"),
+            Self::SharedCollectionNotFound => "Failed to find shared recipe collection".into(),
+            Self::GetSharedCollectionFailed => "Failed to get shared recipe collection".into(),
+            Self::GetProfileFailed { user_id } => format!("Failed to get profile of user with id '{user_id}'"),
+            Self::FollowSelf => "Users cannot follow themselves".into(),
+            Self::GetUserFailed { user_id } => format!("Failed to get user with id '{user_id}'"),
+            Self::FollowUserFailed { user_id } => format!("Failed to follow user with id '{user_id}'"),
+            Self::UnfollowUserFailed { user_id } => format!("Failed to unfollow user with id '{user_id}'"),
+            Self::GetFeedFailed => "Failed to get feed".into(),
+            Self::GetHouseholdFailed { household_id } => format!("Failed to get household with id '{household_id}'"),
+            Self::ManageHouseholdForbidden => "Only owners can manage the household".into(),
+            Self::AlreadyInHousehold => "Users can only be a member of one household".into(),
+            Self::GetHouseholdOfUserFailed => "Failed to get household of user".into(),
+            Self::CreateHouseholdFailed => "Failed to create household".into(),
+            Self::NoHousehold => "User is not a member of any household".into(),
+            Self::InviteCodeNotFound => "Failed to find household with this invite code".into(),
+            Self::GetHouseholdByInviteCodeFailed => "Failed to get household by invite code".into(),
+            Self::JoinHouseholdFailed { household_id } => format!("Failed to join household with id '{household_id}'"),
+            Self::PatchHouseholdFailed { household_id } => format!("Failed to patch household with id '{household_id}'"),
+            Self::DeleteHouseholdFailed { household_id } => format!("Failed to delete household with id '{household_id}'"),
+            Self::RegenerateInviteCodeFailed { household_id } => format!("Failed to regenerate invite code of household with id '{household_id}'"),
+            Self::HouseholdOwnerRequired => "Households need at least one owner".into(),
+            Self::HouseholdMemberNotFound { user_id } => format!("Failed to find member with id '{user_id}' in household"),
+            Self::PatchHouseholdMemberFailed { user_id } => format!("Failed to patch member with id '{user_id}'"),
+            Self::HandOverOwnershipFirst => "Households need at least one owner, hand over ownership first".into(),
+            Self::RemoveHouseholdMemberFailed { user_id } => format!("Failed to remove member with id '{user_id}' from household"),
+            Self::MaintainPricesForbidden => "Only admins can maintain ingredient prices".into(),
+            Self::GetIngredientPricesFailed => "Failed to get ingredient prices".into(),
+            Self::CreateIngredientPriceFailed => "Failed to create ingredient price".into(),
+            Self::NegativePrice => "Price must not be negative".into(),
+            Self::IngredientPriceNotFound { id } => format!("Failed to find ingredient price with id '{id}'"),
+            Self::PatchIngredientPriceFailed { id } => format!("Failed to patch ingredient price with id '{id}'"),
+            Self::DeleteIngredientPriceFailed { id } => format!("Failed to delete ingredient price with id '{id}'"),
+            Self::GetMealPlanFailed { week } => format!("Failed to get meal plan for week '{week}'"),
+            Self::AddMealPlanEntryFailed { week } => format!("Failed to add entry to meal plan for week '{week}'"),
+            Self::MealPlanEntryNotFound { entry_id } => format!("Failed to find meal plan entry with id '{entry_id}'"),
+            Self::PatchMealPlanEntryFailed { entry_id } => format!("Failed to patch meal plan entry with id '{entry_id}'"),
+            Self::DeleteMealPlanEntryFailed { entry_id } => format!("Failed to delete meal plan entry with id '{entry_id}'"),
+            Self::CopyMealPlanFailed { from_week, week } => format!("Failed to copy meal plan from week '{from_week}' to '{week}'"),
+            Self::InvalidWeekFormat { week } => format!("Week '{week}' is not in the format YYYY-Www"),
+            Self::WeekDoesNotExist { week } => format!("Week '{week}' does not exist"),
+            Self::GetNotificationsFailed => "Failed to get notifications".into(),
+            Self::NotificationNotFound { notification_id } => format!("Failed to find notification with id '{notification_id}'"),
+            Self::MarkNotificationReadFailed { notification_id } => format!("Failed to mark notification with id '{notification_id}' read"),
+            Self::MarkNotificationsReadFailed => "Failed to mark notifications read".into(),
+            Self::GetPantryItemsFailed => "Failed to get pantry items".into(),
+            Self::InvalidExpiryDate { err } => format!("Expiry date must be in the format YYYY-MM-DD: {err}"),
+            Self::AddPantryItemFailed => "Failed to add pantry item".into(),
+            Self::PantryItemNotFound { item_id } => format!("Failed to find pantry item with id '{item_id}'"),
+            Self::PatchPantryItemFailed { item_id } => format!("Failed to patch pantry item with id '{item_id}'"),
+            Self::DeletePantryItemFailed { item_id } => format!("Failed to delete pantry item with id '{item_id}'"),
+            Self::GetRecipesFailed => "Failed to get recipes".into(),
+            Self::UserNotFound => "Failed to find user".into(),
+            Self::GetPreferencesFailed => "Failed to get preferences".into(),
+            Self::PatchPreferencesFailed => "Failed to patch preferences".into(),
+            Self::AccessRevisionsForbidden { recipe_id } => format!("Not allowed to access revisions of recipe with id '{recipe_id}'"),
+            Self::GetRevisionsFailed { recipe_id } => format!("Failed to get revisions of recipe with id '{recipe_id}'"),
+            Self::RevisionNotFound { revision, recipe_id } => format!("Revision {revision} of recipe with id '{recipe_id}' not found"),
+            Self::GetRevisionFailed { revision, recipe_id } => format!("Failed to get revision {revision} of recipe with id '{recipe_id}'"),
+            Self::SaveRevisionFailed { recipe_id } => format!("Failed to save revision of recipe with id '{recipe_id}'"),
+            Self::RecipeNotFound { recipe_id } => format!("Recipe with id '{recipe_id}' not found"),
+            Self::RestoreRevisionFailed { revision, recipe_id } => format!("Failed to restore revision {revision} of recipe with id '{recipe_id}'"),
+            Self::EstimateRecipeCostsFailed => "Failed to estimate recipe costs".into(),
+            Self::ForkRecipeFailed { recipe_id } => format!("Failed to fork recipe with id '{recipe_id}'"),
+            Self::GetForksFailed { recipe_id } => format!("Failed to get forks of recipe with id '{recipe_id}'"),
+            Self::EstimateRecipeCostFailed => "Failed to estimate recipe cost".into(),
+            Self::GetSuggestedSubstitutionsFailed => "Failed to get suggested substitutions".into(),
+            Self::CreateRecipeFailed => "Failed to create new recipe".into(),
+            Self::ImportRecipeFailed { err } => format!("Failed to import recipe: {err}"),
+            Self::SaveImportedRecipeFailed => "Failed to save imported recipe".into(),
+            Self::ModifyRecipeForbidden { recipe_id } => format!("Not allowed to modify recipe with id '{recipe_id}'"),
+            Self::RecipeRemovedByModerator { recipe_id } => format!("Recipe with id '{recipe_id}' was removed by a moderator"),
+            Self::RemoveThroughReports => "Recipes are removed through moderation reports".into(),
+            Self::PublishThroughEndpoint => "Recipes are published with POST /recipes/{id}/publish".into(),
+            Self::PatchRecipeFailed { recipe_id } => format!("Failed to patch recipe with id '{recipe_id}'"),
+            Self::FindRecipeFailed { recipe_id } => format!("Failed to find recipe with id '{recipe_id}'"),
+            Self::UpdatePublicationFailed { recipe_id } => format!("Failed to update publication of recipe with id '{recipe_id}'"),
+            Self::DeleteRecipeForbidden { recipe_id } => format!("Not allowed to delete recipe with id '{recipe_id}'"),
+            Self::DeleteRecipeFailed { recipe_id } => format!("Failed to delete recipe with id '{recipe_id}'"),
+            Self::ModerateReportsForbidden => "Only admins can moderate reports".into(),
+            Self::GetReportedTargetFailed { target_type, target_id } => format!("Failed to get reported {target_type} with id '{target_id}'"),
+            Self::CreateReportFailed => "Failed to create new report".into(),
+            Self::GetReportsFailed => "Failed to get reports".into(),
+            Self::GetReportFailed { report_id } => format!("Failed to get report with id '{report_id}'"),
+            Self::ReportAlreadyResolved { report_id } => format!("Report with id '{report_id}' has already been resolved"),
+            Self::DeactivateReportedUsers => "Reported users are moderated by deactivating them".into(),
+            Self::ApplyModerationFailed { action, report_id } => format!("Failed to apply {action} for report with id '{report_id}'"),
+            Self::ResolveReportFailed { report_id } => format!("Failed to resolve report with id '{report_id}'"),
+            Self::GetModerationLogFailed => "Failed to get moderation log".into(),
+            Self::GetReviewsFailed { recipe_id } => format!("Failed to get reviews for recipe with id '{recipe_id}'"),
+            Self::ReviewOwnRecipe => "Authors cannot review their own recipe".into(),
+            Self::RecipeAlreadyReviewed { recipe_id } => format!("Recipe with id '{recipe_id}' has already been reviewed"),
+            Self::CheckExistingReviewFailed => "Failed to check for existing review".into(),
+            Self::CreateReviewFailed => "Failed to create new review".into(),
+            Self::ReviewNotFound { recipe_id } => format!("No review found for recipe with id '{recipe_id}'"),
+            Self::PatchReviewFailed { recipe_id } => format!("Failed to patch review for recipe with id '{recipe_id}'"),
+            Self::DeleteReviewFailed { recipe_id } => format!("Failed to delete review for recipe with id '{recipe_id}'"),
+            Self::UnknownSeasonRegion { region, regions } => format!("Unknown region '{region}', expected one of {regions}"),
+            Self::InvalidMonth => "Month must be between 1 and 12".into(),
+            Self::GetShoppingListsFailed => "Failed to get shopping lists".into(),
+            Self::ShoppingListSourceRequired => "Either recipe ids or a meal plan week are required".into(),
+            Self::GetShoppingListRecipesFailed => "Failed to get recipes for shopping list".into(),
+            Self::CreateShoppingListFailed => "Failed to create shopping list".into(),
+            Self::DefaultShoppingListName => "Shopping list".into(),
+            Self::WeekShoppingListName { week } => format!("Week {week}"),
+            Self::ShoppingListNotFound { list_id } => format!("Failed to find shopping list with id '{list_id}'"),
+            Self::GetShoppingListFailed { list_id } => format!("Failed to get shopping list with id '{list_id}'"),
+            Self::ShoppingListItemNotFound { item_id } => format!("Failed to find item with id '{item_id}' in shopping list"),
+            Self::PatchShoppingListItemFailed { item_id } => format!("Failed to patch shopping list item with id '{item_id}'"),
+            Self::DeleteShoppingListFailed { list_id } => format!("Failed to delete shopping list with id '{list_id}'"),
+            Self::CurateSubstitutionsForbidden => "Only admins can curate substitutions".into(),
+            Self::GetSubstitutionsFailed => "Failed to get substitutions".into(),
+            Self::CreateSubstitutionFailed => "Failed to create substitution".into(),
+            Self::RatioNotPositive => "Ratio must be positive".into(),
+            Self::SubstitutionNotFound { id } => format!("Failed to find substitution with id '{id}'"),
+            Self::PatchSubstitutionFailed { id } => format!("Failed to patch substitution with id '{id}'"),
+            Self::DeleteSubstitutionFailed { id } => format!("Failed to delete substitution with id '{id}'"),
+            Self::GetUsersFailed => "Failed to get multiple users".into(),
+            Self::GetUserByEmailFailed { email } => format!("Failed to get user with email '{email}'"),
+            Self::DeleteUserFailed { user_id } => format!("Failed to delete user with id '{user_id}'"),
+            Self::ActivateUserFailed { user_id } => format!("Failed to activate user with id '{user_id}'"),
+            Self::DeactivateUserFailed { user_id } => format!("Failed to deactivate user with id '{user_id}'"),
+            Self::InvalidFeedCursor { cursor } => format!("Invalid feed cursor '{cursor}'"),
+            Self::InvalidLocale { locale } => format!("Invalid locale '{locale}'"),
+            Self::TranslateOriginalLocale { recipe_id, locale } => format!("Recipe with id '{recipe_id}' is already written in '{locale}'"),
+            Self::TranslationNotFound { locale, recipe_id } => format!("Failed to find translation '{locale}' of recipe with id '{recipe_id}'"),
+            Self::SaveTranslationFailed { locale, recipe_id } => format!("Failed to save translation '{locale}' of recipe with id '{recipe_id}'"),
+            Self::DeleteTranslationFailed { locale, recipe_id } => format!("Failed to delete translation '{locale}' of recipe with id '{recipe_id}'"),
+            Self::InternalError => "Something went wrong, please try again later".into(),
+            Self::InvalidId { id } => format!("Invalid id '{id}'"),
+            Self::DocumentNotFound { collection, id } => format!("Failed to find document in {collection} collection with id '{id}'"),
+            Self::DuplicateDocument => "Document already exists".into(),
+            Self::EmailTaken { email } => format!("User with email '{email}' already exists"),
+            Self::InvalidFields => "Some fields of the request are invalid".into(),
+            Self::FieldEmpty => "Must not be empty".into(),
+            Self::FieldTooShort { min_length } => format!("Must be at least {min_length} characters long"),
+            Self::FieldTooLong { max_length } => format!("Must not be longer than {max_length} characters"),
+            Self::TooFewItems { min_count } => format!("Must contain at least {min_count} entries"),
+            Self::TooManyItems { max_count } => format!("Must not contain more than {max_count} entries"),
+            Self::OutOfRange { min, max } => format!("Must be between {min} and {max}"),
+            Self::InvalidFormat => "Has an invalid format".into(),
+            Self::InvalidEmail => "Must be a valid email address".into(),
+            Self::InvalidFieldValue { err } => format!("Invalid value: {err}"),
+            Self::InvalidJsonBody { err } => format!("Failed to parse the JSON body: {err}"),
+            Self::JsonContentTypeRequired => "Expected a request with the content type application/json".into(),
+            Self::ReadBodyFailed => "Failed to read the request body".into(),
+            Self::InvalidPathParams { err } => format!("Invalid path parameters: {err}"),
+            Self::InvalidQueryParams { err } => format!("Invalid query parameters: {err}"),
+            Self::ForkOwnRecipe => "Recipes can not be forked by their author".into(),
+            Self::ForkUnpublishedRecipe => "Only published and unlisted recipes can be forked".into(),
+            Self::ReviewCreatedNotification => "Someone reviewed your recipe".into(),
+            Self::NewFollowerNotification => "You have a new follower".into(),
+            Self::RecipeForkedNotification => "Someone forked your recipe".into(),
+            Self::ReportResolvedNotification => "Your report was resolved".into(),
+            Self::DigestSubjectSingle => "You have 1 new notification".into(),
+            Self::DigestSubject { count } => format!("You have {count} new notifications"),
+        }
+    }
+
+    fn german(&self) -> String {
+        match self {
+            Self::AuthenticationFailed => "Anfrage konnte nicht authentifiziert werden".into(),
+            Self::InvalidUserData => "Die angegebenen Benutzerdaten konnten nicht verarbeitet werden".into(),
+            Self::UserWithEmailNotFound { email } => format!("Kein Benutzer mit der angegebenen E-Mail-Adresse gefunden: {email}"),
+            Self::IncorrectPassword { email } => format!("Falsches Passwort: {email}"),
+            Self::UserInactive { email } => format!("Benutzer ist nicht aktiviert: {email}"),
+            Self::GenerateTokenFailed => "JWT-Token konnte nicht erstellt werden".into(),
+            Self::GetCommentsFailed { recipe_id } => format!("Kommentare zum Rezept mit der ID '{recipe_id}' konnten nicht geladen werden"),
+            Self::GetRecipeFailed { recipe_id } => format!("Rezept mit der ID '{recipe_id}' konnte nicht geladen werden"),
+            Self::ParentCommentNotFound { parent_id } => format!("Übergeordneter Kommentar mit der ID '{parent_id}' wurde bei diesem Rezept nicht gefunden"),
+            Self::CreateCommentFailed => "Kommentar konnte nicht erstellt werden".into(),
+            Self::CommentNotFound { comment_id } => format!("Kommentar mit der ID '{comment_id}' wurde nicht gefunden"),
+            Self::EditCommentForbidden { comment_id } => format!("Keine Berechtigung, den Kommentar mit der ID '{comment_id}' zu bearbeiten"),
+            Self::PatchCommentFailed { comment_id } => format!("Kommentar mit der ID '{comment_id}' konnte nicht geändert werden"),
+            Self::DeleteCommentForbidden { comment_id } => format!("Keine Berechtigung, den Kommentar mit der ID '{comment_id}' zu löschen"),
+            Self::DeleteCommentFailed { comment_id } => format!("Kommentar mit der ID '{comment_id}' konnte nicht gelöscht werden"),
+            Self::GetFavoritesFailed => "Lieblingsrezepte konnten nicht geladen werden".into(),
+            Self::AddFavoriteFailed { recipe_id } => format!("Rezept mit der ID '{recipe_id}' konnte nicht zu den Favoriten hinzugefügt werden"),
+            Self::RemoveFavoriteFailed { recipe_id } => format!("Rezept mit der ID '{recipe_id}' konnte nicht aus den Favoriten entfernt werden"),
+            Self::GetCollectionsFailed => "Rezeptsammlungen konnten nicht geladen werden".into(),
+            Self::CreateCollectionFailed => "Rezeptsammlung konnte nicht erstellt werden".into(),
+            Self::CollectionNotFound { collection_id } => format!("Rezeptsammlung mit der ID '{collection_id}' wurde nicht gefunden"),
+            Self::PatchCollectionFailed { collection_id } => format!("Rezeptsammlung mit der ID '{collection_id}' konnte nicht geändert werden"),
+            Self::DeleteCollectionFailed { collection_id } => format!("Rezeptsammlung mit der ID '{collection_id}' konnte nicht gelöscht werden"),
+            Self::AddToCollectionFailed { recipe_id, collection_id } => format!("Rezept mit der ID '{recipe_id}' konnte nicht zur Sammlung mit der ID '{collection_id}' hinzugefügt werden"),
+            Self::RemoveFromCollectionFailed { recipe_id, collection_id } => format!("Rezept mit der ID '{recipe_id}' konnte nicht aus der Sammlung mit der ID '{collection_id}' entfernt werden"),
+            Self::SharedCollectionNotFound => "Geteilte Rezeptsammlung wurde nicht gefunden".into(),
+            Self::GetSharedCollectionFailed => "Geteilte Rezeptsammlung konnte nicht geladen werden".into(),
+            Self::GetProfileFailed { user_id } => format!("Profil des Benutzers mit der ID '{user_id}' konnte nicht geladen werden"),
+            Self::FollowSelf => "Benutzer können sich nicht selbst folgen".into(),
+            Self::GetUserFailed { user_id } => format!("Benutzer mit der ID '{user_id}' konnte nicht geladen werden"),
+            Self::FollowUserFailed { user_id } => format!("Dem Benutzer mit der ID '{user_id}' konnte nicht gefolgt werden"),
+            Self::UnfollowUserFailed { user_id } => format!("Dem Benutzer mit der ID '{user_id}' konnte nicht entfolgt werden"),
+            Self::GetFeedFailed => "Feed konnte nicht geladen werden".into(),
+            Self::GetHouseholdFailed { household_id } => format!("Haushalt mit der ID '{household_id}' konnte nicht geladen werden"),
+            Self::ManageHouseholdForbidden => "Nur Eigentümer können den Haushalt verwalten".into(),
+            Self::AlreadyInHousehold => "Benutzer können nur Mitglied eines Haushalts sein".into(),
+            Self::GetHouseholdOfUserFailed => "Haushalt des Benutzers konnte nicht geladen werden".into(),
+            Self::CreateHouseholdFailed => "Haushalt konnte nicht erstellt werden".into(),
+            Self::NoHousehold => "Benutzer ist kein Mitglied eines Haushalts".into(),
+            Self::InviteCodeNotFound => "Kein Haushalt mit diesem Einladungscode gefunden".into(),
+            Self::GetHouseholdByInviteCodeFailed => "Haushalt konnte nicht über den Einladungscode geladen werden".into(),
+            Self::JoinHouseholdFailed { household_id } => format!("Beitritt zum Haushalt mit der ID '{household_id}' fehlgeschlagen"),
+            Self::PatchHouseholdFailed { household_id } => format!("Haushalt mit der ID '{household_id}' konnte nicht geändert werden"),
+            Self::DeleteHouseholdFailed { household_id } => format!("Haushalt mit der ID '{household_id}' konnte nicht gelöscht werden"),
+            Self::RegenerateInviteCodeFailed { household_id } => format!("Einladungscode des Haushalts mit der ID '{household_id}' konnte nicht neu erstellt werden"),
+            Self::HouseholdOwnerRequired => "Haushalte brauchen mindestens einen Eigentümer".into(),
+            Self::HouseholdMemberNotFound { user_id } => format!("Mitglied mit der ID '{user_id}' wurde im Haushalt nicht gefunden"),
+            Self::PatchHouseholdMemberFailed { user_id } => format!("Mitglied mit der ID '{user_id}' konnte nicht geändert werden"),
+            Self::HandOverOwnershipFirst => "Haushalte brauchen mindestens einen Eigentümer, bitte zuerst die Eigentümerschaft übergeben".into(),
+            Self::RemoveHouseholdMemberFailed { user_id } => format!("Mitglied mit der ID '{user_id}' konnte nicht aus dem Haushalt entfernt werden"),
+            Self::MaintainPricesForbidden => "Nur Administratoren können Zutatenpreise pflegen".into(),
+            Self::GetIngredientPricesFailed => "Zutatenpreise konnten nicht geladen werden".into(),
+            Self::CreateIngredientPriceFailed => "Zutatenpreis konnte nicht erstellt werden".into(),
+            Self::NegativePrice => "Der Preis darf nicht negativ sein".into(),
+            Self::IngredientPriceNotFound { id } => format!("Zutatenpreis mit der ID '{id}' wurde nicht gefunden"),
+            Self::PatchIngredientPriceFailed { id } => format!("Zutatenpreis mit der ID '{id}' konnte nicht geändert werden"),
+            Self::DeleteIngredientPriceFailed { id } => format!("Zutatenpreis mit der ID '{id}' konnte nicht gelöscht werden"),
+            Self::GetMealPlanFailed { week } => format!("Essensplan für die Woche '{week}' konnte nicht geladen werden"),
+            Self::AddMealPlanEntryFailed { week } => format!("Eintrag konnte nicht zum Essensplan für die Woche '{week}' hinzugefügt werden"),
+            Self::MealPlanEntryNotFound { entry_id } => format!("Essensplan-Eintrag mit der ID '{entry_id}' wurde nicht gefunden"),
+            Self::PatchMealPlanEntryFailed { entry_id } => format!("Essensplan-Eintrag mit der ID '{entry_id}' konnte nicht geändert werden"),
+            Self::DeleteMealPlanEntryFailed { entry_id } => format!("Essensplan-Eintrag mit der ID '{entry_id}' konnte nicht gelöscht werden"),
+            Self::CopyMealPlanFailed { from_week, week } => format!("Essensplan konnte nicht von der Woche '{from_week}' nach '{week}' kopiert werden"),
+            Self::InvalidWeekFormat { week } => format!("Die Woche '{week}' hat nicht das Format YYYY-Www"),
+            Self::WeekDoesNotExist { week } => format!("Die Woche '{week}' existiert nicht"),
+            Self::GetNotificationsFailed => "Benachrichtigungen konnten nicht geladen werden".into(),
+            Self::NotificationNotFound { notification_id } => format!("Benachrichtigung mit der ID '{notification_id}' wurde nicht gefunden"),
+            Self::MarkNotificationReadFailed { notification_id } => format!("Benachrichtigung mit der ID '{notification_id}' konnte nicht als gelesen markiert werden"),
+            Self::MarkNotificationsReadFailed => "Benachrichtigungen konnten nicht als gelesen markiert werden".into(),
+            Self::GetPantryItemsFailed => "Vorräte konnten nicht geladen werden".into(),
+            Self::InvalidExpiryDate { err } => format!("Das Ablaufdatum muss das Format YYYY-MM-DD haben: {err}"),
+            Self::AddPantryItemFailed => "Vorrat konnte nicht hinzugefügt werden".into(),
+            Self::PantryItemNotFound { item_id } => format!("Vorrat mit der ID '{item_id}' wurde nicht gefunden"),
+            Self::PatchPantryItemFailed { item_id } => format!("Vorrat mit der ID '{item_id}' konnte nicht geändert werden"),
+            Self::DeletePantryItemFailed { item_id } => format!("Vorrat mit der ID '{item_id}' konnte nicht gelöscht werden"),
+            Self::GetRecipesFailed => "Rezepte konnten nicht geladen werden".into(),
+            Self::UserNotFound => "Benutzer wurde nicht gefunden".into(),
+            Self::GetPreferencesFailed => "Einstellungen konnten nicht geladen werden".into(),
+            Self::PatchPreferencesFailed => "Einstellungen konnten nicht geändert werden".into(),
+            Self::AccessRevisionsForbidden { recipe_id } => format!("Keine Berechtigung für die Versionen des Rezepts mit der ID '{recipe_id}'"),
+            Self::GetRevisionsFailed { recipe_id } => format!("Versionen des Rezepts mit der ID '{recipe_id}' konnten nicht geladen werden"),
+            Self::RevisionNotFound { revision, recipe_id } => format!("Version {revision} des Rezepts mit der ID '{recipe_id}' wurde nicht gefunden"),
+            Self::GetRevisionFailed { revision, recipe_id } => format!("Version {revision} des Rezepts mit der ID '{recipe_id}' konnte nicht geladen werden"),
+            Self::SaveRevisionFailed { recipe_id } => format!("Version des Rezepts mit der ID '{recipe_id}' konnte nicht gespeichert werden"),
+            Self::RecipeNotFound { recipe_id } => format!("Rezept mit der ID '{recipe_id}' wurde nicht gefunden"),
+            Self::RestoreRevisionFailed { revision, recipe_id } => format!("Version {revision} des Rezepts mit der ID '{recipe_id}' konnte nicht wiederhergestellt werden"),
+            Self::EstimateRecipeCostsFailed => "Rezeptkosten konnten nicht geschätzt werden".into(),
+            Self::ForkRecipeFailed { recipe_id } => format!("Rezept mit der ID '{recipe_id}' konnte nicht abgewandelt werden"),
+            Self::GetForksFailed { recipe_id } => format!("Abwandlungen des Rezepts mit der ID '{recipe_id}' konnten nicht geladen werden"),
+            Self::EstimateRecipeCostFailed => "Rezeptkosten konnten nicht geschätzt werden".into(),
+            Self::GetSuggestedSubstitutionsFailed => "Vorgeschlagene Ersatzzutaten konnten nicht geladen werden".into(),
+            Self::CreateRecipeFailed => "Rezept konnte nicht erstellt werden".into(),
+            Self::ImportRecipeFailed { err } => format!("Rezept konnte nicht importiert werden: {err}"),
+            Self::SaveImportedRecipeFailed => "Importiertes Rezept konnte nicht gespeichert werden".into(),
+            Self::ModifyRecipeForbidden { recipe_id } => format!("Keine Berechtigung, das Rezept mit der ID '{recipe_id}' zu ändern"),
+            Self::RecipeRemovedByModerator { recipe_id } => format!("Rezept mit der ID '{recipe_id}' wurde von der Moderation entfernt"),
+            Self::RemoveThroughReports => "Rezepte werden über Moderationsmeldungen entfernt".into(),
+            Self::PublishThroughEndpoint => "Rezepte werden mit POST /recipes/{id}/publish veröffentlicht".into(),
+            Self::PatchRecipeFailed { recipe_id } => format!("Rezept mit der ID '{recipe_id}' konnte nicht geändert werden"),
+            Self::FindRecipeFailed { recipe_id } => format!("Rezept mit der ID '{recipe_id}' wurde nicht gefunden"),
+            Self::UpdatePublicationFailed { recipe_id } => format!("Veröffentlichung des Rezepts mit der ID '{recipe_id}' konnte nicht geändert werden"),
+            Self::DeleteRecipeForbidden { recipe_id } => format!("Keine Berechtigung, das Rezept mit der ID '{recipe_id}' zu löschen"),
+            Self::DeleteRecipeFailed { recipe_id } => format!("Rezept mit der ID '{recipe_id}' konnte nicht gelöscht werden"),
+            Self::ModerateReportsForbidden => "Nur Administratoren können Meldungen moderieren".into(),
+            Self::GetReportedTargetFailed { target_type, target_id } => format!("Gemeldetes Objekt {target_type} mit der ID '{target_id}' konnte nicht geladen werden"),
+            Self::CreateReportFailed => "Meldung konnte nicht erstellt werden".into(),
+            Self::GetReportsFailed => "Meldungen konnten nicht geladen werden".into(),
+            Self::GetReportFailed { report_id } => format!("Meldung mit der ID '{report_id}' konnte nicht geladen werden"),
+            Self::ReportAlreadyResolved { report_id } => format!("Meldung mit der ID '{report_id}' wurde bereits bearbeitet"),
+            Self::DeactivateReportedUsers => "Gemeldete Benutzer werden durch Deaktivieren moderiert".into(),
+            Self::ApplyModerationFailed { action, report_id } => format!("{action} für die Meldung mit der ID '{report_id}' konnte nicht angewendet werden"),
+            Self::ResolveReportFailed { report_id } => format!("Meldung mit der ID '{report_id}' konnte nicht abgeschlossen werden"),
+            Self::GetModerationLogFailed => "Moderationsprotokoll konnte nicht geladen werden".into(),
+            Self::GetReviewsFailed { recipe_id } => format!("Bewertungen zum Rezept mit der ID '{recipe_id}' konnten nicht geladen werden"),
+            Self::ReviewOwnRecipe => "Autoren können ihr eigenes Rezept nicht bewerten".into(),
+            Self::RecipeAlreadyReviewed { recipe_id } => format!("Das Rezept mit der ID '{recipe_id}' wurde bereits bewertet"),
+            Self::CheckExistingReviewFailed => "Vorhandene Bewertung konnte nicht geprüft werden".into(),
+            Self::CreateReviewFailed => "Bewertung konnte nicht erstellt werden".into(),
+            Self::ReviewNotFound { recipe_id } => format!("Keine Bewertung zum Rezept mit der ID '{recipe_id}' gefunden"),
+            Self::PatchReviewFailed { recipe_id } => format!("Bewertung zum Rezept mit der ID '{recipe_id}' konnte nicht geändert werden"),
+            Self::DeleteReviewFailed { recipe_id } => format!("Bewertung zum Rezept mit der ID '{recipe_id}' konnte nicht gelöscht werden"),
+            Self::UnknownSeasonRegion { region, regions } => format!("Unbekannte Region '{region}', erwartet wird eine von {regions}"),
+            Self::InvalidMonth => "Der Monat muss zwischen 1 und 12 liegen".into(),
+            Self::GetShoppingListsFailed => "Einkaufslisten konnten nicht geladen werden".into(),
+            Self::ShoppingListSourceRequired => "Es werden entweder Rezept-IDs oder eine Essensplan-Woche benötigt".into(),
+            Self::GetShoppingListRecipesFailed => "Rezepte für die Einkaufsliste konnten nicht geladen werden".into(),
+            Self::CreateShoppingListFailed => "Einkaufsliste konnte nicht erstellt werden".into(),
+            Self::DefaultShoppingListName => "Einkaufsliste".into(),
+            Self::WeekShoppingListName { week } => format!("Woche {week}"),
+            Self::ShoppingListNotFound { list_id } => format!("Einkaufsliste mit der ID '{list_id}' wurde nicht gefunden"),
+            Self::GetShoppingListFailed { list_id } => format!("Einkaufsliste mit der ID '{list_id}' konnte nicht geladen werden"),
+            Self::ShoppingListItemNotFound { item_id } => format!("Eintrag mit der ID '{item_id}' wurde in der Einkaufsliste nicht gefunden"),
+            Self::PatchShoppingListItemFailed { item_id } => format!("Eintrag der Einkaufsliste mit der ID '{item_id}' konnte nicht geändert werden"),
+            Self::DeleteShoppingListFailed { list_id } => format!("Einkaufsliste mit der ID '{list_id}' konnte nicht gelöscht werden"),
+            Self::CurateSubstitutionsForbidden => "Nur Administratoren können Ersatzzutaten pflegen".into(),
+            Self::GetSubstitutionsFailed => "Ersatzzutaten konnten nicht geladen werden".into(),
+            Self::CreateSubstitutionFailed => "Ersatzzutat konnte nicht erstellt werden".into(),
+            Self::RatioNotPositive => "Das Verhältnis muss positiv sein".into(),
+            Self::SubstitutionNotFound { id } => format!("Ersatzzutat mit der ID '{id}' wurde nicht gefunden"),
+            Self::PatchSubstitutionFailed { id } => format!("Ersatzzutat mit der ID '{id}' konnte nicht geändert werden"),
+            Self::DeleteSubstitutionFailed { id } => format!("Ersatzzutat mit der ID '{id}' konnte nicht gelöscht werden"),
+            Self::GetUsersFailed => "Benutzer konnten nicht geladen werden".into(),
+            Self::GetUserByEmailFailed { email } => format!("Benutzer mit der E-Mail-Adresse '{email}' konnte nicht geladen werden"),
+            Self::DeleteUserFailed { user_id } => format!("Benutzer mit der ID '{user_id}' konnte nicht gelöscht werden"),
+            Self::ActivateUserFailed { user_id } => format!("Benutzer mit der ID '{user_id}' konnte nicht aktiviert werden"),
+            Self::DeactivateUserFailed { user_id } => format!("Benutzer mit der ID '{user_id}' konnte nicht deaktiviert werden"),
+            Self::InvalidFeedCursor { cursor } => format!("Ungültiger Feed-Cursor '{cursor}'"),
+            Self::InvalidLocale { locale } => format!("Ungültige Sprache '{locale}'"),
+            Self::TranslateOriginalLocale { recipe_id, locale } => format!("Das Rezept mit der ID '{recipe_id}' ist bereits in '{locale}' verfasst"),
+            Self::TranslationNotFound { locale, recipe_id } => format!("Übersetzung '{locale}' des Rezepts mit der ID '{recipe_id}' wurde nicht gefunden"),
+            Self::SaveTranslationFailed { locale, recipe_id } => format!("Übersetzung '{locale}' des Rezepts mit der ID '{recipe_id}' konnte nicht gespeichert werden"),
+            Self::DeleteTranslationFailed { locale, recipe_id } => format!("Übersetzung '{locale}' des Rezepts mit der ID '{recipe_id}' konnte nicht gelöscht werden"),
+            Self::InternalError => "Etwas ist schiefgelaufen, bitte später erneut versuchen".into(),
+            Self::InvalidId { id } => format!("Ungültige ID '{id}'"),
+            Self::DocumentNotFound { id, collection } => format!("Dokument mit der ID '{id}' wurde in der Sammlung {collection} nicht gefunden"),
+            Self::DuplicateDocument => "Das Dokument existiert bereits".into(),
+            Self::EmailTaken { email } => format!("Ein Benutzer mit der E-Mail-Adresse '{email}' existiert bereits"),
+            Self::InvalidFields => "Einige Felder der Anfrage sind ungültig".into(),
+            Self::FieldEmpty => "Darf nicht leer sein".into(),
+            Self::FieldTooShort { min_length } => format!("Muss mindestens {min_length} Zeichen lang sein"),
+            Self::FieldTooLong { max_length } => format!("Darf höchstens {max_length} Zeichen lang sein"),
+            Self::TooFewItems { min_count } => format!("Muss mindestens {min_count} Einträge enthalten"),
+            Self::TooManyItems { max_count } => format!("Darf höchstens {max_count} Einträge enthalten"),
+            Self::OutOfRange { min, max } => format!("Muss zwischen {min} und {max} liegen"),
+            Self::InvalidFormat => "Hat ein ungültiges Format".into(),
+            Self::InvalidEmail => "Muss eine gültige E-Mail-Adresse sein".into(),
+            Self::InvalidFieldValue { err } => format!("Ungültiger Wert: {err}"),
+            Self::InvalidJsonBody { err } => format!("Der JSON-Inhalt konnte nicht gelesen werden: {err}"),
+            Self::JsonContentTypeRequired => "Erwartet wurde eine Anfrage mit dem Inhaltstyp application/json".into(),
+            Self::ReadBodyFailed => "Der Inhalt der Anfrage konnte nicht gelesen werden".into(),
+            Self::InvalidPathParams { err } => format!("Ungültige Pfadparameter: {err}"),
+            Self::InvalidQueryParams { err } => format!("Ungültige Abfrageparameter: {err}"),
+            Self::ForkOwnRecipe => "Rezepte können nicht von ihrem Autor abgewandelt werden".into(),
+            Self::ForkUnpublishedRecipe => "Nur veröffentlichte und nicht gelistete Rezepte können abgewandelt werden".into(),
+            Self::ReviewCreatedNotification => "Jemand hat Ihr Rezept bewertet".into(),
+            Self::NewFollowerNotification => "Sie haben einen neuen Follower".into(),
+            Self::RecipeForkedNotification => "Jemand hat Ihr Rezept abgewandelt".into(),
+            Self::ReportResolvedNotification => "Ihre Meldung wurde bearbeitet".into(),
+            Self::DigestSubjectSingle => "Sie haben 1 neue Benachrichtigung".into(),
+            Self::DigestSubject { count } => format!("Sie haben {count} neue Benachrichtigungen"),
+        }
+    }
+}
+
+impl Display for Message<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.localize(Locale::current()))
+    }
+}
+
+#[cfg(test)]
+mod unit_tests_messages {
+    use axum::http::{header::ACCEPT_LANGUAGE, HeaderValue};
+
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn negotiates_locale_from_headers() {
+        struct TestCase {
+            title: String,
+            accept_language: Option<String>,
+            expected_locale: Locale,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Defaults to English without header".into(),
+                accept_language: None,
+                expected_locale: Locale::English,
+            },
+            TestCase {
+                title: "Falls back from regional German".into(),
+                accept_language: Some("de-AT,de;q=0.9,en;q=0.8".into()),
+                expected_locale: Locale::German,
+            },
+            TestCase {
+                title: "Respects quality values".into(),
+                accept_language: Some("de;q=0.5,en".into()),
+                expected_locale: Locale::English,
+            },
+            TestCase {
+                title: "Defaults to English for other languages".into(),
+                accept_language: Some("fr-FR,fr".into()),
+                expected_locale: Locale::English,
+            },
+        ];
+
+        for t in test_cases {
+            let mut headers = HeaderMap::new();
+            if let Some(accept_language) = &t.accept_language {
+                headers.insert(
+                    ACCEPT_LANGUAGE,
+                    HeaderValue::from_str(accept_language).expect("Valid header value"),
+                );
+            }
+
+            assert_eq!(
+                Locale::from_headers(&headers),
+                t.expected_locale,
+                "{}",
+                t.title
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn displays_messages_in_request_locale() {
+        let recipe_id = "42";
+        let message = Message::GetRecipeFailed {
+            recipe_id: &recipe_id,
+        };
+
+        assert_eq!(
+            message.to_string(),
+            "Failed to get recipe with id '42'",
+            "Uses English outside of requests"
+        );
+        assert_eq!(
+            LOCALE
+                .scope(Locale::German, async { message.to_string() })
+                .await,
+            "Rezept mit der ID '42' konnte nicht geladen werden"
+        );
+    }
+}
@@ -1,4 +1,8 @@
 pub mod api_response;
+pub mod app_error;
+pub mod messages;
+pub mod request_context;
 pub mod routers;
 pub mod server;
 pub mod services;
+pub mod validation;
@@ -0,0 +1,251 @@
+use axum::{
+    body::{to_bytes, Body},
+    extract::Request,
+    http::{
+        header::{ACCEPT, CONTENT_TYPE},
+        HeaderMap, HeaderName, HeaderValue, StatusCode,
+    },
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use rand::{distributions::Alphanumeric, Rng};
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use super::api_response::{ApiError, ApiResponse, ErrorCode, FieldError};
+
+pub const REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+pub const PROBLEM_JSON_CONTENT_TYPE: &str = "application/problem+json";
+
+const MAX_REQUEST_ID_LENGTH: usize = 64;
+// Error bodies are small, anything bigger is passed on as it is
+const MAX_ERROR_BODY_SIZE: usize = 64 * 1024;
+
+tokio::task_local! {
+    static REQUEST_ID: String;
+}
+
+/// RFC 7807 representation of an ApiError for clients accepting application/problem+json
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ProblemDetails {
+    #[serde(rename = "type")]
+    pub problem_type: String,
+    pub title: String,
+    pub status: u16,
+    pub detail: String,
+    pub code: ErrorCode,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub details: Vec<FieldError>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+}
+
+impl ProblemDetails {
+    pub fn new(status_code: StatusCode, api_error: ApiError) -> Self {
+        Self {
+            // The code tells problems apart, so there are no problem type URIs
+            problem_type: "about:blank".into(),
+            title: status_code
+                .canonical_reason()
+                .unwrap_or_default()
+                .to_string(),
+            status: status_code.as_u16(),
+            detail: api_error.error,
+            code: api_error.code,
+            details: api_error.details,
+            request_id: api_error.request_id,
+        }
+    }
+}
+
+/// Id of the request being handled, None outside of requests
+pub fn get_current_request_id() -> Option<String> {
+    REQUEST_ID.try_with(Clone::clone).ok()
+}
+
+/// Keeps the id of a proxy in front of the API, so logs of both can be matched
+pub fn get_request_id(headers: &HeaderMap) -> String {
+    headers
+        .get(REQUEST_ID_HEADER)
+        .and_then(|request_id| request_id.to_str().ok())
+        .map(str::trim)
+        .filter(|request_id| {
+            !request_id.is_empty()
+                && request_id.len() <= MAX_REQUEST_ID_LENGTH
+                && request_id
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        })
+        .map(String::from)
+        .unwrap_or_else(|| {
+            rand::thread_rng()
+                .sample_iter(&Alphanumeric)
+                .take(16)
+                .map(char::from)
+                .collect()
+        })
+}
+
+pub fn accepts_problem_json(headers: &HeaderMap) -> bool {
+    headers
+        .get_all(ACCEPT)
+        .iter()
+        .filter_map(|accept| accept.to_str().ok())
+        .flat_map(|accept| accept.split(','))
+        .any(|media_range| {
+            media_range
+                .split(';')
+                .next()
+                .is_some_and(|media_type| media_type.trim() == PROBLEM_JSON_CONTENT_TYPE)
+        })
+}
+
+/// Handles the request with an id, which error responses and the X-Request-Id header carry
+pub async fn assign_request_id(request: Request, next: Next) -> Response {
+    let request_id = get_request_id(request.headers());
+
+    let mut response = REQUEST_ID
+        .scope(request_id.clone(), next.run(request))
+        .await;
+
+    if let Ok(header_value) = HeaderValue::from_str(&request_id) {
+        response
+            .headers_mut()
+            .insert(REQUEST_ID_HEADER, header_value);
+    }
+
+    response
+}
+
+/// Answers with problem details instead of an ApiResponse if the client accepts them
+pub async fn negotiate_problem_details(request: Request, next: Next) -> Response {
+    let is_problem_json_accepted = accepts_problem_json(request.headers());
+
+    let response = next.run(request).await;
+
+    let is_json_error = response.status().is_client_error() || response.status().is_server_error();
+    let is_json = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .is_some_and(|content_type| content_type == "application/json");
+
+    if !is_problem_json_accepted || !is_json_error || !is_json {
+        return response;
+    }
+
+    let (parts, body) = response.into_parts();
+    let bytes = match to_bytes(body, MAX_ERROR_BODY_SIZE).await {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            info!("Failed to read error response body: {err}");
+            return (parts.status, parts.headers).into_response();
+        }
+    };
+
+    match serde_json::from_slice::<ApiResponse<serde_json::Value>>(&bytes) {
+        Ok(ApiResponse {
+            error: Some(api_error),
+            ..
+        }) => {
+            let mut response = Json(ProblemDetails::new(parts.status, api_error)).into_response();
+            *response.status_mut() = parts.status;
+            response.headers_mut().insert(
+                CONTENT_TYPE,
+                HeaderValue::from_static(PROBLEM_JSON_CONTENT_TYPE),
+            );
+
+            response
+        }
+        _ => Response::from_parts(parts, Body::from(bytes)),
+    }
+}
+
+#[cfg(test)]
+mod unit_tests_request_context {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn gets_request_id() {
+        let mut headers = HeaderMap::new();
+        headers.insert(REQUEST_ID_HEADER, HeaderValue::from_static("proxy-42"));
+        assert_eq!(get_request_id(&headers), "proxy-42", "Keeps valid ids");
+
+        headers.insert(REQUEST_ID_HEADER, HeaderValue::from_static("<script>"));
+        let request_id = get_request_id(&headers);
+        assert_eq!(request_id.len(), 16, "Replaces invalid ids");
+        assert!(request_id.chars().all(|c| c.is_ascii_alphanumeric()));
+    }
+
+    #[test]
+    fn detects_accepted_problem_json() {
+        struct TestCase {
+            title: String,
+            accept: Option<String>,
+            expected_is_accepted: bool,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Uses ApiResponse without header".into(),
+                accept: None,
+                expected_is_accepted: false,
+            },
+            TestCase {
+                title: "Uses ApiResponse for JSON".into(),
+                accept: Some("application/json".into()),
+                expected_is_accepted: false,
+            },
+            TestCase {
+                title: "Finds problem JSON among other media types".into(),
+                accept: Some("application/json;q=0.9, application/problem+json".into()),
+                expected_is_accepted: true,
+            },
+        ];
+
+        for t in test_cases {
+            let mut headers = HeaderMap::new();
+            if let Some(accept) = &t.accept {
+                headers.insert(
+                    ACCEPT,
+                    HeaderValue::from_str(accept).expect("Valid header value"),
+                );
+            }
+
+            assert_eq!(
+                accepts_problem_json(&headers),
+                t.expected_is_accepted,
+                "{}",
+                t.title
+            );
+        }
+    }
+
+    #[test]
+    fn converts_to_problem_details() {
+        let problem_details = ProblemDetails::new(
+            StatusCode::NOT_FOUND,
+            ApiError {
+                error: "Failed to find recipe with id '42'".into(),
+                code: ErrorCode::NotFound,
+                details: vec![],
+                request_id: Some("abc".into()),
+            },
+        );
+
+        assert_eq!(
+            serde_json::to_value(&problem_details).expect("Serializable problem details"),
+            json!({
+                "type": "about:blank",
+                "title": "Not Found",
+                "status": 404,
+                "detail": "Failed to find recipe with id '42'",
+                "code": "NOT_FOUND",
+                "requestId": "abc",
+            })
+        );
+    }
+}
@@ -1,9 +1,4 @@
-use axum::{
-    extract::{self, State},
-    http::StatusCode,
-    routing::post,
-    Json, Router,
-};
+use axum::{extract::State, http::StatusCode, routing::post, Json, Router};
 use tracing::info;
 
 use crate::{
@@ -12,7 +7,12 @@ use crate::{
     model::user::{AuthPayload, UserCreate},
 };
 
-use super::super::api_response::ApiResponse;
+use super::super::{
+    api_response::ApiResponse,
+    app_error::{AppError, WithMessage},
+    messages::Message,
+    validation::ValidatedJson,
+};
 
 #[derive(Clone)]
 struct RouterState {
@@ -44,123 +44,64 @@ impl AuthRouter {
 
 async fn handle_register(
     State(router_state): State<RouterState>,
-    Json(payload): extract::Json<AuthPayload>,
-) -> (StatusCode, Json<ApiResponse<String>>) {
-    let user_create: UserCreate = match payload.try_into() {
-        Ok(u) => u,
-        Err(err) => {
-            let err_msg = "Failed to process provided user data";
-            info!("{err_msg}: {err}");
-
-            return (
-                StatusCode::BAD_REQUEST,
-                Json(ApiResponse {
-                    data: None,
-                    error: err_msg.into(),
-                }),
-            );
-        }
-    };
-
-    match router_state.db_handler.create_user(user_create).await {
-        Ok(inserted_id) => (
-            // TODO: SEND EMAIL REGARDING VERIFICATION
-            StatusCode::ACCEPTED,
-            Json(ApiResponse {
-                data: Some(inserted_id),
-                error: "".into(),
-            }),
-        ),
-        Err(err) => {
-            let err_msg = "Failed to create new user";
-            info!("{err_msg}: {err}");
-
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse {
-                    data: None,
-                    error: err_msg.into(),
-                }),
-            )
-        }
-    }
+    ValidatedJson(payload): ValidatedJson<AuthPayload>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    let user_create: UserCreate = payload.try_into().map_err(|err| {
+        let err_msg = Message::InvalidUserData.to_string();
+        info!("{err_msg}: {err}");
+
+        AppError::Validation(err_msg)
+    })?;
+
+    // Taken email addresses are a conflict with their own message
+    let inserted_id = router_state.db_handler.create_user(user_create).await?;
+
+    Ok((
+        // TODO: SEND EMAIL REGARDING VERIFICATION
+        StatusCode::ACCEPTED,
+        Json(ApiResponse {
+            data: Some(inserted_id),
+            error: None,
+        }),
+    ))
 }
 
 async fn handle_login(
     State(router_state): State<RouterState>,
-    Json(payload): extract::Json<AuthPayload>,
-) -> (StatusCode, Json<ApiResponse<String>>) {
-    let auth_info = match router_state
+    ValidatedJson(payload): ValidatedJson<AuthPayload>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    let auth_info = router_state
         .db_handler
         .get_user_auth_info(&payload.email)
-        .await
-    {
-        Ok(u) => u,
-        Err(err) => {
-            let err_msg = format!(
-                "Failed to find user with the provided email: {}",
-                &payload.email
-            );
-            info!("{err_msg}: {err}");
-
-            return (
-                StatusCode::NOT_FOUND,
-                Json(ApiResponse {
-                    data: None,
-                    error: err_msg,
-                }),
-            );
-        }
-    };
+        .await?;
 
     if verify_password_hash(&payload.password, &auth_info.password_hash).is_err() {
-        let err_msg = format!("Incorrect password: {}", &payload.email);
-        info!("{err_msg}");
-
-        return (
-            StatusCode::UNAUTHORIZED,
-            Json(ApiResponse {
-                data: None,
-                error: err_msg,
-            }),
-        );
+        return Err(AppError::Unauthorized(
+            Message::IncorrectPassword {
+                email: &payload.email,
+            }
+            .to_string(),
+        ));
     }
 
     if !&auth_info.is_activated {
-        let err_msg = format!("User is inactive: {}", &payload.email);
-        info!("{err_msg}");
-
-        return (
-            StatusCode::UNAUTHORIZED,
-            Json(ApiResponse {
-                data: None,
-                error: err_msg,
-            }),
-        );
+        return Err(AppError::Unauthorized(
+            Message::UserInactive {
+                email: &payload.email,
+            }
+            .to_string(),
+        ));
     }
 
-    let token = match generate_jwt(&auth_info, 1, &router_state.jwt_secret) {
-        Ok(t) => t,
-        Err(err) => {
-            let err_msg = "Failed to generate JWT token";
-            info!("{err_msg}: {err}");
-
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse {
-                    data: None,
-                    error: err_msg.into(),
-                }),
-            );
-        }
-    };
-
-    (
+    let token = generate_jwt(&auth_info, 1, &router_state.jwt_secret)
+        .with_message(Message::GenerateTokenFailed)?;
+
+    Ok((
         // TODO: SET JWT TOKEN with role IN COOKIE
         StatusCode::ACCEPTED,
         Json(ApiResponse {
             data: Some(token),
-            error: "".into(),
+            error: None,
         }),
-    )
+    ))
 }
@@ -0,0 +1,408 @@
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    routing::{get, patch},
+    Json, Router,
+};
+use serde_json::to_value;
+use tracing::info;
+
+use crate::{
+    api::{
+        api_response::ApiResponse,
+        app_error::{AppError, WithMessage},
+        messages::Message,
+        services::{event_service::EventBus, token_service::get_claims_from_headers},
+        validation::{Path, Query, ValidatedJson},
+    },
+    db::{
+        comment_handler::CommentHandler, generic_handler::GenericHandler,
+        mongo_db_handler::MongoDbHandler, recipe_handler::RecipeHandler,
+    },
+    model::{
+        comment::{Comment, CommentCreate, CommentMongoDb, CommentPatch, CommentStatus},
+        event::{Event, EventKind},
+        pagination::{Page, Pagination},
+        user::Role,
+    },
+};
+
+#[derive(Clone)]
+struct RouterState {
+    jwt_secret: String,
+    db_handler: MongoDbHandler,
+    event_bus: EventBus,
+}
+
+pub struct CommentsRouter {
+    pub router: Router,
+}
+
+impl CommentsRouter {
+    pub fn new(db_handler: MongoDbHandler, jwt_secret: &str, event_bus: EventBus) -> Self {
+        let base_path = "/recipes/{id}/comments";
+
+        let router_state = RouterState {
+            db_handler,
+            jwt_secret: String::from(jwt_secret),
+            event_bus,
+        };
+
+        let router = Router::new()
+            .route(
+                base_path,
+                get(handle_get_comments).post(handle_create_comment),
+            )
+            .route(
+                &format!("{base_path}/{{comment_id}}"),
+                patch(handle_patch_comment).delete(handle_delete_comment),
+            )
+            .with_state(router_state);
+
+        Self { router }
+    }
+}
+
+/// Comments of other recipes are treated like missing ones
+async fn get_recipe_comment(
+    db_handler: &MongoDbHandler,
+    recipe_id: &str,
+    comment_id: &str,
+) -> Result<Option<CommentMongoDb>, AppError> {
+    match db_handler
+        .get_by_id::<CommentMongoDb, CommentMongoDb>(comment_id, "comments")
+        .await
+    {
+        Ok(comment) => Ok(Some(comment).filter(|c| c.recipe_id.to_hex() == recipe_id)),
+        Err(AppError::NotFound(_)) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+// Pushes a new comment to everyone viewing the recipe
+async fn publish_comment(router_state: &RouterState, recipe_id: &str, comment_id: &str) {
+    let comment = match router_state
+        .db_handler
+        .get_by_id::<CommentMongoDb, Comment>(comment_id, "comments")
+        .await
+    {
+        Ok(c) => c,
+        Err(err) => {
+            info!("Failed to get comment with id '{comment_id}': {err}");
+            return;
+        }
+    };
+
+    match to_value(comment) {
+        Ok(data) => router_state.event_bus.publish(Event {
+            kind: EventKind::CommentCreated,
+            user_ids: vec![],
+            recipe_id: Some(String::from(recipe_id)),
+            data,
+        }),
+        Err(err) => info!("Failed to push comment with id '{comment_id}': {err}"),
+    }
+}
+
+async fn handle_get_comments(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(recipe_id): Path<String>,
+    Query(pagination): Query<Pagination>,
+) -> Result<(StatusCode, Json<ApiResponse<Page<Comment>>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret).ok();
+
+    router_state
+        .db_handler
+        .get_visible_recipe(&recipe_id, claims.as_ref())
+        .await
+        .with_message(Message::GetRecipeFailed {
+            recipe_id: &recipe_id,
+        })?;
+
+    let comments = router_state
+        .db_handler
+        .get_comment_threads(&recipe_id, &pagination)
+        .await
+        .with_message(Message::GetCommentsFailed {
+            recipe_id: &recipe_id,
+        })?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            data: Some(comments),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_create_comment(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(recipe_id): Path<String>,
+    ValidatedJson(payload): ValidatedJson<CommentCreate>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    router_state
+        .db_handler
+        .get_visible_recipe(&recipe_id, Some(&claims))
+        .await
+        .with_message(Message::GetRecipeFailed {
+            recipe_id: &recipe_id,
+        })?;
+
+    let parent = match &payload.parent_id {
+        Some(parent_id) => {
+            match get_recipe_comment(&router_state.db_handler, &recipe_id, parent_id).await? {
+                Some(parent) => Some(parent),
+                None => {
+                    return Err(AppError::Validation(
+                        Message::ParentCommentNotFound {
+                            parent_id: &parent_id,
+                        }
+                        .to_string(),
+                    ));
+                }
+            }
+        }
+        None => None,
+    };
+
+    let inserted_id = router_state
+        .db_handler
+        .create_comment(&recipe_id, &claims.user_id, payload.text, parent.as_ref())
+        .await
+        .with_message(Message::CreateCommentFailed)?;
+
+    publish_comment(&router_state, &recipe_id, &inserted_id).await;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse {
+            data: Some(inserted_id),
+            error: None,
+        }),
+    ))
+}
+
+/// Only visible comments can be changed, others are treated like missing ones
+async fn get_visible_comment(
+    db_handler: &MongoDbHandler,
+    recipe_id: &str,
+    comment_id: &str,
+) -> Result<CommentMongoDb, AppError> {
+    match get_recipe_comment(db_handler, recipe_id, comment_id).await? {
+        Some(comment) if comment.status == CommentStatus::Visible => Ok(comment),
+        _ => Err(AppError::NotFound(
+            Message::CommentNotFound {
+                comment_id: &comment_id,
+            }
+            .to_string(),
+        )),
+    }
+}
+
+async fn handle_patch_comment(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path((recipe_id, comment_id)): Path<(String, String)>,
+    ValidatedJson(payload): ValidatedJson<CommentPatch>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let comment = get_visible_comment(&router_state.db_handler, &recipe_id, &comment_id).await?;
+
+    if comment.author_id.to_hex() != claims.user_id {
+        let err_msg = Message::EditCommentForbidden {
+            comment_id: &comment_id,
+        }
+        .to_string();
+        info!("{err_msg}");
+
+        return Err(AppError::Forbidden(err_msg));
+    }
+
+    router_state
+        .db_handler
+        .patch_comment_text(&comment_id, payload.text)
+        .await
+        .with_message(Message::PatchCommentFailed {
+            comment_id: &comment_id,
+        })?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(ApiResponse {
+            data: None,
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_delete_comment(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path((recipe_id, comment_id)): Path<(String, String)>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let comment = get_visible_comment(&router_state.db_handler, &recipe_id, &comment_id).await?;
+
+    let status = if comment.author_id.to_hex() == claims.user_id {
+        CommentStatus::Deleted
+    } else if claims.role == Role::Admin {
+        CommentStatus::Removed
+    } else {
+        let err_msg = Message::DeleteCommentForbidden {
+            comment_id: &comment_id,
+        }
+        .to_string();
+        info!("{err_msg}");
+
+        return Err(AppError::Forbidden(err_msg));
+    };
+
+    router_state
+        .db_handler
+        .set_comment_status(&comment_id, status)
+        .await
+        .with_message(Message::DeleteCommentFailed {
+            comment_id: &comment_id,
+        })?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(ApiResponse {
+            data: None,
+            error: None,
+        }),
+    ))
+}
+
+#[cfg(test)]
+mod unit_tests_comments_router {
+    use super::*;
+    use crate::{
+        model::{
+            recipe::{RecipeMongoDb, RecipeStatus},
+            user::Role,
+        },
+        test_utils::{
+            assert_status_codes, db_clean_up, get_bearer_token, get_db_connection, get_db_handler,
+            get_random_recipe_db, get_unconnected_db_handler, RequestTestCase, TEST_JWT_SECRET,
+        },
+    };
+    use anyhow::Result;
+    use axum::http::Method;
+    use bson::oid::ObjectId;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn rejects_invalid_requests() -> Result<()> {
+        let router = CommentsRouter::new(
+            get_unconnected_db_handler()?,
+            TEST_JWT_SECRET,
+            EventBus::default(),
+        )
+        .router;
+        let recipe_id = ObjectId::new().to_hex();
+        let comment_id = ObjectId::new().to_hex();
+        let token = get_bearer_token(ObjectId::new(), Role::User)?;
+
+        let test_cases = vec![
+            RequestTestCase {
+                title: "Rejects comments without a token".into(),
+                method: Method::POST,
+                path: format!("/recipes/{recipe_id}/comments"),
+                token: None,
+                body: Some(json!({"text": "Tasty"})),
+                expected_status_code: StatusCode::UNAUTHORIZED,
+            },
+            RequestTestCase {
+                title: "Rejects deleting a comment without a token".into(),
+                method: Method::DELETE,
+                path: format!("/recipes/{recipe_id}/comments/{comment_id}"),
+                token: None,
+                body: None,
+                expected_status_code: StatusCode::UNAUTHORIZED,
+            },
+            RequestTestCase {
+                title: "Rejects empty comments".into(),
+                method: Method::POST,
+                path: format!("/recipes/{recipe_id}/comments"),
+                token: Some(token),
+                body: Some(json!({"text": " "})),
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            RequestTestCase {
+                title: "Rejects malformed recipe ids".into(),
+                method: Method::GET,
+                path: "/recipes/not-an-id/comments".into(),
+                token: None,
+                body: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+        ];
+
+        assert_status_codes(router, test_cases).await
+    }
+
+    #[tokio::test]
+    async fn hides_comments_of_drafts() -> Result<()> {
+        let router = CommentsRouter::new(
+            get_db_handler().await?,
+            TEST_JWT_SECRET,
+            EventBus::default(),
+        )
+        .router;
+
+        let author_id = ObjectId::new();
+        let mut recipe_db = get_random_recipe_db(None, Some(author_id));
+        recipe_db.status = RecipeStatus::Draft;
+        get_db_connection()
+            .await?
+            .collection::<RecipeMongoDb>("recipes")
+            .insert_one(&recipe_db)
+            .await?;
+
+        let path = format!("/recipes/{}/comments", recipe_db._id.to_hex());
+
+        let test_cases = vec![
+            RequestTestCase {
+                title: "Hides the comments of a draft from anonymous users".into(),
+                method: Method::GET,
+                path: path.clone(),
+                token: None,
+                body: None,
+                expected_status_code: StatusCode::NOT_FOUND,
+            },
+            RequestTestCase {
+                title: "Rejects comments on a draft by other users".into(),
+                method: Method::POST,
+                path: path.clone(),
+                token: Some(get_bearer_token(ObjectId::new(), Role::User)?),
+                body: Some(json!({"text": "Tasty"})),
+                expected_status_code: StatusCode::NOT_FOUND,
+            },
+            RequestTestCase {
+                title: "Shows the comments of a draft to its author".into(),
+                method: Method::GET,
+                path,
+                token: Some(get_bearer_token(author_id, Role::User)?),
+                body: None,
+                expected_status_code: StatusCode::OK,
+            },
+        ];
+
+        assert_status_codes(router, test_cases).await?;
+
+        db_clean_up().await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,216 @@
+use std::convert::Infallible;
+
+use axum::{
+    extract::State,
+    http::HeaderMap,
+    response::sse::{self, KeepAlive, Sse},
+    routing::get,
+    Router,
+};
+use chrono::Utc;
+use futures_util::{stream, Stream};
+use tokio::{
+    sync::broadcast::{error::RecvError, Receiver},
+    time::{timeout_at, Duration, Instant},
+};
+use tracing::info;
+
+use crate::{
+    api::{
+        app_error::{AppError, WithMessage},
+        messages::Message,
+        services::{event_service::EventBus, token_service::get_claims_from_headers},
+        validation::Query,
+    },
+    db::{mongo_db_handler::MongoDbHandler, recipe_handler::RecipeHandler},
+    model::event::{Event, EventsQuery},
+};
+
+#[derive(Clone)]
+struct RouterState {
+    jwt_secret: String,
+    db_handler: MongoDbHandler,
+    event_bus: EventBus,
+}
+
+pub struct EventsRouter {
+    pub router: Router,
+}
+
+impl EventsRouter {
+    pub fn new(db_handler: MongoDbHandler, jwt_secret: &str, event_bus: EventBus) -> Self {
+        let router_state = RouterState {
+            db_handler,
+            jwt_secret: String::from(jwt_secret),
+            event_bus,
+        };
+
+        let router = Router::new()
+            .route("/events", get(handle_get_events))
+            .with_state(router_state);
+
+        Self { router }
+    }
+}
+
+struct Subscription {
+    receiver: Receiver<Event>,
+    user_id: String,
+    recipe_id: Option<String>,
+    expires_at: Instant,
+}
+
+// Yields the events addressed to the subscriber until the bus shuts down or the token expires
+fn get_event_stream(
+    subscription: Subscription,
+) -> impl Stream<Item = Result<sse::Event, Infallible>> {
+    stream::unfold(subscription, |mut subscription| async move {
+        loop {
+            let received = timeout_at(subscription.expires_at, subscription.receiver.recv()).await;
+
+            let event = match received {
+                Err(_) => {
+                    info!(
+                        "Closed event stream of user with id '{}', the token expired",
+                        subscription.user_id
+                    );
+                    return None;
+                }
+                Ok(Ok(event)) => event,
+                Ok(Err(RecvError::Lagged(skipped_count))) => {
+                    info!(
+                        "Event stream of user with id '{}' skipped {skipped_count} events",
+                        subscription.user_id
+                    );
+                    continue;
+                }
+                Ok(Err(RecvError::Closed)) => return None,
+            };
+
+            if !event.is_for(&subscription.user_id, subscription.recipe_id.as_deref()) {
+                continue;
+            }
+
+            match sse::Event::default()
+                .event(event.kind.name())
+                .json_data(&event)
+            {
+                Ok(sse_event) => return Some((Ok(sse_event), subscription)),
+                Err(err) => info!("Failed to serialize event: {err}"),
+            }
+        }
+    })
+}
+
+async fn handle_get_events(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Query(query): Query<EventsQuery>,
+) -> Result<Sse<impl Stream<Item = Result<sse::Event, Infallible>>>, AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    if let Some(recipe_id) = &query.recipe_id {
+        router_state
+            .db_handler
+            .get_visible_recipe(recipe_id, Some(&claims))
+            .await
+            .with_message(Message::GetRecipeFailed {
+                recipe_id: &recipe_id,
+            })?;
+    }
+
+    // Clients reconnect with a renewed token, an expired one must not keep listening
+    let valid_seconds = (claims.exp - Utc::now().timestamp()).max(0) as u64;
+
+    let subscription = Subscription {
+        receiver: router_state.event_bus.subscribe(),
+        user_id: claims.user_id,
+        recipe_id: query.recipe_id,
+        expires_at: Instant::now() + Duration::from_secs(valid_seconds),
+    };
+
+    Ok(Sse::new(get_event_stream(subscription)).keep_alive(KeepAlive::default()))
+}
+
+#[cfg(test)]
+mod unit_tests_events_router {
+    use super::*;
+    use crate::{
+        model::{
+            recipe::{RecipeMongoDb, RecipeStatus},
+            user::Role,
+        },
+        test_utils::{
+            assert_status_codes, db_clean_up, get_bearer_token, get_db_connection, get_db_handler,
+            get_random_recipe_db, get_unconnected_db_handler, RequestTestCase, TEST_JWT_SECRET,
+        },
+    };
+    use anyhow::Result;
+    use axum::http::{Method, StatusCode};
+    use bson::oid::ObjectId;
+
+    #[tokio::test]
+    async fn rejects_invalid_requests() -> Result<()> {
+        let router = EventsRouter::new(
+            get_unconnected_db_handler()?,
+            TEST_JWT_SECRET,
+            EventBus::default(),
+        )
+        .router;
+
+        let test_cases = vec![
+            RequestTestCase {
+                title: "Rejects listening without a token".into(),
+                method: Method::GET,
+                path: "/events".into(),
+                token: None,
+                body: None,
+                expected_status_code: StatusCode::UNAUTHORIZED,
+            },
+            RequestTestCase {
+                title: "Rejects malformed recipe ids".into(),
+                method: Method::GET,
+                path: "/events?recipeId=not-an-id".into(),
+                token: Some(get_bearer_token(ObjectId::new(), Role::User)?),
+                body: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+        ];
+
+        assert_status_codes(router, test_cases).await
+    }
+
+    #[tokio::test]
+    async fn hides_events_of_drafts() -> Result<()> {
+        let router = EventsRouter::new(
+            get_db_handler().await?,
+            TEST_JWT_SECRET,
+            EventBus::default(),
+        )
+        .router;
+
+        let mut recipe_db = get_random_recipe_db(None, Some(ObjectId::new()));
+        recipe_db.status = RecipeStatus::Draft;
+        get_db_connection()
+            .await?
+            .collection::<RecipeMongoDb>("recipes")
+            .insert_one(&recipe_db)
+            .await?;
+
+        let test_cases = vec![RequestTestCase {
+            title: "Rejects listening to a draft of another user".into(),
+            method: Method::GET,
+            path: format!("/events?recipeId={}", recipe_db._id.to_hex()),
+            token: Some(get_bearer_token(ObjectId::new(), Role::User)?),
+            body: None,
+            expected_status_code: StatusCode::NOT_FOUND,
+        }];
+
+        assert_status_codes(router, test_cases).await?;
+
+        db_clean_up().await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,477 @@
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    routing::{get, patch, post},
+    Json, Router,
+};
+
+use crate::{
+    api::{
+        api_response::ApiResponse,
+        app_error::{AppError, WithMessage},
+        messages::Message,
+        services::token_service::get_claims_from_headers,
+        validation::{Path, ValidatedJson},
+    },
+    db::{
+        favorite_handler::FavoriteHandler, mongo_db_handler::MongoDbHandler,
+        recipe_handler::RecipeHandler,
+    },
+    model::{
+        favorite::{
+            RecipeCollection, RecipeCollectionCreate, RecipeCollectionPatch, SharedRecipeCollection,
+        },
+        recipe::Recipe,
+    },
+};
+
+#[derive(Clone)]
+struct RouterState {
+    jwt_secret: String,
+    db_handler: MongoDbHandler,
+}
+
+pub struct FavoritesRouter {
+    pub router: Router,
+}
+
+impl FavoritesRouter {
+    pub fn new(db_handler: MongoDbHandler, jwt_secret: &str) -> Self {
+        let router_state = RouterState {
+            db_handler,
+            jwt_secret: String::from(jwt_secret),
+        };
+
+        let router = Router::new()
+            .route("/me/favorites", get(handle_get_favorites))
+            .route(
+                "/me/favorites/{recipe_id}",
+                post(handle_add_favorite).delete(handle_remove_favorite),
+            )
+            .route(
+                "/me/collections",
+                get(handle_get_collections).post(handle_create_collection),
+            )
+            .route(
+                "/me/collections/{collection_id}",
+                patch(handle_patch_collection).delete(handle_delete_collection),
+            )
+            .route(
+                "/me/collections/{collection_id}/recipes/{recipe_id}",
+                post(handle_add_recipe_to_collection).delete(handle_remove_recipe_from_collection),
+            )
+            .route(
+                "/collections/shared/{share_token}",
+                get(handle_get_shared_collection),
+            )
+            .with_state(router_state);
+
+        Self { router }
+    }
+}
+
+async fn handle_get_favorites(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+) -> Result<(StatusCode, Json<ApiResponse<Vec<Recipe>>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let recipes = router_state
+        .db_handler
+        .get_favorite_recipes(&claims.user_id)
+        .await
+        .with_message(Message::GetFavoritesFailed)?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            data: Some(recipes),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_add_favorite(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(recipe_id): Path<String>,
+) -> Result<(StatusCode, Json<ApiResponse<bool>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    router_state
+        .db_handler
+        .get_visible_recipe(&recipe_id, Some(&claims))
+        .await
+        .with_message(Message::GetRecipeFailed {
+            recipe_id: &recipe_id,
+        })?;
+
+    let is_added = router_state
+        .db_handler
+        .add_favorite(&claims.user_id, &recipe_id)
+        .await
+        .with_message(Message::AddFavoriteFailed {
+            recipe_id: &recipe_id,
+        })?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            data: Some(is_added),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_remove_favorite(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(recipe_id): Path<String>,
+) -> Result<(StatusCode, Json<ApiResponse<bool>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let is_removed = router_state
+        .db_handler
+        .remove_favorite(&claims.user_id, &recipe_id)
+        .await
+        .with_message(Message::RemoveFavoriteFailed {
+            recipe_id: &recipe_id,
+        })?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            data: Some(is_removed),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_get_collections(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+) -> Result<(StatusCode, Json<ApiResponse<Vec<RecipeCollection>>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let collections = router_state
+        .db_handler
+        .get_recipe_collections(&claims.user_id)
+        .await
+        .with_message(Message::GetCollectionsFailed)?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            data: Some(collections),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_create_collection(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    ValidatedJson(payload): ValidatedJson<RecipeCollectionCreate>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let inserted_id = router_state
+        .db_handler
+        .create_recipe_collection(&claims.user_id, payload)
+        .await
+        .with_message(Message::CreateCollectionFailed)?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse {
+            data: Some(inserted_id),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_patch_collection(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(collection_id): Path<String>,
+    ValidatedJson(payload): ValidatedJson<RecipeCollectionPatch>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let update_count = router_state
+        .db_handler
+        .patch_recipe_collection(&claims.user_id, &collection_id, payload)
+        .await
+        .with_message(Message::PatchCollectionFailed {
+            collection_id: &collection_id,
+        })?;
+
+    if update_count == 0 {
+        return Err(AppError::NotFound(
+            Message::CollectionNotFound {
+                collection_id: &collection_id,
+            }
+            .to_string(),
+        ));
+    }
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(ApiResponse {
+            data: None,
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_delete_collection(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(collection_id): Path<String>,
+) -> Result<(StatusCode, Json<ApiResponse<u64>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let delete_count = router_state
+        .db_handler
+        .delete_recipe_collection(&claims.user_id, &collection_id)
+        .await
+        .with_message(Message::DeleteCollectionFailed {
+            collection_id: &collection_id,
+        })?;
+
+    if delete_count == 0 {
+        return Err(AppError::NotFound(
+            Message::CollectionNotFound {
+                collection_id: &collection_id,
+            }
+            .to_string(),
+        ));
+    }
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(ApiResponse {
+            data: Some(delete_count),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_add_recipe_to_collection(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path((collection_id, recipe_id)): Path<(String, String)>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    router_state
+        .db_handler
+        .get_visible_recipe(&recipe_id, Some(&claims))
+        .await
+        .with_message(Message::GetRecipeFailed {
+            recipe_id: &recipe_id,
+        })?;
+
+    let update_count = router_state
+        .db_handler
+        .add_recipe_to_collection(&claims.user_id, &collection_id, &recipe_id)
+        .await
+        .with_message(Message::AddToCollectionFailed {
+            recipe_id: &recipe_id,
+            collection_id: &collection_id,
+        })?;
+
+    if update_count == 0 {
+        return Err(AppError::NotFound(
+            Message::CollectionNotFound {
+                collection_id: &collection_id,
+            }
+            .to_string(),
+        ));
+    }
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(ApiResponse {
+            data: None,
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_remove_recipe_from_collection(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path((collection_id, recipe_id)): Path<(String, String)>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let update_count = router_state
+        .db_handler
+        .remove_recipe_from_collection(&claims.user_id, &collection_id, &recipe_id)
+        .await
+        .with_message(Message::RemoveFromCollectionFailed {
+            recipe_id: &recipe_id,
+            collection_id: &collection_id,
+        })?;
+
+    if update_count == 0 {
+        return Err(AppError::NotFound(
+            Message::CollectionNotFound {
+                collection_id: &collection_id,
+            }
+            .to_string(),
+        ));
+    }
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(ApiResponse {
+            data: None,
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_get_shared_collection(
+    State(router_state): State<RouterState>,
+    Path(share_token): Path<String>,
+) -> Result<(StatusCode, Json<ApiResponse<SharedRecipeCollection>>), AppError> {
+    match router_state
+        .db_handler
+        .get_shared_recipe_collection(&share_token)
+        .await
+        .with_message(Message::GetSharedCollectionFailed)?
+    {
+        Some(collection) => Ok((
+            StatusCode::OK,
+            Json(ApiResponse {
+                data: Some(collection),
+                error: None,
+            }),
+        )),
+        None => Err(AppError::NotFound(
+            Message::SharedCollectionNotFound.to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod unit_tests_favorites_router {
+    use super::*;
+    use crate::{
+        model::{
+            recipe::{RecipeMongoDb, RecipeStatus},
+            user::Role,
+        },
+        test_utils::{
+            assert_status_codes, db_clean_up, get_bearer_token, get_db_connection, get_db_handler,
+            get_random_recipe_db, get_unconnected_db_handler, RequestTestCase, TEST_JWT_SECRET,
+        },
+    };
+    use anyhow::Result;
+    use axum::http::Method;
+    use bson::oid::ObjectId;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn rejects_invalid_requests() -> Result<()> {
+        let router = FavoritesRouter::new(get_unconnected_db_handler()?, TEST_JWT_SECRET).router;
+        let token = get_bearer_token(ObjectId::new(), Role::User)?;
+
+        let test_cases = vec![
+            RequestTestCase {
+                title: "Rejects listing favorites without a token".into(),
+                method: Method::GET,
+                path: "/me/favorites".into(),
+                token: None,
+                body: None,
+                expected_status_code: StatusCode::UNAUTHORIZED,
+            },
+            RequestTestCase {
+                title: "Rejects listing collections without a token".into(),
+                method: Method::GET,
+                path: "/me/collections".into(),
+                token: None,
+                body: None,
+                expected_status_code: StatusCode::UNAUTHORIZED,
+            },
+            RequestTestCase {
+                title: "Rejects malformed recipe ids".into(),
+                method: Method::POST,
+                path: "/me/favorites/not-an-id".into(),
+                token: Some(token.clone()),
+                body: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            RequestTestCase {
+                title: "Rejects collections without a name".into(),
+                method: Method::POST,
+                path: "/me/collections".into(),
+                token: Some(token),
+                body: Some(json!({"name": ""})),
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+        ];
+
+        assert_status_codes(router, test_cases).await
+    }
+
+    #[tokio::test]
+    async fn hides_drafts_from_favorites() -> Result<()> {
+        let router = FavoritesRouter::new(get_db_handler().await?, TEST_JWT_SECRET).router;
+
+        let author_id = ObjectId::new();
+        let mut recipe_db = get_random_recipe_db(None, Some(author_id));
+        recipe_db.status = RecipeStatus::Draft;
+        get_db_connection()
+            .await?
+            .collection::<RecipeMongoDb>("recipes")
+            .insert_one(&recipe_db)
+            .await?;
+
+        let path = format!("/me/favorites/{}", recipe_db._id.to_hex());
+
+        let test_cases = vec![
+            RequestTestCase {
+                title: "Rejects favoriting a draft of another user".into(),
+                method: Method::POST,
+                path: path.clone(),
+                token: Some(get_bearer_token(ObjectId::new(), Role::User)?),
+                body: None,
+                expected_status_code: StatusCode::NOT_FOUND,
+            },
+            RequestTestCase {
+                title: "Lets authors favorite their drafts".into(),
+                method: Method::POST,
+                path,
+                token: Some(get_bearer_token(author_id, Role::User)?),
+                body: None,
+                expected_status_code: StatusCode::OK,
+            },
+            RequestTestCase {
+                title: "Hides unknown shared collections".into(),
+                method: Method::GET,
+                path: "/collections/shared/unknown".into(),
+                token: None,
+                body: None,
+                expected_status_code: StatusCode::NOT_FOUND,
+            },
+        ];
+
+        assert_status_codes(router, test_cases).await?;
+
+        db_clean_up().await?;
+
+        Ok(())
+    }
+}
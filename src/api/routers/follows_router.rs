@@ -0,0 +1,273 @@
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    routing::{get, post},
+    Json, Router,
+};
+
+use crate::{
+    api::{
+        api_response::ApiResponse,
+        app_error::{AppError, WithMessage},
+        messages::Message,
+        services::{
+            event_service::EventBus, notification_service::notify,
+            token_service::get_claims_from_headers,
+        },
+        validation::{Path, Query},
+    },
+    db::{
+        follow_handler::FollowHandler, generic_handler::GenericHandler,
+        mongo_db_handler::MongoDbHandler,
+    },
+    model::{
+        feed::{FeedCursor, FeedPage, FeedQuery},
+        follow::PublicProfile,
+        notification::{NotificationCreate, NotificationKind},
+        user::{User, UserMongoDb},
+    },
+};
+
+#[derive(Clone)]
+struct RouterState {
+    jwt_secret: String,
+    db_handler: MongoDbHandler,
+    event_bus: EventBus,
+}
+
+pub struct FollowsRouter {
+    pub router: Router,
+}
+
+impl FollowsRouter {
+    pub fn new(db_handler: MongoDbHandler, jwt_secret: &str, event_bus: EventBus) -> Self {
+        let router_state = RouterState {
+            db_handler,
+            jwt_secret: String::from(jwt_secret),
+            event_bus,
+        };
+
+        let router = Router::new()
+            .route("/users/{id}/profile", get(handle_get_profile))
+            .route(
+                "/users/{id}/follow",
+                post(handle_follow_user).delete(handle_unfollow_user),
+            )
+            .route("/me/feed", get(handle_get_feed))
+            .with_state(router_state);
+
+        Self { router }
+    }
+}
+
+async fn handle_get_profile(
+    State(router_state): State<RouterState>,
+    Path(user_id): Path<String>,
+) -> Result<(StatusCode, Json<ApiResponse<PublicProfile>>), AppError> {
+    let profile = router_state
+        .db_handler
+        .get_public_profile(&user_id)
+        .await
+        .with_message(Message::GetProfileFailed { user_id: &user_id })?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            data: Some(profile),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_follow_user(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(user_id): Path<String>,
+) -> Result<(StatusCode, Json<ApiResponse<bool>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    if claims.user_id == user_id {
+        return Err(AppError::Validation(Message::FollowSelf.to_string()));
+    }
+
+    router_state
+        .db_handler
+        .get_by_id::<UserMongoDb, User>(&user_id, "users")
+        .await
+        .with_message(Message::GetUserFailed { user_id: &user_id })?;
+
+    let is_followed = router_state
+        .db_handler
+        .follow_user(&claims.user_id, &user_id)
+        .await
+        .with_message(Message::FollowUserFailed { user_id: &user_id })?;
+
+    if is_followed {
+        notify(
+            &router_state.db_handler,
+            &router_state.event_bus,
+            &user_id,
+            NotificationCreate {
+                kind: NotificationKind::NewFollower,
+                actor_id: Some(claims.user_id),
+                recipe_id: None,
+                report_id: None,
+            },
+        )
+        .await;
+    }
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            data: Some(is_followed),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_unfollow_user(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(user_id): Path<String>,
+) -> Result<(StatusCode, Json<ApiResponse<bool>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let is_unfollowed = router_state
+        .db_handler
+        .unfollow_user(&claims.user_id, &user_id)
+        .await
+        .with_message(Message::UnfollowUserFailed { user_id: &user_id })?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            data: Some(is_unfollowed),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_get_feed(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Query(query): Query<FeedQuery>,
+) -> Result<(StatusCode, Json<ApiResponse<FeedPage>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    if let Some(Err(err)) = query.cursor.as_deref().map(str::parse::<FeedCursor>) {
+        return Err(AppError::Validation(err.to_string()));
+    }
+
+    let feed = router_state
+        .db_handler
+        .get_feed(&claims.user_id, &query)
+        .await
+        .with_message(Message::GetFeedFailed)?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            data: Some(feed),
+            error: None,
+        }),
+    ))
+}
+
+#[cfg(test)]
+mod unit_tests_follows_router {
+    use super::*;
+    use crate::{
+        model::user::Role,
+        test_utils::{
+            assert_status_codes, get_bearer_token, get_db_handler, get_unconnected_db_handler,
+            RequestTestCase, TEST_JWT_SECRET,
+        },
+    };
+    use anyhow::Result;
+    use axum::http::Method;
+    use bson::oid::ObjectId;
+
+    #[tokio::test]
+    async fn rejects_invalid_requests() -> Result<()> {
+        let router = FollowsRouter::new(
+            get_unconnected_db_handler()?,
+            TEST_JWT_SECRET,
+            EventBus::default(),
+        )
+        .router;
+        let user_id = ObjectId::new();
+
+        let test_cases = vec![
+            RequestTestCase {
+                title: "Rejects following without a token".into(),
+                method: Method::POST,
+                path: format!("/users/{user_id}/follow"),
+                token: None,
+                body: None,
+                expected_status_code: StatusCode::UNAUTHORIZED,
+            },
+            RequestTestCase {
+                title: "Rejects reading the feed without a token".into(),
+                method: Method::GET,
+                path: "/me/feed".into(),
+                token: None,
+                body: None,
+                expected_status_code: StatusCode::UNAUTHORIZED,
+            },
+            RequestTestCase {
+                title: "Rejects following oneself".into(),
+                method: Method::POST,
+                path: format!("/users/{user_id}/follow"),
+                token: Some(get_bearer_token(user_id, Role::User)?),
+                body: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            RequestTestCase {
+                title: "Rejects malformed user ids".into(),
+                method: Method::GET,
+                path: "/users/not-an-id/profile".into(),
+                token: None,
+                body: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+        ];
+
+        assert_status_codes(router, test_cases).await
+    }
+
+    #[tokio::test]
+    async fn hides_unknown_users() -> Result<()> {
+        let router = FollowsRouter::new(
+            get_db_handler().await?,
+            TEST_JWT_SECRET,
+            EventBus::default(),
+        )
+        .router;
+        let user_id = ObjectId::new();
+
+        let test_cases = vec![
+            RequestTestCase {
+                title: "Hides the profile of unknown users".into(),
+                method: Method::GET,
+                path: format!("/users/{user_id}/profile"),
+                token: None,
+                body: None,
+                expected_status_code: StatusCode::NOT_FOUND,
+            },
+            RequestTestCase {
+                title: "Rejects following unknown users".into(),
+                method: Method::POST,
+                path: format!("/users/{user_id}/follow"),
+                token: Some(get_bearer_token(ObjectId::new(), Role::User)?),
+                body: None,
+                expected_status_code: StatusCode::NOT_FOUND,
+            },
+        ];
+
+        assert_status_codes(router, test_cases).await
+    }
+}
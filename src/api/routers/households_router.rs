@@ -0,0 +1,557 @@
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    routing::{get, patch, post},
+    Json, Router,
+};
+
+use crate::{
+    api::{
+        api_response::ApiResponse,
+        app_error::{AppError, WithMessage},
+        messages::Message,
+        services::token_service::{get_claims_from_headers, Claims},
+        validation::{Path, ValidatedJson},
+    },
+    db::{
+        generic_handler::{parse_object_id, GenericHandler},
+        household_handler::HouseholdHandler,
+        mongo_db_handler::MongoDbHandler,
+    },
+    model::{
+        household::{
+            Household, HouseholdCreate, HouseholdJoin, HouseholdMemberPatch, HouseholdMongoDb,
+            HouseholdRole,
+        },
+        user::Role,
+    },
+};
+
+#[derive(Clone)]
+struct RouterState {
+    jwt_secret: String,
+    db_handler: MongoDbHandler,
+}
+
+pub struct HouseholdsRouter {
+    pub router: Router,
+}
+
+impl HouseholdsRouter {
+    pub fn new(db_handler: MongoDbHandler, jwt_secret: &str) -> Self {
+        let base_path = "/households";
+
+        let router_state = RouterState {
+            db_handler,
+            jwt_secret: String::from(jwt_secret),
+        };
+
+        let router = Router::new()
+            .route(base_path, post(handle_create_household))
+            .route("/me/household", get(handle_get_my_household))
+            .route(&format!("{base_path}/join"), post(handle_join_household))
+            .route(
+                &format!("{base_path}/{{id}}"),
+                get(handle_get_household)
+                    .patch(handle_patch_household)
+                    .delete(handle_delete_household),
+            )
+            .route(
+                &format!("{base_path}/{{id}}/invite-code"),
+                post(handle_regenerate_invite_code),
+            )
+            .route(
+                &format!("{base_path}/{{id}}/members/{{user_id}}"),
+                patch(handle_patch_member).delete(handle_remove_member),
+            )
+            .with_state(router_state);
+
+        Self { router }
+    }
+}
+
+/// Gets a household the user holds at least the required role in, admins hold every role
+pub async fn get_household_with_role(
+    db_handler: &MongoDbHandler,
+    claims: &Claims,
+    household_id: &str,
+    required: HouseholdRole,
+) -> Result<HouseholdMongoDb, AppError> {
+    let household = db_handler
+        .get_by_id::<HouseholdMongoDb, HouseholdMongoDb>(household_id, "households")
+        .await
+        .with_message(Message::GetHouseholdFailed {
+            household_id: &household_id,
+        })?;
+
+    let user_id = parse_object_id(&claims.user_id)?;
+
+    // Households are private, so strangers do not learn which ones exist
+    if household.get_role(&user_id).is_none() && claims.role != Role::Admin {
+        return Err(AppError::NotFound(
+            Message::GetHouseholdFailed {
+                household_id: &household_id,
+            }
+            .to_string(),
+        ));
+    }
+
+    if !household.has_role(&user_id, &claims.role, required) {
+        return Err(AppError::Forbidden(
+            Message::ManageHouseholdForbidden.to_string(),
+        ));
+    }
+
+    Ok(household)
+}
+
+// Users belong to at most one household, so shared plans and lists are unambiguous
+async fn check_has_no_household(
+    db_handler: &MongoDbHandler,
+    user_id: &str,
+) -> Result<(), AppError> {
+    if db_handler
+        .get_household_of_user(user_id)
+        .await
+        .with_message(Message::GetHouseholdOfUserFailed)?
+        .is_some()
+    {
+        return Err(AppError::Conflict(Message::AlreadyInHousehold.to_string()));
+    }
+
+    Ok(())
+}
+
+// The unique index on members rejects requests that passed the check above concurrently,
+// the handlers return conflicts for nothing else
+fn map_membership_conflict(err: AppError) -> AppError {
+    match err {
+        AppError::Conflict(_) => AppError::Conflict(Message::AlreadyInHousehold.to_string()),
+        err => err,
+    }
+}
+
+async fn handle_create_household(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    ValidatedJson(payload): ValidatedJson<HouseholdCreate>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    check_has_no_household(&router_state.db_handler, &claims.user_id).await?;
+
+    let household_id = router_state
+        .db_handler
+        .create_household(&claims.user_id, payload)
+        .await
+        .with_message(Message::CreateHouseholdFailed)
+        .map_err(map_membership_conflict)?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse {
+            data: Some(household_id),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_get_my_household(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+) -> Result<(StatusCode, Json<ApiResponse<Household>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let household = router_state
+        .db_handler
+        .get_household_of_user(&claims.user_id)
+        .await
+        .with_message(Message::GetHouseholdOfUserFailed)?
+        .ok_or_else(|| AppError::NotFound(Message::NoHousehold.to_string()))?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            data: Some(household.into()),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_join_household(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    ValidatedJson(payload): ValidatedJson<HouseholdJoin>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    check_has_no_household(&router_state.db_handler, &claims.user_id).await?;
+
+    let household = router_state
+        .db_handler
+        .get_household_by_invite_code(&payload.invite_code)
+        .await
+        .with_message(Message::GetHouseholdByInviteCodeFailed)?
+        .ok_or_else(|| AppError::NotFound(Message::InviteCodeNotFound.to_string()))?;
+
+    let household_id = household._id.to_hex();
+
+    router_state
+        .db_handler
+        .add_household_member(&household_id, &claims.user_id)
+        .await
+        .with_message(Message::JoinHouseholdFailed {
+            household_id: &household_id,
+        })
+        .map_err(map_membership_conflict)?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            data: Some(household_id),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_get_household(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(household_id): Path<String>,
+) -> Result<(StatusCode, Json<ApiResponse<Household>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let household = get_household_with_role(
+        &router_state.db_handler,
+        &claims,
+        &household_id,
+        HouseholdRole::Member,
+    )
+    .await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            data: Some(household.into()),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_patch_household(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(household_id): Path<String>,
+    ValidatedJson(payload): ValidatedJson<HouseholdCreate>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    get_household_with_role(
+        &router_state.db_handler,
+        &claims,
+        &household_id,
+        HouseholdRole::Owner,
+    )
+    .await?;
+
+    router_state
+        .db_handler
+        .rename_household(&household_id, &payload.name)
+        .await
+        .with_message(Message::PatchHouseholdFailed {
+            household_id: &household_id,
+        })?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(ApiResponse {
+            data: None,
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_delete_household(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(household_id): Path<String>,
+) -> Result<(StatusCode, Json<ApiResponse<u64>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    get_household_with_role(
+        &router_state.db_handler,
+        &claims,
+        &household_id,
+        HouseholdRole::Owner,
+    )
+    .await?;
+
+    let delete_count = router_state
+        .db_handler
+        .delete_household(&household_id)
+        .await
+        .with_message(Message::DeleteHouseholdFailed {
+            household_id: &household_id,
+        })?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(ApiResponse {
+            data: Some(delete_count),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_regenerate_invite_code(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(household_id): Path<String>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    get_household_with_role(
+        &router_state.db_handler,
+        &claims,
+        &household_id,
+        HouseholdRole::Owner,
+    )
+    .await?;
+
+    let invite_code = router_state
+        .db_handler
+        .regenerate_invite_code(&household_id)
+        .await
+        .with_message(Message::RegenerateInviteCodeFailed {
+            household_id: &household_id,
+        })?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            data: Some(invite_code),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_patch_member(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path((household_id, user_id)): Path<(String, String)>,
+    ValidatedJson(payload): ValidatedJson<HouseholdMemberPatch>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let household = get_household_with_role(
+        &router_state.db_handler,
+        &claims,
+        &household_id,
+        HouseholdRole::Owner,
+    )
+    .await?;
+
+    let member_id = parse_object_id(&user_id)?;
+    let is_last_owner = household.get_role(&member_id) == Some(HouseholdRole::Owner)
+        && household.owner_count() == 1;
+
+    if is_last_owner && payload.role != HouseholdRole::Owner {
+        return Err(AppError::Validation(
+            Message::HouseholdOwnerRequired.to_string(),
+        ));
+    }
+
+    let update_count = router_state
+        .db_handler
+        .set_household_member_role(&household_id, &user_id, payload.role)
+        .await
+        .with_message(Message::PatchHouseholdMemberFailed { user_id: &user_id })?;
+
+    if update_count == 0 {
+        return Err(AppError::NotFound(
+            Message::HouseholdMemberNotFound { user_id: &user_id }.to_string(),
+        ));
+    }
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(ApiResponse {
+            data: None,
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_remove_member(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path((household_id, user_id)): Path<(String, String)>,
+) -> Result<(StatusCode, Json<ApiResponse<u64>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    // Members may leave on their own, removing others is up to owners
+    let required = match claims.user_id == user_id {
+        true => HouseholdRole::Member,
+        false => HouseholdRole::Owner,
+    };
+
+    let household =
+        get_household_with_role(&router_state.db_handler, &claims, &household_id, required).await?;
+
+    let member_id = parse_object_id(&user_id)?;
+    let is_last_owner = household.get_role(&member_id) == Some(HouseholdRole::Owner)
+        && household.owner_count() == 1;
+
+    if is_last_owner && household.members.len() > 1 {
+        return Err(AppError::Validation(
+            Message::HandOverOwnershipFirst.to_string(),
+        ));
+    }
+
+    // The last member leaving takes the household and its shared data along
+    let remove_count = match household.members.len() {
+        1 if household.get_role(&member_id).is_some() => {
+            router_state
+                .db_handler
+                .delete_household(&household_id)
+                .await
+        }
+        _ => {
+            router_state
+                .db_handler
+                .remove_household_member(&household_id, &user_id)
+                .await
+        }
+    }
+    .with_message(Message::RemoveHouseholdMemberFailed { user_id: &user_id })?;
+
+    if remove_count == 0 {
+        return Err(AppError::NotFound(
+            Message::HouseholdMemberNotFound { user_id: &user_id }.to_string(),
+        ));
+    }
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(ApiResponse {
+            data: Some(remove_count),
+            error: None,
+        }),
+    ))
+}
+
+#[cfg(test)]
+mod unit_tests_households_router {
+    use super::*;
+    use crate::test_utils::{
+        assert_status_codes, db_clean_up, get_bearer_token, get_db_handler,
+        get_unconnected_db_handler, RequestTestCase, TEST_JWT_SECRET,
+    };
+    use anyhow::Result;
+    use axum::http::Method;
+    use bson::oid::ObjectId;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn rejects_invalid_requests() -> Result<()> {
+        let router = HouseholdsRouter::new(get_unconnected_db_handler()?, TEST_JWT_SECRET).router;
+        let household_id = ObjectId::new().to_hex();
+        let token = get_bearer_token(ObjectId::new(), Role::User)?;
+
+        let test_cases = vec![
+            RequestTestCase {
+                title: "Rejects reading the own household without a token".into(),
+                method: Method::GET,
+                path: "/me/household".into(),
+                token: None,
+                body: None,
+                expected_status_code: StatusCode::UNAUTHORIZED,
+            },
+            RequestTestCase {
+                title: "Rejects deleting a household without a token".into(),
+                method: Method::DELETE,
+                path: format!("/households/{household_id}"),
+                token: None,
+                body: None,
+                expected_status_code: StatusCode::UNAUTHORIZED,
+            },
+            RequestTestCase {
+                title: "Rejects households without a name".into(),
+                method: Method::POST,
+                path: "/households".into(),
+                token: Some(token.clone()),
+                body: Some(json!({"name": ""})),
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            RequestTestCase {
+                title: "Rejects malformed household ids".into(),
+                method: Method::GET,
+                path: "/households/not-an-id".into(),
+                token: Some(token),
+                body: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+        ];
+
+        assert_status_codes(router, test_cases).await
+    }
+
+    #[tokio::test]
+    async fn hides_households_from_strangers() -> Result<()> {
+        let db_handler = get_db_handler().await?;
+        let owner_id = ObjectId::new();
+        let household_id = db_handler
+            .create_household(
+                &owner_id.to_hex(),
+                HouseholdCreate {
+                    name: "Home".into(),
+                },
+            )
+            .await?;
+        let router = HouseholdsRouter::new(db_handler, TEST_JWT_SECRET).router;
+
+        let path = format!("/households/{household_id}");
+
+        let test_cases = vec![
+            RequestTestCase {
+                title: "Hides a household from strangers".into(),
+                method: Method::GET,
+                path: path.clone(),
+                token: Some(get_bearer_token(ObjectId::new(), Role::User)?),
+                body: None,
+                expected_status_code: StatusCode::NOT_FOUND,
+            },
+            RequestTestCase {
+                title: "Rejects deleting a household by strangers".into(),
+                method: Method::DELETE,
+                path: path.clone(),
+                token: Some(get_bearer_token(ObjectId::new(), Role::User)?),
+                body: None,
+                expected_status_code: StatusCode::NOT_FOUND,
+            },
+            RequestTestCase {
+                title: "Shows a household to its owner".into(),
+                method: Method::GET,
+                path,
+                token: Some(get_bearer_token(owner_id, Role::User)?),
+                body: None,
+                expected_status_code: StatusCode::OK,
+            },
+        ];
+
+        assert_status_codes(router, test_cases).await?;
+
+        db_clean_up().await?;
+
+        Ok(())
+    }
+}
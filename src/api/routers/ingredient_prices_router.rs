@@ -0,0 +1,233 @@
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    routing::{get, patch},
+    Json, Router,
+};
+
+use crate::{
+    api::{
+        api_response::ApiResponse,
+        app_error::{AppError, WithMessage},
+        messages::Message,
+        services::token_service::get_claims_from_headers,
+        validation::{Path, Query, ValidatedJson},
+    },
+    db::{ingredient_price_handler::IngredientPriceHandler, mongo_db_handler::MongoDbHandler},
+    model::{
+        ingredient_price::{
+            IngredientPrice, IngredientPriceCreate, IngredientPricePatch, IngredientPriceQuery,
+        },
+        user::Role,
+    },
+};
+
+#[derive(Clone)]
+struct RouterState {
+    jwt_secret: String,
+    db_handler: MongoDbHandler,
+}
+
+pub struct IngredientPricesRouter {
+    pub router: Router,
+}
+
+impl IngredientPricesRouter {
+    pub fn new(db_handler: MongoDbHandler, jwt_secret: &str) -> Self {
+        let base_path = "/ingredient-prices";
+
+        let router_state = RouterState {
+            db_handler,
+            jwt_secret: String::from(jwt_secret),
+        };
+
+        let router = Router::new()
+            .route(
+                base_path,
+                get(handle_get_ingredient_prices).post(handle_create_ingredient_price),
+            )
+            .route(
+                &format!("{base_path}/{{id}}"),
+                patch(handle_patch_ingredient_price).delete(handle_delete_ingredient_price),
+            )
+            .with_state(router_state);
+
+        Self { router }
+    }
+}
+
+fn check_is_admin(headers: &HeaderMap, jwt_secret: &str) -> Result<(), AppError> {
+    let claims = get_claims_from_headers(headers, jwt_secret).map_err(AppError::unauthenticated)?;
+
+    if claims.role != Role::Admin {
+        return Err(AppError::Forbidden(
+            Message::MaintainPricesForbidden.to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+async fn handle_get_ingredient_prices(
+    State(router_state): State<RouterState>,
+    Query(query): Query<IngredientPriceQuery>,
+) -> Result<(StatusCode, Json<ApiResponse<Vec<IngredientPrice>>>), AppError> {
+    let prices = router_state
+        .db_handler
+        .get_ingredient_prices(&query)
+        .await
+        .with_message(Message::GetIngredientPricesFailed)?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            data: Some(prices.into_iter().map(Into::into).collect()),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_create_ingredient_price(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    ValidatedJson(payload): ValidatedJson<IngredientPriceCreate>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    check_is_admin(&headers, &router_state.jwt_secret)?;
+
+    let id = router_state
+        .db_handler
+        .create_ingredient_price(payload)
+        .await
+        .with_message(Message::CreateIngredientPriceFailed)?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse {
+            data: Some(id),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_patch_ingredient_price(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(id): Path<String>,
+    ValidatedJson(payload): ValidatedJson<IngredientPricePatch>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    check_is_admin(&headers, &router_state.jwt_secret)?;
+
+    let update_count = router_state
+        .db_handler
+        .patch_ingredient_price(&id, payload)
+        .await
+        .with_message(Message::PatchIngredientPriceFailed { id: &id })?;
+
+    if update_count == 0 {
+        return Err(AppError::NotFound(
+            Message::IngredientPriceNotFound { id: &id }.to_string(),
+        ));
+    }
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(ApiResponse {
+            data: None,
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_delete_ingredient_price(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(id): Path<String>,
+) -> Result<(StatusCode, Json<ApiResponse<u64>>), AppError> {
+    check_is_admin(&headers, &router_state.jwt_secret)?;
+
+    let delete_count = router_state
+        .db_handler
+        .delete_ingredient_price(&id)
+        .await
+        .with_message(Message::DeleteIngredientPriceFailed { id: &id })?;
+
+    if delete_count == 0 {
+        return Err(AppError::NotFound(
+            Message::IngredientPriceNotFound { id: &id }.to_string(),
+        ));
+    }
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(ApiResponse {
+            data: Some(delete_count),
+            error: None,
+        }),
+    ))
+}
+
+#[cfg(test)]
+mod unit_tests_ingredient_prices_router {
+    use super::*;
+    use crate::test_utils::{
+        assert_status_codes, get_bearer_token, get_unconnected_db_handler, RequestTestCase,
+        TEST_JWT_SECRET,
+    };
+    use anyhow::Result;
+    use axum::http::Method;
+    use bson::oid::ObjectId;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn rejects_invalid_requests() -> Result<()> {
+        let router =
+            IngredientPricesRouter::new(get_unconnected_db_handler()?, TEST_JWT_SECRET).router;
+        let price_id = ObjectId::new().to_hex();
+        let admin_token = get_bearer_token(ObjectId::new(), Role::Admin)?;
+
+        let test_cases = vec![
+            RequestTestCase {
+                title: "Rejects prices without a token".into(),
+                method: Method::POST,
+                path: "/ingredient-prices".into(),
+                token: None,
+                body: Some(json!({"ingredient": "Oats", "price": 1.5, "currency": "EUR"})),
+                expected_status_code: StatusCode::UNAUTHORIZED,
+            },
+            RequestTestCase {
+                title: "Rejects prices by users".into(),
+                method: Method::POST,
+                path: "/ingredient-prices".into(),
+                token: Some(get_bearer_token(ObjectId::new(), Role::User)?),
+                body: Some(json!({"ingredient": "Oats", "price": 1.5, "currency": "EUR"})),
+                expected_status_code: StatusCode::FORBIDDEN,
+            },
+            RequestTestCase {
+                title: "Rejects deleting prices by users".into(),
+                method: Method::DELETE,
+                path: format!("/ingredient-prices/{price_id}"),
+                token: Some(get_bearer_token(ObjectId::new(), Role::User)?),
+                body: None,
+                expected_status_code: StatusCode::FORBIDDEN,
+            },
+            RequestTestCase {
+                title: "Rejects negative prices".into(),
+                method: Method::POST,
+                path: "/ingredient-prices".into(),
+                token: Some(admin_token.clone()),
+                body: Some(json!({"ingredient": "Oats", "price": -1.5, "currency": "EUR"})),
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            RequestTestCase {
+                title: "Rejects malformed price ids".into(),
+                method: Method::DELETE,
+                path: "/ingredient-prices/not-an-id".into(),
+                token: Some(admin_token),
+                body: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+        ];
+
+        assert_status_codes(router, test_cases).await
+    }
+}
@@ -0,0 +1,414 @@
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    routing::{get, patch, post},
+    Json, Router,
+};
+
+use crate::{
+    api::{
+        api_response::ApiResponse,
+        app_error::{AppError, WithMessage},
+        messages::Message,
+        routers::households_router::get_household_with_role,
+        services::token_service::{get_claims_from_headers, Claims},
+        validation::{Path, Query, ValidatedJson},
+    },
+    db::{
+        meal_plan_handler::MealPlanHandler, mongo_db_handler::MongoDbHandler,
+        recipe_handler::RecipeHandler,
+    },
+    model::{
+        household::{HouseholdRole, HouseholdScopeQuery, Owner},
+        meal_plan::{
+            IsoWeek, MealPlan, MealPlanCopyQuery, MealPlanEntryCreate, MealPlanEntryPatch,
+        },
+    },
+};
+
+#[derive(Clone)]
+struct RouterState {
+    jwt_secret: String,
+    db_handler: MongoDbHandler,
+}
+
+pub struct MealPlansRouter {
+    pub router: Router,
+}
+
+impl MealPlansRouter {
+    pub fn new(db_handler: MongoDbHandler, jwt_secret: &str) -> Self {
+        let base_path = "/me/meal-plans/{week}";
+
+        let router_state = RouterState {
+            db_handler,
+            jwt_secret: String::from(jwt_secret),
+        };
+
+        let router = Router::new()
+            .route(base_path, get(handle_get_meal_plan))
+            .route(
+                &format!("{base_path}/entries"),
+                post(handle_add_meal_plan_entry),
+            )
+            .route(
+                &format!("{base_path}/entries/{{entry_id}}"),
+                patch(handle_patch_meal_plan_entry).delete(handle_delete_meal_plan_entry),
+            )
+            .route(&format!("{base_path}/copy"), post(handle_copy_meal_plan))
+            .with_state(router_state);
+
+        Self { router }
+    }
+}
+
+// Members work on the meal plans of their household when it is selected
+async fn get_owner(
+    router_state: &RouterState,
+    claims: &Claims,
+    scope: &HouseholdScopeQuery,
+) -> Result<Owner, AppError> {
+    match &scope.household_id {
+        Some(household_id) => {
+            get_household_with_role(
+                &router_state.db_handler,
+                claims,
+                household_id,
+                HouseholdRole::Member,
+            )
+            .await?;
+
+            Ok(Owner {
+                user_id: claims.user_id.clone(),
+                household_id: Some(household_id.clone()),
+            })
+        }
+        None => Ok(Owner::user(&claims.user_id)),
+    }
+}
+
+async fn handle_get_meal_plan(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(week): Path<String>,
+    Query(scope): Query<HouseholdScopeQuery>,
+) -> Result<(StatusCode, Json<ApiResponse<MealPlan>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let owner = get_owner(&router_state, &claims, &scope).await?;
+
+    let week = week
+        .parse::<IsoWeek>()
+        .map_err(|err| AppError::Validation(err.to_string()))?;
+
+    let meal_plan = router_state
+        .db_handler
+        .get_meal_plan(&owner, &week)
+        .await
+        .with_message(Message::GetMealPlanFailed { week: &week })?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            data: Some(meal_plan),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_add_meal_plan_entry(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(week): Path<String>,
+    Query(scope): Query<HouseholdScopeQuery>,
+    ValidatedJson(payload): ValidatedJson<MealPlanEntryCreate>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let owner = get_owner(&router_state, &claims, &scope).await?;
+
+    let week = week
+        .parse::<IsoWeek>()
+        .map_err(|err| AppError::Validation(err.to_string()))?;
+
+    router_state
+        .db_handler
+        .get_visible_recipe(&payload.recipe_id, Some(&claims))
+        .await
+        .with_message(Message::GetRecipeFailed {
+            recipe_id: &payload.recipe_id,
+        })?;
+
+    let entry_id = router_state
+        .db_handler
+        .add_meal_plan_entry(&owner, &week, payload)
+        .await
+        .with_message(Message::AddMealPlanEntryFailed { week: &week })?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse {
+            data: Some(entry_id),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_patch_meal_plan_entry(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path((week, entry_id)): Path<(String, String)>,
+    Query(scope): Query<HouseholdScopeQuery>,
+    ValidatedJson(payload): ValidatedJson<MealPlanEntryPatch>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let owner = get_owner(&router_state, &claims, &scope).await?;
+
+    let week = week
+        .parse::<IsoWeek>()
+        .map_err(|err| AppError::Validation(err.to_string()))?;
+
+    let update_count = router_state
+        .db_handler
+        .patch_meal_plan_entry(&owner, &week, &entry_id, payload)
+        .await
+        .with_message(Message::PatchMealPlanEntryFailed {
+            entry_id: &entry_id,
+        })?;
+
+    if update_count == 0 {
+        return Err(AppError::NotFound(
+            Message::MealPlanEntryNotFound {
+                entry_id: &entry_id,
+            }
+            .to_string(),
+        ));
+    }
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(ApiResponse {
+            data: None,
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_delete_meal_plan_entry(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path((week, entry_id)): Path<(String, String)>,
+    Query(scope): Query<HouseholdScopeQuery>,
+) -> Result<(StatusCode, Json<ApiResponse<u64>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let owner = get_owner(&router_state, &claims, &scope).await?;
+
+    let week = week
+        .parse::<IsoWeek>()
+        .map_err(|err| AppError::Validation(err.to_string()))?;
+
+    let delete_count = router_state
+        .db_handler
+        .delete_meal_plan_entry(&owner, &week, &entry_id)
+        .await
+        .with_message(Message::DeleteMealPlanEntryFailed {
+            entry_id: &entry_id,
+        })?;
+
+    if delete_count == 0 {
+        return Err(AppError::NotFound(
+            Message::MealPlanEntryNotFound {
+                entry_id: &entry_id,
+            }
+            .to_string(),
+        ));
+    }
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(ApiResponse {
+            data: Some(delete_count),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_copy_meal_plan(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(week): Path<String>,
+    Query(scope): Query<HouseholdScopeQuery>,
+    Query(query): Query<MealPlanCopyQuery>,
+) -> Result<(StatusCode, Json<ApiResponse<usize>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let owner = get_owner(&router_state, &claims, &scope).await?;
+
+    let week = week
+        .parse::<IsoWeek>()
+        .map_err(|err| AppError::Validation(err.to_string()))?;
+
+    let from_week = match query.from {
+        Some(from) => from
+            .parse::<IsoWeek>()
+            .map_err(|err| AppError::Validation(err.to_string()))?,
+        None => week.previous(),
+    };
+
+    let copied_count = router_state
+        .db_handler
+        .copy_meal_plan(&owner, &from_week, &week)
+        .await
+        .with_message(Message::CopyMealPlanFailed {
+            from_week: &from_week,
+            week: &week,
+        })?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            data: Some(copied_count),
+            error: None,
+        }),
+    ))
+}
+
+#[cfg(test)]
+mod unit_tests_meal_plans_router {
+    use super::*;
+    use crate::{
+        db::household_handler::HouseholdHandler,
+        model::{
+            household::HouseholdCreate,
+            recipe::{RecipeMongoDb, RecipeStatus},
+            user::Role,
+        },
+        test_utils::{
+            assert_status_codes, db_clean_up, get_bearer_token, get_db_connection, get_db_handler,
+            get_random_recipe_db, get_unconnected_db_handler, RequestTestCase, TEST_JWT_SECRET,
+        },
+    };
+    use anyhow::Result;
+    use axum::http::Method;
+    use bson::oid::ObjectId;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn rejects_invalid_requests() -> Result<()> {
+        let router = MealPlansRouter::new(get_unconnected_db_handler()?, TEST_JWT_SECRET).router;
+        let token = get_bearer_token(ObjectId::new(), Role::User)?;
+        let recipe_id = ObjectId::new().to_hex();
+
+        let test_cases = vec![
+            RequestTestCase {
+                title: "Rejects reading a meal plan without a token".into(),
+                method: Method::GET,
+                path: "/me/meal-plans/2026-W42".into(),
+                token: None,
+                body: None,
+                expected_status_code: StatusCode::UNAUTHORIZED,
+            },
+            RequestTestCase {
+                title: "Rejects malformed weeks".into(),
+                method: Method::GET,
+                path: "/me/meal-plans/2026-42".into(),
+                token: Some(token.clone()),
+                body: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            RequestTestCase {
+                title: "Rejects malformed household ids".into(),
+                method: Method::GET,
+                path: "/me/meal-plans/2026-W42?householdId=not-an-id".into(),
+                token: Some(token.clone()),
+                body: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            RequestTestCase {
+                title: "Rejects entries without servings".into(),
+                method: Method::POST,
+                path: "/me/meal-plans/2026-W42/entries".into(),
+                token: Some(token),
+                body: Some(json!({
+                    "day": "Monday",
+                    "slot": "Dinner",
+                    "recipeId": recipe_id,
+                    "servings": 0
+                })),
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+        ];
+
+        assert_status_codes(router, test_cases).await
+    }
+
+    #[tokio::test]
+    async fn hides_meal_plans_and_recipes_of_others() -> Result<()> {
+        let db_handler = get_db_handler().await?;
+        let owner_id = ObjectId::new();
+        let household_id = db_handler
+            .create_household(
+                &owner_id.to_hex(),
+                HouseholdCreate {
+                    name: "Home".into(),
+                },
+            )
+            .await?;
+        let router = MealPlansRouter::new(db_handler, TEST_JWT_SECRET).router;
+
+        let mut recipe_db = get_random_recipe_db(None, Some(owner_id));
+        recipe_db.status = RecipeStatus::Draft;
+        get_db_connection()
+            .await?
+            .collection::<RecipeMongoDb>("recipes")
+            .insert_one(&recipe_db)
+            .await?;
+
+        let entry = json!({
+            "day": "Monday",
+            "slot": "Dinner",
+            "recipeId": recipe_db._id.to_hex(),
+            "servings": 2
+        });
+
+        let test_cases = vec![
+            RequestTestCase {
+                title: "Hides the meal plan of a household from strangers".into(),
+                method: Method::GET,
+                path: format!("/me/meal-plans/2026-W42?householdId={household_id}"),
+                token: Some(get_bearer_token(ObjectId::new(), Role::User)?),
+                body: None,
+                expected_status_code: StatusCode::NOT_FOUND,
+            },
+            RequestTestCase {
+                title: "Rejects planning a draft of another user".into(),
+                method: Method::POST,
+                path: "/me/meal-plans/2026-W42/entries".into(),
+                token: Some(get_bearer_token(ObjectId::new(), Role::User)?),
+                body: Some(entry.clone()),
+                expected_status_code: StatusCode::NOT_FOUND,
+            },
+            RequestTestCase {
+                title: "Lets owners plan their drafts for the household".into(),
+                method: Method::POST,
+                path: format!("/me/meal-plans/2026-W42/entries?householdId={household_id}"),
+                token: Some(get_bearer_token(owner_id, Role::User)?),
+                body: Some(entry),
+                expected_status_code: StatusCode::CREATED,
+            },
+        ];
+
+        assert_status_codes(router, test_cases).await?;
+
+        db_clean_up().await?;
+
+        Ok(())
+    }
+}
@@ -1,4 +1,20 @@
 pub mod auth_router;
+pub mod comments_router;
+pub mod events_router;
+pub mod favorites_router;
+pub mod follows_router;
 pub mod heart_beat_router;
+pub mod households_router;
+pub mod ingredient_prices_router;
+pub mod meal_plans_router;
+pub mod notifications_router;
+pub mod pantry_router;
+pub mod preferences_router;
+pub mod recipe_revisions_router;
 pub mod recipes_router;
+pub mod reports_router;
+pub mod reviews_router;
+pub mod seasons_router;
+pub mod shopping_lists_router;
+pub mod substitutions_router;
 pub mod users_router;
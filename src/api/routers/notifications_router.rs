@@ -0,0 +1,242 @@
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    routing::{get, post},
+    Json, Router,
+};
+
+use crate::{
+    api::{
+        api_response::ApiResponse,
+        app_error::{AppError, WithMessage},
+        messages::Message,
+        services::token_service::get_claims_from_headers,
+        validation::{Path, Query},
+    },
+    db::{mongo_db_handler::MongoDbHandler, notification_handler::NotificationHandler},
+    model::{
+        notification::{Notification, NotificationQuery},
+        pagination::{Page, Pagination},
+    },
+};
+
+#[derive(Clone)]
+struct RouterState {
+    jwt_secret: String,
+    db_handler: MongoDbHandler,
+}
+
+pub struct NotificationsRouter {
+    pub router: Router,
+}
+
+impl NotificationsRouter {
+    pub fn new(db_handler: MongoDbHandler, jwt_secret: &str) -> Self {
+        let base_path = "/me/notifications";
+
+        let router_state = RouterState {
+            db_handler,
+            jwt_secret: String::from(jwt_secret),
+        };
+
+        let router = Router::new()
+            .route(base_path, get(handle_get_notifications))
+            .route(
+                &format!("{base_path}/read-all"),
+                post(handle_read_all_notifications),
+            )
+            .route(
+                &format!("{base_path}/{{id}}/read"),
+                post(handle_read_notification),
+            )
+            .with_state(router_state);
+
+        Self { router }
+    }
+}
+
+async fn handle_get_notifications(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Query(query): Query<NotificationQuery>,
+    Query(pagination): Query<Pagination>,
+) -> Result<(StatusCode, Json<ApiResponse<Page<Notification>>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let notifications = router_state
+        .db_handler
+        .get_notifications(&claims.user_id, &query, &pagination)
+        .await
+        .with_message(Message::GetNotificationsFailed)?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            data: Some(notifications),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_read_notification(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(notification_id): Path<String>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let update_count = router_state
+        .db_handler
+        .mark_notification_read(&claims.user_id, &notification_id)
+        .await
+        .with_message(Message::MarkNotificationReadFailed {
+            notification_id: &notification_id,
+        })?;
+
+    if update_count == 0 {
+        return Err(AppError::NotFound(
+            Message::NotificationNotFound {
+                notification_id: &notification_id,
+            }
+            .to_string(),
+        ));
+    }
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(ApiResponse {
+            data: None,
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_read_all_notifications(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+) -> Result<(StatusCode, Json<ApiResponse<u64>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let read_count = router_state
+        .db_handler
+        .mark_all_notifications_read(&claims.user_id)
+        .await
+        .with_message(Message::MarkNotificationsReadFailed)?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            data: Some(read_count),
+            error: None,
+        }),
+    ))
+}
+
+#[cfg(test)]
+mod unit_tests_notifications_router {
+    use super::*;
+    use crate::{
+        model::{
+            notification::{NotificationCreate, NotificationKind},
+            user::{Role, UserMongoDb},
+        },
+        test_utils::{
+            assert_status_codes, db_clean_up, get_bearer_token, get_db_connection, get_db_handler,
+            get_random_user_db, get_unconnected_db_handler, RequestTestCase, TEST_JWT_SECRET,
+        },
+    };
+    use anyhow::{anyhow, Result};
+    use axum::http::Method;
+    use bson::oid::ObjectId;
+
+    #[tokio::test]
+    async fn rejects_invalid_requests() -> Result<()> {
+        let router =
+            NotificationsRouter::new(get_unconnected_db_handler()?, TEST_JWT_SECRET).router;
+
+        let test_cases = vec![
+            RequestTestCase {
+                title: "Rejects listing notifications without a token".into(),
+                method: Method::GET,
+                path: "/me/notifications".into(),
+                token: None,
+                body: None,
+                expected_status_code: StatusCode::UNAUTHORIZED,
+            },
+            RequestTestCase {
+                title: "Rejects reading all notifications without a token".into(),
+                method: Method::POST,
+                path: "/me/notifications/read-all".into(),
+                token: None,
+                body: None,
+                expected_status_code: StatusCode::UNAUTHORIZED,
+            },
+            RequestTestCase {
+                title: "Rejects malformed notification ids".into(),
+                method: Method::POST,
+                path: "/me/notifications/not-an-id/read".into(),
+                token: Some(get_bearer_token(ObjectId::new(), Role::User)?),
+                body: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+        ];
+
+        assert_status_codes(router, test_cases).await
+    }
+
+    #[tokio::test]
+    async fn hides_notifications_of_others() -> Result<()> {
+        let db_handler = get_db_handler().await?;
+
+        let user_db = get_random_user_db(None);
+        get_db_connection()
+            .await?
+            .collection::<UserMongoDb>("users")
+            .insert_one(&user_db)
+            .await?;
+
+        let notification = db_handler
+            .create_notification(
+                &user_db._id.to_hex(),
+                NotificationCreate {
+                    kind: NotificationKind::NewFollower,
+                    actor_id: Some(ObjectId::new().to_hex()),
+                    recipe_id: None,
+                    report_id: None,
+                },
+            )
+            .await?
+            .ok_or(anyhow!("Notification was not created"))?;
+        let router = NotificationsRouter::new(db_handler, TEST_JWT_SECRET).router;
+
+        let path = format!("/me/notifications/{}/read", notification._id.to_hex());
+
+        let test_cases = vec![
+            RequestTestCase {
+                title: "Rejects reading a notification of another user".into(),
+                method: Method::POST,
+                path: path.clone(),
+                token: Some(get_bearer_token(ObjectId::new(), Role::User)?),
+                body: None,
+                expected_status_code: StatusCode::NOT_FOUND,
+            },
+            RequestTestCase {
+                title: "Reads a notification of the user".into(),
+                method: Method::POST,
+                path,
+                token: Some(get_bearer_token(user_db._id, Role::User)?),
+                body: None,
+                expected_status_code: StatusCode::NO_CONTENT,
+            },
+        ];
+
+        assert_status_codes(router, test_cases).await?;
+
+        db_clean_up().await?;
+
+        Ok(())
+    }
+}
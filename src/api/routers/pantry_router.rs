@@ -0,0 +1,301 @@
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    routing::{get, patch},
+    Json, Router,
+};
+use chrono::Utc;
+
+use crate::{
+    api::{
+        api_response::ApiResponse,
+        app_error::{AppError, WithMessage},
+        messages::Message,
+        services::{pantry_service::match_pantry_recipes, token_service::get_claims_from_headers},
+        validation::{Path, Query, ValidatedJson},
+    },
+    db::{mongo_db_handler::MongoDbHandler, pantry_handler::PantryHandler},
+    model::pantry::{
+        PantryItem, PantryItemCreate, PantryItemPatch, PantryMatchQuery, PantryRecipeMatch,
+    },
+};
+
+const DEFAULT_MATCH_LIMIT: usize = 20;
+
+#[derive(Clone)]
+struct RouterState {
+    jwt_secret: String,
+    db_handler: MongoDbHandler,
+}
+
+pub struct PantryRouter {
+    pub router: Router,
+}
+
+impl PantryRouter {
+    pub fn new(db_handler: MongoDbHandler, jwt_secret: &str) -> Self {
+        let base_path = "/me/pantry";
+
+        let router_state = RouterState {
+            db_handler,
+            jwt_secret: String::from(jwt_secret),
+        };
+
+        let router = Router::new()
+            .route(
+                base_path,
+                get(handle_get_pantry_items).post(handle_add_pantry_item),
+            )
+            .route(
+                &format!("{base_path}/matches"),
+                get(handle_get_pantry_matches),
+            )
+            .route(
+                &format!("{base_path}/{{item_id}}"),
+                patch(handle_patch_pantry_item).delete(handle_delete_pantry_item),
+            )
+            .with_state(router_state);
+
+        Self { router }
+    }
+}
+
+async fn handle_get_pantry_items(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+) -> Result<(StatusCode, Json<ApiResponse<Vec<PantryItem>>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let items = router_state
+        .db_handler
+        .get_pantry_items(&claims.user_id)
+        .await
+        .with_message(Message::GetPantryItemsFailed)?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            data: Some(items.into_iter().map(Into::into).collect()),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_add_pantry_item(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    ValidatedJson(payload): ValidatedJson<PantryItemCreate>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let item_id = router_state
+        .db_handler
+        .add_pantry_item(&claims.user_id, payload)
+        .await
+        .with_message(Message::AddPantryItemFailed)?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse {
+            data: Some(item_id),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_patch_pantry_item(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(item_id): Path<String>,
+    ValidatedJson(payload): ValidatedJson<PantryItemPatch>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let update_count = router_state
+        .db_handler
+        .patch_pantry_item(&claims.user_id, &item_id, payload)
+        .await
+        .with_message(Message::PatchPantryItemFailed { item_id: &item_id })?;
+
+    if update_count == 0 {
+        return Err(AppError::NotFound(
+            Message::PantryItemNotFound { item_id: &item_id }.to_string(),
+        ));
+    }
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(ApiResponse {
+            data: None,
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_delete_pantry_item(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(item_id): Path<String>,
+) -> Result<(StatusCode, Json<ApiResponse<u64>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let delete_count = router_state
+        .db_handler
+        .delete_pantry_item(&claims.user_id, &item_id)
+        .await
+        .with_message(Message::DeletePantryItemFailed { item_id: &item_id })?;
+
+    if delete_count == 0 {
+        return Err(AppError::NotFound(
+            Message::PantryItemNotFound { item_id: &item_id }.to_string(),
+        ));
+    }
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(ApiResponse {
+            data: Some(delete_count),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_get_pantry_matches(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Query(query): Query<PantryMatchQuery>,
+) -> Result<(StatusCode, Json<ApiResponse<Vec<PantryRecipeMatch>>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let pantry_items = router_state
+        .db_handler
+        .get_pantry_items(&claims.user_id)
+        .await
+        .with_message(Message::GetPantryItemsFailed)?;
+
+    let recipes = router_state
+        .db_handler
+        .get_matchable_recipes(&claims.user_id)
+        .await
+        .with_message(Message::GetRecipesFailed)?;
+
+    let mut matches = match_pantry_recipes(&pantry_items, recipes, Utc::now().date_naive());
+    matches.truncate(query.limit.unwrap_or(DEFAULT_MATCH_LIMIT));
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            data: Some(matches),
+            error: None,
+        }),
+    ))
+}
+
+#[cfg(test)]
+mod unit_tests_pantry_router {
+    use super::*;
+    use crate::{
+        model::user::Role,
+        test_utils::{
+            assert_status_codes, db_clean_up, get_bearer_token, get_db_handler,
+            get_unconnected_db_handler, RequestTestCase, TEST_JWT_SECRET,
+        },
+    };
+    use anyhow::Result;
+    use axum::http::Method;
+    use bson::oid::ObjectId;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn rejects_invalid_requests() -> Result<()> {
+        let router = PantryRouter::new(get_unconnected_db_handler()?, TEST_JWT_SECRET).router;
+        let token = get_bearer_token(ObjectId::new(), Role::User)?;
+
+        let test_cases = vec![
+            RequestTestCase {
+                title: "Rejects listing the pantry without a token".into(),
+                method: Method::GET,
+                path: "/me/pantry".into(),
+                token: None,
+                body: None,
+                expected_status_code: StatusCode::UNAUTHORIZED,
+            },
+            RequestTestCase {
+                title: "Rejects matching recipes without a token".into(),
+                method: Method::GET,
+                path: "/me/pantry/matches".into(),
+                token: None,
+                body: None,
+                expected_status_code: StatusCode::UNAUTHORIZED,
+            },
+            RequestTestCase {
+                title: "Rejects malformed expiry dates".into(),
+                method: Method::POST,
+                path: "/me/pantry".into(),
+                token: Some(token.clone()),
+                body: Some(json!({"name": "Oats", "expiresOn": "tomorrow"})),
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            RequestTestCase {
+                title: "Rejects malformed item ids".into(),
+                method: Method::DELETE,
+                path: "/me/pantry/not-an-id".into(),
+                token: Some(token),
+                body: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+        ];
+
+        assert_status_codes(router, test_cases).await
+    }
+
+    #[tokio::test]
+    async fn hides_pantry_items_of_others() -> Result<()> {
+        let db_handler = get_db_handler().await?;
+        let user_id = ObjectId::new();
+        let item_id = db_handler
+            .add_pantry_item(
+                &user_id.to_hex(),
+                PantryItemCreate {
+                    name: "Oats".into(),
+                    quantity: None,
+                    unit: None,
+                    expires_on: None,
+                },
+            )
+            .await?;
+        let router = PantryRouter::new(db_handler, TEST_JWT_SECRET).router;
+
+        let path = format!("/me/pantry/{item_id}");
+
+        let test_cases = vec![
+            RequestTestCase {
+                title: "Rejects deleting a pantry item of another user".into(),
+                method: Method::DELETE,
+                path: path.clone(),
+                token: Some(get_bearer_token(ObjectId::new(), Role::User)?),
+                body: None,
+                expected_status_code: StatusCode::NOT_FOUND,
+            },
+            RequestTestCase {
+                title: "Deletes a pantry item of the user".into(),
+                method: Method::DELETE,
+                path,
+                token: Some(get_bearer_token(user_id, Role::User)?),
+                body: None,
+                expected_status_code: StatusCode::NO_CONTENT,
+            },
+        ];
+
+        assert_status_codes(router, test_cases).await?;
+
+        db_clean_up().await?;
+
+        Ok(())
+    }
+}
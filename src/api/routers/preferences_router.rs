@@ -0,0 +1,174 @@
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    routing::get,
+    Json, Router,
+};
+
+use crate::{
+    api::{
+        api_response::ApiResponse,
+        app_error::{AppError, WithMessage},
+        messages::Message,
+        services::token_service::get_claims_from_headers,
+        validation::ValidatedJson,
+    },
+    db::{mongo_db_handler::MongoDbHandler, user_handler::UserHandler},
+    model::user::{UserPreferences, UserPreferencesPatch},
+};
+
+#[derive(Clone)]
+struct RouterState {
+    jwt_secret: String,
+    db_handler: MongoDbHandler,
+}
+
+pub struct PreferencesRouter {
+    pub router: Router,
+}
+
+impl PreferencesRouter {
+    pub fn new(db_handler: MongoDbHandler, jwt_secret: &str) -> Self {
+        let router_state = RouterState {
+            db_handler,
+            jwt_secret: String::from(jwt_secret),
+        };
+
+        let router = Router::new()
+            .route(
+                "/me/preferences",
+                get(handle_get_preferences).patch(handle_patch_preferences),
+            )
+            .with_state(router_state);
+
+        Self { router }
+    }
+}
+
+async fn handle_get_preferences(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+) -> Result<(StatusCode, Json<ApiResponse<UserPreferences>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    match router_state
+        .db_handler
+        .get_user_preferences(&claims.user_id)
+        .await
+        .with_message(Message::GetPreferencesFailed)?
+    {
+        Some(preferences) => Ok((
+            StatusCode::OK,
+            Json(ApiResponse {
+                data: Some(preferences),
+                error: None,
+            }),
+        )),
+        None => Err(AppError::NotFound(Message::UserNotFound.to_string())),
+    }
+}
+
+async fn handle_patch_preferences(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    ValidatedJson(payload): ValidatedJson<UserPreferencesPatch>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let update_count = router_state
+        .db_handler
+        .patch_user_preferences(&claims.user_id, payload)
+        .await
+        .with_message(Message::PatchPreferencesFailed)?;
+
+    if update_count == 0 {
+        return Err(AppError::NotFound(Message::UserNotFound.to_string()));
+    }
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(ApiResponse {
+            data: None,
+            error: None,
+        }),
+    ))
+}
+
+#[cfg(test)]
+mod unit_tests_preferences_router {
+    use super::*;
+    use crate::{
+        model::user::Role,
+        test_utils::{
+            assert_status_codes, get_bearer_token, get_db_handler, get_unconnected_db_handler,
+            RequestTestCase, TEST_JWT_SECRET,
+        },
+    };
+    use anyhow::Result;
+    use axum::http::Method;
+    use bson::oid::ObjectId;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn rejects_invalid_requests() -> Result<()> {
+        let router = PreferencesRouter::new(get_unconnected_db_handler()?, TEST_JWT_SECRET).router;
+
+        let test_cases = vec![
+            RequestTestCase {
+                title: "Rejects reading preferences without a token".into(),
+                method: Method::GET,
+                path: "/me/preferences".into(),
+                token: None,
+                body: None,
+                expected_status_code: StatusCode::UNAUTHORIZED,
+            },
+            RequestTestCase {
+                title: "Rejects changing preferences without a token".into(),
+                method: Method::PATCH,
+                path: "/me/preferences".into(),
+                token: None,
+                body: Some(json!({"locale": "de"})),
+                expected_status_code: StatusCode::UNAUTHORIZED,
+            },
+            RequestTestCase {
+                title: "Rejects malformed locales".into(),
+                method: Method::PATCH,
+                path: "/me/preferences".into(),
+                token: Some(get_bearer_token(ObjectId::new(), Role::User)?),
+                body: Some(json!({"locale": "de/../x"})),
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+        ];
+
+        assert_status_codes(router, test_cases).await
+    }
+
+    #[tokio::test]
+    async fn rejects_unknown_users() -> Result<()> {
+        let router = PreferencesRouter::new(get_db_handler().await?, TEST_JWT_SECRET).router;
+        let token = get_bearer_token(ObjectId::new(), Role::User)?;
+
+        let test_cases = vec![
+            RequestTestCase {
+                title: "Rejects reading preferences of unknown users".into(),
+                method: Method::GET,
+                path: "/me/preferences".into(),
+                token: Some(token.clone()),
+                body: None,
+                expected_status_code: StatusCode::NOT_FOUND,
+            },
+            RequestTestCase {
+                title: "Rejects changing preferences of unknown users".into(),
+                method: Method::PATCH,
+                path: "/me/preferences".into(),
+                token: Some(token),
+                body: Some(json!({"locale": "de"})),
+                expected_status_code: StatusCode::NOT_FOUND,
+            },
+        ];
+
+        assert_status_codes(router, test_cases).await
+    }
+}
@@ -0,0 +1,327 @@
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    routing::{get, post},
+    Json, Router,
+};
+use tracing::info;
+
+use super::recipes_router::{check_is_not_removed, is_author_or_admin};
+use crate::{
+    api::{
+        api_response::ApiResponse,
+        app_error::{AppError, WithMessage},
+        messages::Message,
+        services::{
+            revision_service::diff_recipe,
+            token_service::{get_claims_from_headers, Claims},
+        },
+        validation::Path,
+    },
+    db::{
+        generic_handler::GenericHandler, mongo_db_handler::MongoDbHandler,
+        recipe_revision_handler::RecipeRevisionHandler,
+    },
+    model::{
+        recipe::{Recipe, RecipeMongoDb},
+        recipe_revision::{RecipeRevision, RecipeRevisionDetail},
+    },
+};
+
+#[derive(Clone)]
+struct RouterState {
+    jwt_secret: String,
+    db_handler: MongoDbHandler,
+}
+
+pub struct RecipeRevisionsRouter {
+    pub router: Router,
+}
+
+impl RecipeRevisionsRouter {
+    pub fn new(db_handler: MongoDbHandler, jwt_secret: &str) -> Self {
+        let base_path = "/recipes/{id}/revisions";
+
+        let router_state = RouterState {
+            db_handler,
+            jwt_secret: String::from(jwt_secret),
+        };
+
+        let router = Router::new()
+            .route(base_path, get(handle_get_revisions))
+            .route(
+                &format!("{base_path}/{{revision}}"),
+                get(handle_get_revision),
+            )
+            .route(
+                &format!("{base_path}/{{revision}}/restore"),
+                post(handle_restore_revision),
+            )
+            .with_state(router_state);
+
+        Self { router }
+    }
+}
+
+// Revisions may hold unpublished content, so only the author and admins can see them
+async fn get_recipe_for_author(
+    router_state: &RouterState,
+    headers: &HeaderMap,
+    recipe_id: &str,
+) -> Result<(Claims, Recipe), AppError> {
+    let claims = get_claims_from_headers(headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let recipe = router_state
+        .db_handler
+        .get_by_id::<RecipeMongoDb, Recipe>(recipe_id, "recipes")
+        .await
+        .with_message(Message::GetRecipeFailed {
+            recipe_id: &recipe_id,
+        })?;
+
+    if !is_author_or_admin(&recipe, &claims) {
+        let err_msg = Message::AccessRevisionsForbidden {
+            recipe_id: &recipe_id,
+        }
+        .to_string();
+        info!("{err_msg}");
+
+        return Err(AppError::Forbidden(err_msg));
+    }
+
+    Ok((claims, recipe))
+}
+
+async fn handle_get_revisions(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(recipe_id): Path<String>,
+) -> Result<(StatusCode, Json<ApiResponse<Vec<RecipeRevision>>>), AppError> {
+    get_recipe_for_author(&router_state, &headers, &recipe_id).await?;
+
+    let revisions = router_state
+        .db_handler
+        .get_recipe_revisions(&recipe_id)
+        .await
+        .with_message(Message::GetRevisionsFailed {
+            recipe_id: &recipe_id,
+        })?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            data: Some(revisions.into_iter().map(Into::into).collect()),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_get_revision(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path((recipe_id, revision)): Path<(String, u32)>,
+) -> Result<(StatusCode, Json<ApiResponse<RecipeRevisionDetail>>), AppError> {
+    let (_, recipe) = get_recipe_for_author(&router_state, &headers, &recipe_id).await?;
+
+    match router_state
+        .db_handler
+        .get_recipe_revision(&recipe_id, revision)
+        .await
+        .with_message(Message::GetRevisionFailed {
+            revision: &revision,
+            recipe_id: &recipe_id,
+        })? {
+        Some(revision_db) => {
+            let revision: RecipeRevision = revision_db.into();
+            let diff = diff_recipe(&revision.snapshot, &(&recipe).into());
+
+            Ok((
+                StatusCode::OK,
+                Json(ApiResponse {
+                    data: Some(RecipeRevisionDetail { revision, diff }),
+                    error: None,
+                }),
+            ))
+        }
+        None => Err(AppError::NotFound(
+            Message::RevisionNotFound {
+                revision: &revision,
+                recipe_id: &recipe_id,
+            }
+            .to_string(),
+        )),
+    }
+}
+
+async fn handle_restore_revision(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path((recipe_id, revision)): Path<(String, u32)>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    let (claims, recipe) = get_recipe_for_author(&router_state, &headers, &recipe_id).await?;
+
+    check_is_not_removed(&recipe, &claims)?;
+
+    let snapshot = router_state
+        .db_handler
+        .get_recipe_revision(&recipe_id, revision)
+        .await
+        .with_message(Message::GetRevisionFailed {
+            revision: &revision,
+            recipe_id: &recipe_id,
+        })?
+        .ok_or_else(|| {
+            AppError::NotFound(
+                Message::RevisionNotFound {
+                    revision: &revision,
+                    recipe_id: &recipe_id,
+                }
+                .to_string(),
+            )
+        })?
+        .snapshot;
+
+    // Keep the current content as a revision so the restore can be undone
+    router_state
+        .db_handler
+        .create_recipe_revision(&recipe_id, &claims.user_id, (&recipe).into())
+        .await
+        .with_message(Message::SaveRevisionFailed {
+            recipe_id: &recipe_id,
+        })?;
+
+    let update_count = router_state
+        .db_handler
+        .restore_recipe_snapshot(&recipe_id, snapshot)
+        .await
+        .with_message(Message::RestoreRevisionFailed {
+            revision: &revision,
+            recipe_id: &recipe_id,
+        })?;
+
+    if update_count == 0 {
+        return Err(AppError::NotFound(
+            Message::RecipeNotFound {
+                recipe_id: &recipe_id,
+            }
+            .to_string(),
+        ));
+    }
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(ApiResponse {
+            data: None,
+            error: None,
+        }),
+    ))
+}
+
+#[cfg(test)]
+mod unit_tests_recipe_revisions_router {
+    use super::*;
+    use crate::{
+        model::user::Role,
+        test_utils::{
+            assert_status_codes, db_clean_up, get_bearer_token, get_db_connection, get_db_handler,
+            get_random_recipe_db, get_unconnected_db_handler, RequestTestCase, TEST_JWT_SECRET,
+        },
+    };
+    use anyhow::Result;
+    use axum::http::Method;
+    use bson::oid::ObjectId;
+
+    #[tokio::test]
+    async fn rejects_invalid_requests() -> Result<()> {
+        let router =
+            RecipeRevisionsRouter::new(get_unconnected_db_handler()?, TEST_JWT_SECRET).router;
+        let recipe_id = ObjectId::new().to_hex();
+        let token = get_bearer_token(ObjectId::new(), Role::User)?;
+
+        let test_cases = vec![
+            RequestTestCase {
+                title: "Rejects listing revisions without a token".into(),
+                method: Method::GET,
+                path: format!("/recipes/{recipe_id}/revisions"),
+                token: None,
+                body: None,
+                expected_status_code: StatusCode::UNAUTHORIZED,
+            },
+            RequestTestCase {
+                title: "Rejects restoring revisions without a token".into(),
+                method: Method::POST,
+                path: format!("/recipes/{recipe_id}/revisions/1/restore"),
+                token: None,
+                body: None,
+                expected_status_code: StatusCode::UNAUTHORIZED,
+            },
+            RequestTestCase {
+                title: "Rejects malformed recipe ids".into(),
+                method: Method::GET,
+                path: "/recipes/not-an-id/revisions".into(),
+                token: Some(token.clone()),
+                body: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            RequestTestCase {
+                title: "Rejects malformed revision numbers".into(),
+                method: Method::GET,
+                path: format!("/recipes/{recipe_id}/revisions/latest"),
+                token: Some(token),
+                body: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+        ];
+
+        assert_status_codes(router, test_cases).await
+    }
+
+    #[tokio::test]
+    async fn hides_revisions_from_other_users() -> Result<()> {
+        let router = RecipeRevisionsRouter::new(get_db_handler().await?, TEST_JWT_SECRET).router;
+
+        let author_id = ObjectId::new();
+        let recipe_db = get_random_recipe_db(None, Some(author_id));
+        get_db_connection()
+            .await?
+            .collection::<RecipeMongoDb>("recipes")
+            .insert_one(&recipe_db)
+            .await?;
+
+        let path = format!("/recipes/{}/revisions", recipe_db._id.to_hex());
+
+        let test_cases = vec![
+            RequestTestCase {
+                title: "Hides the revisions of a published recipe from other users".into(),
+                method: Method::GET,
+                path: path.clone(),
+                token: Some(get_bearer_token(ObjectId::new(), Role::User)?),
+                body: None,
+                expected_status_code: StatusCode::FORBIDDEN,
+            },
+            RequestTestCase {
+                title: "Shows the revisions to the author".into(),
+                method: Method::GET,
+                path: path.clone(),
+                token: Some(get_bearer_token(author_id, Role::User)?),
+                body: None,
+                expected_status_code: StatusCode::OK,
+            },
+            RequestTestCase {
+                title: "Shows the revisions to admins".into(),
+                method: Method::GET,
+                path,
+                token: Some(get_bearer_token(ObjectId::new(), Role::Admin)?),
+                body: None,
+                expected_status_code: StatusCode::OK,
+            },
+        ];
+
+        assert_status_codes(router, test_cases).await?;
+
+        db_clean_up().await?;
+
+        Ok(())
+    }
+}
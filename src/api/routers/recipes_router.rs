@@ -1,19 +1,66 @@
 use axum::{
     extract::State,
-    http::{HeaderMap, StatusCode},
-    routing::get,
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    routing::{get, post, put},
     Json, Router,
 };
+use std::collections::BTreeMap;
+
+use chrono::{Datelike, Utc};
+use futures_util::TryStreamExt;
 
 use crate::{
-    api::{api_response::ApiResponse, services::token_service::decode_jwt},
-    db::mongo_db_handler::MongoDbHandler,
+    api::{
+        api_response::ApiResponse,
+        app_error::{AppError, WithMessage},
+        messages::Message,
+        services::{
+            cooklang_service::{export_cooklang, import_cooklang},
+            cost_service::estimate_cost,
+            event_service::EventBus,
+            import_service::import_recipe,
+            jsonld_service::export_json_ld,
+            locale_service::{
+                get_requested_locales, is_valid_locale, localize_recipe, normalize_locale,
+            },
+            markdown_service::{export_markdown, import_markdown},
+            notification_service::notify,
+            season_service::{get_season_region, get_seasonality_score},
+            step_analysis_service::analyze_recipe,
+            substitution_service::suggest_substitutions,
+            token_service::{get_claims_from_headers, Claims},
+            vegan_service::check_vegan,
+        },
+        validation::{Path, Query, Validate, ValidatedJson},
+    },
+    db::{
+        generic_handler::GenericHandler, ingredient_price_handler::IngredientPriceHandler,
+        mongo_db_handler::MongoDbHandler, pantry_handler::PantryHandler,
+        recipe_handler::RecipeHandler, recipe_revision_handler::RecipeRevisionHandler,
+        substitution_handler::SubstitutionHandler, user_handler::UserHandler,
+    },
+    model::{
+        cooking::CookingMode,
+        ingredient_price::{CostQuery, IngredientPriceMongoDb, IngredientPriceQuery},
+        notification::{NotificationCreate, NotificationKind},
+        pagination::{Page, Pagination},
+        recipe::{
+            Recipe, RecipeCreate, RecipeDetail, RecipeFormat, RecipeFormatQuery,
+            RecipeImportReport, RecipeMongoDb, RecipePatch, RecipeQuery, RecipeStatus,
+            RecipeTranslation,
+        },
+        season::SeasonQuery,
+        substitution::{SubstitutionQuery, SuggestedSubstitution},
+        user::Role,
+    },
 };
 
 #[derive(Clone)]
 struct RouterState {
     jwt_secret: String,
     db_handler: MongoDbHandler,
+    event_bus: EventBus,
 }
 
 pub struct RecipesRouter {
@@ -21,73 +68,996 @@ pub struct RecipesRouter {
 }
 
 impl RecipesRouter {
-    pub fn new(db_handler: MongoDbHandler, jwt_secret: &str) -> Self {
+    pub fn new(db_handler: MongoDbHandler, jwt_secret: &str, event_bus: EventBus) -> Self {
         let base_path = "/recipes";
 
         let router_state = RouterState {
             db_handler,
             jwt_secret: String::from(jwt_secret),
+            event_bus,
         };
 
         let router = Router::new()
-            .route(base_path, get(handle_get_recipes))
+            .route(
+                base_path,
+                get(handle_get_recipes).post(handle_create_recipe),
+            )
+            .route(&format!("{base_path}/import"), post(handle_import_recipe))
+            .route(
+                &format!("{base_path}/{{id}}"),
+                get(handle_get_recipe_by_id)
+                    .patch(handle_patch_recipe)
+                    .delete(handle_delete_recipe),
+            )
+            .route(
+                &format!("{base_path}/{{id}}/cook"),
+                get(handle_get_cooking_mode),
+            )
+            .route(
+                &format!("{base_path}/{{id}}/fork"),
+                post(handle_fork_recipe),
+            )
+            .route(&format!("{base_path}/{{id}}/forks"), get(handle_get_forks))
+            .route(
+                &format!("{base_path}/{{id}}/jsonld"),
+                get(handle_get_recipe_json_ld),
+            )
+            .route(
+                &format!("{base_path}/{{id}}/publish"),
+                post(handle_publish_recipe),
+            )
+            .route(
+                &format!("{base_path}/{{id}}/translations"),
+                get(handle_get_translations),
+            )
+            .route(
+                &format!("{base_path}/{{id}}/translations/{{locale}}"),
+                put(handle_put_translation).delete(handle_delete_translation),
+            )
+            .route(
+                &format!("{base_path}/{{id}}/unpublish"),
+                post(handle_unpublish_recipe),
+            )
             .with_state(router_state);
 
         Self { router }
     }
 }
 
+pub fn is_author_or_admin(recipe: &Recipe, claims: &Claims) -> bool {
+    recipe.author_id == claims.user_id || claims.role == Role::Admin
+}
+
+/// Recipes removed by a moderator are locked for everyone but admins
+pub fn check_is_not_removed(recipe: &Recipe, claims: &Claims) -> Result<(), AppError> {
+    if recipe.status == RecipeStatus::Removed && claims.role != Role::Admin {
+        return Err(AppError::Forbidden(
+            Message::RecipeRemovedByModerator {
+                recipe_id: &recipe.id,
+            }
+            .to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
 async fn handle_get_recipes(
     State(router_state): State<RouterState>,
     headers: HeaderMap,
-) -> (StatusCode, Json<ApiResponse<String>>) {
-    println!("{}", router_state.db_handler.db.name());
-
-    let token = match headers.get("Authorization") {
-        Some(t) => match t.to_str() {
-            Ok(t) => t.split("Bearer").collect::<Vec<&str>>()[1].trim(),
-            Err(e) => {
-                println!("FAILED TO GET TOKEN");
-                return (
-                    StatusCode::UNAUTHORIZED,
-                    Json(ApiResponse {
-                        data: None,
-                        error: format!("Failed to get token from headers: {e}"),
-                    }),
-                );
+    Query(query): Query<RecipeQuery>,
+    Query(pagination): Query<Pagination>,
+) -> Result<(StatusCode, Json<ApiResponse<Page<Recipe>>>), AppError> {
+    // Signed in users also see their own unpublished recipes
+    let viewer_id = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .ok()
+        .map(|claims| claims.user_id);
+
+    let cost_query = query.get_cost_query();
+    let prices = get_prices(&router_state.db_handler, &cost_query)
+        .await
+        .with_message(Message::EstimateRecipeCostsFailed)?;
+    let currency = cost_query.currency();
+    let season_region = get_season_region(query.region.as_deref());
+    let month = Utc::now().month();
+    let add_computed_fields = |recipe: &mut Recipe| {
+        recipe.estimated_cost = estimate_cost(recipe, &prices, &currency);
+        recipe.seasonality_score = get_seasonality_score(recipe, season_region, month);
+    };
+
+    let mut page = match query.has_computed_filters() {
+        false => {
+            let mut page = router_state
+                .db_handler
+                .get_recipes(&query, viewer_id.as_deref(), &pagination)
+                .await
+                .with_message(Message::GetRecipesFailed)?;
+            page.items.iter_mut().for_each(add_computed_fields);
+
+            page
+        }
+        // Matches are only known once computed, so they are counted while streaming through
+        // the candidates and only the requested page is kept
+        true => {
+            let mut candidates = router_state
+                .db_handler
+                .get_recipe_candidates(&query, viewer_id.as_deref())
+                .await
+                .with_message(Message::GetRecipesFailed)?;
+
+            let mut items = vec![];
+            let mut total = 0;
+            while let Some(recipe_db) = candidates
+                .try_next()
+                .await
+                .with_message(Message::GetRecipesFailed)?
+            {
+                let mut recipe = Recipe::from(recipe_db);
+                add_computed_fields(&mut recipe);
+
+                if !query.matches_computed_filters(&recipe) {
+                    continue;
+                }
+
+                if total >= pagination.skip() && (items.len() as u64) < pagination.limit() {
+                    items.push(recipe);
+                }
+                total += 1;
+            }
+
+            Page {
+                items,
+                page: pagination.page.max(1),
+                page_size: pagination.limit(),
+                total,
             }
-        },
-        None => {
-            println!("MISSING TOKEN");
-            return (
-                StatusCode::UNAUTHORIZED,
-                Json(ApiResponse {
-                    data: None,
-                    error: "Missing access token".into(),
-                }),
-            );
         }
     };
 
-    let claims = match decode_jwt(token, &router_state.jwt_secret) {
-        Ok(c) => c,
-        Err(e) => {
-            println!("FAILED TO GET CLAIMS");
-            return (
-                StatusCode::UNAUTHORIZED,
+    let requested_locales = get_requested_locales(&headers);
+    for recipe in page.items.iter_mut() {
+        localize_recipe(recipe, &requested_locales);
+    }
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            data: Some(page),
+            error: None,
+        }),
+    ))
+}
+
+// Prices are loaded once per request, only for the requested currency and region
+async fn get_prices(
+    db_handler: &MongoDbHandler,
+    cost_query: &CostQuery,
+) -> Result<Vec<IngredientPriceMongoDb>, AppError> {
+    let region = cost_query.region();
+    let mut prices = db_handler
+        .get_ingredient_prices(&IngredientPriceQuery {
+            ingredient: None,
+            currency: Some(cost_query.currency()),
+            region: region.clone(),
+        })
+        .await?;
+
+    // Without a region only the prices applying everywhere are used
+    if region.is_none() {
+        prices.retain(|price| price.region.is_none());
+    }
+
+    Ok(prices)
+}
+
+async fn get_suggested_substitutions(
+    db_handler: &MongoDbHandler,
+    recipe: &Recipe,
+    user_id: &str,
+) -> Result<Vec<SuggestedSubstitution>, AppError> {
+    let allergens = db_handler
+        .get_user_preferences(user_id)
+        .await?
+        .map(|preferences| preferences.allergens)
+        .unwrap_or_default();
+    let pantry_items = db_handler.get_pantry_items(user_id).await?;
+
+    if allergens.is_empty() && pantry_items.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let substitutions = db_handler
+        .get_substitutions(&SubstitutionQuery::default())
+        .await?;
+
+    Ok(suggest_substitutions(
+        recipe,
+        &substitutions,
+        &allergens,
+        &pantry_items,
+    ))
+}
+
+async fn handle_get_recipe_by_id(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(recipe_id): Path<String>,
+    Query(query): Query<RecipeFormatQuery>,
+    Query(cost_query): Query<CostQuery>,
+    Query(season_query): Query<SeasonQuery>,
+) -> Result<Response, AppError> {
+    let (status_code, Json(api_response)) = handle_get_recipe_detail(
+        State(router_state),
+        headers,
+        Path(recipe_id),
+        Query(cost_query),
+        Query(season_query),
+    )
+    .await?;
+
+    let (format, recipe_detail) = match (query.format, api_response.data) {
+        (Some(format), Some(recipe_detail)) => (format, recipe_detail),
+        (_, data) => {
+            return Ok((
+                status_code,
                 Json(ApiResponse {
-                    data: None,
-                    error: format!("Failed to get claims from token: {e}"),
+                    data,
+                    error: api_response.error,
                 }),
-            );
+            )
+                .into_response())
         }
     };
 
-    (
+    let (content_type, body) = match format {
+        RecipeFormat::Cooklang => (
+            "text/plain; charset=utf-8",
+            export_cooklang(&recipe_detail.recipe),
+        ),
+        RecipeFormat::Markdown => (
+            "text/markdown; charset=utf-8",
+            export_markdown(&recipe_detail.recipe),
+        ),
+    };
+
+    Ok((StatusCode::OK, [(header::CONTENT_TYPE, content_type)], body).into_response())
+}
+
+async fn handle_get_recipe_json_ld(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(recipe_id): Path<String>,
+) -> Result<Response, AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret).ok();
+
+    let mut recipe = router_state
+        .db_handler
+        .get_visible_recipe(&recipe_id, claims.as_ref())
+        .await
+        .with_message(Message::GetRecipeFailed {
+            recipe_id: &recipe_id,
+        })?;
+
+    localize_recipe(&mut recipe, &get_requested_locales(&headers));
+
+    Ok((
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, "application/ld+json")],
+        export_json_ld(&recipe).to_string(),
+    )
+        .into_response())
+}
+
+async fn handle_get_cooking_mode(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(recipe_id): Path<String>,
+) -> Result<(StatusCode, Json<ApiResponse<CookingMode>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret).ok();
+
+    let mut recipe = router_state
+        .db_handler
+        .get_visible_recipe(&recipe_id, claims.as_ref())
+        .await
+        .with_message(Message::GetRecipeFailed {
+            recipe_id: &recipe_id,
+        })?;
+
+    localize_recipe(&mut recipe, &get_requested_locales(&headers));
+
+    Ok((
         StatusCode::OK,
         Json(ApiResponse {
-            data: Some(claims.sub),
-            error: "".into(),
+            data: Some(analyze_recipe(&recipe)),
+            error: None,
         }),
+    ))
+}
+
+async fn handle_fork_recipe(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(recipe_id): Path<String>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let recipe = router_state
+        .db_handler
+        .get_visible_recipe(&recipe_id, Some(&claims))
+        .await
+        .with_message(Message::GetRecipeFailed {
+            recipe_id: &recipe_id,
+        })?;
+
+    recipe
+        .check_forkable_by(&claims.user_id)
+        .map_err(|message| AppError::Validation(message.to_string()))?;
+
+    let fork_id = router_state
+        .db_handler
+        .fork_recipe(&recipe_id, &claims.user_id)
+        .await
+        .with_message(Message::ForkRecipeFailed {
+            recipe_id: &recipe_id,
+        })?;
+
+    notify(
+        &router_state.db_handler,
+        &router_state.event_bus,
+        &recipe.author_id,
+        NotificationCreate {
+            kind: NotificationKind::RecipeForked,
+            actor_id: Some(claims.user_id),
+            recipe_id: Some(fork_id.clone()),
+            report_id: None,
+        },
     )
+    .await;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse {
+            data: Some(fork_id),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_get_forks(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(recipe_id): Path<String>,
+) -> Result<(StatusCode, Json<ApiResponse<Vec<Recipe>>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret).ok();
+
+    router_state
+        .db_handler
+        .get_visible_recipe(&recipe_id, claims.as_ref())
+        .await
+        .with_message(Message::GetRecipeFailed {
+            recipe_id: &recipe_id,
+        })?;
+
+    let mut forks = router_state
+        .db_handler
+        .get_forks(&recipe_id)
+        .await
+        .with_message(Message::GetForksFailed {
+            recipe_id: &recipe_id,
+        })?;
+
+    let requested_locales = get_requested_locales(&headers);
+    for fork in forks.iter_mut() {
+        localize_recipe(fork, &requested_locales);
+    }
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            data: Some(forks),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_get_recipe_detail(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(recipe_id): Path<String>,
+    Query(cost_query): Query<CostQuery>,
+    Query(season_query): Query<SeasonQuery>,
+) -> Result<(StatusCode, Json<ApiResponse<RecipeDetail>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret).ok();
+
+    let mut recipe = router_state
+        .db_handler
+        .get_visible_recipe(&recipe_id, claims.as_ref())
+        .await
+        .with_message(Message::GetRecipeFailed {
+            recipe_id: &recipe_id,
+        })?;
+
+    localize_recipe(&mut recipe, &get_requested_locales(&headers));
+
+    let prices = get_prices(&router_state.db_handler, &cost_query)
+        .await
+        .with_message(Message::EstimateRecipeCostFailed)?;
+    recipe.estimated_cost = estimate_cost(&recipe, &prices, &cost_query.currency());
+
+    recipe.seasonality_score = get_seasonality_score(
+        &recipe,
+        get_season_region(season_query.region.as_deref()),
+        season_query.month.unwrap_or(Utc::now().month()),
+    );
+
+    // Substitutions are only suggested to signed in users
+    let suggested_substitutions = match claims {
+        Some(claims) => {
+            get_suggested_substitutions(&router_state.db_handler, &recipe, &claims.user_id)
+                .await
+                .with_message(Message::GetSuggestedSubstitutionsFailed)?
+        }
+        None => vec![],
+    };
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            data: Some(RecipeDetail {
+                recipe,
+                suggested_substitutions,
+            }),
+            error: None,
+        }),
+    ))
+}
+
+fn check_requested_status(status: RecipeStatus, claims: &Claims) -> Result<(), AppError> {
+    status
+        .check_requested(&claims.role)
+        .map_err(|message| AppError::Validation(message.to_string()))
+}
+
+async fn handle_create_recipe(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    ValidatedJson(payload): ValidatedJson<RecipeCreate>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    check_requested_status(payload.status, &claims)?;
+
+    let inserted_id = router_state
+        .db_handler
+        .create_recipe(&claims.user_id, payload)
+        .await
+        .with_message(Message::CreateRecipeFailed)?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse {
+            data: Some(inserted_id),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_import_recipe(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Query(query): Query<RecipeFormatQuery>,
+    body: String,
+) -> Result<(StatusCode, Json<ApiResponse<RecipeImportReport>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    // Without a format the body is schema.org JSON-LD or HTML
+    let imported = match query.format {
+        Some(RecipeFormat::Cooklang) => import_cooklang(&body),
+        Some(RecipeFormat::Markdown) => import_markdown(&body),
+        None => import_recipe(&body),
+    }
+    .map_err(|err| AppError::Validation(Message::ImportRecipeFailed { err: &err }.to_string()))?;
+
+    imported
+        .recipe
+        .validate()
+        .map_err(AppError::InvalidFields)?;
+
+    let vegan_check = check_vegan(&imported.recipe.ingredients);
+    let status = imported.recipe.status;
+
+    let recipe_id = router_state
+        .db_handler
+        .create_recipe(&claims.user_id, imported.recipe)
+        .await
+        .with_message(Message::SaveImportedRecipeFailed)?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse {
+            data: Some(RecipeImportReport {
+                recipe_id,
+                status,
+                unmapped_fields: imported.unmapped_fields,
+                vegan_check,
+            }),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_patch_recipe(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(recipe_id): Path<String>,
+    ValidatedJson(payload): ValidatedJson<RecipePatch>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let recipe = router_state
+        .db_handler
+        .get_by_id::<RecipeMongoDb, Recipe>(&recipe_id, "recipes")
+        .await
+        .with_message(Message::GetRecipeFailed {
+            recipe_id: &recipe_id,
+        })?;
+
+    if !is_author_or_admin(&recipe, &claims) {
+        return Err(AppError::Forbidden(
+            Message::ModifyRecipeForbidden {
+                recipe_id: &recipe_id,
+            }
+            .to_string(),
+        ));
+    }
+
+    check_is_not_removed(&recipe, &claims)?;
+
+    if let Some(status) = payload.status {
+        check_requested_status(status, &claims)?;
+    }
+
+    router_state
+        .db_handler
+        .create_recipe_revision(&recipe_id, &claims.user_id, (&recipe).into())
+        .await
+        .with_message(Message::SaveRevisionFailed {
+            recipe_id: &recipe_id,
+        })?;
+
+    router_state
+        .db_handler
+        .patch_recipe_by_id(&recipe_id, payload)
+        .await
+        .with_message(Message::PatchRecipeFailed {
+            recipe_id: &recipe_id,
+        })?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(ApiResponse {
+            data: None,
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_get_translations(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(recipe_id): Path<String>,
+) -> Result<
+    (
+        StatusCode,
+        Json<ApiResponse<BTreeMap<String, RecipeTranslation>>>,
+    ),
+    AppError,
+> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret).ok();
+
+    let recipe = router_state
+        .db_handler
+        .get_visible_recipe(&recipe_id, claims.as_ref())
+        .await
+        .with_message(Message::GetRecipeFailed {
+            recipe_id: &recipe_id,
+        })?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            data: Some(recipe.translations),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_put_translation(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path((recipe_id, locale)): Path<(String, String)>,
+    ValidatedJson(payload): ValidatedJson<RecipeTranslation>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    if !is_valid_locale(&locale) {
+        return Err(AppError::Validation(
+            Message::InvalidLocale { locale: &locale }.to_string(),
+        ));
+    }
+    let locale = normalize_locale(&locale);
+
+    let recipe = router_state
+        .db_handler
+        .get_by_id::<RecipeMongoDb, Recipe>(&recipe_id, "recipes")
+        .await
+        .with_message(Message::GetRecipeFailed {
+            recipe_id: &recipe_id,
+        })?;
+
+    if !is_author_or_admin(&recipe, &claims) {
+        return Err(AppError::Forbidden(
+            Message::ModifyRecipeForbidden {
+                recipe_id: &recipe_id,
+            }
+            .to_string(),
+        ));
+    }
+
+    check_is_not_removed(&recipe, &claims)?;
+
+    // The original texts are edited on the recipe itself
+    if recipe.locale == locale {
+        return Err(AppError::Validation(
+            Message::TranslateOriginalLocale {
+                recipe_id: &recipe_id,
+                locale: &locale,
+            }
+            .to_string(),
+        ));
+    }
+
+    let update_count = router_state
+        .db_handler
+        .set_recipe_translation(&recipe_id, &locale, payload)
+        .await
+        .with_message(Message::SaveTranslationFailed {
+            locale: &locale,
+            recipe_id: &recipe_id,
+        })?;
+
+    if update_count == 0 {
+        return Err(AppError::NotFound(
+            Message::FindRecipeFailed {
+                recipe_id: &recipe_id,
+            }
+            .to_string(),
+        ));
+    }
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(ApiResponse {
+            data: None,
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_delete_translation(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path((recipe_id, locale)): Path<(String, String)>,
+) -> Result<(StatusCode, Json<ApiResponse<u64>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    if !is_valid_locale(&locale) {
+        return Err(AppError::Validation(
+            Message::InvalidLocale { locale: &locale }.to_string(),
+        ));
+    }
+
+    let recipe = router_state
+        .db_handler
+        .get_by_id::<RecipeMongoDb, Recipe>(&recipe_id, "recipes")
+        .await
+        .with_message(Message::GetRecipeFailed {
+            recipe_id: &recipe_id,
+        })?;
+
+    if !is_author_or_admin(&recipe, &claims) {
+        return Err(AppError::Forbidden(
+            Message::ModifyRecipeForbidden {
+                recipe_id: &recipe_id,
+            }
+            .to_string(),
+        ));
+    }
+
+    check_is_not_removed(&recipe, &claims)?;
+
+    let delete_count = router_state
+        .db_handler
+        .delete_recipe_translation(&recipe_id, &locale)
+        .await
+        .with_message(Message::DeleteTranslationFailed {
+            locale: &locale,
+            recipe_id: &recipe_id,
+        })?;
+
+    if delete_count == 0 {
+        return Err(AppError::NotFound(
+            Message::TranslationNotFound {
+                locale: &locale,
+                recipe_id: &recipe_id,
+            }
+            .to_string(),
+        ));
+    }
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(ApiResponse {
+            data: Some(delete_count),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_publish_recipe(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(recipe_id): Path<String>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    update_recipe_publication(router_state, headers, recipe_id, true).await
+}
+
+async fn handle_unpublish_recipe(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(recipe_id): Path<String>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    update_recipe_publication(router_state, headers, recipe_id, false).await
+}
+
+async fn update_recipe_publication(
+    router_state: RouterState,
+    headers: HeaderMap,
+    recipe_id: String,
+    is_published: bool,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let recipe = router_state
+        .db_handler
+        .get_by_id::<RecipeMongoDb, Recipe>(&recipe_id, "recipes")
+        .await
+        .with_message(Message::GetRecipeFailed {
+            recipe_id: &recipe_id,
+        })?;
+
+    if !is_author_or_admin(&recipe, &claims) {
+        return Err(AppError::Forbidden(
+            Message::ModifyRecipeForbidden {
+                recipe_id: &recipe_id,
+            }
+            .to_string(),
+        ));
+    }
+
+    check_is_not_removed(&recipe, &claims)?;
+
+    // Publishing again keeps the original publication date
+    let update_count = match (is_published, recipe.status) {
+        (true, RecipeStatus::Published) | (false, RecipeStatus::Draft) => Ok(1),
+        (true, _) => router_state.db_handler.publish_recipe(&recipe_id).await,
+        (false, _) => router_state.db_handler.unpublish_recipe(&recipe_id).await,
+    }
+    .with_message(Message::UpdatePublicationFailed {
+        recipe_id: &recipe_id,
+    })?;
+
+    if update_count == 0 {
+        return Err(AppError::NotFound(
+            Message::FindRecipeFailed {
+                recipe_id: &recipe_id,
+            }
+            .to_string(),
+        ));
+    }
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(ApiResponse {
+            data: None,
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_delete_recipe(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(recipe_id): Path<String>,
+) -> Result<(StatusCode, Json<ApiResponse<u64>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let recipe = router_state
+        .db_handler
+        .get_by_id::<RecipeMongoDb, Recipe>(&recipe_id, "recipes")
+        .await
+        .with_message(Message::GetRecipeFailed {
+            recipe_id: &recipe_id,
+        })?;
+
+    if !is_author_or_admin(&recipe, &claims) {
+        return Err(AppError::Forbidden(
+            Message::DeleteRecipeForbidden {
+                recipe_id: &recipe_id,
+            }
+            .to_string(),
+        ));
+    }
+
+    check_is_not_removed(&recipe, &claims)?;
+
+    let delete_count = router_state
+        .db_handler
+        .delete_recipe_by_id(&recipe_id)
+        .await
+        .with_message(Message::DeleteRecipeFailed {
+            recipe_id: &recipe_id,
+        })?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(ApiResponse {
+            data: Some(delete_count),
+            error: None,
+        }),
+    ))
+}
+
+#[cfg(test)]
+mod unit_tests_recipes_router {
+    use super::*;
+    use crate::test_utils::{
+        assert_status_codes, db_clean_up, get_bearer_token, get_db_connection, get_db_handler,
+        get_random_recipe_db, get_unconnected_db_handler, RequestTestCase, TEST_JWT_SECRET,
+    };
+    use anyhow::Result;
+    use axum::http::Method;
+    use bson::oid::ObjectId;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn rejects_invalid_requests() -> Result<()> {
+        let router = RecipesRouter::new(
+            get_unconnected_db_handler()?,
+            TEST_JWT_SECRET,
+            EventBus::default(),
+        )
+        .router;
+        let recipe_id = ObjectId::new().to_hex();
+
+        let test_cases = vec![
+            RequestTestCase {
+                title: "Rejects deleting a recipe without a token".into(),
+                method: Method::DELETE,
+                path: format!("/recipes/{recipe_id}"),
+                token: None,
+                body: None,
+                expected_status_code: StatusCode::UNAUTHORIZED,
+            },
+            RequestTestCase {
+                title: "Rejects forking a recipe without a token".into(),
+                method: Method::POST,
+                path: format!("/recipes/{recipe_id}/fork"),
+                token: None,
+                body: None,
+                expected_status_code: StatusCode::UNAUTHORIZED,
+            },
+            RequestTestCase {
+                title: "Rejects recipes without a title".into(),
+                method: Method::POST,
+                path: "/recipes".into(),
+                token: Some(get_bearer_token(ObjectId::new(), Role::User)?),
+                body: Some(json!({"title": ""})),
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            RequestTestCase {
+                title: "Rejects malformed recipe ids".into(),
+                method: Method::GET,
+                path: "/recipes/not-an-id".into(),
+                token: None,
+                body: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+        ];
+
+        assert_status_codes(router, test_cases).await
+    }
+
+    #[tokio::test]
+    async fn hides_and_locks_recipes() -> Result<()> {
+        let router = RecipesRouter::new(
+            get_db_handler().await?,
+            TEST_JWT_SECRET,
+            EventBus::default(),
+        )
+        .router;
+
+        let author_id = ObjectId::new();
+        let mut draft_db = get_random_recipe_db(None, Some(author_id));
+        draft_db.status = RecipeStatus::Draft;
+        let published_db = get_random_recipe_db(None, Some(author_id));
+        let mut removed_db = get_random_recipe_db(None, Some(author_id));
+        removed_db.status = RecipeStatus::Removed;
+        get_db_connection()
+            .await?
+            .collection::<RecipeMongoDb>("recipes")
+            .insert_many([&draft_db, &published_db, &removed_db])
+            .await?;
+
+        let draft_path = format!("/recipes/{}", draft_db._id.to_hex());
+        let author_token = get_bearer_token(author_id, Role::User)?;
+        let other_token = get_bearer_token(ObjectId::new(), Role::User)?;
+
+        let test_cases = vec![
+            RequestTestCase {
+                title: "Hides a draft from anonymous users".into(),
+                method: Method::GET,
+                path: draft_path.clone(),
+                token: None,
+                body: None,
+                expected_status_code: StatusCode::NOT_FOUND,
+            },
+            RequestTestCase {
+                title: "Hides a draft from other users".into(),
+                method: Method::GET,
+                path: draft_path.clone(),
+                token: Some(other_token.clone()),
+                body: None,
+                expected_status_code: StatusCode::NOT_FOUND,
+            },
+            RequestTestCase {
+                title: "Shows a draft to its author".into(),
+                method: Method::GET,
+                path: draft_path,
+                token: Some(author_token.clone()),
+                body: None,
+                expected_status_code: StatusCode::OK,
+            },
+            RequestTestCase {
+                title: "Rejects deleting a recipe of another user".into(),
+                method: Method::DELETE,
+                path: format!("/recipes/{}", published_db._id.to_hex()),
+                token: Some(other_token),
+                body: None,
+                expected_status_code: StatusCode::FORBIDDEN,
+            },
+            RequestTestCase {
+                title: "Rejects deleting a removed recipe by its author".into(),
+                method: Method::DELETE,
+                path: format!("/recipes/{}", removed_db._id.to_hex()),
+                token: Some(author_token),
+                body: None,
+                expected_status_code: StatusCode::FORBIDDEN,
+            },
+        ];
+
+        assert_status_codes(router, test_cases).await?;
+
+        db_clean_up().await?;
+
+        Ok(())
+    }
 }
@@ -0,0 +1,482 @@
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    routing::{get, post},
+    Json, Router,
+};
+
+use crate::{
+    api::{
+        api_response::ApiResponse,
+        app_error::{AppError, WithMessage},
+        messages::Message,
+        services::{
+            event_service::EventBus,
+            notification_service::notify,
+            token_service::{get_claims_from_headers, Claims},
+        },
+        validation::{Path, Query, ValidatedJson},
+    },
+    db::{
+        comment_handler::CommentHandler, generic_handler::GenericHandler,
+        mongo_db_handler::MongoDbHandler, recipe_handler::RecipeHandler,
+        recipe_revision_handler::RecipeRevisionHandler, report_handler::ReportHandler,
+        user_handler::UserHandler,
+    },
+    model::{
+        comment::{CommentMongoDb, CommentStatus},
+        notification::{NotificationCreate, NotificationKind},
+        pagination::{Page, Pagination},
+        recipe::{Recipe, RecipeMongoDb, RecipePatch, RecipeStatus},
+        report::{
+            ModerationAction, ModerationLogEntry, Report, ReportCreate, ReportMongoDb, ReportQuery,
+            ReportResolution, ReportStatus, ReportTarget,
+        },
+        user::{Role, User, UserMongoDb, UserPatch},
+    },
+};
+
+#[derive(Clone)]
+struct RouterState {
+    jwt_secret: String,
+    db_handler: MongoDbHandler,
+    event_bus: EventBus,
+}
+
+pub struct ReportsRouter {
+    pub router: Router,
+}
+
+impl ReportsRouter {
+    pub fn new(db_handler: MongoDbHandler, jwt_secret: &str, event_bus: EventBus) -> Self {
+        let router_state = RouterState {
+            db_handler,
+            jwt_secret: String::from(jwt_secret),
+            event_bus,
+        };
+
+        let router = Router::new()
+            .route("/reports", post(handle_create_report))
+            .route("/admin/reports", get(handle_get_reports))
+            .route("/admin/reports/{id}/resolve", post(handle_resolve_report))
+            .route("/admin/moderation_log", get(handle_get_moderation_log))
+            .with_state(router_state);
+
+        Self { router }
+    }
+}
+
+fn check_is_admin(headers: &HeaderMap, jwt_secret: &str) -> Result<Claims, AppError> {
+    let claims = get_claims_from_headers(headers, jwt_secret).map_err(AppError::unauthenticated)?;
+
+    if claims.role != Role::Admin {
+        return Err(AppError::Forbidden(
+            Message::ModerateReportsForbidden.to_string(),
+        ));
+    }
+
+    Ok(claims)
+}
+
+// Also checks that the reported content exists and is visible to the caller
+async fn get_target_author_id(
+    db_handler: &MongoDbHandler,
+    target_type: ReportTarget,
+    target_id: &str,
+    claims: &Claims,
+) -> Result<String, AppError> {
+    match target_type {
+        ReportTarget::Recipe => Ok(db_handler
+            .get_visible_recipe(target_id, Some(claims))
+            .await?
+            .author_id),
+        ReportTarget::Comment => {
+            let comment = db_handler
+                .get_by_id::<CommentMongoDb, CommentMongoDb>(target_id, "comments")
+                .await?;
+
+            // Admins still reach removed comments, e.g. to deactivate their authors
+            if comment.status != CommentStatus::Visible && claims.role != Role::Admin {
+                return Err(AppError::NotFound(
+                    Message::CommentNotFound {
+                        comment_id: &target_id,
+                    }
+                    .to_string(),
+                ));
+            }
+
+            db_handler
+                .get_visible_recipe(&comment.recipe_id.to_hex(), Some(claims))
+                .await?;
+
+            Ok(comment.author_id.to_hex())
+        }
+        ReportTarget::User => Ok(db_handler
+            .get_by_id::<UserMongoDb, User>(target_id, "users")
+            .await?
+            .id),
+    }
+}
+
+async fn apply_moderation_action(
+    db_handler: &MongoDbHandler,
+    report: &Report,
+    action: ModerationAction,
+    claims: &Claims,
+) -> Result<(), AppError> {
+    match (action, report.target_type) {
+        (ModerationAction::Dismiss, _) => Ok(()),
+        (ModerationAction::HideContent, ReportTarget::Recipe) => {
+            let recipe = db_handler
+                .get_by_id::<RecipeMongoDb, Recipe>(&report.target_id, "recipes")
+                .await?;
+
+            // Keep the content at removal time so it can be restored afterwards
+            db_handler
+                .create_recipe_revision(&report.target_id, &claims.user_id, (&recipe).into())
+                .await?;
+
+            db_handler
+                .patch_recipe_by_id(
+                    &report.target_id,
+                    RecipePatch {
+                        status: Some(RecipeStatus::Removed),
+                        ..Default::default()
+                    },
+                )
+                .await
+        }
+        (ModerationAction::HideContent, ReportTarget::Comment) => {
+            db_handler
+                .set_comment_status(&report.target_id, CommentStatus::Removed)
+                .await
+        }
+        (ModerationAction::HideContent, ReportTarget::User) => {
+            // Rejected before, a profile has no content apart from its author
+            Ok(())
+        }
+        (ModerationAction::DeactivateAuthor, target_type) => {
+            let author_id =
+                get_target_author_id(db_handler, target_type, &report.target_id, claims).await?;
+
+            db_handler
+                .patch_user_by_id(
+                    &author_id,
+                    UserPatch {
+                        email: None,
+                        password_hash: None,
+                        role: None,
+                        is_activated: Some(false),
+                    },
+                )
+                .await
+        }
+    }
+}
+
+async fn handle_create_report(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    ValidatedJson(payload): ValidatedJson<ReportCreate>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    get_target_author_id(
+        &router_state.db_handler,
+        payload.target_type,
+        &payload.target_id,
+        &claims,
+    )
+    .await
+    .with_message(Message::GetReportedTargetFailed {
+        target_type: &payload.target_type,
+        target_id: &payload.target_id,
+    })?;
+
+    let inserted_id = router_state
+        .db_handler
+        .create_report(&claims.user_id, payload)
+        .await
+        .with_message(Message::CreateReportFailed)?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse {
+            data: Some(inserted_id),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_get_reports(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Query(query): Query<ReportQuery>,
+    Query(pagination): Query<Pagination>,
+) -> Result<(StatusCode, Json<ApiResponse<Page<Report>>>), AppError> {
+    check_is_admin(&headers, &router_state.jwt_secret)?;
+
+    let reports = router_state
+        .db_handler
+        .get_reports(&query, &pagination)
+        .await
+        .with_message(Message::GetReportsFailed)?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            data: Some(reports),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_resolve_report(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(report_id): Path<String>,
+    ValidatedJson(payload): ValidatedJson<ReportResolution>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    let claims = check_is_admin(&headers, &router_state.jwt_secret)?;
+
+    let report_db = router_state
+        .db_handler
+        .get_by_id::<ReportMongoDb, ReportMongoDb>(&report_id, "reports")
+        .await
+        .with_message(Message::GetReportFailed {
+            report_id: &report_id,
+        })?;
+
+    if report_db.status == ReportStatus::Resolved {
+        return Err(AppError::Conflict(
+            Message::ReportAlreadyResolved {
+                report_id: &report_id,
+            }
+            .to_string(),
+        ));
+    }
+
+    if payload.action == ModerationAction::HideContent
+        && report_db.target_type == ReportTarget::User
+    {
+        return Err(AppError::Validation(
+            Message::DeactivateReportedUsers.to_string(),
+        ));
+    }
+
+    let report: Report = report_db.clone().into();
+
+    apply_moderation_action(&router_state.db_handler, &report, payload.action, &claims)
+        .await
+        .with_message(Message::ApplyModerationFailed {
+            action: &payload.action,
+            report_id: &report_id,
+        })?;
+
+    let update_count = router_state
+        .db_handler
+        .resolve_report(&report_db, &claims.user_id, payload)
+        .await
+        .with_message(Message::ResolveReportFailed {
+            report_id: &report_id,
+        })?;
+
+    if update_count == 0 {
+        return Err(AppError::Conflict(
+            Message::ReportAlreadyResolved {
+                report_id: &report_id,
+            }
+            .to_string(),
+        ));
+    }
+
+    notify(
+        &router_state.db_handler,
+        &router_state.event_bus,
+        &report.reporter_id,
+        NotificationCreate {
+            kind: NotificationKind::ReportResolved,
+            actor_id: Some(claims.user_id),
+            recipe_id: None,
+            report_id: Some(report_id),
+        },
+    )
+    .await;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(ApiResponse {
+            data: None,
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_get_moderation_log(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Query(pagination): Query<Pagination>,
+) -> Result<(StatusCode, Json<ApiResponse<Page<ModerationLogEntry>>>), AppError> {
+    check_is_admin(&headers, &router_state.jwt_secret)?;
+
+    let log = router_state
+        .db_handler
+        .get_moderation_log(&pagination)
+        .await
+        .with_message(Message::GetModerationLogFailed)?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            data: Some(log),
+            error: None,
+        }),
+    ))
+}
+
+#[cfg(test)]
+mod unit_tests_reports_router {
+    use super::*;
+    use crate::{
+        model::recipe::{RecipeMongoDb, RecipeStatus},
+        test_utils::{
+            assert_status_codes, db_clean_up, get_bearer_token, get_db_connection, get_db_handler,
+            get_random_recipe_db, get_unconnected_db_handler, RequestTestCase, TEST_JWT_SECRET,
+        },
+    };
+    use anyhow::Result;
+    use axum::http::Method;
+    use bson::oid::ObjectId;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn rejects_invalid_requests() -> Result<()> {
+        let router = ReportsRouter::new(
+            get_unconnected_db_handler()?,
+            TEST_JWT_SECRET,
+            EventBus::default(),
+        )
+        .router;
+        let report_id = ObjectId::new().to_hex();
+        let token = get_bearer_token(ObjectId::new(), Role::User)?;
+
+        let test_cases = vec![
+            RequestTestCase {
+                title: "Rejects reports without a token".into(),
+                method: Method::POST,
+                path: "/reports".into(),
+                token: None,
+                body: Some(json!({
+                    "targetType": "Recipe",
+                    "targetId": ObjectId::new().to_hex(),
+                    "reason": "Spam"
+                })),
+                expected_status_code: StatusCode::UNAUTHORIZED,
+            },
+            RequestTestCase {
+                title: "Rejects reports without a reason".into(),
+                method: Method::POST,
+                path: "/reports".into(),
+                token: Some(token.clone()),
+                body: Some(json!({
+                    "targetType": "Recipe",
+                    "targetId": ObjectId::new().to_hex(),
+                    "reason": ""
+                })),
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            RequestTestCase {
+                title: "Rejects reports of malformed ids".into(),
+                method: Method::POST,
+                path: "/reports".into(),
+                token: Some(token.clone()),
+                body: Some(json!({
+                    "targetType": "Recipe",
+                    "targetId": "not-an-id",
+                    "reason": "Spam"
+                })),
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            RequestTestCase {
+                title: "Rejects reading the moderation log without a token".into(),
+                method: Method::GET,
+                path: "/admin/moderation_log".into(),
+                token: None,
+                body: None,
+                expected_status_code: StatusCode::UNAUTHORIZED,
+            },
+            RequestTestCase {
+                title: "Rejects listing reports by users".into(),
+                method: Method::GET,
+                path: "/admin/reports".into(),
+                token: Some(token.clone()),
+                body: None,
+                expected_status_code: StatusCode::FORBIDDEN,
+            },
+            RequestTestCase {
+                title: "Rejects resolving reports by users".into(),
+                method: Method::POST,
+                path: format!("/admin/reports/{report_id}/resolve"),
+                token: Some(token),
+                body: Some(json!({"action": "Dismiss"})),
+                expected_status_code: StatusCode::FORBIDDEN,
+            },
+        ];
+
+        assert_status_codes(router, test_cases).await
+    }
+
+    #[tokio::test]
+    async fn rejects_reports_of_hidden_content() -> Result<()> {
+        let router = ReportsRouter::new(
+            get_db_handler().await?,
+            TEST_JWT_SECRET,
+            EventBus::default(),
+        )
+        .router;
+
+        let mut recipe_db = get_random_recipe_db(None, Some(ObjectId::new()));
+        recipe_db.status = RecipeStatus::Draft;
+        get_db_connection()
+            .await?
+            .collection::<RecipeMongoDb>("recipes")
+            .insert_one(&recipe_db)
+            .await?;
+
+        let token = get_bearer_token(ObjectId::new(), Role::User)?;
+
+        let test_cases = vec![
+            RequestTestCase {
+                title: "Rejects reports of a draft of another user".into(),
+                method: Method::POST,
+                path: "/reports".into(),
+                token: Some(token.clone()),
+                body: Some(json!({
+                    "targetType": "Recipe",
+                    "targetId": recipe_db._id.to_hex(),
+                    "reason": "Spam"
+                })),
+                expected_status_code: StatusCode::NOT_FOUND,
+            },
+            RequestTestCase {
+                title: "Rejects reports of unknown comments".into(),
+                method: Method::POST,
+                path: "/reports".into(),
+                token: Some(token),
+                body: Some(json!({
+                    "targetType": "Comment",
+                    "targetId": ObjectId::new().to_hex(),
+                    "reason": "Spam"
+                })),
+                expected_status_code: StatusCode::NOT_FOUND,
+            },
+        ];
+
+        assert_status_codes(router, test_cases).await?;
+
+        db_clean_up().await?;
+
+        Ok(())
+    }
+}
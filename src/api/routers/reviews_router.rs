@@ -0,0 +1,369 @@
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    routing::get,
+    Json, Router,
+};
+use tracing::info;
+
+use crate::{
+    api::{
+        api_response::ApiResponse,
+        app_error::{AppError, WithMessage},
+        messages::Message,
+        services::{
+            event_service::EventBus, notification_service::notify,
+            token_service::get_claims_from_headers,
+        },
+        validation::{Path, ValidatedJson},
+    },
+    db::{
+        mongo_db_handler::MongoDbHandler, recipe_handler::RecipeHandler,
+        review_handler::ReviewHandler,
+    },
+    model::{
+        notification::{NotificationCreate, NotificationKind},
+        review::{Review, ReviewPayload},
+    },
+};
+
+#[derive(Clone)]
+struct RouterState {
+    jwt_secret: String,
+    db_handler: MongoDbHandler,
+    event_bus: EventBus,
+}
+
+pub struct ReviewsRouter {
+    pub router: Router,
+}
+
+impl ReviewsRouter {
+    pub fn new(db_handler: MongoDbHandler, jwt_secret: &str, event_bus: EventBus) -> Self {
+        let base_path = "/recipes/{id}/reviews";
+
+        let router_state = RouterState {
+            db_handler,
+            jwt_secret: String::from(jwt_secret),
+            event_bus,
+        };
+
+        let router = Router::new()
+            .route(
+                base_path,
+                get(handle_get_reviews)
+                    .post(handle_create_review)
+                    .patch(handle_patch_review)
+                    .delete(handle_delete_review),
+            )
+            .with_state(router_state);
+
+        Self { router }
+    }
+}
+
+async fn handle_get_reviews(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(recipe_id): Path<String>,
+) -> Result<(StatusCode, Json<ApiResponse<Vec<Review>>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret).ok();
+
+    router_state
+        .db_handler
+        .get_visible_recipe(&recipe_id, claims.as_ref())
+        .await
+        .with_message(Message::GetRecipeFailed {
+            recipe_id: &recipe_id,
+        })?;
+
+    let reviews = router_state
+        .db_handler
+        .get_reviews_by_recipe_id(&recipe_id)
+        .await
+        .with_message(Message::GetReviewsFailed {
+            recipe_id: &recipe_id,
+        })?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            data: Some(reviews),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_create_review(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(recipe_id): Path<String>,
+    ValidatedJson(payload): ValidatedJson<ReviewPayload>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let recipe = router_state
+        .db_handler
+        .get_visible_recipe(&recipe_id, Some(&claims))
+        .await
+        .with_message(Message::GetRecipeFailed {
+            recipe_id: &recipe_id,
+        })?;
+
+    if recipe.author_id == claims.user_id {
+        let err_msg = Message::ReviewOwnRecipe.to_string();
+        info!("{err_msg}: {recipe_id}");
+
+        return Err(AppError::Forbidden(err_msg));
+    }
+
+    let existing_review = router_state
+        .db_handler
+        .get_review(&recipe_id, &claims.user_id)
+        .await
+        .with_message(Message::CheckExistingReviewFailed)?;
+
+    if existing_review.is_some() {
+        return Err(AppError::Conflict(
+            Message::RecipeAlreadyReviewed {
+                recipe_id: &recipe_id,
+            }
+            .to_string(),
+        ));
+    }
+
+    let inserted_id = router_state
+        .db_handler
+        .create_review(&recipe_id, &claims.user_id, payload)
+        .await
+        .with_message(Message::CreateReviewFailed)?;
+
+    notify(
+        &router_state.db_handler,
+        &router_state.event_bus,
+        &recipe.author_id,
+        NotificationCreate {
+            kind: NotificationKind::ReviewCreated,
+            actor_id: Some(claims.user_id),
+            recipe_id: Some(recipe_id),
+            report_id: None,
+        },
+    )
+    .await;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse {
+            data: Some(inserted_id),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_patch_review(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(recipe_id): Path<String>,
+    ValidatedJson(payload): ValidatedJson<ReviewPayload>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let patch_count = router_state
+        .db_handler
+        .patch_review(&recipe_id, &claims.user_id, payload)
+        .await
+        .with_message(Message::PatchReviewFailed {
+            recipe_id: &recipe_id,
+        })?;
+
+    if patch_count == 0 {
+        return Err(AppError::NotFound(
+            Message::ReviewNotFound {
+                recipe_id: &recipe_id,
+            }
+            .to_string(),
+        ));
+    }
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(ApiResponse {
+            data: None,
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_delete_review(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(recipe_id): Path<String>,
+) -> Result<(StatusCode, Json<ApiResponse<u64>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let delete_count = router_state
+        .db_handler
+        .delete_review(&recipe_id, &claims.user_id)
+        .await
+        .with_message(Message::DeleteReviewFailed {
+            recipe_id: &recipe_id,
+        })?;
+
+    if delete_count == 0 {
+        return Err(AppError::NotFound(
+            Message::ReviewNotFound {
+                recipe_id: &recipe_id,
+            }
+            .to_string(),
+        ));
+    }
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(ApiResponse {
+            data: Some(delete_count),
+            error: None,
+        }),
+    ))
+}
+
+#[cfg(test)]
+mod unit_tests_reviews_router {
+    use super::*;
+    use crate::{
+        model::{
+            recipe::{RecipeMongoDb, RecipeStatus},
+            user::Role,
+        },
+        test_utils::{
+            assert_status_codes, db_clean_up, get_bearer_token, get_db_connection, get_db_handler,
+            get_random_recipe_db, get_unconnected_db_handler, RequestTestCase, TEST_JWT_SECRET,
+        },
+    };
+    use anyhow::Result;
+    use axum::http::Method;
+    use bson::oid::ObjectId;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn rejects_invalid_requests() -> Result<()> {
+        let router = ReviewsRouter::new(
+            get_unconnected_db_handler()?,
+            TEST_JWT_SECRET,
+            EventBus::default(),
+        )
+        .router;
+        let recipe_id = ObjectId::new().to_hex();
+        let token = get_bearer_token(ObjectId::new(), Role::User)?;
+
+        let test_cases = vec![
+            RequestTestCase {
+                title: "Rejects reviewing without a token".into(),
+                method: Method::POST,
+                path: format!("/recipes/{recipe_id}/reviews"),
+                token: None,
+                body: Some(json!({"rating": 5, "text": "Tasty"})),
+                expected_status_code: StatusCode::UNAUTHORIZED,
+            },
+            RequestTestCase {
+                title: "Rejects deleting a review without a token".into(),
+                method: Method::DELETE,
+                path: format!("/recipes/{recipe_id}/reviews"),
+                token: None,
+                body: None,
+                expected_status_code: StatusCode::UNAUTHORIZED,
+            },
+            RequestTestCase {
+                title: "Rejects ratings out of range".into(),
+                method: Method::POST,
+                path: format!("/recipes/{recipe_id}/reviews"),
+                token: Some(token),
+                body: Some(json!({"rating": 6, "text": "Tasty"})),
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            RequestTestCase {
+                title: "Rejects malformed recipe ids".into(),
+                method: Method::GET,
+                path: "/recipes/not-an-id/reviews".into(),
+                token: None,
+                body: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+        ];
+
+        assert_status_codes(router, test_cases).await
+    }
+
+    #[tokio::test]
+    async fn hides_reviews_of_drafts() -> Result<()> {
+        let router = ReviewsRouter::new(
+            get_db_handler().await?,
+            TEST_JWT_SECRET,
+            EventBus::default(),
+        )
+        .router;
+
+        let author_id = ObjectId::new();
+        let mut recipe_db = get_random_recipe_db(None, Some(author_id));
+        recipe_db.status = RecipeStatus::Draft;
+        get_db_connection()
+            .await?
+            .collection::<RecipeMongoDb>("recipes")
+            .insert_one(&recipe_db)
+            .await?;
+
+        let path = format!("/recipes/{}/reviews", recipe_db._id.to_hex());
+
+        let test_cases = vec![
+            RequestTestCase {
+                title: "Hides the reviews of a draft from anonymous users".into(),
+                method: Method::GET,
+                path: path.clone(),
+                token: None,
+                body: None,
+                expected_status_code: StatusCode::NOT_FOUND,
+            },
+            RequestTestCase {
+                title: "Hides the reviews of a draft from other users".into(),
+                method: Method::GET,
+                path: path.clone(),
+                token: Some(get_bearer_token(ObjectId::new(), Role::User)?),
+                body: None,
+                expected_status_code: StatusCode::NOT_FOUND,
+            },
+            RequestTestCase {
+                title: "Rejects reviews of a draft by other users".into(),
+                method: Method::POST,
+                path: path.clone(),
+                token: Some(get_bearer_token(ObjectId::new(), Role::User)?),
+                body: Some(json!({"rating": 5, "text": "Tasty"})),
+                expected_status_code: StatusCode::NOT_FOUND,
+            },
+            RequestTestCase {
+                title: "Shows the reviews of a draft to its author".into(),
+                method: Method::GET,
+                path: path.clone(),
+                token: Some(get_bearer_token(author_id, Role::User)?),
+                body: None,
+                expected_status_code: StatusCode::OK,
+            },
+            RequestTestCase {
+                title: "Shows the reviews of a draft to admins".into(),
+                method: Method::GET,
+                path,
+                token: Some(get_bearer_token(ObjectId::new(), Role::Admin)?),
+                body: None,
+                expected_status_code: StatusCode::OK,
+            },
+        ];
+
+        assert_status_codes(router, test_cases).await?;
+
+        db_clean_up().await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+use axum::{http::StatusCode, routing::get, Json, Router};
+use chrono::{Datelike, Utc};
+
+use crate::{
+    api::{
+        api_response::ApiResponse,
+        app_error::AppError,
+        messages::Message,
+        services::season_service::{get_season_calendar, DEFAULT_SEASON_REGION, SEASON_REGIONS},
+        validation::Query,
+    },
+    model::season::{SeasonCalendar, SeasonQuery},
+};
+
+pub struct SeasonsRouter {
+    pub router: Router,
+}
+
+impl SeasonsRouter {
+    pub fn new() -> Self {
+        let router = Router::new().route("/seasons", get(handle_get_season_calendar));
+
+        Self { router }
+    }
+}
+
+async fn handle_get_season_calendar(
+    Query(query): Query<SeasonQuery>,
+) -> Result<(StatusCode, Json<ApiResponse<SeasonCalendar>>), AppError> {
+    let region = query
+        .region
+        .as_deref()
+        .unwrap_or(DEFAULT_SEASON_REGION)
+        .trim()
+        .to_lowercase();
+
+    if !SEASON_REGIONS.contains(&region.as_str()) {
+        return Err(AppError::Validation(
+            Message::UnknownSeasonRegion {
+                region: &region,
+                regions: &SEASON_REGIONS.join(", "),
+            }
+            .to_string(),
+        ));
+    }
+
+    let month = query.month.unwrap_or(Utc::now().month());
+
+    if !(1..=12).contains(&month) {
+        return Err(AppError::Validation(Message::InvalidMonth.to_string()));
+    }
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            data: Some(get_season_calendar(&region, month)),
+            error: None,
+        }),
+    ))
+}
+
+#[cfg(test)]
+mod unit_tests_seasons_router {
+    use super::*;
+    use crate::test_utils::{assert_status_codes, RequestTestCase};
+    use anyhow::Result;
+    use axum::http::Method;
+    use axum_test::TestServer;
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn get_season_calendar() -> Result<()> {
+        let server = TestServer::new(SeasonsRouter::new().router)?;
+
+        let response = server.get("/seasons?region=Southern-Europe&month=7").await;
+
+        response.assert_status_ok();
+        let body = response.json::<ApiResponse<SeasonCalendar>>();
+        let calendar = body.data.expect("Season calendar");
+        assert_eq!(calendar.region, "southern-europe");
+        assert_eq!(calendar.month, 7);
+        assert!(body.error.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_requests() -> Result<()> {
+        let test_cases = vec![
+            RequestTestCase {
+                title: "Rejects unknown regions".into(),
+                method: Method::GET,
+                path: "/seasons?region=atlantis".into(),
+                token: None,
+                body: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            RequestTestCase {
+                title: "Rejects months out of range".into(),
+                method: Method::GET,
+                path: "/seasons?month=13".into(),
+                token: None,
+                body: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+        ];
+
+        assert_status_codes(SeasonsRouter::new().router, test_cases).await
+    }
+}
@@ -0,0 +1,480 @@
+use axum::{
+    extract::State,
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    routing::{get, patch},
+    Json, Router,
+};
+use serde_json::{json, to_value};
+use tracing::info;
+
+use crate::{
+    api::{
+        api_response::ApiResponse,
+        app_error::{AppError, WithMessage},
+        messages::Message,
+        routers::households_router::get_household_with_role,
+        services::{
+            event_service::EventBus,
+            shopping_list_service::{export_shopping_list, merge_ingredients},
+            token_service::{get_claims_from_headers, Claims},
+        },
+        validation::{Path, Query, ValidatedJson},
+    },
+    db::{
+        generic_handler::GenericHandler, mongo_db_handler::MongoDbHandler,
+        shopping_list_handler::ShoppingListHandler,
+    },
+    model::{
+        event::{Event, EventKind},
+        household::{HouseholdMongoDb, HouseholdRole, HouseholdScopeQuery, Owner},
+        meal_plan::IsoWeek,
+        shopping_list::{
+            ShoppingList, ShoppingListCreate, ShoppingListExportFormat, ShoppingListExportQuery,
+            ShoppingListItemPatch,
+        },
+    },
+};
+
+#[derive(Clone)]
+struct RouterState {
+    jwt_secret: String,
+    db_handler: MongoDbHandler,
+    event_bus: EventBus,
+}
+
+pub struct ShoppingListsRouter {
+    pub router: Router,
+}
+
+impl ShoppingListsRouter {
+    pub fn new(db_handler: MongoDbHandler, jwt_secret: &str, event_bus: EventBus) -> Self {
+        let base_path = "/me/shopping-lists";
+
+        let router_state = RouterState {
+            db_handler,
+            jwt_secret: String::from(jwt_secret),
+            event_bus,
+        };
+
+        let router = Router::new()
+            .route(
+                base_path,
+                get(handle_get_shopping_lists).post(handle_create_shopping_list),
+            )
+            .route(
+                &format!("{base_path}/{{list_id}}"),
+                get(handle_get_shopping_list).delete(handle_delete_shopping_list),
+            )
+            .route(
+                &format!("{base_path}/{{list_id}}/export"),
+                get(handle_export_shopping_list),
+            )
+            .route(
+                &format!("{base_path}/{{list_id}}/items/{{item_id}}"),
+                patch(handle_patch_shopping_list_item),
+            )
+            .with_state(router_state);
+
+        Self { router }
+    }
+}
+
+// Members work on the shopping lists of their household when it is selected
+async fn get_owner(
+    router_state: &RouterState,
+    claims: &Claims,
+    scope: &HouseholdScopeQuery,
+) -> Result<Owner, AppError> {
+    match &scope.household_id {
+        Some(household_id) => {
+            get_household_with_role(
+                &router_state.db_handler,
+                claims,
+                household_id,
+                HouseholdRole::Member,
+            )
+            .await?;
+
+            Ok(Owner {
+                user_id: claims.user_id.clone(),
+                household_id: Some(household_id.clone()),
+            })
+        }
+        None => Ok(Owner::user(&claims.user_id)),
+    }
+}
+
+// Everyone sharing the lists of the owner
+async fn get_recipients(router_state: &RouterState, owner: &Owner) -> Vec<String> {
+    let household_id = match &owner.household_id {
+        Some(household_id) => household_id,
+        None => return vec![owner.user_id.clone()],
+    };
+
+    match router_state
+        .db_handler
+        .get_by_id::<HouseholdMongoDb, HouseholdMongoDb>(household_id, "households")
+        .await
+    {
+        Ok(household) => household
+            .members
+            .iter()
+            .map(|member| member.user_id.to_hex())
+            .collect(),
+        Err(err) => {
+            info!("Failed to get household with id '{household_id}': {err}");
+            vec![owner.user_id.clone()]
+        }
+    }
+}
+
+// Pushes the current state of a list to every session sharing it
+async fn publish_shopping_list(router_state: &RouterState, owner: &Owner, list_id: &str) {
+    let shopping_list = match router_state
+        .db_handler
+        .get_shopping_list(owner, list_id)
+        .await
+    {
+        Ok(Some(shopping_list)) => shopping_list,
+        Ok(None) => return,
+        Err(err) => {
+            info!("Failed to get shopping list with id '{list_id}': {err}");
+            return;
+        }
+    };
+
+    match to_value(shopping_list) {
+        Ok(data) => router_state.event_bus.publish(Event {
+            kind: EventKind::ShoppingListUpdated,
+            user_ids: get_recipients(router_state, owner).await,
+            recipe_id: None,
+            data,
+        }),
+        Err(err) => info!("Failed to push shopping list with id '{list_id}': {err}"),
+    }
+}
+
+async fn handle_get_shopping_lists(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Query(scope): Query<HouseholdScopeQuery>,
+) -> Result<(StatusCode, Json<ApiResponse<Vec<ShoppingList>>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let owner = get_owner(&router_state, &claims, &scope).await?;
+
+    let shopping_lists = router_state
+        .db_handler
+        .get_shopping_lists(&owner)
+        .await
+        .with_message(Message::GetShoppingListsFailed)?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            data: Some(shopping_lists),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_create_shopping_list(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Query(scope): Query<HouseholdScopeQuery>,
+    ValidatedJson(payload): ValidatedJson<ShoppingListCreate>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let owner = get_owner(&router_state, &claims, &scope).await?;
+
+    let meal_plan_week = match payload.meal_plan_week.map(|week| week.parse::<IsoWeek>()) {
+        Some(Ok(week)) => Some(week),
+        Some(Err(err)) => {
+            return Err(AppError::Validation(err.to_string()));
+        }
+        None => None,
+    };
+
+    let recipes = router_state
+        .db_handler
+        .get_shopping_list_recipes(&owner, &payload.recipe_ids, meal_plan_week.as_ref())
+        .await
+        .with_message(Message::GetShoppingListRecipesFailed)?;
+
+    let name = match (payload.name, meal_plan_week) {
+        (Some(name), _) => name,
+        (None, Some(week)) => Message::WeekShoppingListName { week: &week }.to_string(),
+        (None, None) => Message::DefaultShoppingListName.to_string(),
+    };
+
+    let list_id = router_state
+        .db_handler
+        .create_shopping_list(&owner, &name, merge_ingredients(&recipes))
+        .await
+        .with_message(Message::CreateShoppingListFailed)?;
+
+    publish_shopping_list(&router_state, &owner, &list_id).await;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse {
+            data: Some(list_id),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_get_shopping_list(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(list_id): Path<String>,
+    Query(scope): Query<HouseholdScopeQuery>,
+) -> Result<(StatusCode, Json<ApiResponse<ShoppingList>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let owner = get_owner(&router_state, &claims, &scope).await?;
+
+    match router_state
+        .db_handler
+        .get_shopping_list(&owner, &list_id)
+        .await
+        .with_message(Message::GetShoppingListFailed { list_id: &list_id })?
+    {
+        Some(shopping_list) => Ok((
+            StatusCode::OK,
+            Json(ApiResponse {
+                data: Some(shopping_list),
+                error: None,
+            }),
+        )),
+        None => Err(AppError::NotFound(
+            Message::ShoppingListNotFound { list_id: &list_id }.to_string(),
+        )),
+    }
+}
+
+async fn handle_export_shopping_list(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(list_id): Path<String>,
+    Query(scope): Query<HouseholdScopeQuery>,
+    Query(query): Query<ShoppingListExportQuery>,
+) -> Result<Response, AppError> {
+    let (status_code, Json(api_response)) =
+        handle_get_shopping_list(State(router_state), headers, Path(list_id), Query(scope)).await?;
+
+    let shopping_list = match api_response.data {
+        Some(shopping_list) => shopping_list,
+        None => return Ok((status_code, Json(api_response)).into_response()),
+    };
+
+    let content_type = match query.format {
+        ShoppingListExportFormat::Text => "text/plain; charset=utf-8",
+        ShoppingListExportFormat::Markdown => "text/markdown; charset=utf-8",
+    };
+
+    Ok((
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, content_type)],
+        export_shopping_list(&shopping_list, query.format),
+    )
+        .into_response())
+}
+
+async fn handle_patch_shopping_list_item(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path((list_id, item_id)): Path<(String, String)>,
+    Query(scope): Query<HouseholdScopeQuery>,
+    ValidatedJson(payload): ValidatedJson<ShoppingListItemPatch>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let owner = get_owner(&router_state, &claims, &scope).await?;
+
+    let update_count = router_state
+        .db_handler
+        .patch_shopping_list_item(&owner, &list_id, &item_id, payload)
+        .await
+        .with_message(Message::PatchShoppingListItemFailed { item_id: &item_id })?;
+
+    if update_count == 0 {
+        return Err(AppError::NotFound(
+            Message::ShoppingListItemNotFound { item_id: &item_id }.to_string(),
+        ));
+    }
+
+    publish_shopping_list(&router_state, &owner, &list_id).await;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(ApiResponse {
+            data: None,
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_delete_shopping_list(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(list_id): Path<String>,
+    Query(scope): Query<HouseholdScopeQuery>,
+) -> Result<(StatusCode, Json<ApiResponse<u64>>), AppError> {
+    let claims = get_claims_from_headers(&headers, &router_state.jwt_secret)
+        .map_err(AppError::unauthenticated)?;
+
+    let owner = get_owner(&router_state, &claims, &scope).await?;
+
+    let delete_count = router_state
+        .db_handler
+        .delete_shopping_list(&owner, &list_id)
+        .await
+        .with_message(Message::DeleteShoppingListFailed { list_id: &list_id })?;
+
+    if delete_count == 0 {
+        return Err(AppError::NotFound(
+            Message::ShoppingListNotFound { list_id: &list_id }.to_string(),
+        ));
+    }
+
+    router_state.event_bus.publish(Event {
+        kind: EventKind::ShoppingListDeleted,
+        user_ids: get_recipients(&router_state, &owner).await,
+        recipe_id: None,
+        data: json!({ "id": list_id }),
+    });
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(ApiResponse {
+            data: Some(delete_count),
+            error: None,
+        }),
+    ))
+}
+
+#[cfg(test)]
+mod unit_tests_shopping_lists_router {
+    use super::*;
+    use crate::{
+        db::household_handler::HouseholdHandler,
+        model::{household::HouseholdCreate, user::Role},
+        test_utils::{
+            assert_status_codes, db_clean_up, get_bearer_token, get_db_handler,
+            get_unconnected_db_handler, RequestTestCase, TEST_JWT_SECRET,
+        },
+    };
+    use anyhow::Result;
+    use axum::http::Method;
+    use bson::oid::ObjectId;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn rejects_invalid_requests() -> Result<()> {
+        let router = ShoppingListsRouter::new(
+            get_unconnected_db_handler()?,
+            TEST_JWT_SECRET,
+            EventBus::default(),
+        )
+        .router;
+        let token = get_bearer_token(ObjectId::new(), Role::User)?;
+
+        let test_cases = vec![
+            RequestTestCase {
+                title: "Rejects listing shopping lists without a token".into(),
+                method: Method::GET,
+                path: "/me/shopping-lists".into(),
+                token: None,
+                body: None,
+                expected_status_code: StatusCode::UNAUTHORIZED,
+            },
+            RequestTestCase {
+                title: "Rejects shopping lists of malformed weeks".into(),
+                method: Method::POST,
+                path: "/me/shopping-lists".into(),
+                token: Some(token.clone()),
+                body: Some(json!({"mealPlanWeek": "next week"})),
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            RequestTestCase {
+                title: "Rejects malformed household ids".into(),
+                method: Method::GET,
+                path: "/me/shopping-lists?householdId=not-an-id".into(),
+                token: Some(token.clone()),
+                body: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            RequestTestCase {
+                title: "Rejects malformed list ids".into(),
+                method: Method::GET,
+                path: "/me/shopping-lists/not-an-id".into(),
+                token: Some(token),
+                body: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+        ];
+
+        assert_status_codes(router, test_cases).await
+    }
+
+    #[tokio::test]
+    async fn hides_shopping_lists_of_others() -> Result<()> {
+        let db_handler = get_db_handler().await?;
+        let user_id = ObjectId::new();
+        let list_id = db_handler
+            .create_shopping_list(&Owner::user(&user_id.to_hex()), "Weekly", vec![])
+            .await?;
+        let household_id = db_handler
+            .create_household(
+                &user_id.to_hex(),
+                HouseholdCreate {
+                    name: "Home".into(),
+                },
+            )
+            .await?;
+        let router =
+            ShoppingListsRouter::new(db_handler, TEST_JWT_SECRET, EventBus::default()).router;
+
+        let path = format!("/me/shopping-lists/{list_id}");
+
+        let test_cases = vec![
+            RequestTestCase {
+                title: "Hides a shopping list from other users".into(),
+                method: Method::GET,
+                path: path.clone(),
+                token: Some(get_bearer_token(ObjectId::new(), Role::User)?),
+                body: None,
+                expected_status_code: StatusCode::NOT_FOUND,
+            },
+            RequestTestCase {
+                title: "Hides the shopping lists of a household from strangers".into(),
+                method: Method::GET,
+                path: format!("/me/shopping-lists?householdId={household_id}"),
+                token: Some(get_bearer_token(ObjectId::new(), Role::User)?),
+                body: None,
+                expected_status_code: StatusCode::NOT_FOUND,
+            },
+            RequestTestCase {
+                title: "Shows a shopping list to its owner".into(),
+                method: Method::GET,
+                path,
+                token: Some(get_bearer_token(user_id, Role::User)?),
+                body: None,
+                expected_status_code: StatusCode::OK,
+            },
+        ];
+
+        assert_status_codes(router, test_cases).await?;
+
+        db_clean_up().await?;
+
+        Ok(())
+    }
+}
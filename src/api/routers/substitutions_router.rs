@@ -0,0 +1,237 @@
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    routing::{get, patch},
+    Json, Router,
+};
+
+use crate::{
+    api::{
+        api_response::ApiResponse,
+        app_error::{AppError, WithMessage},
+        messages::Message,
+        services::token_service::get_claims_from_headers,
+        validation::{Path, Query, ValidatedJson},
+    },
+    db::{mongo_db_handler::MongoDbHandler, substitution_handler::SubstitutionHandler},
+    model::{
+        substitution::{Substitution, SubstitutionCreate, SubstitutionPatch, SubstitutionQuery},
+        user::Role,
+    },
+};
+
+#[derive(Clone)]
+struct RouterState {
+    jwt_secret: String,
+    db_handler: MongoDbHandler,
+}
+
+pub struct SubstitutionsRouter {
+    pub router: Router,
+}
+
+impl SubstitutionsRouter {
+    pub fn new(db_handler: MongoDbHandler, jwt_secret: &str) -> Self {
+        let base_path = "/substitutions";
+
+        let router_state = RouterState {
+            db_handler,
+            jwt_secret: String::from(jwt_secret),
+        };
+
+        let router = Router::new()
+            .route(
+                base_path,
+                get(handle_get_substitutions).post(handle_create_substitution),
+            )
+            .route(
+                &format!("{base_path}/{{id}}"),
+                patch(handle_patch_substitution).delete(handle_delete_substitution),
+            )
+            .with_state(router_state);
+
+        Self { router }
+    }
+}
+
+fn check_is_admin(headers: &HeaderMap, jwt_secret: &str) -> Result<(), AppError> {
+    let claims = get_claims_from_headers(headers, jwt_secret).map_err(AppError::unauthenticated)?;
+
+    if claims.role != Role::Admin {
+        return Err(AppError::Forbidden(
+            Message::CurateSubstitutionsForbidden.to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+async fn handle_get_substitutions(
+    State(router_state): State<RouterState>,
+    Query(query): Query<SubstitutionQuery>,
+) -> Result<(StatusCode, Json<ApiResponse<Vec<Substitution>>>), AppError> {
+    let substitutions = router_state
+        .db_handler
+        .get_substitutions(&query)
+        .await
+        .with_message(Message::GetSubstitutionsFailed)?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            data: Some(substitutions.into_iter().map(Into::into).collect()),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_create_substitution(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    ValidatedJson(payload): ValidatedJson<SubstitutionCreate>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    check_is_admin(&headers, &router_state.jwt_secret)?;
+
+    let id = router_state
+        .db_handler
+        .create_substitution(payload)
+        .await
+        .with_message(Message::CreateSubstitutionFailed)?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse {
+            data: Some(id),
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_patch_substitution(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(id): Path<String>,
+    ValidatedJson(payload): ValidatedJson<SubstitutionPatch>,
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    check_is_admin(&headers, &router_state.jwt_secret)?;
+
+    let update_count = router_state
+        .db_handler
+        .patch_substitution(&id, payload)
+        .await
+        .with_message(Message::PatchSubstitutionFailed { id: &id })?;
+
+    if update_count == 0 {
+        return Err(AppError::NotFound(
+            Message::SubstitutionNotFound { id: &id }.to_string(),
+        ));
+    }
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(ApiResponse {
+            data: None,
+            error: None,
+        }),
+    ))
+}
+
+async fn handle_delete_substitution(
+    State(router_state): State<RouterState>,
+    headers: HeaderMap,
+    Path(id): Path<String>,
+) -> Result<(StatusCode, Json<ApiResponse<u64>>), AppError> {
+    check_is_admin(&headers, &router_state.jwt_secret)?;
+
+    let delete_count = router_state
+        .db_handler
+        .delete_substitution(&id)
+        .await
+        .with_message(Message::DeleteSubstitutionFailed { id: &id })?;
+
+    if delete_count == 0 {
+        return Err(AppError::NotFound(
+            Message::SubstitutionNotFound { id: &id }.to_string(),
+        ));
+    }
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(ApiResponse {
+            data: Some(delete_count),
+            error: None,
+        }),
+    ))
+}
+
+#[cfg(test)]
+mod unit_tests_substitutions_router {
+    use super::*;
+    use crate::test_utils::{
+        assert_status_codes, get_bearer_token, get_unconnected_db_handler, RequestTestCase,
+        TEST_JWT_SECRET,
+    };
+    use anyhow::Result;
+    use axum::http::Method;
+    use bson::oid::ObjectId;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn rejects_invalid_requests() -> Result<()> {
+        let router =
+            SubstitutionsRouter::new(get_unconnected_db_handler()?, TEST_JWT_SECRET).router;
+        let substitution_id = ObjectId::new().to_hex();
+        let admin_token = get_bearer_token(ObjectId::new(), Role::Admin)?;
+
+        let test_cases = vec![
+            RequestTestCase {
+                title: "Rejects substitutions without a token".into(),
+                method: Method::POST,
+                path: "/substitutions".into(),
+                token: None,
+                body: Some(
+                    json!({"ingredient": "Butter", "substitute": "Margarine", "ratio": 1.0}),
+                ),
+                expected_status_code: StatusCode::UNAUTHORIZED,
+            },
+            RequestTestCase {
+                title: "Rejects substitutions by users".into(),
+                method: Method::POST,
+                path: "/substitutions".into(),
+                token: Some(get_bearer_token(ObjectId::new(), Role::User)?),
+                body: Some(
+                    json!({"ingredient": "Butter", "substitute": "Margarine", "ratio": 1.0}),
+                ),
+                expected_status_code: StatusCode::FORBIDDEN,
+            },
+            RequestTestCase {
+                title: "Rejects deleting substitutions by users".into(),
+                method: Method::DELETE,
+                path: format!("/substitutions/{substitution_id}"),
+                token: Some(get_bearer_token(ObjectId::new(), Role::User)?),
+                body: None,
+                expected_status_code: StatusCode::FORBIDDEN,
+            },
+            RequestTestCase {
+                title: "Rejects ratios that are not positive".into(),
+                method: Method::POST,
+                path: "/substitutions".into(),
+                token: Some(admin_token.clone()),
+                body: Some(
+                    json!({"ingredient": "Butter", "substitute": "Margarine", "ratio": 0.0}),
+                ),
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            RequestTestCase {
+                title: "Rejects malformed substitution ids".into(),
+                method: Method::DELETE,
+                path: "/substitutions/not-an-id".into(),
+                token: Some(admin_token),
+                body: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+        ];
+
+        assert_status_codes(router, test_cases).await
+    }
+}
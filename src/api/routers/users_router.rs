@@ -1,11 +1,3 @@
-use axum::{
-    extract::{Path, State},
-    http::StatusCode,
-    routing::{delete, get, patch},
-    Json, Router,
-};
-use tracing::info;
-
 use crate::{
     db::{
         generic_handler::GenericHandler, mongo_db_handler::MongoDbHandler,
@@ -13,8 +5,19 @@ use crate::{
     },
     model::user::{User, UserMongoDb, UserPatch},
 };
+use axum::{
+    extract::State,
+    http::StatusCode,
+    routing::{delete, get, patch},
+    Json, Router,
+};
 
-use super::super::api_response::ApiResponse;
+use super::super::{
+    api_response::ApiResponse,
+    app_error::{AppError, WithMessage},
+    messages::Message,
+    validation::Path,
+};
 
 pub struct UsersRouter {
     pub router: Router,
@@ -37,119 +40,80 @@ impl UsersRouter {
 
 async fn handle_users(
     State(db_handler): State<MongoDbHandler>,
-) -> (StatusCode, Json<ApiResponse<Vec<User>>>) {
-    match db_handler.get_multiple::<UserMongoDb, User>("users").await {
-        Ok(users) => (
-            StatusCode::OK,
-            Json(ApiResponse {
-                data: Some(users),
-                error: "".into(),
-            }),
-        ),
-        Err(err) => {
-            let err_msg = "Failed to get multiple users";
-            info!("{err_msg}: {err}");
-
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse {
-                    data: None,
-                    error: err_msg.into(),
-                }),
-            )
-        }
-    }
+) -> Result<(StatusCode, Json<ApiResponse<Vec<User>>>), AppError> {
+    let users = db_handler
+        .get_multiple::<UserMongoDb, User>("users")
+        .await
+        .with_message(Message::GetUsersFailed)?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            data: Some(users),
+            error: None,
+        }),
+    ))
 }
 
 async fn handle_user_by_id(
     State(db_handler): State<MongoDbHandler>,
     Path(user_id): Path<String>,
-) -> (StatusCode, Json<ApiResponse<User>>) {
-    match db_handler
+) -> Result<(StatusCode, Json<ApiResponse<User>>), AppError> {
+    let user = db_handler
         .get_by_id::<UserMongoDb, User>(&user_id, "users")
         .await
-    {
-        Ok(user) => (
-            StatusCode::OK,
-            Json(ApiResponse {
-                data: Some(user),
-                error: "".into(),
-            }),
-        ),
-        Err(err) => {
-            let err_msg = format!("Failed to get user with id '{user_id}'");
-            info!("{err_msg}: {err}");
-
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse {
-                    data: None,
-                    error: err_msg,
-                }),
-            )
-        }
-    }
+        .with_message(Message::GetUserFailed { user_id: &user_id })?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            data: Some(user),
+            error: None,
+        }),
+    ))
 }
 
 async fn handle_user_by_email(
     State(db_handler): State<MongoDbHandler>,
     Path(email): Path<String>,
-) -> (StatusCode, Json<ApiResponse<User>>) {
-    match db_handler.get_user_by_email(&email).await {
-        Ok(user) => (
-            StatusCode::OK,
-            Json(ApiResponse {
-                data: Some(user),
-                error: "".into(),
-            }),
-        ),
-        Err(err) => {
-            let err_msg = format!("Failed to get user with email '{email}'");
-            info!("{err_msg}: {err}");
-
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse {
-                    data: None,
-                    error: err_msg,
-                }),
-            )
-        }
-    }
+) -> Result<(StatusCode, Json<ApiResponse<User>>), AppError> {
+    let user = db_handler
+        .get_user_by_email(&email)
+        .await
+        .with_message(Message::GetUserByEmailFailed { email: &email })?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            data: Some(user),
+            error: None,
+        }),
+    ))
 }
 
 async fn handle_user_delete(
     State(db_handler): State<MongoDbHandler>,
     Path(user_id): Path<String>,
-) -> (StatusCode, Json<ApiResponse<u64>>) {
-    match db_handler.delete_user_by_id(&user_id).await {
-        Ok(delete_count) => (
-            StatusCode::NO_CONTENT,
-            Json(ApiResponse {
-                data: Some(delete_count),
-                error: "".into(),
-            }),
-        ),
-        Err(err) => {
-            let err_msg = format!("Failed to delete user with id '{user_id}'");
-            info!("{err_msg}: {err}");
-
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse {
-                    data: None,
-                    error: err_msg,
-                }),
-            )
-        }
-    }
+) -> Result<(StatusCode, Json<ApiResponse<u64>>), AppError> {
+    let delete_count = db_handler
+        .delete_user_by_id(&user_id)
+        .await
+        .with_message(Message::DeleteUserFailed { user_id: &user_id })?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(ApiResponse {
+            data: Some(delete_count),
+            error: None,
+        }),
+    ))
 }
 
 async fn handle_activate_user(
     State(db_handler): State<MongoDbHandler>,
     Path(user_id): Path<String>,
-) -> (StatusCode, Json<ApiResponse<String>>) {
-    match db_handler
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    db_handler
         .patch_user_by_id(
             &user_id,
             UserPatch {
@@ -160,34 +124,22 @@ async fn handle_activate_user(
             },
         )
         .await
-    {
-        Ok(_) => (
-            StatusCode::NO_CONTENT,
-            Json(ApiResponse {
-                data: None,
-                error: "".into(),
-            }),
-        ),
-        Err(err) => {
-            let err_msg = format!("Failed to activate user with id '{user_id}'");
-            info!("{err_msg}: {err}");
-
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse {
-                    data: None,
-                    error: err_msg,
-                }),
-            )
-        }
-    }
+        .with_message(Message::ActivateUserFailed { user_id: &user_id })?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(ApiResponse {
+            data: None,
+            error: None,
+        }),
+    ))
 }
 
 async fn handle_deactivate_user(
     State(db_handler): State<MongoDbHandler>,
     Path(user_id): Path<String>,
-) -> (StatusCode, Json<ApiResponse<String>>) {
-    match db_handler
+) -> Result<(StatusCode, Json<ApiResponse<String>>), AppError> {
+    db_handler
         .patch_user_by_id(
             &user_id,
             UserPatch {
@@ -198,25 +150,13 @@ async fn handle_deactivate_user(
             },
         )
         .await
-    {
-        Ok(_) => (
-            StatusCode::NO_CONTENT,
-            Json(ApiResponse {
-                data: None,
-                error: "".into(),
-            }),
-        ),
-        Err(err) => {
-            let err_msg = format!("Failed to deactivate user with id '{user_id}'");
-            info!("{err_msg}: {err}");
-
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse {
-                    data: None,
-                    error: err_msg,
-                }),
-            )
-        }
-    }
+        .with_message(Message::DeactivateUserFailed { user_id: &user_id })?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(ApiResponse {
+            data: None,
+            error: None,
+        }),
+    ))
 }
@@ -1,9 +1,14 @@
 use anyhow::Result;
-use axum::{serve, Router};
+use axum::{middleware::from_fn, serve, Router};
 use tokio::{self, net::TcpListener};
 use tower_http::trace::{DefaultMakeSpan, DefaultOnRequest, DefaultOnResponse, TraceLayer};
 use tracing::{info, Level};
 
+use super::{
+    messages::localize_messages,
+    request_context::{assign_request_id, negotiate_problem_details},
+};
+
 pub struct Server {}
 
 impl Server {
@@ -14,6 +19,9 @@ impl Server {
                 acc = acc.merge(r);
                 acc
             })
+            .layer(from_fn(negotiate_problem_details))
+            .layer(from_fn(localize_messages))
+            .layer(from_fn(assign_request_id))
             .layer(
                 TraceLayer::new_for_http()
                     .make_span_with(DefaultMakeSpan::new().level(Level::INFO))
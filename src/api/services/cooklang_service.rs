@@ -0,0 +1,493 @@
+use anyhow::{anyhow, Result};
+
+use super::import_service::{
+    apply_recipe_metadata, get_draft_recipe, parse_number, ImportedRecipe,
+};
+use crate::model::recipe::{Ingredient, Recipe};
+
+// Nouns only, since verbs like "whisk" or "grill" would mark up instructions
+const COOKWARE: [&str; 13] = [
+    "baking sheet",
+    "baking dish",
+    "frying pan",
+    "saucepan",
+    "skillet",
+    "pan",
+    "pot",
+    "wok",
+    "bowl",
+    "oven",
+    "blender",
+    "colander",
+    "tray",
+];
+
+const TIMER_UNITS: [&str; 12] = [
+    "second", "seconds", "sec", "secs", "minute", "minutes", "min", "mins", "hour", "hours", "hr",
+    "hrs",
+];
+
+fn format_amount(quantity: Option<f64>, unit: Option<&str>) -> String {
+    match (quantity, unit) {
+        (Some(quantity), Some(unit)) => format!("{quantity}%{unit}"),
+        (Some(quantity), None) => quantity.to_string(),
+        _ => "".into(),
+    }
+}
+
+fn format_ingredient(ingredient: &Ingredient) -> String {
+    let amount = format_amount(ingredient.quantity, ingredient.unit.as_deref());
+
+    match &ingredient.note {
+        Some(note) => format!("@{}{{{amount}}}({note})", ingredient.name),
+        None => format!("@{}{{{amount}}}", ingredient.name),
+    }
+}
+
+// Finds a whole word match in text that is already lowercase
+fn find_word(text: &str, word: &str) -> Option<usize> {
+    if word.is_empty() {
+        return None;
+    }
+
+    text.match_indices(word)
+        .map(|(start, _)| start)
+        .find(|start| {
+            let end = start + word.len();
+            !text[..*start].ends_with(char::is_alphanumeric)
+                && !text[end..].starts_with(char::is_alphanumeric)
+        })
+}
+
+fn get_words(text: &str) -> Vec<(usize, &str)> {
+    let mut words = vec![];
+    let mut start = None;
+
+    for (i, c) in text.char_indices() {
+        match (c.is_whitespace(), start) {
+            (true, Some(word_start)) => {
+                words.push((word_start, &text[word_start..i]));
+                start = None;
+            }
+            (false, None) => start = Some(i),
+            _ => {}
+        }
+    }
+
+    if let Some(word_start) = start {
+        words.push((word_start, &text[word_start..]));
+    }
+
+    words
+}
+
+/// Marks up ingredients, cookware and timers mentioned in a plain text step
+fn annotate_step(step: &str, ingredients: &[Ingredient], mentioned: &mut [bool]) -> String {
+    // ASCII lowercasing keeps byte offsets aligned with the original
+    let lowercase_step = step.to_ascii_lowercase();
+    let mut spans: Vec<(usize, usize, String)> = vec![];
+
+    let overlaps = |spans: &[(usize, usize, String)], start: usize, end: usize| {
+        spans
+            .iter()
+            .any(|(span_start, span_end, _)| start < *span_end && *span_start < end)
+    };
+
+    for (i, ingredient) in ingredients.iter().enumerate() {
+        if mentioned[i] {
+            continue;
+        }
+
+        // "Mash the bananas" mentions "ripe bananas"
+        let name = ingredient.name.to_ascii_lowercase();
+        let last_word = name.split_whitespace().last().unwrap_or_default();
+        let found = find_word(&lowercase_step, &name)
+            .map(|start| (start, start + name.len()))
+            .or_else(|| {
+                find_word(&lowercase_step, last_word).map(|start| (start, start + last_word.len()))
+            });
+
+        if let Some((start, end)) = found.filter(|(start, end)| !overlaps(&spans, *start, *end)) {
+            spans.push((start, end, format_ingredient(ingredient)));
+            mentioned[i] = true;
+        }
+    }
+
+    for cookware in COOKWARE {
+        if let Some(start) = find_word(&lowercase_step, cookware) {
+            let end = start + cookware.len();
+            if !overlaps(&spans, start, end) {
+                spans.push((start, end, format!("#{}{{}}", &step[start..end])));
+            }
+        }
+    }
+
+    for pair in get_words(step).windows(2) {
+        let [(start, number), (unit_start, unit)] = pair else {
+            continue;
+        };
+        let unit = unit.trim_end_matches(|c: char| !c.is_alphanumeric());
+        let end = unit_start + unit.len();
+
+        if parse_number(number).is_some()
+            && TIMER_UNITS.contains(&unit.to_lowercase().as_str())
+            && !overlaps(&spans, *start, end)
+        {
+            spans.push((*start, end, format!("~{{{number}%{unit}}}")));
+        }
+    }
+
+    spans.sort_by_key(|(start, _, _)| *start);
+
+    let mut annotated = String::new();
+    let mut position = 0;
+    for (start, end, markup) in spans {
+        annotated.push_str(&step[position..start]);
+        annotated.push_str(&markup);
+        position = end;
+    }
+    annotated.push_str(&step[position..]);
+
+    annotated
+}
+
+pub fn export_cooklang(recipe: &Recipe) -> String {
+    let mut lines = vec![format!(">> title: {}", recipe.title)];
+
+    if !recipe.description.is_empty() {
+        let description = recipe.description.split_whitespace().collect::<Vec<&str>>();
+        lines.push(format!(">> description: {}", description.join(" ")));
+    }
+
+    lines.push(format!(">> servings: {}", recipe.servings));
+
+    if let Some(prep_time_minutes) = recipe.prep_time_minutes {
+        lines.push(format!(">> prep time: {prep_time_minutes} minutes"));
+    }
+
+    if let Some(cook_time_minutes) = recipe.cook_time_minutes {
+        lines.push(format!(">> cook time: {cook_time_minutes} minutes"));
+    }
+
+    if let Some(nutrition) = recipe.nutrition {
+        lines.push(format!(">> calories: {}", nutrition.calories));
+        lines.push(format!(">> protein: {}", nutrition.protein));
+        lines.push(format!(">> carbohydrates: {}", nutrition.carbohydrates));
+        lines.push(format!(">> fat: {}", nutrition.fat));
+        lines.push(format!(">> fiber: {}", nutrition.fiber));
+    }
+
+    let mut mentioned = vec![false; recipe.ingredients.len()];
+    let mut steps: Vec<String> = recipe
+        .steps
+        .iter()
+        .map(|step| annotate_step(step, &recipe.ingredients, &mut mentioned))
+        .collect();
+
+    // Cooklang has no ingredient list, so unmentioned ingredients get their own step
+    let unmentioned: Vec<String> = recipe
+        .ingredients
+        .iter()
+        .zip(&mentioned)
+        .filter(|(_, is_mentioned)| !**is_mentioned)
+        .map(|(ingredient, _)| format_ingredient(ingredient))
+        .collect();
+    if !unmentioned.is_empty() {
+        steps.insert(0, format!("Gather {}.", unmentioned.join(", ")));
+    }
+
+    for step in steps {
+        lines.push("".into());
+        lines.push(step);
+    }
+
+    lines.join("\n") + "\n"
+}
+
+fn parse_amount(amount: &str) -> (Option<f64>, Option<String>) {
+    let (quantity, unit) = match amount.split_once('%') {
+        Some((quantity, unit)) => (quantity, Some(unit.trim())),
+        None => (amount, None),
+    };
+
+    // Mixed numbers like "1 1/2"
+    let quantity = quantity
+        .split_whitespace()
+        .map(parse_number)
+        .try_fold(None, |total: Option<f64>, value| {
+            value.map(|value| Some(total.unwrap_or(0.0) + value))
+        })
+        .flatten();
+
+    (
+        quantity,
+        unit.filter(|unit| !unit.is_empty()).map(String::from),
+    )
+}
+
+// Returns the component name, its amount and the position after it
+fn parse_component(chars: &[char], start: usize) -> (String, Option<String>, usize) {
+    let brace = chars[start..]
+        .iter()
+        .position(|c| *c == '{')
+        .map(|i| start + i);
+    let close = brace.and_then(|brace| {
+        chars[brace..]
+            .iter()
+            .position(|c| *c == '}')
+            .map(|i| brace + i)
+    });
+
+    // Multi-word names must be followed by braces without another component in between
+    if let (Some(brace), Some(close)) = (brace, close) {
+        let name = &chars[start..brace];
+        if !name.iter().any(|c| ['@', '#', '~', '}'].contains(c)) {
+            return (
+                name.iter().collect::<String>().trim().into(),
+                Some(chars[brace + 1..close].iter().collect()),
+                close + 1,
+            );
+        }
+    }
+
+    let end = chars[start..]
+        .iter()
+        .position(|c| !c.is_alphanumeric() && *c != '_')
+        .map_or(chars.len(), |i| start + i);
+
+    (chars[start..end].iter().collect(), None, end)
+}
+
+fn add_ingredient(ingredients: &mut Vec<Ingredient>, ingredient: Ingredient) {
+    let existing = ingredients.iter_mut().find(|existing| {
+        existing.name.eq_ignore_ascii_case(&ingredient.name) && existing.unit == ingredient.unit
+    });
+
+    match existing {
+        Some(existing) => {
+            if let (Some(total), Some(quantity)) = (existing.quantity, ingredient.quantity) {
+                existing.quantity = Some(total + quantity);
+            }
+        }
+        None => ingredients.push(ingredient),
+    }
+}
+
+fn parse_step(step: &str, ingredients: &mut Vec<Ingredient>) -> String {
+    let chars: Vec<char> = step.chars().collect();
+    let mut text = String::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let starts_component = ['@', '#', '~'].contains(&c)
+            && chars
+                .get(i + 1)
+                .is_some_and(|next| next.is_alphanumeric() || *next == '{');
+
+        if !starts_component {
+            text.push(c);
+            i += 1;
+            continue;
+        }
+
+        let (name, amount, next) = parse_component(&chars, i + 1);
+        i = next;
+
+        match c {
+            '@' => {
+                let (quantity, unit) = parse_amount(amount.as_deref().unwrap_or_default());
+
+                let mut note = None;
+                if chars.get(i) == Some(&'(') {
+                    if let Some(end) = chars[i..].iter().position(|c| *c == ')') {
+                        note = Some(chars[i + 1..i + end].iter().collect::<String>());
+                        i += end + 1;
+                    }
+                }
+
+                text.push_str(&name);
+                add_ingredient(
+                    ingredients,
+                    Ingredient {
+                        name,
+                        quantity,
+                        unit,
+                        note,
+                    },
+                );
+            }
+            '#' => text.push_str(&name),
+            _ => match amount {
+                Some(amount) => text.push_str(&amount.replace('%', " ")),
+                None => text.push_str(&name),
+            },
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+fn strip_comments(text: &str) -> String {
+    let mut stripped = String::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find("[-") {
+        stripped.push_str(&rest[..start]);
+        rest = match rest[start..].find("-]") {
+            Some(end) => &rest[start + end + 2..],
+            None => "",
+        };
+    }
+    stripped.push_str(rest);
+
+    stripped
+        .lines()
+        .map(|line| line.find("--").map_or(line, |start| &line[..start]))
+        .collect::<Vec<&str>>()
+        .join("\n")
+}
+
+pub fn import_cooklang(text: &str) -> Result<ImportedRecipe> {
+    let mut recipe = get_draft_recipe();
+    let mut unmapped_fields = vec![];
+    let text = strip_comments(text);
+    let mut paragraphs = vec![vec![]];
+
+    for line in text.lines() {
+        let line = line.trim();
+
+        if let Some(metadata) = line.strip_prefix(">>") {
+            if let Some((key, value)) = metadata.split_once(':') {
+                apply_recipe_metadata(&mut recipe, key, value, &mut unmapped_fields);
+            }
+        } else if line.is_empty() {
+            paragraphs.push(vec![]);
+        } else if let Some(paragraph) = paragraphs.last_mut() {
+            paragraph.push(line);
+        }
+    }
+
+    for paragraph in paragraphs.iter().filter(|paragraph| !paragraph.is_empty()) {
+        let step = parse_step(&paragraph.join(" "), &mut recipe.ingredients);
+        if !step.is_empty() {
+            recipe.steps.push(step);
+        }
+    }
+
+    if recipe.title.is_empty() {
+        return Err(anyhow!("Recipe has no title metadata"));
+    }
+
+    Ok(ImportedRecipe {
+        recipe,
+        unmapped_fields,
+    })
+}
+
+#[cfg(test)]
+mod unit_tests_cooklang_service {
+    use super::*;
+    use crate::{model::recipe::RecipeStatus, test_utils::get_random_recipe_db};
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+
+    fn get_ingredient(name: &str, quantity: Option<f64>, unit: Option<&str>) -> Ingredient {
+        Ingredient {
+            name: name.into(),
+            quantity,
+            unit: unit.map(String::from),
+            note: None,
+        }
+    }
+
+    #[test]
+    fn exports_cooklang() {
+        let mut recipe_db = get_random_recipe_db(None, None);
+        recipe_db.title = "Banana pancakes".into();
+        recipe_db.description = "".into();
+        recipe_db.servings = 2;
+        recipe_db.prep_time_minutes = Some(5);
+        recipe_db.cook_time_minutes = None;
+        recipe_db.nutrition = None;
+        recipe_db.ingredients = vec![
+            get_ingredient("ripe bananas", Some(2.0), None),
+            get_ingredient("oat milk", Some(250.0), Some("ml")),
+            get_ingredient("salt", None, None),
+        ];
+        recipe_db.steps = vec![
+            "Mash the bananas in a bowl.".into(),
+            "Whisk in the oat milk and rest for 10 minutes.".into(),
+        ];
+
+        assert_eq!(
+            export_cooklang(&recipe_db.into()),
+            [
+                ">> title: Banana pancakes",
+                ">> servings: 2",
+                ">> prep time: 5 minutes",
+                "",
+                "Gather @salt{}.",
+                "",
+                "Mash the @ripe bananas{2} in a #bowl{}.",
+                "",
+                "Whisk in the @oat milk{250%ml} and rest for ~{10%minutes}.",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn imports_cooklang() -> Result<()> {
+        let text = [
+            ">> title: Garlic rice",
+            ">> servings: 4",
+            ">> cook time: 1 hour 5 minutes",
+            ">> source: https://example.com",
+            "",
+            "Rinse @rice{1 1/2%cups} in a #fine mesh sieve{}. -- until clear",
+            "",
+            "Fry @garlic{2%cloves}(minced) in @olive oil{1%tbsp}",
+            "for ~{2%minutes} and add @salt.",
+            "",
+            "[- Optional -]Add more @garlic{1%cloves} to taste.",
+        ]
+        .join("\n");
+
+        let imported = import_cooklang(&text)?;
+
+        assert_eq!(imported.recipe.title, "Garlic rice");
+        assert_eq!(imported.recipe.servings, 4);
+        assert_eq!(imported.recipe.cook_time_minutes, Some(65));
+        assert_eq!(imported.recipe.status, RecipeStatus::Draft);
+        assert_eq!(imported.unmapped_fields, vec!["source".to_string()]);
+        assert_eq!(
+            imported.recipe.steps,
+            vec![
+                "Rinse rice in a fine mesh sieve.".to_string(),
+                "Fry garlic in olive oil for 2 minutes and add salt.".into(),
+                "Add more garlic to taste.".into(),
+            ]
+        );
+        assert_eq!(
+            imported.recipe.ingredients,
+            vec![
+                get_ingredient("rice", Some(1.5), Some("cups")),
+                Ingredient {
+                    note: Some("minced".into()),
+                    ..get_ingredient("garlic", Some(3.0), Some("cloves"))
+                },
+                get_ingredient("olive oil", Some(1.0), Some("tbsp")),
+                get_ingredient("salt", None, None),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_cooklang_without_title() {
+        assert!(import_cooklang("Boil @water{1%l}.").is_err());
+    }
+}
@@ -0,0 +1,233 @@
+use std::cmp::Reverse;
+
+use super::{
+    pantry_service::names_match,
+    unit_service::{convert, get_name_words, normalize_ingredient_name},
+};
+use crate::model::{
+    ingredient_price::{IngredientPriceMongoDb, RecipeCost},
+    recipe::{Ingredient, Recipe},
+};
+
+fn round_price(price: f64) -> f64 {
+    (price * 100.0).round() / 100.0
+}
+
+fn get_ingredient_cost(ingredient: &Ingredient, price: &IngredientPriceMongoDb) -> Option<f64> {
+    let quantity = ingredient.quantity?;
+
+    let priced_quantity = match (&ingredient.unit, &price.unit) {
+        // Prices without unit are per piece, e.g. one lemon
+        (None, None) => quantity,
+        (Some(unit), Some(price_unit)) if unit.trim().eq_ignore_ascii_case(price_unit.trim()) => {
+            quantity
+        }
+        (Some(unit), Some(price_unit)) => convert(quantity, unit, price_unit, &ingredient.name)?,
+        _ => return None,
+    };
+
+    Some(priced_quantity * price.price)
+}
+
+// Exact names first, then the ones sharing most words, so "olive oil" beats "oil"
+fn get_name_specificity(price_name: &str, ingredient_name: &str) -> (bool, usize) {
+    let ingredient_words = get_name_words(ingredient_name);
+    let shared_count = get_name_words(price_name)
+        .iter()
+        .filter(|word| ingredient_words.contains(word))
+        .count();
+
+    (
+        normalize_ingredient_name(price_name) == normalize_ingredient_name(ingredient_name),
+        shared_count,
+    )
+}
+
+/// Estimates the cost of a recipe from prices in one currency, None if no ingredient has a price
+pub fn estimate_cost(
+    recipe: &Recipe,
+    prices: &[IngredientPriceMongoDb],
+    currency: &str,
+) -> Option<RecipeCost> {
+    // Regional prices are more accurate than the ones that apply everywhere
+    let mut sorted_prices = prices
+        .iter()
+        .filter(|price| price.currency.eq_ignore_ascii_case(currency))
+        .collect::<Vec<&IngredientPriceMongoDb>>();
+    sorted_prices.sort_by_key(|price| price.region.is_none());
+
+    let mut total = 0.0;
+    let mut priced_count = 0;
+    let mut missing_ingredients = vec![];
+
+    for ingredient in recipe.ingredients.iter() {
+        if ingredient.quantity.is_none() {
+            continue;
+        }
+
+        let mut matching_prices = sorted_prices
+            .iter()
+            .filter(|price| names_match(&price.ingredient, &ingredient.name))
+            .collect::<Vec<_>>();
+        matching_prices.sort_by_key(|price| {
+            Reverse(get_name_specificity(&price.ingredient, &ingredient.name))
+        });
+
+        let cost = matching_prices
+            .into_iter()
+            .find_map(|price| get_ingredient_cost(ingredient, price));
+
+        match cost {
+            Some(cost) => {
+                total += cost;
+                priced_count += 1;
+            }
+            None => missing_ingredients.push(ingredient.name.clone()),
+        }
+    }
+
+    if priced_count == 0 {
+        return None;
+    }
+
+    Some(RecipeCost {
+        currency: currency.to_uppercase(),
+        total: round_price(total),
+        per_serving: round_price(total / recipe.servings.max(1) as f64),
+        missing_ingredients,
+    })
+}
+
+#[cfg(test)]
+mod unit_tests_cost_service {
+    use bson::{oid::ObjectId, DateTime};
+
+    use super::*;
+    use crate::test_utils::get_random_recipe_db;
+    use pretty_assertions::assert_eq;
+
+    fn get_price(
+        ingredient: &str,
+        unit: Option<&str>,
+        price: f64,
+        region: Option<&str>,
+    ) -> IngredientPriceMongoDb {
+        IngredientPriceMongoDb {
+            _id: ObjectId::new(),
+            ingredient: ingredient.into(),
+            unit: unit.map(Into::into),
+            price,
+            currency: "EUR".into(),
+            region: region.map(Into::into),
+            created_at: DateTime::now(),
+            modified_at: DateTime::now(),
+        }
+    }
+
+    fn get_ingredient(name: &str, quantity: Option<f64>, unit: Option<&str>) -> Ingredient {
+        Ingredient {
+            name: name.into(),
+            quantity,
+            unit: unit.map(Into::into),
+            note: None,
+        }
+    }
+
+    #[test]
+    fn estimates_recipe_cost() {
+        struct TestCase {
+            title: String,
+            ingredients: Vec<Ingredient>,
+            prices: Vec<IngredientPriceMongoDb>,
+            expected_cost: Option<RecipeCost>,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Converts units and divides by servings".into(),
+                ingredients: vec![
+                    get_ingredient("Flour", Some(500.0), Some("g")),
+                    get_ingredient("Lemons", Some(2.0), None),
+                    get_ingredient("Salt", None, None),
+                ],
+                prices: vec![
+                    get_price("flour", Some("kg"), 0.8, None),
+                    get_price("lemon", None, 0.5, None),
+                ],
+                expected_cost: Some(RecipeCost {
+                    currency: "EUR".into(),
+                    total: 1.4,
+                    per_serving: 0.7,
+                    missing_ingredients: vec![],
+                }),
+            },
+            TestCase {
+                title: "Prefers regional prices".into(),
+                ingredients: vec![get_ingredient("Flour", Some(1.0), Some("kg"))],
+                prices: vec![
+                    get_price("flour", Some("kg"), 0.8, None),
+                    get_price("flour", Some("kg"), 1.0, Some("at")),
+                ],
+                expected_cost: Some(RecipeCost {
+                    currency: "EUR".into(),
+                    total: 1.0,
+                    per_serving: 0.5,
+                    missing_ingredients: vec![],
+                }),
+            },
+            TestCase {
+                title: "Prefers the most specific name".into(),
+                ingredients: vec![
+                    get_ingredient("Olive oil", Some(1.0), Some("l")),
+                    get_ingredient("Extra virgin olive oil", Some(1.0), Some("l")),
+                    get_ingredient("Oil", Some(1.0), Some("l")),
+                ],
+                prices: vec![
+                    get_price("oil", Some("l"), 2.0, Some("at")),
+                    get_price("olive oil", Some("l"), 8.0, None),
+                    get_price("Olive Oil", Some("l"), 9.0, Some("at")),
+                ],
+                expected_cost: Some(RecipeCost {
+                    currency: "EUR".into(),
+                    total: 20.0,
+                    per_serving: 10.0,
+                    missing_ingredients: vec![],
+                }),
+            },
+            TestCase {
+                title: "Reports ingredients without usable price".into(),
+                ingredients: vec![
+                    get_ingredient("Flour", Some(250.0), Some("g")),
+                    get_ingredient("Tofu", Some(200.0), Some("g")),
+                    get_ingredient("Leeks", Some(2.0), None),
+                ],
+                prices: vec![
+                    get_price("flour", Some("kg"), 0.8, None),
+                    get_price("leek", Some("kg"), 2.0, None),
+                ],
+                expected_cost: Some(RecipeCost {
+                    currency: "EUR".into(),
+                    total: 0.2,
+                    per_serving: 0.1,
+                    missing_ingredients: vec!["Tofu".into(), "Leeks".into()],
+                }),
+            },
+            TestCase {
+                title: "Returns nothing without any price".into(),
+                ingredients: vec![get_ingredient("Tofu", Some(200.0), Some("g"))],
+                prices: vec![],
+                expected_cost: None,
+            },
+        ];
+
+        for t in test_cases {
+            let mut recipe_db = get_random_recipe_db(None, None);
+            recipe_db.ingredients = t.ingredients;
+            recipe_db.servings = 2;
+
+            let cost = estimate_cost(&recipe_db.into(), &t.prices, "eur");
+
+            assert_eq!(cost, t.expected_cost, "{}", t.title);
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use tokio::sync::broadcast::{self, Receiver, Sender};
+
+use crate::model::event::Event;
+
+/// Subscribers that fall further behind than this skip the oldest events
+pub const EVENT_BUS_CAPACITY: usize = 256;
+
+/// In-process bus fanning out events to all open event streams
+#[derive(Clone)]
+pub struct EventBus {
+    sender: Sender<Event>,
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        let (sender, _) = broadcast::channel(EVENT_BUS_CAPACITY);
+
+        Self { sender }
+    }
+}
+
+impl EventBus {
+    pub fn publish(&self, event: Event) {
+        // Sending only fails without subscribers, in which case nobody misses the event
+        let _ = self.sender.send(event);
+    }
+
+    pub fn subscribe(&self) -> Receiver<Event> {
+        self.sender.subscribe()
+    }
+}
+
+#[cfg(test)]
+mod unit_tests_event_service {
+    use super::*;
+    use crate::model::event::EventKind;
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn fans_out_events() -> Result<()> {
+        let event_bus = EventBus::default();
+
+        event_bus.publish(Event {
+            kind: EventKind::Notification,
+            user_ids: vec!["nobody".into()],
+            recipe_id: None,
+            data: json!({}),
+        });
+
+        let mut first = event_bus.subscribe();
+        let mut second = event_bus.subscribe();
+
+        event_bus.publish(Event {
+            kind: EventKind::ShoppingListDeleted,
+            user_ids: vec!["user".into()],
+            recipe_id: None,
+            data: json!({"id": "list"}),
+        });
+
+        for receiver in [&mut first, &mut second] {
+            let event = receiver.recv().await?;
+            assert_eq!(event.kind, EventKind::ShoppingListDeleted);
+            assert_eq!(event.data, json!({"id": "list"}));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,855 @@
+use anyhow::{anyhow, Result};
+use serde_json::{Map, Value};
+
+use super::unit_service::parse_unit;
+use crate::model::recipe::{Ingredient, Nutrition, RecipeCreate, RecipeStatus};
+
+const DEFAULT_SERVINGS: u32 = 1;
+
+// Units that are kept as written since they have no mass or volume conversion
+const COUNT_UNITS: [&str; 32] = [
+    "clove", "cloves", "can", "cans", "pinch", "pinches", "dash", "dashes", "slice", "slices",
+    "bunch", "bunches", "sprig", "sprigs", "stick", "sticks", "head", "heads", "handful",
+    "handfuls", "package", "packages", "jar", "jars", "piece", "pieces", "leaf", "leaves", "stalk",
+    "stalks", "knob", "knobs",
+];
+
+const UNICODE_FRACTIONS: [(char, &str); 10] = [
+    ('½', " 1/2"),
+    ('⅓', " 1/3"),
+    ('⅔', " 2/3"),
+    ('¼', " 1/4"),
+    ('¾', " 3/4"),
+    ('⅕', " 1/5"),
+    ('⅛', " 1/8"),
+    ('⅜', " 3/8"),
+    ('⅝', " 5/8"),
+    ('⅞', " 7/8"),
+];
+
+const MAPPED_FIELDS: [&str; 14] = [
+    "@context",
+    "@type",
+    "@id",
+    "name",
+    "description",
+    "recipeIngredient",
+    "ingredients",
+    "recipeInstructions",
+    "recipeYield",
+    "prepTime",
+    "cookTime",
+    "totalTime",
+    "nutrition",
+    "inLanguage",
+];
+
+pub struct ImportedRecipe {
+    pub recipe: RecipeCreate,
+    pub unmapped_fields: Vec<String>,
+}
+
+pub fn get_draft_recipe() -> RecipeCreate {
+    RecipeCreate {
+        title: "".into(),
+        description: "".into(),
+        ingredients: vec![],
+        steps: vec![],
+        servings: DEFAULT_SERVINGS,
+        prep_time_minutes: None,
+        cook_time_minutes: None,
+        nutrition: None,
+        status: RecipeStatus::Draft,
+        locale: None,
+    }
+}
+
+/// Maps a "key: value" metadata entry of the text formats onto the recipe
+pub fn apply_recipe_metadata(
+    recipe: &mut RecipeCreate,
+    key: &str,
+    value: &str,
+    unmapped_fields: &mut Vec<String>,
+) {
+    let value = value.trim().trim_matches('"').trim();
+    let normalized_key = key.trim().to_lowercase().replace(['_', '-'], " ");
+    let normalized_key = normalized_key.trim_end_matches(" minutes");
+
+    let is_mapped = match normalized_key {
+        "title" | "name" => {
+            recipe.title = value.into();
+            !value.is_empty()
+        }
+        "description" => {
+            recipe.description = value.into();
+            true
+        }
+        "servings" | "serves" | "yield" => get_first_number(value)
+            .filter(|servings| *servings >= 1.0)
+            .map(|servings| recipe.servings = servings.round() as u32)
+            .is_some(),
+        "prep time" => parse_minutes(value)
+            .map(|minutes| recipe.prep_time_minutes = Some(minutes))
+            .is_some(),
+        "cook time" => parse_minutes(value)
+            .map(|minutes| recipe.cook_time_minutes = Some(minutes))
+            .is_some(),
+        "calories" | "protein" | "carbohydrates" | "fat" | "fiber" => {
+            match get_first_number(value) {
+                Some(amount) => {
+                    let nutrition = recipe.nutrition.get_or_insert_with(Nutrition::default);
+                    let field = match normalized_key {
+                        "calories" => &mut nutrition.calories,
+                        "protein" => &mut nutrition.protein,
+                        "carbohydrates" => &mut nutrition.carbohydrates,
+                        "fat" => &mut nutrition.fat,
+                        _ => &mut nutrition.fiber,
+                    };
+                    *field = amount;
+                    true
+                }
+                None => false,
+            }
+        }
+        _ => false,
+    };
+
+    if !is_mapped {
+        unmapped_fields.push(key.trim().into());
+    }
+}
+
+fn decode_entity(entity: &str) -> Option<char> {
+    match entity {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some(' '),
+        _ => {
+            let code = match entity
+                .strip_prefix("#x")
+                .or_else(|| entity.strip_prefix("#X"))
+            {
+                Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                None => entity.strip_prefix('#').and_then(|dec| dec.parse().ok()),
+            };
+            code.and_then(char::from_u32)
+        }
+    }
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest
+            .find(';')
+            .filter(|end| *end <= 10)
+            .and_then(|end| decode_entity(&rest[1..end]).map(|c| (c, end)));
+
+        match entity {
+            Some((c, end)) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+fn strip_tags(text: &str) -> String {
+    let mut stripped = String::new();
+    let mut in_tag = false;
+
+    for c in text.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => {
+                in_tag = false;
+                stripped.push(' ');
+            }
+            _ if !in_tag => stripped.push(c),
+            _ => {}
+        }
+    }
+
+    stripped
+}
+
+/// Removes markup and entities from blog text and collapses whitespace
+fn clean_text(text: &str) -> String {
+    decode_entities(&strip_tags(text))
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+fn get_text(value: &Value) -> Option<String> {
+    let text = match value {
+        Value::String(text) => clean_text(text),
+        Value::Number(number) => number.to_string(),
+        Value::Array(values) => return values.iter().find_map(get_text),
+        _ => return None,
+    };
+
+    (!text.is_empty()).then_some(text)
+}
+
+pub fn get_first_number(text: &str) -> Option<f64> {
+    let start = text.find(|c: char| c.is_ascii_digit())?;
+    let number: String = text[start..]
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == '.')
+        .collect();
+
+    number.parse().ok()
+}
+
+pub fn parse_number(token: &str) -> Option<f64> {
+    if !token.starts_with(|c: char| c.is_ascii_digit() || c == '.') {
+        return None;
+    }
+
+    match token.split_once('/') {
+        Some((numerator, denominator)) => {
+            let numerator: f64 = numerator.parse().ok()?;
+            let denominator: f64 = denominator.parse().ok()?;
+            (denominator != 0.0).then(|| numerator / denominator)
+        }
+        None => token.replace(',', ".").parse().ok(),
+    }
+}
+
+/// Parses a duration like "PT1H30M" into minutes
+pub fn parse_iso8601_duration(duration: &str) -> Option<u32> {
+    let duration = duration.trim().to_ascii_uppercase();
+    let components = duration.strip_prefix('P')?;
+
+    let mut minutes = 0.0;
+    let mut in_time = false;
+    let mut has_component = false;
+    let mut number = String::new();
+
+    for c in components.chars() {
+        match c {
+            'T' if !in_time && number.is_empty() => in_time = true,
+            '0'..='9' | '.' => number.push(c),
+            ',' => number.push('.'),
+            _ => {
+                let value: f64 = number.parse().ok()?;
+                number.clear();
+
+                minutes += value
+                    * match (c, in_time) {
+                        ('W', false) => 7.0 * 24.0 * 60.0,
+                        ('D', false) => 24.0 * 60.0,
+                        ('H', true) => 60.0,
+                        ('M', true) => 1.0,
+                        ('S', true) => 1.0 / 60.0,
+                        _ => return None,
+                    };
+                has_component = true;
+            }
+        }
+    }
+
+    if !has_component || !number.is_empty() {
+        return None;
+    }
+
+    Some(minutes.round() as u32)
+}
+
+/// Parses a duration like "1 hour 30 minutes", "45" or "PT45M" into minutes
+pub fn parse_minutes(duration: &str) -> Option<u32> {
+    if let Some(minutes) = parse_iso8601_duration(duration) {
+        return Some(minutes);
+    }
+
+    let mut minutes = 0.0;
+    let mut number = None;
+    let mut has_component = false;
+
+    for token in duration.split_whitespace() {
+        match (parse_number(token), number) {
+            (Some(value), None) => number = Some(value),
+            (None, Some(value)) => {
+                let token = token.to_lowercase();
+                let factor = match token.chars().next() {
+                    Some('h') => 60.0,
+                    Some('m') => 1.0,
+                    Some('s') => 1.0 / 60.0,
+                    _ => return None,
+                };
+                minutes += value * factor;
+                number = None;
+                has_component = true;
+            }
+            _ => return None,
+        }
+    }
+
+    if let Some(value) = number {
+        minutes += value;
+        has_component = true;
+    }
+
+    has_component.then(|| minutes.round() as u32)
+}
+
+// Returns the quantity and how many tokens it spans
+fn parse_quantity(tokens: &[&str]) -> Option<(f64, usize)> {
+    // Ranges like "2-3" use the lower bound
+    let first = tokens.first()?.split(['-', '–']).next()?;
+    let mut quantity = parse_number(first)?;
+    let mut consumed = 1;
+
+    if let Some(fraction) = tokens.get(1).filter(|token| token.contains('/')) {
+        if let Some(value) = parse_number(fraction) {
+            quantity += value;
+            consumed = 2;
+        }
+    }
+
+    let is_range_separator = tokens
+        .get(consumed)
+        .is_some_and(|token| ["-", "–", "to", "or"].contains(token));
+    if is_range_separator
+        && tokens
+            .get(consumed + 1)
+            .and_then(|token| parse_number(token))
+            .is_some()
+    {
+        consumed += 2;
+    }
+
+    Some((quantity, consumed))
+}
+
+// Returns the unit and how many tokens it spans
+fn parse_ingredient_unit(tokens: &[&str]) -> Option<(String, usize)> {
+    let clean = |token: &str| token.trim_end_matches([',', '.']).to_lowercase();
+
+    if let [first, second, ..] = tokens {
+        let unit = format!("{} {}", clean(first), clean(second));
+        if ["fl oz", "fluid ounce", "fluid ounces"].contains(&unit.as_str()) {
+            return Some(("fl oz".into(), 2));
+        }
+    }
+
+    let unit = clean(tokens.first()?);
+    (parse_unit(&unit).is_some() || COUNT_UNITS.contains(&unit.as_str())).then_some((unit, 1))
+}
+
+// Splits "flour (sifted), plus extra" into the name and its notes
+fn split_ingredient_note(text: &str, notes: &mut Vec<String>) -> String {
+    let mut name = text.to_string();
+
+    while let (Some(start), Some(end)) = (name.find('('), name.find(')')) {
+        if end < start {
+            break;
+        }
+        notes.push(name[start + 1..end].trim().to_string());
+        name.replace_range(start..=end, "");
+    }
+
+    if let Some((head, note)) = name.clone().split_once(',') {
+        notes.push(note.trim().to_string());
+        name = head.to_string();
+    }
+
+    name.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+/// Parses a free-text line like "1 ½ cups flour, sifted" into a structured ingredient
+pub fn parse_ingredient(line: &str) -> Ingredient {
+    let mut line = clean_text(line);
+    for (fraction, replacement) in UNICODE_FRACTIONS {
+        line = line.replace(fraction, replacement);
+    }
+
+    let tokens: Vec<&str> = line.split_whitespace().collect();
+    let mut notes = vec![];
+    let mut position = 0;
+
+    let quantity = parse_quantity(&tokens).map(|(quantity, consumed)| {
+        position = consumed;
+        quantity
+    });
+
+    // Package sizes like "1 (14 oz) can"
+    if quantity.is_some() && tokens.get(position).is_some_and(|t| t.starts_with('(')) {
+        if let Some(end) = tokens[position..].iter().position(|t| t.ends_with(')')) {
+            let note = tokens[position..=position + end].join(" ");
+            notes.push(note.trim_matches(['(', ')']).to_string());
+            position += end + 1;
+        }
+    }
+
+    let unit = match quantity {
+        Some(_) => parse_ingredient_unit(&tokens[position..]).map(|(unit, consumed)| {
+            position += consumed;
+            unit
+        }),
+        None => None,
+    };
+
+    if unit.is_some() && tokens.get(position) == Some(&"of") {
+        position += 1;
+    }
+
+    let mut name = split_ingredient_note(&tokens[position..].join(" "), &mut notes);
+    if name.is_empty() {
+        name = line.trim().to_string();
+    }
+
+    notes.retain(|note| !note.is_empty());
+
+    Ingredient {
+        name,
+        quantity,
+        unit,
+        note: (!notes.is_empty()).then(|| notes.join("; ")),
+    }
+}
+
+fn extract_json_ld_scripts(html: &str) -> Vec<&str> {
+    // ASCII lowercasing keeps byte offsets aligned with the original
+    let lowercase_html = html.to_ascii_lowercase();
+    let mut scripts = vec![];
+    let mut position = 0;
+
+    while let Some(start) = lowercase_html[position..].find("<script") {
+        let tag_start = position + start;
+        let Some(tag_end) = lowercase_html[tag_start..]
+            .find('>')
+            .map(|end| tag_start + end + 1)
+        else {
+            break;
+        };
+        let Some(script_end) = lowercase_html[tag_end..]
+            .find("</script")
+            .map(|end| tag_end + end)
+        else {
+            break;
+        };
+
+        if lowercase_html[tag_start..tag_end].contains("application/ld+json") {
+            scripts.push(&html[tag_end..script_end]);
+        }
+
+        position = script_end;
+    }
+
+    scripts
+}
+
+fn is_recipe_type(value: &Value) -> bool {
+    match value {
+        Value::String(schema_type) => {
+            schema_type == "Recipe" || schema_type.ends_with("schema.org/Recipe")
+        }
+        Value::Array(schema_types) => schema_types.iter().any(is_recipe_type),
+        _ => false,
+    }
+}
+
+fn find_recipe_node(value: &Value) -> Option<&Map<String, Value>> {
+    match value {
+        Value::Array(values) => values.iter().find_map(find_recipe_node),
+        Value::Object(object) => {
+            if object.get("@type").is_some_and(is_recipe_type) {
+                return Some(object);
+            }
+
+            object
+                .get("@graph")
+                .and_then(find_recipe_node)
+                .or_else(|| object.get("mainEntity").and_then(find_recipe_node))
+        }
+        _ => None,
+    }
+}
+
+fn get_ingredients(value: &Value) -> Vec<Ingredient> {
+    let lines: Vec<String> = match value {
+        Value::String(text) => text.lines().map(String::from).collect(),
+        Value::Array(values) => values.iter().filter_map(get_text).collect(),
+        _ => vec![],
+    };
+
+    lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| parse_ingredient(line))
+        .collect()
+}
+
+fn get_instructions(value: &Value) -> Vec<String> {
+    match value {
+        Value::String(text) => text
+            .lines()
+            .map(clean_text)
+            .filter(|step| !step.is_empty())
+            .collect(),
+        Value::Array(values) => values.iter().flat_map(get_instructions).collect(),
+        // HowToSection and ItemList group steps, HowToStep holds the text
+        Value::Object(object) => match object.get("itemListElement") {
+            Some(steps) => get_instructions(steps),
+            None => object
+                .get("text")
+                .or_else(|| object.get("name"))
+                .map(get_instructions)
+                .unwrap_or_default(),
+        },
+        _ => vec![],
+    }
+}
+
+fn get_servings(value: &Value) -> Option<u32> {
+    let servings = match value {
+        Value::Number(number) => number.as_f64(),
+        Value::String(text) => get_first_number(text),
+        Value::Array(values) => return values.iter().find_map(get_servings),
+        _ => None,
+    }?;
+
+    (servings >= 1.0).then(|| servings.round() as u32)
+}
+
+fn get_nutrition(value: &Value, unmapped_fields: &mut Vec<String>) -> Option<Nutrition> {
+    let Value::Object(object) = value else {
+        unmapped_fields.push("nutrition".into());
+        return None;
+    };
+
+    let mut nutrition = Nutrition::default();
+    let mut has_value = false;
+
+    for (key, value) in object {
+        let field = match key.as_str() {
+            "calories" => &mut nutrition.calories,
+            "proteinContent" => &mut nutrition.protein,
+            "carbohydrateContent" => &mut nutrition.carbohydrates,
+            "fatContent" => &mut nutrition.fat,
+            "fiberContent" => &mut nutrition.fiber,
+            "@type" => continue,
+            _ => {
+                unmapped_fields.push(format!("nutrition.{key}"));
+                continue;
+            }
+        };
+
+        match get_text(value).and_then(|text| get_first_number(&text)) {
+            Some(amount) => {
+                *field = amount;
+                has_value = true;
+            }
+            None => unmapped_fields.push(format!("nutrition.{key}")),
+        }
+    }
+
+    has_value.then_some(nutrition)
+}
+
+fn get_duration(
+    recipe: &Map<String, Value>,
+    key: &str,
+    unmapped_fields: &mut Vec<String>,
+) -> Option<u32> {
+    let duration = recipe.get(key)?;
+    let minutes = get_text(duration).and_then(|text| parse_iso8601_duration(&text));
+
+    if minutes.is_none() {
+        unmapped_fields.push(key.into());
+    }
+
+    minutes
+}
+
+fn map_recipe(recipe: &Map<String, Value>) -> Result<ImportedRecipe> {
+    let mut unmapped_fields: Vec<String> = recipe
+        .keys()
+        .filter(|key| !MAPPED_FIELDS.contains(&key.as_str()))
+        .cloned()
+        .collect();
+
+    let title = recipe
+        .get("name")
+        .and_then(get_text)
+        .ok_or_else(|| anyhow!("Recipe has no name"))?;
+
+    let ingredients = recipe
+        .get("recipeIngredient")
+        .or_else(|| recipe.get("ingredients"))
+        .map(get_ingredients)
+        .unwrap_or_default();
+
+    let steps = recipe
+        .get("recipeInstructions")
+        .map(get_instructions)
+        .unwrap_or_default();
+
+    let servings = match recipe.get("recipeYield").and_then(get_servings) {
+        Some(servings) => servings,
+        None => {
+            unmapped_fields.push("recipeYield".into());
+            DEFAULT_SERVINGS
+        }
+    };
+
+    let prep_time_minutes = get_duration(recipe, "prepTime", &mut unmapped_fields);
+    let total_time_minutes = get_duration(recipe, "totalTime", &mut unmapped_fields);
+    let cook_time_minutes = get_duration(recipe, "cookTime", &mut unmapped_fields).or_else(|| {
+        total_time_minutes.map(|total| total.saturating_sub(prep_time_minutes.unwrap_or(0)))
+    });
+
+    let nutrition = recipe
+        .get("nutrition")
+        .and_then(|nutrition| get_nutrition(nutrition, &mut unmapped_fields));
+
+    unmapped_fields.sort();
+    unmapped_fields.dedup();
+
+    Ok(ImportedRecipe {
+        recipe: RecipeCreate {
+            title,
+            description: recipe
+                .get("description")
+                .and_then(get_text)
+                .unwrap_or_default(),
+            ingredients,
+            steps,
+            servings,
+            prep_time_minutes,
+            cook_time_minutes,
+            nutrition,
+            status: RecipeStatus::Draft,
+            locale: recipe.get("inLanguage").and_then(get_text),
+        },
+        unmapped_fields,
+    })
+}
+
+/// Maps a schema.org Recipe, either as JSON-LD or embedded in an HTML page
+pub fn import_recipe(body: &str) -> Result<ImportedRecipe> {
+    let body = body.trim_start();
+
+    let documents: Vec<Value> = if body.starts_with('{') || body.starts_with('[') {
+        vec![serde_json::from_str(body)?]
+    } else {
+        extract_json_ld_scripts(body)
+            .into_iter()
+            .filter_map(|script| serde_json::from_str(script.trim()).ok())
+            .collect()
+    };
+
+    let recipe = documents
+        .iter()
+        .find_map(find_recipe_node)
+        .ok_or_else(|| anyhow!("No schema.org Recipe found"))?;
+
+    map_recipe(recipe)
+}
+
+#[cfg(test)]
+mod unit_tests_import_service {
+    use super::*;
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parses_ingredients() {
+        struct TestCase {
+            title: String,
+            line: String,
+            expected_ingredient: Ingredient,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Parses unicode mixed fractions and notes".into(),
+                line: "1½ cups all-purpose flour, sifted".into(),
+                expected_ingredient: Ingredient {
+                    name: "all-purpose flour".into(),
+                    quantity: Some(1.5),
+                    unit: Some("cups".into()),
+                    note: Some("sifted".into()),
+                },
+            },
+            TestCase {
+                title: "Parses package sizes and count units".into(),
+                line: "1 (14 oz) can chickpeas (drained)".into(),
+                expected_ingredient: Ingredient {
+                    name: "chickpeas".into(),
+                    quantity: Some(1.0),
+                    unit: Some("can".into()),
+                    note: Some("14 oz; drained".into()),
+                },
+            },
+            TestCase {
+                title: "Uses the lower bound of ranges".into(),
+                line: "2-3 Tbsp. of olive oil".into(),
+                expected_ingredient: Ingredient {
+                    name: "olive oil".into(),
+                    quantity: Some(2.0),
+                    unit: Some("tbsp".into()),
+                    note: None,
+                },
+            },
+            TestCase {
+                title: "Parses ingredients without units".into(),
+                line: "3 ripe bananas".into(),
+                expected_ingredient: Ingredient {
+                    name: "ripe bananas".into(),
+                    quantity: Some(3.0),
+                    unit: None,
+                    note: None,
+                },
+            },
+            TestCase {
+                title: "Keeps ingredients without quantities".into(),
+                line: "Salt &amp; pepper, to taste".into(),
+                expected_ingredient: Ingredient {
+                    name: "Salt & pepper".into(),
+                    quantity: None,
+                    unit: None,
+                    note: Some("to taste".into()),
+                },
+            },
+        ];
+
+        for t in test_cases {
+            assert_eq!(
+                parse_ingredient(&t.line),
+                t.expected_ingredient,
+                "{}",
+                t.title
+            );
+        }
+    }
+
+    #[test]
+    fn parses_iso8601_durations() {
+        struct TestCase {
+            title: String,
+            duration: String,
+            expected_minutes: Option<u32>,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Parses hours and minutes".into(),
+                duration: "PT1H30M".into(),
+                expected_minutes: Some(90),
+            },
+            TestCase {
+                title: "Parses days".into(),
+                duration: "P1DT2H".into(),
+                expected_minutes: Some(1560),
+            },
+            TestCase {
+                title: "Rounds seconds".into(),
+                duration: "PT90S".into(),
+                expected_minutes: Some(2),
+            },
+            TestCase {
+                title: "Rejects minutes in the date part".into(),
+                duration: "P5M".into(),
+                expected_minutes: None,
+            },
+            TestCase {
+                title: "Rejects plain text".into(),
+                duration: "20 minutes".into(),
+                expected_minutes: None,
+            },
+        ];
+
+        for t in test_cases {
+            assert_eq!(
+                parse_iso8601_duration(&t.duration),
+                t.expected_minutes,
+                "{}",
+                t.title
+            );
+        }
+    }
+
+    #[test]
+    fn imports_recipe_from_html() -> Result<()> {
+        let html = r#"
+            <html><head>
+            <script type="application/ld+json">{"@type": "WebSite", "name": "Blog"}</script>
+            <script type="application/ld+json">
+            {
+                "@context": "https://schema.org",
+                "@graph": [{
+                    "@type": ["Recipe", "NewsArticle"],
+                    "name": "Banana &amp; Oat Pancakes",
+                    "description": "<p>Fluffy pancakes</p>",
+                    "recipeYield": ["4", "4 pancakes"],
+                    "prepTime": "PT10M",
+                    "totalTime": "PT25M",
+                    "recipeIngredient": ["2 ripe bananas", "1 cup oat milk", "1 egg"],
+                    "recipeInstructions": [{
+                        "@type": "HowToSection",
+                        "itemListElement": [
+                            {"@type": "HowToStep", "text": "Mash the bananas."},
+                            {"@type": "HowToStep", "text": "Fry the batter."}
+                        ]
+                    }],
+                    "nutrition": {"@type": "NutritionInformation", "calories": "240 kcal", "sodiumContent": "100 mg"},
+                    "image": "https://example.com/pancakes.jpg"
+                }]
+            }
+            </script>
+            </head></html>
+        "#;
+
+        let imported = import_recipe(html)?;
+
+        assert_eq!(imported.recipe.title, "Banana & Oat Pancakes");
+        assert_eq!(imported.recipe.description, "Fluffy pancakes");
+        assert_eq!(imported.recipe.servings, 4);
+        assert_eq!(imported.recipe.prep_time_minutes, Some(10));
+        assert_eq!(imported.recipe.cook_time_minutes, Some(15));
+        assert_eq!(imported.recipe.ingredients.len(), 3);
+        assert_eq!(
+            imported.recipe.steps,
+            vec!["Mash the bananas.".to_string(), "Fry the batter.".into()]
+        );
+        assert_eq!(
+            imported
+                .recipe
+                .nutrition
+                .map(|nutrition| nutrition.calories),
+            Some(240.0)
+        );
+        assert_eq!(imported.recipe.status, RecipeStatus::Draft);
+        assert_eq!(
+            imported.unmapped_fields,
+            vec!["image".to_string(), "nutrition.sodiumContent".into()]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_documents_without_recipe() {
+        assert!(import_recipe(r#"{"@type": "Person", "name": "Jane"}"#).is_err());
+        assert!(import_recipe(r#"{"@type": "Recipe"}"#).is_err());
+        assert!(import_recipe("<html><body>No recipe here</body></html>").is_err());
+    }
+}
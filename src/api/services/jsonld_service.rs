@@ -0,0 +1,223 @@
+use serde_json::{json, Map, Value};
+
+use super::{markdown_service::format_ingredient, vegan_service::check_vegan};
+use crate::model::recipe::{Nutrition, Recipe};
+
+/// Formats minutes as an ISO 8601 duration like "PT1H30M"
+pub fn format_iso8601_duration(minutes: u32) -> String {
+    match (minutes / 60, minutes % 60) {
+        (0, minutes) => format!("PT{minutes}M"),
+        (hours, 0) => format!("PT{hours}H"),
+        (hours, minutes) => format!("PT{hours}H{minutes}M"),
+    }
+}
+
+fn round(value: f64) -> f64 {
+    (value * 10.0).round() / 10.0
+}
+
+fn get_nutrition_json_ld(nutrition: &Nutrition) -> Value {
+    json!({
+        "@type": "NutritionInformation",
+        "calories": format!("{} kcal", round(nutrition.calories)),
+        "proteinContent": format!("{} g", round(nutrition.protein)),
+        "carbohydrateContent": format!("{} g", round(nutrition.carbohydrates)),
+        "fatContent": format!("{} g", round(nutrition.fat)),
+        "fiberContent": format!("{} g", round(nutrition.fiber)),
+    })
+}
+
+/// Renders a recipe as a schema.org Recipe for search engine rich results
+pub fn export_json_ld(recipe: &Recipe) -> Value {
+    let mut json_ld = Map::new();
+
+    json_ld.insert("@context".into(), "https://schema.org".into());
+    json_ld.insert("@type".into(), "Recipe".into());
+    json_ld.insert("name".into(), recipe.title.clone().into());
+    json_ld.insert("description".into(), recipe.description.clone().into());
+    json_ld.insert("inLanguage".into(), recipe.locale.clone().into());
+    json_ld.insert(
+        "recipeYield".into(),
+        format!("{} servings", recipe.servings).into(),
+    );
+
+    let published_at = recipe.published_at.unwrap_or(recipe.created_at);
+    if let Ok(date_published) = published_at.try_to_rfc3339_string() {
+        json_ld.insert("datePublished".into(), date_published.into());
+    }
+
+    if let Ok(date_modified) = recipe.modified_at.try_to_rfc3339_string() {
+        json_ld.insert("dateModified".into(), date_modified.into());
+    }
+
+    if let Some(prep_time_minutes) = recipe.prep_time_minutes {
+        json_ld.insert(
+            "prepTime".into(),
+            format_iso8601_duration(prep_time_minutes).into(),
+        );
+    }
+
+    if let Some(cook_time_minutes) = recipe.cook_time_minutes {
+        json_ld.insert(
+            "cookTime".into(),
+            format_iso8601_duration(cook_time_minutes).into(),
+        );
+    }
+
+    if recipe.prep_time_minutes.is_some() || recipe.cook_time_minutes.is_some() {
+        let total_time_minutes =
+            recipe.prep_time_minutes.unwrap_or(0) + recipe.cook_time_minutes.unwrap_or(0);
+        json_ld.insert(
+            "totalTime".into(),
+            format_iso8601_duration(total_time_minutes).into(),
+        );
+    }
+
+    json_ld.insert(
+        "recipeIngredient".into(),
+        recipe
+            .ingredients
+            .iter()
+            .map(format_ingredient)
+            .collect::<Vec<String>>()
+            .into(),
+    );
+
+    json_ld.insert(
+        "recipeInstructions".into(),
+        recipe
+            .steps
+            .iter()
+            .enumerate()
+            .map(|(i, step)| {
+                json!({
+                    "@type": "HowToStep",
+                    "position": i + 1,
+                    "text": step,
+                })
+            })
+            .collect::<Vec<Value>>()
+            .into(),
+    );
+
+    if let Some(nutrition) = &recipe.nutrition {
+        json_ld.insert("nutrition".into(), get_nutrition_json_ld(nutrition));
+    }
+
+    // Search engines reject ratings without any reviews
+    if recipe.rating_count > 0 {
+        json_ld.insert(
+            "aggregateRating".into(),
+            json!({
+                "@type": "AggregateRating",
+                "ratingValue": round(recipe.rating_average),
+                "ratingCount": recipe.rating_count,
+                "bestRating": 5,
+                "worstRating": 1,
+            }),
+        );
+    }
+
+    if check_vegan(&recipe.ingredients).is_vegan {
+        json_ld.insert(
+            "suitableForDiet".into(),
+            "https://schema.org/VeganDiet".into(),
+        );
+    }
+
+    Value::Object(json_ld)
+}
+
+#[cfg(test)]
+mod unit_tests_jsonld_service {
+    use super::*;
+    use crate::{
+        api::services::import_service::import_recipe, model::recipe::Ingredient,
+        test_utils::get_random_recipe_db,
+    };
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn formats_iso8601_durations() {
+        assert_eq!(format_iso8601_duration(0), "PT0M");
+        assert_eq!(format_iso8601_duration(45), "PT45M");
+        assert_eq!(format_iso8601_duration(120), "PT2H");
+        assert_eq!(format_iso8601_duration(95), "PT1H35M");
+    }
+
+    #[test]
+    fn exports_json_ld() -> Result<()> {
+        let mut recipe_db = get_random_recipe_db(None, None);
+        recipe_db.servings = 2;
+        recipe_db.prep_time_minutes = Some(15);
+        recipe_db.cook_time_minutes = Some(90);
+        recipe_db.rating_average = 4.25;
+        recipe_db.rating_count = 8;
+        recipe_db.nutrition = Some(Nutrition {
+            calories: 320.0,
+            ..Default::default()
+        });
+        recipe_db.ingredients = vec![Ingredient {
+            name: "lentils".into(),
+            quantity: Some(200.0),
+            unit: Some("g".into()),
+            note: Some("rinsed".into()),
+        }];
+        recipe_db.steps = vec!["Simmer the lentils.".into()];
+        let recipe: Recipe = recipe_db.into();
+
+        let json_ld = export_json_ld(&recipe);
+
+        assert_eq!(json_ld["@type"], "Recipe");
+        assert_eq!(json_ld["recipeYield"], "2 servings");
+        assert_eq!(json_ld["totalTime"], "PT1H45M");
+        assert_eq!(
+            json_ld["recipeIngredient"],
+            json!(["200 g lentils, rinsed"])
+        );
+        assert_eq!(
+            json_ld["recipeInstructions"],
+            json!([{"@type": "HowToStep", "position": 1, "text": "Simmer the lentils."}])
+        );
+        assert_eq!(json_ld["nutrition"]["calories"], "320 kcal");
+        assert_eq!(json_ld["aggregateRating"]["ratingValue"], 4.3);
+        assert_eq!(json_ld["suitableForDiet"], "https://schema.org/VeganDiet");
+
+        // The export is readable by the importer
+        let imported = import_recipe(&json_ld.to_string())?;
+        assert_eq!(imported.recipe.title, recipe.title);
+        assert_eq!(imported.recipe.ingredients, recipe.ingredients);
+        assert_eq!(imported.recipe.steps, recipe.steps);
+        assert_eq!(imported.recipe.prep_time_minutes, Some(15));
+        assert_eq!(imported.recipe.cook_time_minutes, Some(90));
+        assert_eq!(
+            imported.unmapped_fields,
+            vec![
+                "aggregateRating".to_string(),
+                "dateModified".into(),
+                "datePublished".into(),
+                "suitableForDiet".into(),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn omits_ratings_and_vegan_diet_when_not_applicable() {
+        let mut recipe_db = get_random_recipe_db(None, None);
+        recipe_db.rating_count = 0;
+        recipe_db.ingredients = vec![Ingredient {
+            name: "Butter".into(),
+            quantity: Some(50.0),
+            unit: Some("g".into()),
+            note: None,
+        }];
+
+        let json_ld = export_json_ld(&recipe_db.into());
+
+        assert_eq!(json_ld.get("aggregateRating"), None);
+        assert_eq!(json_ld.get("suitableForDiet"), None);
+    }
+}
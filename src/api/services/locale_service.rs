@@ -0,0 +1,265 @@
+use axum::http::{header::ACCEPT_LANGUAGE, HeaderMap};
+
+use crate::model::recipe::Recipe;
+
+pub fn normalize_locale(locale: &str) -> String {
+    locale.trim().replace('_', "-").to_lowercase()
+}
+
+/// Accepts language tags like "de" or "de-AT", which also keeps them safe as document keys
+pub fn is_valid_locale(locale: &str) -> bool {
+    let locale = normalize_locale(locale);
+    let mut subtags = locale.split('-');
+    let language = subtags.next().unwrap_or_default();
+
+    (2..=3).contains(&language.len())
+        && language.chars().all(|c| c.is_ascii_lowercase())
+        && subtags.all(|subtag| {
+            (2..=8).contains(&subtag.len()) && subtag.chars().all(|c| c.is_ascii_alphanumeric())
+        })
+}
+
+fn get_language(locale: &str) -> &str {
+    locale.split('-').next().unwrap_or(locale)
+}
+
+/// Language tags of an Accept-Language header, the most preferred first
+pub fn parse_accept_language(accept_language: &str) -> Vec<String> {
+    let mut weighted_locales = accept_language
+        .split(',')
+        .filter_map(|entry| {
+            let mut parameters = entry.split(';');
+            let locale = normalize_locale(parameters.next()?);
+            let quality = parameters
+                .find_map(|parameter| parameter.trim().strip_prefix("q="))
+                .map_or(Some(1.0), |quality| quality.trim().parse::<f64>().ok())?;
+
+            (!locale.is_empty() && locale != "*" && quality > 0.0).then_some((locale, quality))
+        })
+        .collect::<Vec<(String, f64)>>();
+
+    // The sort is stable, so equally weighted locales keep the order of the header
+    weighted_locales.sort_by(|(_, quality_1), (_, quality_2)| quality_2.total_cmp(quality_1));
+
+    weighted_locales
+        .into_iter()
+        .map(|(locale, _)| locale)
+        .collect()
+}
+
+pub fn get_requested_locales(headers: &HeaderMap) -> Vec<String> {
+    headers
+        .get(ACCEPT_LANGUAGE)
+        .and_then(|accept_language| accept_language.to_str().ok())
+        .map(parse_accept_language)
+        .unwrap_or_default()
+}
+
+/// Picks the first requested locale that is available, falling back from "de-AT" to "de" or any other "de-*"
+pub fn negotiate_locale(
+    requested_locales: &[String],
+    available_locales: &[&str],
+) -> Option<String> {
+    requested_locales.iter().find_map(|requested_locale| {
+        available_locales
+            .iter()
+            .find(|available_locale| **available_locale == requested_locale)
+            .or_else(|| {
+                available_locales.iter().find(|available_locale| {
+                    get_language(available_locale) == get_language(requested_locale)
+                })
+            })
+            .map(|available_locale| available_locale.to_string())
+    })
+}
+
+/// Replaces the texts of the recipe with the best matching translation, keeping the original without match
+pub fn localize_recipe(recipe: &mut Recipe, requested_locales: &[String]) {
+    let available_locales = recipe
+        .available_locales
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<&str>>();
+
+    // The original locale is available without translation
+    let translation = negotiate_locale(requested_locales, &available_locales).and_then(|locale| {
+        recipe
+            .translations
+            .get(&locale)
+            .map(|translation| (locale, translation.clone()))
+    });
+    let (locale, translation) = match translation {
+        Some(translation) => translation,
+        None => return,
+    };
+
+    if let Some(title) = translation.title {
+        recipe.title = title;
+    }
+
+    if let Some(description) = translation.description {
+        recipe.description = description;
+    }
+
+    for (ingredient, note) in recipe.ingredients.iter_mut().zip(translation.notes) {
+        if note.is_some() {
+            ingredient.note = note;
+        }
+    }
+
+    // Steps are only translated as a whole, so they never mix languages
+    if translation.steps.len() == recipe.steps.len() {
+        recipe.steps = translation.steps;
+    }
+
+    recipe.locale = locale;
+}
+
+#[cfg(test)]
+mod unit_tests_locale_service {
+    use super::*;
+    use crate::{
+        model::recipe::{Ingredient, RecipeTranslation},
+        test_utils::get_random_recipe_db,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parses_accept_language() {
+        struct TestCase {
+            title: String,
+            accept_language: String,
+            expected_locales: Vec<String>,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Orders by quality".into(),
+                accept_language: "en;q=0.7, de-AT, de;q=0.9".into(),
+                expected_locales: vec!["de-at".into(), "de".into(), "en".into()],
+            },
+            TestCase {
+                title: "Skips wildcards and refused locales".into(),
+                accept_language: "fr;q=0, *;q=0.5, it".into(),
+                expected_locales: vec!["it".into()],
+            },
+            TestCase {
+                title: "Skips entries with invalid quality".into(),
+                accept_language: "es;q=high, pt_BR".into(),
+                expected_locales: vec!["pt-br".into()],
+            },
+        ];
+
+        for t in test_cases {
+            assert_eq!(
+                parse_accept_language(&t.accept_language),
+                t.expected_locales,
+                "{}",
+                t.title
+            );
+        }
+    }
+
+    #[test]
+    fn negotiates_locale() {
+        struct TestCase {
+            title: String,
+            requested_locales: Vec<String>,
+            expected_locale: Option<String>,
+        }
+
+        let available_locales = ["en", "de", "fr-ca"];
+
+        let test_cases = vec![
+            TestCase {
+                title: "Prefers exact matches".into(),
+                requested_locales: vec!["de".into(), "en".into()],
+                expected_locale: Some("de".into()),
+            },
+            TestCase {
+                title: "Falls back to the language".into(),
+                requested_locales: vec!["de-ch".into(), "en".into()],
+                expected_locale: Some("de".into()),
+            },
+            TestCase {
+                title: "Falls back to another region".into(),
+                requested_locales: vec!["fr".into()],
+                expected_locale: Some("fr-ca".into()),
+            },
+            TestCase {
+                title: "Finds nothing for unavailable languages".into(),
+                requested_locales: vec!["it".into()],
+                expected_locale: None,
+            },
+        ];
+
+        for t in test_cases {
+            assert_eq!(
+                negotiate_locale(&t.requested_locales, &available_locales),
+                t.expected_locale,
+                "{}",
+                t.title
+            );
+        }
+    }
+
+    #[test]
+    fn validates_locales() {
+        assert!(is_valid_locale("de"));
+        assert!(is_valid_locale("de_AT"));
+        assert!(is_valid_locale("zh-Hant-TW"));
+        assert!(!is_valid_locale("german"));
+        assert!(!is_valid_locale("de.at"));
+        assert!(!is_valid_locale("de-"));
+    }
+
+    #[test]
+    fn localizes_recipe() {
+        let mut recipe_db = get_random_recipe_db(None, None);
+        recipe_db.title = "Lentil soup".into();
+        recipe_db.ingredients = vec![
+            Ingredient {
+                name: "Lentils".into(),
+                quantity: Some(200.0),
+                unit: Some("g".into()),
+                note: Some("rinsed".into()),
+            },
+            Ingredient {
+                name: "Onion".into(),
+                quantity: Some(1.0),
+                unit: None,
+                note: Some("diced".into()),
+            },
+        ];
+        recipe_db.steps = vec!["Dice the onion".into(), "Simmer everything".into()];
+        recipe_db.translations.insert(
+            "de".into(),
+            RecipeTranslation {
+                title: Some("Linsensuppe".into()),
+                description: None,
+                notes: vec![Some("gewaschen".into())],
+                steps: vec!["Alles köcheln lassen".into()],
+            },
+        );
+        let original_description = recipe_db.description.clone();
+
+        let mut recipe: Recipe = recipe_db.into();
+        assert_eq!(recipe.available_locales, vec!["en", "de"]);
+
+        localize_recipe(&mut recipe, &["fr".into(), "de-de".into()]);
+
+        assert_eq!(recipe.locale, "de");
+        assert_eq!(recipe.title, "Linsensuppe");
+        assert_eq!(recipe.description, original_description);
+        assert_eq!(recipe.ingredients[0].note, Some("gewaschen".into()));
+        assert_eq!(recipe.ingredients[1].note, Some("diced".into()));
+        assert_eq!(
+            recipe.steps,
+            vec![
+                "Dice the onion".to_string(),
+                "Simmer everything".to_string()
+            ],
+            "Keeps steps with a different count"
+        );
+    }
+}
@@ -0,0 +1,40 @@
+use anyhow::Result;
+use tracing::info;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Email {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+}
+
+/// Sends emails from the configured sender address, delivery is only logged until a
+/// transport is configured
+#[derive(Clone)]
+pub struct Mailer {
+    from: String,
+}
+
+impl Mailer {
+    pub fn new(from: &str) -> Self {
+        Self {
+            from: String::from(from),
+        }
+    }
+
+    /// Without a transport nothing is delivered, so callers keep what they meant to send
+    pub fn is_log_only(&self) -> bool {
+        true
+    }
+
+    pub async fn send(&self, email: &Email) -> Result<()> {
+        info!(
+            from = self.from,
+            to = email.to,
+            subject = email.subject,
+            "Sending email"
+        );
+
+        Ok(())
+    }
+}
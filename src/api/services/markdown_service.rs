@@ -0,0 +1,299 @@
+use anyhow::{anyhow, Result};
+
+use super::import_service::{
+    apply_recipe_metadata, get_draft_recipe, parse_ingredient, ImportedRecipe,
+};
+use crate::model::recipe::{Ingredient, Recipe};
+
+enum Section {
+    Description,
+    Ingredients,
+    Steps,
+    Other,
+}
+
+pub fn format_ingredient(ingredient: &Ingredient) -> String {
+    let mut line = match (ingredient.quantity, &ingredient.unit) {
+        (Some(quantity), Some(unit)) => format!("{quantity} {unit} {}", ingredient.name),
+        (Some(quantity), None) => format!("{quantity} {}", ingredient.name),
+        _ => ingredient.name.clone(),
+    };
+
+    if let Some(note) = &ingredient.note {
+        line.push_str(&format!(", {note}"));
+    }
+
+    line
+}
+
+pub fn export_markdown(recipe: &Recipe) -> String {
+    let mut lines = vec![
+        "---".to_string(),
+        format!("title: {}", recipe.title),
+        format!("servings: {}", recipe.servings),
+    ];
+
+    if let Some(prep_time_minutes) = recipe.prep_time_minutes {
+        lines.push(format!("prep_time_minutes: {prep_time_minutes}"));
+    }
+
+    if let Some(cook_time_minutes) = recipe.cook_time_minutes {
+        lines.push(format!("cook_time_minutes: {cook_time_minutes}"));
+    }
+
+    if let Some(nutrition) = recipe.nutrition {
+        lines.push(format!("calories: {}", nutrition.calories));
+        lines.push(format!("protein: {}", nutrition.protein));
+        lines.push(format!("carbohydrates: {}", nutrition.carbohydrates));
+        lines.push(format!("fat: {}", nutrition.fat));
+        lines.push(format!("fiber: {}", nutrition.fiber));
+    }
+
+    lines.push("---".into());
+    lines.push("".into());
+    lines.push(format!("# {}", recipe.title));
+
+    if !recipe.description.is_empty() {
+        lines.push("".into());
+        lines.push(recipe.description.clone());
+    }
+
+    lines.push("".into());
+    lines.push("## Ingredients".into());
+    lines.push("".into());
+    for ingredient in &recipe.ingredients {
+        lines.push(format!("- {}", format_ingredient(ingredient)));
+    }
+
+    lines.push("".into());
+    lines.push("## Steps".into());
+    lines.push("".into());
+    for (i, step) in recipe.steps.iter().enumerate() {
+        lines.push(format!("{}. {step}", i + 1));
+    }
+
+    lines.join("\n") + "\n"
+}
+
+// Returns the item text of "- item", "* item" or "1. item" lines
+fn strip_list_marker(line: &str) -> Option<&str> {
+    if let Some(item) = ["- ", "* ", "+ "]
+        .iter()
+        .find_map(|marker| line.strip_prefix(marker))
+    {
+        return Some(item.trim());
+    }
+
+    let digits = line.find(|c: char| !c.is_ascii_digit())?;
+    if digits == 0 {
+        return None;
+    }
+
+    line[digits..]
+        .strip_prefix(". ")
+        .or_else(|| line[digits..].strip_prefix(") "))
+        .map(str::trim)
+}
+
+pub fn import_markdown(text: &str) -> Result<ImportedRecipe> {
+    let mut recipe = get_draft_recipe();
+    let mut unmapped_fields = vec![];
+
+    let mut lines = text.lines().map(str::trim).peekable();
+
+    if lines.peek() == Some(&"---") {
+        lines.next();
+        for line in lines.by_ref().take_while(|line| *line != "---") {
+            if let Some((key, value)) = line.split_once(':') {
+                apply_recipe_metadata(&mut recipe, key, value, &mut unmapped_fields);
+            }
+        }
+    }
+
+    let mut section = Section::Description;
+    let mut description = vec![];
+    let mut ingredient_lines = vec![];
+
+    for line in lines {
+        if let Some(heading) = line.strip_prefix("## ") {
+            let heading = heading.trim().to_lowercase();
+            section = if heading.contains("ingredient") {
+                Section::Ingredients
+            } else if ["step", "instruction", "method", "direction"]
+                .iter()
+                .any(|keyword| heading.contains(keyword))
+            {
+                Section::Steps
+            } else {
+                unmapped_fields.push(line[3..].trim().to_string());
+                Section::Other
+            };
+            continue;
+        }
+
+        match section {
+            Section::Description => match line.strip_prefix("# ") {
+                Some(title) if recipe.title.is_empty() => recipe.title = title.trim().into(),
+                Some(_) => {}
+                None => description.push(line),
+            },
+            Section::Ingredients => {
+                if let Some(item) = strip_list_marker(line) {
+                    ingredient_lines.push(item);
+                }
+            }
+            Section::Steps => match (strip_list_marker(line), recipe.steps.last_mut()) {
+                (Some(step), _) => recipe.steps.push(step.into()),
+                // Wrapped lines continue the previous step
+                (None, Some(step)) if !line.is_empty() => {
+                    step.push(' ');
+                    step.push_str(line);
+                }
+                _ => {}
+            },
+            Section::Other => {}
+        }
+    }
+
+    let description = description.join("\n");
+    if !description.trim().is_empty() {
+        recipe.description = description.trim().into();
+    }
+
+    recipe.ingredients = ingredient_lines
+        .iter()
+        .map(|line| parse_ingredient(line))
+        .collect();
+
+    if recipe.title.is_empty() {
+        return Err(anyhow!("Recipe has no title"));
+    }
+
+    Ok(ImportedRecipe {
+        recipe,
+        unmapped_fields,
+    })
+}
+
+#[cfg(test)]
+mod unit_tests_markdown_service {
+    use super::*;
+    use crate::test_utils::get_random_recipe_db;
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn round_trips_markdown() -> Result<()> {
+        let mut recipe_db = get_random_recipe_db(None, None);
+        recipe_db.title = "Chickpea curry".into();
+        recipe_db.description = "A quick weeknight curry.".into();
+        recipe_db.servings = 4;
+        recipe_db.prep_time_minutes = Some(10);
+        recipe_db.cook_time_minutes = Some(25);
+        recipe_db.nutrition = None;
+        recipe_db.ingredients = vec![
+            Ingredient {
+                name: "chickpeas".into(),
+                quantity: Some(1.0),
+                unit: Some("can".into()),
+                note: Some("drained".into()),
+            },
+            Ingredient {
+                name: "coconut milk".into(),
+                quantity: Some(400.0),
+                unit: Some("ml".into()),
+                note: None,
+            },
+            Ingredient {
+                name: "salt".into(),
+                quantity: None,
+                unit: None,
+                note: None,
+            },
+        ];
+        recipe_db.steps = vec![
+            "Fry the spices.".into(),
+            "Add the chickpeas and coconut milk.".into(),
+        ];
+        let recipe: Recipe = recipe_db.into();
+
+        let markdown = export_markdown(&recipe);
+        assert_eq!(
+            markdown,
+            [
+                "---",
+                "title: Chickpea curry",
+                "servings: 4",
+                "prep_time_minutes: 10",
+                "cook_time_minutes: 25",
+                "---",
+                "",
+                "# Chickpea curry",
+                "",
+                "A quick weeknight curry.",
+                "",
+                "## Ingredients",
+                "",
+                "- 1 can chickpeas, drained",
+                "- 400 ml coconut milk",
+                "- salt",
+                "",
+                "## Steps",
+                "",
+                "1. Fry the spices.",
+                "2. Add the chickpeas and coconut milk.",
+                "",
+            ]
+            .join("\n")
+        );
+
+        let imported = import_markdown(&markdown)?;
+
+        assert_eq!(imported.recipe.title, recipe.title);
+        assert_eq!(imported.recipe.description, recipe.description);
+        assert_eq!(imported.recipe.servings, recipe.servings);
+        assert_eq!(imported.recipe.prep_time_minutes, recipe.prep_time_minutes);
+        assert_eq!(imported.recipe.cook_time_minutes, recipe.cook_time_minutes);
+        assert_eq!(imported.recipe.ingredients, recipe.ingredients);
+        assert_eq!(imported.recipe.steps, recipe.steps);
+        assert!(imported.unmapped_fields.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn imports_markdown_without_front_matter() -> Result<()> {
+        let markdown = [
+            "# Lemonade",
+            "",
+            "## Ingredients",
+            "* 4 lemons",
+            "* 1 l water",
+            "",
+            "## Method",
+            "1) Squeeze the lemons",
+            "   and strain the juice.",
+            "2) Mix with the water.",
+            "",
+            "## Tips",
+            "Serve cold.",
+        ]
+        .join("\n");
+
+        let imported = import_markdown(&markdown)?;
+
+        assert_eq!(imported.recipe.title, "Lemonade");
+        assert_eq!(imported.recipe.servings, 1);
+        assert_eq!(imported.recipe.ingredients.len(), 2);
+        assert_eq!(
+            imported.recipe.steps,
+            vec![
+                "Squeeze the lemons and strain the juice.".to_string(),
+                "Mix with the water.".into(),
+            ]
+        );
+        assert_eq!(imported.unmapped_fields, vec!["Tips".to_string()]);
+
+        Ok(())
+    }
+}
@@ -1,2 +1,19 @@
+pub mod cooklang_service;
+pub mod cost_service;
+pub mod event_service;
 pub mod hash_service;
+pub mod import_service;
+pub mod jsonld_service;
+pub mod locale_service;
+pub mod mail_service;
+pub mod markdown_service;
+pub mod notification_service;
+pub mod pantry_service;
+pub mod revision_service;
+pub mod season_service;
+pub mod shopping_list_service;
+pub mod step_analysis_service;
+pub mod substitution_service;
 pub mod token_service;
+pub mod unit_service;
+pub mod vegan_service;
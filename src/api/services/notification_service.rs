@@ -0,0 +1,201 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use serde_json::to_value;
+use tokio::time::{interval_at, Instant};
+use tracing::info;
+
+use super::{
+    event_service::EventBus,
+    mail_service::{Email, Mailer},
+};
+use crate::api::messages::{Locale, Message};
+use crate::{
+    db::{
+        generic_handler::GenericHandler, mongo_db_handler::MongoDbHandler,
+        notification_handler::NotificationHandler,
+    },
+    model::{
+        event::{Event, EventKind},
+        notification::{Notification, NotificationCreate, NotificationMongoDb},
+        user::UserMongoDb,
+    },
+};
+
+pub const DIGEST_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Notifications are a side effect, so failing to store one never fails the request
+pub async fn notify(
+    db_handler: &MongoDbHandler,
+    event_bus: &EventBus,
+    user_id: &str,
+    notification: NotificationCreate,
+) {
+    if notification.actor_id.as_deref() == Some(user_id) {
+        return;
+    }
+
+    let notification_db = match db_handler.create_notification(user_id, notification).await {
+        Ok(Some(n)) if n.is_in_app => n,
+        Ok(_) => return,
+        Err(err) => {
+            info!("Failed to notify user with id '{user_id}': {err}");
+            return;
+        }
+    };
+
+    match to_value(Notification::from(notification_db)) {
+        Ok(data) => event_bus.publish(Event {
+            kind: EventKind::Notification,
+            user_ids: vec![String::from(user_id)],
+            recipe_id: None,
+            data,
+        }),
+        Err(err) => info!("Failed to push notification to user with id '{user_id}': {err}"),
+    }
+}
+
+pub fn format_digest(to: &str, notifications: &[NotificationMongoDb], locale: Locale) -> Email {
+    let lines = notifications
+        .iter()
+        .map(|notification| {
+            format!(
+                "- {} ({})",
+                notification.kind.message().localize(locale),
+                notification.created_at
+            )
+        })
+        .collect::<Vec<_>>();
+
+    Email {
+        to: String::from(to),
+        subject: match notifications.len() {
+            1 => Message::DigestSubjectSingle.localize(locale),
+            count => Message::DigestSubject { count: &count }.localize(locale),
+        },
+        body: lines.join("\n"),
+    }
+}
+
+/// Sends one email per user with all notifications waiting for the digest, notifications
+/// stay pending until their email was delivered
+pub async fn send_email_digests(db_handler: &MongoDbHandler, mailer: &Mailer) -> Result<usize> {
+    if mailer.is_log_only() {
+        info!("Keeping email notifications pending without a mail transport");
+        return Ok(0);
+    }
+
+    let pending = db_handler.get_pending_email_notifications().await?;
+    let mut sent_count = 0;
+
+    for notifications in pending.chunk_by(|a, b| a.user_id == b.user_id) {
+        let user_id = notifications[0].user_id.to_hex();
+
+        let user = match db_handler
+            .get_by_id::<UserMongoDb, UserMongoDb>(&user_id, "users")
+            .await
+        {
+            Ok(u) => u,
+            Err(err) => {
+                info!("Failed to get user with id '{user_id}' for email digest: {err}");
+                continue;
+            }
+        };
+
+        if let Err(err) = mailer
+            .send(&format_digest(
+                &user.email,
+                notifications,
+                // Digests are sent outside of requests, so only the stored preference tells
+                Locale::from_preference(user.preferences.locale.as_deref()),
+            ))
+            .await
+        {
+            info!("Failed to send email digest to user with id '{user_id}': {err}");
+            continue;
+        }
+
+        let ids = notifications
+            .iter()
+            .map(|notification| notification._id)
+            .collect::<Vec<_>>();
+        db_handler.mark_notifications_emailed(&ids).await?;
+
+        sent_count += 1;
+    }
+
+    Ok(sent_count)
+}
+
+pub async fn run_email_digests(db_handler: MongoDbHandler, mailer: Mailer) {
+    let mut interval = interval_at(Instant::now() + DIGEST_INTERVAL, DIGEST_INTERVAL);
+
+    loop {
+        interval.tick().await;
+
+        match send_email_digests(&db_handler, &mailer).await {
+            Ok(sent_count) => info!("Sent {sent_count} email digests"),
+            Err(err) => info!("Failed to send email digests: {err}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_tests_notification_service {
+    use super::*;
+    use crate::model::notification::NotificationKind;
+    use bson::{oid::ObjectId, DateTime};
+    use pretty_assertions::assert_eq;
+
+    fn get_notification_db(kind: NotificationKind) -> NotificationMongoDb {
+        NotificationMongoDb {
+            _id: ObjectId::new(),
+            user_id: ObjectId::new(),
+            kind,
+            actor_id: None,
+            recipe_id: None,
+            report_id: None,
+            is_in_app: true,
+            is_read: false,
+            is_email_pending: true,
+            created_at: DateTime::from_millis(0),
+        }
+    }
+
+    #[test]
+    fn formats_digest() {
+        let email = format_digest(
+            "cook@example.com",
+            &[
+                get_notification_db(NotificationKind::NewFollower),
+                get_notification_db(NotificationKind::RecipeForked),
+            ],
+            Locale::English,
+        );
+
+        assert_eq!(
+            email,
+            Email {
+                to: "cook@example.com".into(),
+                subject: "You have 2 new notifications".into(),
+                body: [
+                    "- You have a new follower (1970-01-01 0:00:00.0 +00:00:00)",
+                    "- Someone forked your recipe (1970-01-01 0:00:00.0 +00:00:00)",
+                ]
+                .join("\n"),
+            }
+        );
+
+        let email = format_digest(
+            "koch@example.com",
+            &[get_notification_db(NotificationKind::ReviewCreated)],
+            Locale::German,
+        );
+
+        assert_eq!(email.subject, "Sie haben 1 neue Benachrichtigung");
+        assert_eq!(
+            email.body,
+            "- Jemand hat Ihr Rezept bewertet (1970-01-01 0:00:00.0 +00:00:00)"
+        );
+    }
+}
@@ -0,0 +1,263 @@
+use chrono::{Duration, NaiveDate};
+
+use super::unit_service::{contains_words, convert};
+use crate::model::{
+    pantry::{PantryItemMongoDb, PantryRecipeMatch},
+    recipe::{Ingredient, RecipeMongoDb},
+};
+
+pub const EXPIRING_SOON_DAYS: i64 = 3;
+const EXPIRING_ITEM_BONUS: f64 = 0.25;
+
+// "Onions" matches "red onion", but "oil" does not match "soil"
+pub fn names_match(name_1: &str, name_2: &str) -> bool {
+    contains_words(name_1, name_2) || contains_words(name_2, name_1)
+}
+
+fn is_expiring_soon(item: &PantryItemMongoDb, today: NaiveDate) -> bool {
+    match item.expires_at {
+        Some(expires_at) => {
+            let expires_on = expires_at.to_chrono().date_naive();
+            expires_on >= today && expires_on <= today + Duration::days(EXPIRING_SOON_DAYS)
+        }
+        None => false,
+    }
+}
+
+// Returns how much of the ingredient is covered (0 to 1) and the available quantity
+fn get_ingredient_coverage(
+    ingredient: &Ingredient,
+    pantry_items: &[&PantryItemMongoDb],
+) -> (f64, f64) {
+    if pantry_items.is_empty() {
+        return (0.0, 0.0);
+    }
+
+    let required = match ingredient.quantity {
+        Some(required) if required > 0.0 => required,
+        _ => return (1.0, 0.0),
+    };
+
+    let mut available = 0.0;
+
+    for item in pantry_items {
+        let quantity = match item.quantity {
+            Some(quantity) => quantity,
+            None => return (1.0, 0.0),
+        };
+
+        let converted = match (&item.unit, &ingredient.unit) {
+            (Some(from_unit), Some(to_unit)) if from_unit.eq_ignore_ascii_case(to_unit) => {
+                Some(quantity)
+            }
+            (Some(from_unit), Some(to_unit)) => {
+                convert(quantity, from_unit, to_unit, &ingredient.name)
+            }
+            (None, None) => Some(quantity),
+            _ => None,
+        };
+
+        match converted {
+            Some(converted) => available += converted,
+            // Units that can't be compared, assume there is enough
+            None => return (1.0, 0.0),
+        }
+    }
+
+    ((available / required).min(1.0), available)
+}
+
+pub fn match_pantry_recipes(
+    pantry_items: &[PantryItemMongoDb],
+    recipes: Vec<RecipeMongoDb>,
+    today: NaiveDate,
+) -> Vec<PantryRecipeMatch> {
+    let mut matches = vec![];
+
+    for recipe in recipes {
+        if recipe.ingredients.is_empty() {
+            continue;
+        }
+
+        let mut covered = 0.0;
+        let mut missing_ingredients = vec![];
+        let mut expiring_ingredients: Vec<String> = vec![];
+
+        for ingredient in &recipe.ingredients {
+            let matched_items = pantry_items
+                .iter()
+                .filter(|item| names_match(&item.name, &ingredient.name))
+                .collect::<Vec<&PantryItemMongoDb>>();
+
+            let (coverage, available) = get_ingredient_coverage(ingredient, &matched_items);
+            covered += coverage;
+
+            if coverage < 1.0 {
+                missing_ingredients.push(Ingredient {
+                    quantity: ingredient.quantity.map(|quantity| quantity - available),
+                    ..ingredient.clone()
+                });
+            }
+
+            for item in matched_items {
+                if is_expiring_soon(item, today) && !expiring_ingredients.contains(&item.name) {
+                    expiring_ingredients.push(item.name.clone());
+                }
+            }
+        }
+
+        let coverage = covered / recipe.ingredients.len() as f64;
+
+        if coverage > 0.0 {
+            matches.push(PantryRecipeMatch {
+                recipe: recipe.into(),
+                coverage,
+                missing_ingredients,
+                expiring_ingredients,
+            });
+        }
+    }
+
+    let get_score = |recipe_match: &PantryRecipeMatch| {
+        recipe_match.coverage + EXPIRING_ITEM_BONUS * recipe_match.expiring_ingredients.len() as f64
+    };
+
+    matches.sort_by(|a, b| {
+        get_score(b)
+            .total_cmp(&get_score(a))
+            .then(b.coverage.total_cmp(&a.coverage))
+    });
+
+    matches
+}
+
+#[cfg(test)]
+mod unit_tests_pantry_service {
+    use bson::oid::ObjectId;
+
+    use super::*;
+    use crate::{model::pantry::parse_expiry_date, test_utils::get_random_recipe_db};
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+
+    fn get_ingredient(name: &str, quantity: Option<f64>, unit: Option<&str>) -> Ingredient {
+        Ingredient {
+            name: name.into(),
+            quantity,
+            unit: unit.map(Into::into),
+            note: None,
+        }
+    }
+
+    fn get_pantry_item(
+        name: &str,
+        quantity: Option<f64>,
+        unit: Option<&str>,
+        expires_on: Option<&str>,
+    ) -> Result<PantryItemMongoDb> {
+        Ok(PantryItemMongoDb {
+            _id: ObjectId::new(),
+            name: name.into(),
+            quantity,
+            unit: unit.map(Into::into),
+            expires_at: expires_on.map(parse_expiry_date).transpose()?,
+        })
+    }
+
+    #[test]
+    fn matches_names() {
+        struct TestCase {
+            title: String,
+            name_1: String,
+            name_2: String,
+            expected_match: bool,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Matches plural and qualified names".into(),
+                name_1: "Onions".into(),
+                name_2: "red onion".into(),
+                expected_match: true,
+            },
+            TestCase {
+                title: "Does not match partial words".into(),
+                name_1: "oil".into(),
+                name_2: "soil".into(),
+                expected_match: false,
+            },
+            TestCase {
+                title: "Does not match different ingredients".into(),
+                name_1: "Coconut milk".into(),
+                name_2: "Oat milk".into(),
+                expected_match: false,
+            },
+        ];
+
+        for t in test_cases {
+            assert_eq!(
+                names_match(&t.name_1, &t.name_2),
+                t.expected_match,
+                "{}",
+                t.title
+            );
+        }
+    }
+
+    #[test]
+    fn ranks_recipes_by_coverage_and_expiry() -> Result<()> {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap_or_default();
+
+        let pantry_items = vec![
+            get_pantry_item("Flour", Some(0.5), Some("kg"), None)?,
+            get_pantry_item("Oat milk", Some(1.0), Some("cup"), None)?,
+            get_pantry_item("Spinach", None, None, Some("2026-10-20"))?,
+            get_pantry_item("Tofu", Some(200.0), Some("g"), Some("2026-10-30"))?,
+        ];
+
+        let mut pancakes = get_random_recipe_db(None, None);
+        pancakes.ingredients = vec![
+            get_ingredient("flour", Some(250.0), Some("g")),
+            get_ingredient("oat milk", Some(500.0), Some("ml")),
+        ];
+
+        let mut spinach_tofu = get_random_recipe_db(None, None);
+        spinach_tofu.ingredients = vec![
+            get_ingredient("Fresh spinach", Some(300.0), Some("g")),
+            get_ingredient("Tofu", Some(400.0), Some("g")),
+            get_ingredient("Garlic", Some(2.0), Some("cloves")),
+        ];
+
+        let mut soup = get_random_recipe_db(None, None);
+        soup.ingredients = vec![get_ingredient("Potatoes", Some(1.0), Some("kg"))];
+
+        let matches = match_pantry_recipes(
+            &pantry_items,
+            vec![pancakes.clone(), spinach_tofu.clone(), soup],
+            today,
+        );
+
+        assert_eq!(matches.len(), 2, "Skips recipes without any coverage");
+
+        assert_eq!(
+            matches[0].recipe.id,
+            spinach_tofu._id.to_hex(),
+            "Prefers recipes using items expiring soon"
+        );
+        assert_eq!(matches[0].coverage, 0.5);
+        assert_eq!(matches[0].expiring_ingredients, vec!["Spinach".to_string()]);
+        assert_eq!(
+            matches[0].missing_ingredients,
+            vec![
+                get_ingredient("Tofu", Some(200.0), Some("g")),
+                get_ingredient("Garlic", Some(2.0), Some("cloves")),
+            ]
+        );
+
+        assert_eq!(matches[1].recipe.id, pancakes._id.to_hex());
+        assert_eq!(matches[1].missing_ingredients.len(), 1);
+        assert_eq!(matches[1].missing_ingredients[0].name, "oat milk");
+
+        Ok(())
+    }
+}
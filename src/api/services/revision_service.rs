@@ -0,0 +1,137 @@
+use super::unit_service::normalize_ingredient_name;
+use crate::model::recipe_revision::{IngredientChange, RecipeDiff, RecipeSnapshot, StepChange};
+
+pub fn diff_recipe(from: &RecipeSnapshot, to: &RecipeSnapshot) -> RecipeDiff {
+    let mut diff = RecipeDiff::default();
+
+    let fields = [
+        ("title", from.title != to.title),
+        ("description", from.description != to.description),
+        ("servings", from.servings != to.servings),
+        (
+            "prepTimeMinutes",
+            from.prep_time_minutes != to.prep_time_minutes,
+        ),
+        (
+            "cookTimeMinutes",
+            from.cook_time_minutes != to.cook_time_minutes,
+        ),
+        ("nutrition", from.nutrition != to.nutrition),
+    ];
+    diff.changed_fields = fields
+        .iter()
+        .filter(|(_, is_changed)| *is_changed)
+        .map(|(field, _)| field.to_string())
+        .collect();
+
+    // Ingredients are matched by name, so a new quantity is a change and not a replacement
+    for ingredient in &to.ingredients {
+        let name = normalize_ingredient_name(&ingredient.name);
+
+        match from
+            .ingredients
+            .iter()
+            .find(|previous| normalize_ingredient_name(&previous.name) == name)
+        {
+            Some(previous) if previous != ingredient => {
+                diff.ingredients_changed.push(IngredientChange {
+                    name: ingredient.name.clone(),
+                    from: previous.clone(),
+                    to: ingredient.clone(),
+                })
+            }
+            Some(_) => {}
+            None => diff.ingredients_added.push(ingredient.clone()),
+        }
+    }
+
+    diff.ingredients_removed = from
+        .ingredients
+        .iter()
+        .filter(|previous| {
+            let name = normalize_ingredient_name(&previous.name);
+            !to.ingredients
+                .iter()
+                .any(|ingredient| normalize_ingredient_name(&ingredient.name) == name)
+        })
+        .cloned()
+        .collect();
+
+    for i in 0..from.steps.len().max(to.steps.len()) {
+        let (previous, current) = (from.steps.get(i), to.steps.get(i));
+
+        if previous != current {
+            diff.steps_changed.push(StepChange {
+                position: i + 1,
+                from: previous.cloned(),
+                to: current.cloned(),
+            });
+        }
+    }
+
+    diff
+}
+
+#[cfg(test)]
+mod unit_tests_revision_service {
+    use super::*;
+    use crate::model::recipe::Ingredient;
+    use pretty_assertions::assert_eq;
+
+    fn get_ingredient(name: &str, quantity: f64) -> Ingredient {
+        Ingredient {
+            name: name.into(),
+            quantity: Some(quantity),
+            unit: Some("g".into()),
+            note: None,
+        }
+    }
+
+    #[test]
+    fn diffs_recipes() {
+        let from = RecipeSnapshot {
+            title: "Lentil soup".into(),
+            description: "Warming".into(),
+            ingredients: vec![
+                get_ingredient("Red lentils", 200.0),
+                get_ingredient("Carrot", 100.0),
+            ],
+            steps: vec!["Chop the carrot.".into(), "Simmer everything.".into()],
+            servings: 4,
+            prep_time_minutes: Some(10),
+            cook_time_minutes: Some(30),
+            nutrition: None,
+        };
+
+        let to = RecipeSnapshot {
+            title: "Spicy lentil soup".into(),
+            ingredients: vec![
+                get_ingredient("red lentils", 250.0),
+                get_ingredient("Chili flakes", 2.0),
+            ],
+            steps: vec!["Chop the carrot.".into()],
+            ..from.clone()
+        };
+
+        assert_eq!(
+            diff_recipe(&from, &to),
+            RecipeDiff {
+                changed_fields: vec!["title".into()],
+                ingredients_added: vec![get_ingredient("Chili flakes", 2.0)],
+                ingredients_removed: vec![get_ingredient("Carrot", 100.0)],
+                ingredients_changed: vec![IngredientChange {
+                    name: "red lentils".into(),
+                    from: get_ingredient("Red lentils", 200.0),
+                    to: get_ingredient("red lentils", 250.0),
+                }],
+                steps_changed: vec![StepChange {
+                    position: 2,
+                    from: Some("Simmer everything.".into()),
+                    to: None,
+                }],
+            }
+        );
+
+        assert_eq!(diff_recipe(&from, &from), RecipeDiff::default());
+    }
+}
@@ -0,0 +1,325 @@
+use super::unit_service::contains_words;
+use crate::model::{
+    recipe::Recipe,
+    season::{SeasonCalendar, SeasonalProduce},
+};
+
+pub const DEFAULT_SEASON_REGION: &str = "central-europe";
+pub const SEASON_REGIONS: [&str; 2] = ["central-europe", "southern-europe"];
+/// Recipes count as in season if at least this share of their seasonal produce is
+pub const IN_SEASON_MIN_SCORE: f64 = 0.75;
+
+// Preserved produce is available all year
+const PRESERVED_KEYWORDS: [&str; 12] = [
+    "canned", "tinned", "dried", "frozen", "pickled", "paste", "juice", "jam", "seeds", "vinegar",
+    "flour", "oil",
+];
+
+struct ProduceSeason {
+    /// Singular and plural names, the first one is shown in the calendar
+    names: &'static [&'static str],
+    /// Region with first and last month of the season, seasons may wrap around the new year
+    seasons: &'static [(&'static str, u32, u32)],
+}
+
+// Checked in order, so "cherry tomatoes" are found as tomatoes
+const PRODUCE_SEASONS: [ProduceSeason; 28] = [
+    ProduceSeason {
+        names: &["tomato", "tomatoes"],
+        seasons: &[("central-europe", 7, 9), ("southern-europe", 6, 10)],
+    },
+    ProduceSeason {
+        names: &["asparagus"],
+        seasons: &[("central-europe", 4, 6), ("southern-europe", 3, 5)],
+    },
+    ProduceSeason {
+        names: &["rhubarb"],
+        seasons: &[("central-europe", 4, 6)],
+    },
+    ProduceSeason {
+        names: &["wild garlic"],
+        seasons: &[("central-europe", 3, 5)],
+    },
+    ProduceSeason {
+        names: &["strawberry", "strawberries"],
+        seasons: &[("central-europe", 5, 7), ("southern-europe", 3, 6)],
+    },
+    ProduceSeason {
+        names: &["cherry", "cherries"],
+        seasons: &[("central-europe", 6, 8), ("southern-europe", 5, 7)],
+    },
+    ProduceSeason {
+        names: &["raspberry", "raspberries"],
+        seasons: &[("central-europe", 6, 9)],
+    },
+    ProduceSeason {
+        names: &["blueberry", "blueberries"],
+        seasons: &[("central-europe", 7, 9)],
+    },
+    ProduceSeason {
+        names: &["peach", "peaches"],
+        seasons: &[("southern-europe", 6, 9)],
+    },
+    ProduceSeason {
+        names: &["plum", "plums"],
+        seasons: &[("central-europe", 8, 9), ("southern-europe", 7, 9)],
+    },
+    ProduceSeason {
+        names: &["fig", "figs"],
+        seasons: &[("southern-europe", 8, 10)],
+    },
+    ProduceSeason {
+        names: &["apple", "apples"],
+        seasons: &[("central-europe", 8, 11), ("southern-europe", 9, 12)],
+    },
+    ProduceSeason {
+        names: &["pear", "pears"],
+        seasons: &[("central-europe", 8, 11), ("southern-europe", 8, 11)],
+    },
+    ProduceSeason {
+        names: &["orange", "oranges"],
+        seasons: &[("southern-europe", 11, 4)],
+    },
+    ProduceSeason {
+        names: &["zucchini", "courgette"],
+        seasons: &[("central-europe", 6, 9), ("southern-europe", 5, 9)],
+    },
+    ProduceSeason {
+        names: &["cucumber"],
+        seasons: &[("central-europe", 6, 9), ("southern-europe", 5, 9)],
+    },
+    ProduceSeason {
+        names: &["eggplant", "aubergine"],
+        seasons: &[("central-europe", 7, 9), ("southern-europe", 6, 10)],
+    },
+    ProduceSeason {
+        names: &["bell pepper", "bell peppers"],
+        seasons: &[("central-europe", 7, 9), ("southern-europe", 6, 10)],
+    },
+    ProduceSeason {
+        names: &["green beans"],
+        seasons: &[("central-europe", 7, 9), ("southern-europe", 5, 9)],
+    },
+    ProduceSeason {
+        names: &["corn"],
+        seasons: &[("central-europe", 8, 9), ("southern-europe", 7, 9)],
+    },
+    ProduceSeason {
+        names: &["artichoke", "artichokes"],
+        seasons: &[("southern-europe", 2, 5)],
+    },
+    ProduceSeason {
+        names: &["pumpkin", "squash"],
+        seasons: &[("central-europe", 9, 11), ("southern-europe", 9, 12)],
+    },
+    ProduceSeason {
+        names: &["beetroot", "beet", "beets"],
+        seasons: &[("central-europe", 7, 11)],
+    },
+    ProduceSeason {
+        names: &["cauliflower"],
+        seasons: &[("central-europe", 6, 10), ("southern-europe", 10, 3)],
+    },
+    ProduceSeason {
+        names: &["broccoli"],
+        seasons: &[("central-europe", 6, 10), ("southern-europe", 10, 4)],
+    },
+    ProduceSeason {
+        names: &["brussels sprouts"],
+        seasons: &[("central-europe", 10, 2)],
+    },
+    ProduceSeason {
+        names: &["kale"],
+        seasons: &[("central-europe", 11, 2), ("southern-europe", 11, 3)],
+    },
+    ProduceSeason {
+        names: &["leek", "leeks"],
+        seasons: &[("central-europe", 9, 3), ("southern-europe", 10, 3)],
+    },
+];
+
+/// Returns the known region, regions without a calendar fall back to the default
+pub fn get_season_region(region: Option<&str>) -> &'static str {
+    let region = region.unwrap_or_default().trim().to_lowercase();
+
+    SEASON_REGIONS
+        .iter()
+        .find(|known_region| **known_region == region)
+        .copied()
+        .unwrap_or(DEFAULT_SEASON_REGION)
+}
+
+fn get_season_months(first_month: u32, last_month: u32) -> Vec<u32> {
+    if first_month <= last_month {
+        (first_month..=last_month).collect()
+    } else {
+        (first_month..=12).chain(1..=last_month).collect()
+    }
+}
+
+fn find_produce_season(ingredient_name: &str) -> Option<&'static ProduceSeason> {
+    if PRESERVED_KEYWORDS
+        .iter()
+        .any(|keyword| contains_words(ingredient_name, keyword))
+    {
+        return None;
+    }
+
+    PRODUCE_SEASONS.iter().find(|produce| {
+        produce
+            .names
+            .iter()
+            .any(|name| contains_words(ingredient_name, name))
+    })
+}
+
+fn get_produce_months(produce: &ProduceSeason, region: &str) -> Option<Vec<u32>> {
+    produce
+        .seasons
+        .iter()
+        .find(|(season_region, _, _)| *season_region == region)
+        .map(|(_, first_month, last_month)| get_season_months(*first_month, *last_month))
+}
+
+/// Share of the recipe's seasonal produce that is in season, None if it uses none
+pub fn get_seasonality_score(recipe: &Recipe, region: &str, month: u32) -> Option<f64> {
+    let produce_months = recipe
+        .ingredients
+        .iter()
+        .filter_map(|ingredient| find_produce_season(&ingredient.name))
+        .filter_map(|produce| get_produce_months(produce, region))
+        .collect::<Vec<Vec<u32>>>();
+
+    if produce_months.is_empty() {
+        return None;
+    }
+
+    let in_season_count = produce_months
+        .iter()
+        .filter(|months| months.contains(&month))
+        .count();
+    let score = in_season_count as f64 / produce_months.len() as f64;
+
+    Some((score * 100.0).round() / 100.0)
+}
+
+pub fn get_season_calendar(region: &str, month: u32) -> SeasonCalendar {
+    let produce = PRODUCE_SEASONS
+        .iter()
+        .filter_map(|produce| {
+            get_produce_months(produce, region).map(|months| SeasonalProduce {
+                ingredient: produce.names[0].into(),
+                in_season: months.contains(&month),
+                months,
+            })
+        })
+        .collect();
+
+    SeasonCalendar {
+        region: region.into(),
+        month,
+        produce,
+    }
+}
+
+#[cfg(test)]
+mod unit_tests_season_service {
+    use super::*;
+    use crate::{model::recipe::Ingredient, test_utils::get_random_recipe_db};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn scores_recipe_seasonality() {
+        struct TestCase {
+            title: String,
+            ingredient_names: Vec<String>,
+            region: String,
+            month: u32,
+            expected_score: Option<f64>,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Scores the share of produce in season".into(),
+                ingredient_names: vec![
+                    "Cherry tomatoes".into(),
+                    "Zucchini".into(),
+                    "Pumpkin".into(),
+                    "Olive oil".into(),
+                ],
+                region: "central-europe".into(),
+                month: 8,
+                expected_score: Some(0.67),
+            },
+            TestCase {
+                title: "Handles seasons around the new year".into(),
+                ingredient_names: vec!["Leeks".into(), "Kale".into()],
+                region: "central-europe".into(),
+                month: 1,
+                expected_score: Some(1.0),
+            },
+            TestCase {
+                title: "Uses the calendar of the region".into(),
+                ingredient_names: vec!["Oranges".into(), "Artichoke hearts".into()],
+                region: "southern-europe".into(),
+                month: 3,
+                expected_score: Some(1.0),
+            },
+            TestCase {
+                title: "Ignores preserved produce".into(),
+                ingredient_names: vec!["Canned tomatoes".into(), "Dried figs".into()],
+                region: "southern-europe".into(),
+                month: 1,
+                expected_score: None,
+            },
+            TestCase {
+                title: "Ignores produce without a season in the region".into(),
+                ingredient_names: vec!["Rhubarb".into(), "Asparagus".into()],
+                region: "southern-europe".into(),
+                month: 8,
+                expected_score: Some(0.0),
+            },
+        ];
+
+        for t in test_cases {
+            let mut recipe_db = get_random_recipe_db(None, None);
+            recipe_db.ingredients = t
+                .ingredient_names
+                .into_iter()
+                .map(|name| Ingredient {
+                    name,
+                    quantity: None,
+                    unit: None,
+                    note: None,
+                })
+                .collect();
+
+            let score = get_seasonality_score(&recipe_db.into(), &t.region, t.month);
+
+            assert_eq!(score, t.expected_score, "{}", t.title);
+        }
+    }
+
+    #[test]
+    fn builds_season_calendar() {
+        assert_eq!(
+            get_season_region(Some(" Southern-Europe ")),
+            "southern-europe"
+        );
+        assert_eq!(get_season_region(Some("at")), DEFAULT_SEASON_REGION);
+
+        let calendar = get_season_calendar("southern-europe", 12);
+        let oranges = calendar
+            .produce
+            .iter()
+            .find(|produce| produce.ingredient == "orange")
+            .expect("Oranges have a season in southern Europe");
+
+        assert_eq!(oranges.months, vec![11, 12, 1, 2, 3, 4]);
+        assert!(oranges.in_season);
+        assert!(!calendar
+            .produce
+            .iter()
+            .any(|produce| produce.ingredient == "rhubarb"));
+    }
+}
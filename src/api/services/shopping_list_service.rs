@@ -0,0 +1,470 @@
+use bson::oid::ObjectId;
+
+use super::unit_service::{
+    find_by_keyword, format_base_quantity, get_density, normalize_ingredient_name, to_base_unit,
+    Dimension,
+};
+use crate::model::{
+    recipe::RecipeMongoDb,
+    shopping_list::{Aisle, ShoppingList, ShoppingListExportFormat, ShoppingListItemMongoDb},
+};
+
+const AISLE_KEYWORDS: [(Aisle, &[&str]); 8] = [
+    (Aisle::Frozen, &["frozen", "ice cream", "edamame"]),
+    (
+        Aisle::DairyAndAlternatives,
+        &[
+            "milk",
+            "cheese",
+            "yogurt",
+            "yoghurt",
+            "butter",
+            "margarine",
+            "cream",
+            "egg",
+        ],
+    ),
+    (
+        Aisle::Protein,
+        &[
+            "tofu", "tempeh", "seitan", "chicken", "beef", "pork", "fish", "salmon", "tuna",
+            "shrimp", "bacon", "sausage",
+        ],
+    ),
+    (
+        Aisle::Bakery,
+        &["bread", "baguette", "bun", "tortilla", "pita", "croissant"],
+    ),
+    (
+        Aisle::SpicesAndSeasonings,
+        &[
+            "salt",
+            "pepper",
+            "cumin",
+            "paprika",
+            "cinnamon",
+            "oregano",
+            "thyme",
+            "turmeric",
+            "curry",
+            "chili flakes",
+            "nutmeg",
+            "vanilla",
+        ],
+    ),
+    (
+        Aisle::Beverages,
+        &["juice", "coffee", "tea", "wine", "beer", "soda"],
+    ),
+    (
+        Aisle::Produce,
+        &[
+            "onion",
+            "garlic",
+            "tomato",
+            "potato",
+            "carrot",
+            "lettuce",
+            "spinach",
+            "kale",
+            "apple",
+            "banana",
+            "lemon",
+            "lime",
+            "avocado",
+            "zucchini",
+            "cucumber",
+            "mushroom",
+            "broccoli",
+            "cabbage",
+            "celery",
+            "ginger",
+            "basil",
+            "parsley",
+            "cilantro",
+            "coriander",
+            "bell pepper",
+            "eggplant",
+            "berries",
+            "leek",
+        ],
+    ),
+    (
+        Aisle::Pantry,
+        &[
+            "flour",
+            "sugar",
+            "rice",
+            "pasta",
+            "noodle",
+            "oil",
+            "vinegar",
+            "oats",
+            "lentils",
+            "beans",
+            "chickpeas",
+            "sauce",
+            "stock",
+            "broth",
+            "syrup",
+            "honey",
+            "peanut butter",
+            "nuts",
+            "cocoa",
+            "baking",
+        ],
+    ),
+];
+
+pub fn get_aisle(ingredient_name: &str) -> Aisle {
+    let keywords = AISLE_KEYWORDS
+        .iter()
+        .flat_map(|(aisle, keywords)| keywords.iter().map(move |keyword| (*keyword, *aisle)));
+
+    find_by_keyword(ingredient_name, keywords).unwrap_or(Aisle::Other)
+}
+
+struct MergedIngredient {
+    name: String,
+    mass: Option<f64>,
+    volume: Option<f64>,
+    // Quantities in units without a known conversion, keyed by unit
+    others: Vec<(Option<String>, Option<f64>)>,
+    recipe_ids: Vec<ObjectId>,
+}
+
+fn add_optional(total: Option<f64>, quantity: Option<f64>) -> Option<f64> {
+    match (total, quantity) {
+        (Some(total), Some(quantity)) => Some(total + quantity),
+        (total, quantity) => total.or(quantity),
+    }
+}
+
+/// Merges the ingredients of recipes, each scaled by a servings factor
+pub fn merge_ingredients(recipes: &[(RecipeMongoDb, f64)]) -> Vec<ShoppingListItemMongoDb> {
+    let mut merged: Vec<(String, MergedIngredient)> = vec![];
+
+    for (recipe, factor) in recipes {
+        for ingredient in &recipe.ingredients {
+            let key = normalize_ingredient_name(&ingredient.name);
+
+            let merged_ingredient = match merged.iter().position(|(k, _)| *k == key) {
+                Some(idx) => &mut merged[idx].1,
+                None => {
+                    merged.push((
+                        key,
+                        MergedIngredient {
+                            name: ingredient.name.trim().into(),
+                            mass: None,
+                            volume: None,
+                            others: vec![],
+                            recipe_ids: vec![],
+                        },
+                    ));
+                    &mut merged.last_mut().expect("Ingredient was just pushed").1
+                }
+            };
+
+            if !merged_ingredient.recipe_ids.contains(&recipe._id) {
+                merged_ingredient.recipe_ids.push(recipe._id);
+            }
+
+            let quantity = ingredient.quantity.map(|q| q * factor);
+            let unit = ingredient.unit.as_ref().map(|u| u.trim().to_lowercase());
+
+            let base_quantity = match (quantity, &unit) {
+                (Some(quantity), Some(unit)) => to_base_unit(quantity, unit),
+                _ => None,
+            };
+
+            match base_quantity {
+                Some((Dimension::Mass, quantity)) => {
+                    merged_ingredient.mass = add_optional(merged_ingredient.mass, Some(quantity))
+                }
+                Some((Dimension::Volume, quantity)) => {
+                    merged_ingredient.volume =
+                        add_optional(merged_ingredient.volume, Some(quantity))
+                }
+                None => match merged_ingredient
+                    .others
+                    .iter_mut()
+                    .find(|(u, _)| *u == unit)
+                {
+                    Some(other) => other.1 = add_optional(other.1, quantity),
+                    None => merged_ingredient.others.push((unit, quantity)),
+                },
+            }
+        }
+    }
+
+    let mut items = vec![];
+
+    for (_, mut merged_ingredient) in merged {
+        if let (Some(mass), Some(volume), Some(density)) = (
+            merged_ingredient.mass,
+            merged_ingredient.volume,
+            get_density(&merged_ingredient.name),
+        ) {
+            merged_ingredient.mass = Some(mass + volume * density);
+            merged_ingredient.volume = None;
+        }
+
+        let aisle = get_aisle(&merged_ingredient.name);
+        let mut quantities = vec![];
+
+        if let Some(mass) = merged_ingredient.mass {
+            let (quantity, unit) = format_base_quantity(Dimension::Mass, mass);
+            quantities.push((Some(quantity), Some(unit.to_string())));
+        }
+
+        if let Some(volume) = merged_ingredient.volume {
+            let (quantity, unit) = format_base_quantity(Dimension::Volume, volume);
+            quantities.push((Some(quantity), Some(unit.to_string())));
+        }
+
+        quantities.extend(
+            merged_ingredient
+                .others
+                .into_iter()
+                .map(|(unit, quantity)| (quantity, unit)),
+        );
+
+        for (quantity, unit) in quantities {
+            items.push(ShoppingListItemMongoDb {
+                _id: ObjectId::new(),
+                name: merged_ingredient.name.clone(),
+                quantity,
+                unit,
+                aisle,
+                checked: false,
+                recipe_ids: merged_ingredient.recipe_ids.clone(),
+            });
+        }
+    }
+
+    items.sort_by_key(|item| (item.aisle, item.name.to_lowercase()));
+
+    items
+}
+
+pub fn export_shopping_list(
+    shopping_list: &ShoppingList,
+    format: ShoppingListExportFormat,
+) -> String {
+    let mut lines = match format {
+        ShoppingListExportFormat::Text => vec![shopping_list.name.clone()],
+        ShoppingListExportFormat::Markdown => vec![format!("# {}", shopping_list.name)],
+    };
+
+    let mut current_aisle = None;
+
+    for item in &shopping_list.items {
+        if current_aisle != Some(item.aisle) {
+            current_aisle = Some(item.aisle);
+
+            lines.push("".into());
+            lines.push(match format {
+                ShoppingListExportFormat::Text => format!("{}:", item.aisle),
+                ShoppingListExportFormat::Markdown => format!("## {}", item.aisle),
+            });
+        }
+
+        let amount = match (item.quantity, &item.unit) {
+            (Some(quantity), Some(unit)) => format!("{quantity} {unit} "),
+            (Some(quantity), None) => format!("{quantity} "),
+            _ => "".into(),
+        };
+
+        lines.push(match format {
+            ShoppingListExportFormat::Text => {
+                let check = if item.checked { "x" } else { " " };
+                format!("[{check}] {amount}{}", item.name)
+            }
+            ShoppingListExportFormat::Markdown => {
+                let check = if item.checked { "x" } else { " " };
+                format!("- [{check}] {amount}{}", item.name)
+            }
+        });
+    }
+
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod unit_tests_shopping_list_service {
+    use bson::DateTime;
+
+    use super::*;
+    use crate::{
+        model::{recipe::Ingredient, shopping_list::ShoppingListItem},
+        test_utils::get_random_recipe_db,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn get_ingredient(name: &str, quantity: Option<f64>, unit: Option<&str>) -> Ingredient {
+        Ingredient {
+            name: name.into(),
+            quantity,
+            unit: unit.map(Into::into),
+            note: None,
+        }
+    }
+
+    #[test]
+    fn gets_aisle() {
+        struct TestCase {
+            title: String,
+            ingredient_name: String,
+            expected_aisle: Aisle,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Gets produce aisle".into(),
+                ingredient_name: "Red Onion".into(),
+                expected_aisle: Aisle::Produce,
+            },
+            TestCase {
+                title: "Prefers more specific aisle".into(),
+                ingredient_name: "Frozen spinach".into(),
+                expected_aisle: Aisle::Frozen,
+            },
+            TestCase {
+                title: "Prefers longer keywords".into(),
+                ingredient_name: "Red bell pepper".into(),
+                expected_aisle: Aisle::Produce,
+            },
+            TestCase {
+                title: "Prefers longer keywords of other aisles".into(),
+                ingredient_name: "Crunchy peanut butter".into(),
+                expected_aisle: Aisle::Pantry,
+            },
+            TestCase {
+                title: "Does not match keywords inside words".into(),
+                ingredient_name: "Eggplant".into(),
+                expected_aisle: Aisle::Produce,
+            },
+            TestCase {
+                title: "Does not match keywords inside quantities".into(),
+                ingredient_name: "Bunch of parsley".into(),
+                expected_aisle: Aisle::Produce,
+            },
+            TestCase {
+                title: "Falls back to other".into(),
+                ingredient_name: "Nutritional yeast".into(),
+                expected_aisle: Aisle::Other,
+            },
+        ];
+
+        for t in test_cases {
+            assert_eq!(
+                get_aisle(&t.ingredient_name),
+                t.expected_aisle,
+                "{}",
+                t.title
+            );
+        }
+    }
+
+    #[test]
+    fn merges_ingredients() {
+        let mut recipe_1 = get_random_recipe_db(None, None);
+        recipe_1.ingredients = vec![
+            get_ingredient("Flour", Some(200.0), Some("g")),
+            get_ingredient("Salt", None, None),
+            get_ingredient("Garlic", Some(2.0), Some("cloves")),
+        ];
+
+        let mut recipe_2 = get_random_recipe_db(None, None);
+        recipe_2.ingredients = vec![
+            get_ingredient("flour", Some(1.0), Some("cup")),
+            get_ingredient("Garlic", Some(1.0), Some("cloves")),
+            get_ingredient("Lemon", Some(1.0), None),
+            get_ingredient("Soy milk", Some(1.0), Some("cup")),
+            get_ingredient("Soy milk", Some(100.0), Some("g")),
+        ];
+
+        let items = merge_ingredients(&[(recipe_1.clone(), 1.0), (recipe_2.clone(), 2.0)]);
+        let items = items
+            .into_iter()
+            .map(|item| {
+                (
+                    item.name,
+                    item.quantity,
+                    item.unit,
+                    item.aisle,
+                    item.recipe_ids.len(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            items,
+            vec![
+                (
+                    "Garlic".into(),
+                    Some(4.0),
+                    Some("cloves".into()),
+                    Aisle::Produce,
+                    2
+                ),
+                ("Lemon".into(), Some(2.0), None, Aisle::Produce, 1),
+                (
+                    "Soy milk".into(),
+                    Some(687.37),
+                    Some("g".into()),
+                    Aisle::DairyAndAlternatives,
+                    1
+                ),
+                (
+                    "Flour".into(),
+                    Some(450.78),
+                    Some("g".into()),
+                    Aisle::Pantry,
+                    2
+                ),
+                ("Salt".into(), None, None, Aisle::SpicesAndSeasonings, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn exports_shopping_list() {
+        let shopping_list = ShoppingList {
+            id: ObjectId::new().to_hex(),
+            household_id: None,
+            name: "Week 42".into(),
+            items: vec![
+                ShoppingListItem {
+                    id: ObjectId::new().to_hex(),
+                    name: "Lemon".into(),
+                    quantity: Some(2.0),
+                    unit: None,
+                    aisle: Aisle::Produce,
+                    checked: true,
+                    recipe_ids: vec![],
+                },
+                ShoppingListItem {
+                    id: ObjectId::new().to_hex(),
+                    name: "Flour".into(),
+                    quantity: Some(450.5),
+                    unit: Some("g".into()),
+                    aisle: Aisle::Pantry,
+                    checked: false,
+                    recipe_ids: vec![],
+                },
+            ],
+            created_at: DateTime::now(),
+            modified_at: DateTime::now(),
+        };
+
+        assert_eq!(
+            export_shopping_list(&shopping_list, ShoppingListExportFormat::Text),
+            "Week 42\n\nProduce:\n[x] 2 Lemon\n\nPantry:\n[ ] 450.5 g Flour\n"
+        );
+        assert_eq!(
+            export_shopping_list(&shopping_list, ShoppingListExportFormat::Markdown),
+            "# Week 42\n\n## Produce\n- [x] 2 Lemon\n\n## Pantry\n- [ ] 450.5 g Flour\n"
+        );
+    }
+}
@@ -0,0 +1,456 @@
+use crate::model::{
+    cooking::{CookingMode, CookingStep, StepTemperature, StepTimer, TemperatureUnit},
+    recipe::{Ingredient, Recipe},
+};
+
+#[derive(Clone, PartialEq, Debug)]
+enum TokenKind {
+    Number(f64),
+    Word(String),
+    Degree,
+    Dash,
+}
+
+#[derive(Clone, Debug)]
+struct Token {
+    kind: TokenKind,
+    start: usize,
+    end: usize,
+}
+
+impl Token {
+    fn word(&self) -> Option<&str> {
+        match &self.kind {
+            TokenKind::Word(word) => Some(word),
+            _ => None,
+        }
+    }
+}
+
+fn parse_number(number: &str) -> Option<f64> {
+    match number.split_once('/') {
+        Some((numerator, denominator)) => {
+            let denominator = denominator.parse::<f64>().ok()?;
+
+            if denominator == 0.0 {
+                return None;
+            }
+
+            Some(numerator.parse::<f64>().ok()? / denominator)
+        }
+        // Decimal commas as in "1,5 hours"
+        None => number.replace(',', ".").parse::<f64>().ok(),
+    }
+}
+
+// Splits a step into numbers, lowercase words and the symbols durations and temperatures use
+fn tokenize(text: &str) -> Vec<Token> {
+    let chars = text.char_indices().collect::<Vec<(usize, char)>>();
+    let get_offset = |i: usize| {
+        chars
+            .get(i)
+            .map(|(offset, _)| *offset)
+            .unwrap_or(text.len())
+    };
+    let is_digit_at = |i: usize| chars.get(i).is_some_and(|(_, c)| c.is_ascii_digit());
+
+    let mut tokens = vec![];
+    let mut i = 0;
+
+    while i < chars.len() {
+        let (start, c) = chars[i];
+        let mut j = i + 1;
+
+        let kind = if c.is_ascii_digit() {
+            while j < chars.len()
+                && (chars[j].1.is_ascii_digit()
+                    || (matches!(chars[j].1, '.' | ',' | '/') && is_digit_at(j + 1)))
+            {
+                j += 1;
+            }
+
+            parse_number(&text[start..get_offset(j)]).map(TokenKind::Number)
+        } else if c.is_alphabetic() {
+            while j < chars.len() && chars[j].1.is_alphabetic() {
+                j += 1;
+            }
+
+            Some(TokenKind::Word(text[start..get_offset(j)].to_lowercase()))
+        } else if c == '°' || c == 'º' {
+            Some(TokenKind::Degree)
+        } else if c == '-' || c == '–' {
+            Some(TokenKind::Dash)
+        } else {
+            None
+        };
+
+        if let Some(kind) = kind {
+            tokens.push(Token {
+                kind,
+                start,
+                end: get_offset(j),
+            });
+        }
+
+        i = j;
+    }
+
+    tokens
+}
+
+fn get_number_word(word: &str) -> Option<f64> {
+    let number = match word {
+        "a" | "an" | "one" => 1.0,
+        "two" => 2.0,
+        "three" => 3.0,
+        "four" => 4.0,
+        "five" => 5.0,
+        "six" => 6.0,
+        "seven" => 7.0,
+        "eight" => 8.0,
+        "nine" => 9.0,
+        "ten" => 10.0,
+        "fifteen" => 15.0,
+        "twenty" => 20.0,
+        "thirty" => 30.0,
+        "forty" => 40.0,
+        _ => return None,
+    };
+
+    Some(number)
+}
+
+fn get_duration_factor(word: &str) -> Option<u32> {
+    let factor = match word {
+        "sec" | "secs" | "second" | "seconds" => 1,
+        "min" | "mins" | "minute" | "minutes" => 60,
+        "h" | "hr" | "hrs" | "hour" | "hours" => 3600,
+        _ => return None,
+    };
+
+    Some(factor)
+}
+
+// Reads "15", "1 1/2", "ten" or "half an" and returns the value and the index after it
+fn parse_quantity(tokens: &[Token], i: usize) -> Option<(f64, usize)> {
+    let token = tokens.get(i)?;
+
+    match &token.kind {
+        TokenKind::Number(number) => match tokens.get(i + 1).map(|t| &t.kind) {
+            Some(TokenKind::Number(fraction)) if *fraction < 1.0 => {
+                Some((number + fraction, i + 2))
+            }
+            _ => Some((*number, i + 1)),
+        },
+        TokenKind::Word(word) if word == "half" => match tokens.get(i + 1).and_then(Token::word) {
+            Some("a" | "an") => Some((0.5, i + 2)),
+            _ => None,
+        },
+        TokenKind::Word(word) => get_number_word(word).map(|number| (number, i + 1)),
+        _ => None,
+    }
+}
+
+// Ranges like "25-30 minutes" use the lower bound, so cooks check early instead of too late
+fn parse_duration(tokens: &[Token], i: usize) -> Option<(u32, u32, usize)> {
+    let (value, mut j) = parse_quantity(tokens, i)?;
+
+    let is_range = match tokens.get(j).map(|t| &t.kind) {
+        Some(TokenKind::Dash) => true,
+        Some(TokenKind::Word(word)) => word == "to" || word == "or",
+        _ => false,
+    };
+    if is_range {
+        if let Some((_, k)) = parse_quantity(tokens, j + 1) {
+            j = k;
+        }
+    }
+
+    let factor = tokens
+        .get(j)
+        .and_then(Token::word)
+        .and_then(get_duration_factor)?;
+
+    Some(((value * factor as f64).round() as u32, factor, j + 1))
+}
+
+fn get_temperature_unit(word: &str) -> Option<TemperatureUnit> {
+    match word {
+        "c" | "celsius" => Some(TemperatureUnit::Celsius),
+        "f" | "fahrenheit" => Some(TemperatureUnit::Fahrenheit),
+        _ => None,
+    }
+}
+
+// Reads "180°C", "180 °C", "350 degrees F" or "180C", but not "2 c" which means cups
+fn parse_temperature(tokens: &[Token], i: usize) -> Option<(f64, TemperatureUnit, usize)> {
+    let value = match tokens.get(i)?.kind {
+        TokenKind::Number(number) => number,
+        _ => return None,
+    };
+    let next = tokens.get(i + 1)?;
+
+    let unit_index = match &next.kind {
+        TokenKind::Degree => i + 2,
+        TokenKind::Word(word) if word == "degree" || word == "degrees" || word == "deg" => i + 2,
+        TokenKind::Word(word) if word.len() > 1 => i + 1,
+        TokenKind::Word(_) if next.start == tokens[i].end => i + 1,
+        _ => return None,
+    };
+
+    let unit = tokens
+        .get(unit_index)
+        .and_then(Token::word)
+        .and_then(get_temperature_unit)?;
+
+    Some((value, unit, unit_index + 1))
+}
+
+fn get_words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_lowercase().trim_end_matches('s').to_string())
+        .collect()
+}
+
+// Steps usually name an ingredient by its last word, "the butter" for "unsalted butter"
+fn uses_ingredient(step_words: &[String], ingredient: &Ingredient) -> bool {
+    let name = ingredient.name.split(',').next().unwrap_or_default();
+
+    get_words(name)
+        .last()
+        .is_some_and(|word| step_words.contains(word))
+}
+
+pub fn analyze_step(number: usize, text: &str, ingredients: &[Ingredient]) -> CookingStep {
+    let tokens = tokenize(text);
+    let mut timers = vec![];
+    let mut temperatures = vec![];
+    let mut i = 0;
+
+    while i < tokens.len() {
+        if let Some((mut seconds, mut factor, mut end)) = parse_duration(&tokens, i) {
+            // "1 hour and 30 minutes" is one timer
+            loop {
+                let next = match tokens.get(end).and_then(Token::word) {
+                    Some("and") => end + 1,
+                    _ => end,
+                };
+
+                match parse_duration(&tokens, next) {
+                    Some((next_seconds, next_factor, next_end)) if next_factor < factor => {
+                        seconds += next_seconds;
+                        factor = next_factor;
+                        end = next_end;
+                    }
+                    _ => break,
+                }
+            }
+
+            timers.push(StepTimer {
+                text: text[tokens[i].start..tokens[end - 1].end].into(),
+                seconds,
+            });
+            i = end;
+        } else if let Some((value, unit, end)) = parse_temperature(&tokens, i) {
+            let (celsius, fahrenheit) = match unit {
+                TemperatureUnit::Celsius => (value, value * 9.0 / 5.0 + 32.0),
+                TemperatureUnit::Fahrenheit => ((value - 32.0) * 5.0 / 9.0, value),
+            };
+
+            temperatures.push(StepTemperature {
+                text: text[tokens[i].start..tokens[end - 1].end].into(),
+                unit,
+                celsius: celsius.round() as i32,
+                fahrenheit: fahrenheit.round() as i32,
+            });
+            i = end;
+        } else {
+            i += 1;
+        }
+    }
+
+    let step_words = get_words(text);
+
+    CookingStep {
+        number,
+        text: text.into(),
+        timers,
+        temperatures,
+        ingredients: ingredients
+            .iter()
+            .filter(|ingredient| uses_ingredient(&step_words, ingredient))
+            .cloned()
+            .collect(),
+    }
+}
+
+pub fn analyze_recipe(recipe: &Recipe) -> CookingMode {
+    let steps = recipe
+        .steps
+        .iter()
+        .enumerate()
+        .map(|(index, step)| analyze_step(index + 1, step, &recipe.ingredients))
+        .collect::<Vec<CookingStep>>();
+
+    let total_timer_seconds = steps
+        .iter()
+        .flat_map(|step| &step.timers)
+        .map(|timer| timer.seconds)
+        .sum();
+
+    CookingMode {
+        recipe_id: recipe.id.clone(),
+        title: recipe.title.clone(),
+        servings: recipe.servings,
+        ingredients: recipe.ingredients.clone(),
+        steps,
+        total_timer_seconds,
+    }
+}
+
+#[cfg(test)]
+mod unit_tests_step_analysis_service {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn get_ingredient(name: &str) -> Ingredient {
+        Ingredient {
+            name: name.into(),
+            quantity: None,
+            unit: None,
+            note: None,
+        }
+    }
+
+    #[test]
+    fn detects_timers() {
+        struct TestCase {
+            title: String,
+            step: String,
+            expected_timers: Vec<(String, u32)>,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Detects minutes".into(),
+                step: "Simmer for 15 minutes.".into(),
+                expected_timers: vec![("15 minutes".into(), 900)],
+            },
+            TestCase {
+                title: "Uses the lower bound of ranges".into(),
+                step: "Bake for 25-30 mins until golden".into(),
+                expected_timers: vec![("25-30 mins".into(), 1500)],
+            },
+            TestCase {
+                title: "Combines hours and minutes".into(),
+                step: "Braise for 1 hour and 30 minutes".into(),
+                expected_timers: vec![("1 hour and 30 minutes".into(), 5400)],
+            },
+            TestCase {
+                title: "Reads fractions and words".into(),
+                step: "Rest for 1 1/2 hours, then cook for half an hour and stir for a minute"
+                    .into(),
+                expected_timers: vec![
+                    ("1 1/2 hours".into(), 5400),
+                    ("half an hour".into(), 1800),
+                    ("a minute".into(), 60),
+                ],
+            },
+            TestCase {
+                title: "Ignores quantities without duration".into(),
+                step: "Add 2 cups of flour".into(),
+                expected_timers: vec![],
+            },
+        ];
+
+        for t in test_cases {
+            let step = analyze_step(1, &t.step, &[]);
+
+            assert_eq!(
+                step.timers
+                    .into_iter()
+                    .map(|timer| (timer.text, timer.seconds))
+                    .collect::<Vec<_>>(),
+                t.expected_timers,
+                "{}",
+                t.title
+            );
+        }
+    }
+
+    #[test]
+    fn converts_temperatures() {
+        struct TestCase {
+            title: String,
+            step: String,
+            expected_temperatures: Vec<(TemperatureUnit, i32, i32)>,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Converts Celsius".into(),
+                step: "Preheat the oven to 180°C".into(),
+                expected_temperatures: vec![(TemperatureUnit::Celsius, 180, 356)],
+            },
+            TestCase {
+                title: "Converts Fahrenheit in words".into(),
+                step: "Bake at 350 degrees F for 20 minutes".into(),
+                expected_temperatures: vec![(TemperatureUnit::Fahrenheit, 177, 350)],
+            },
+            TestCase {
+                title: "Reads units attached to the number".into(),
+                step: "Heat the oil to 170C".into(),
+                expected_temperatures: vec![(TemperatureUnit::Celsius, 170, 338)],
+            },
+            TestCase {
+                title: "Does not mistake cups for Celsius".into(),
+                step: "Add 2 c flour".into(),
+                expected_temperatures: vec![],
+            },
+        ];
+
+        for t in test_cases {
+            let step = analyze_step(1, &t.step, &[]);
+
+            assert_eq!(
+                step.temperatures
+                    .into_iter()
+                    .map(|temperature| (
+                        temperature.unit,
+                        temperature.celsius,
+                        temperature.fahrenheit
+                    ))
+                    .collect::<Vec<_>>(),
+                t.expected_temperatures,
+                "{}",
+                t.title
+            );
+        }
+    }
+
+    #[test]
+    fn matches_step_ingredients() {
+        let ingredients = vec![
+            get_ingredient("Unsalted butter"),
+            get_ingredient("Onions, diced"),
+            get_ingredient("Olive oil"),
+            get_ingredient("Sugar"),
+        ];
+
+        let step = analyze_step(
+            2,
+            "Melt the butter, then add the onion and cook for 5 minutes.",
+            &ingredients,
+        );
+
+        assert_eq!(step.number, 2);
+        assert_eq!(
+            step.ingredients
+                .iter()
+                .map(|ingredient| ingredient.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["Unsalted butter", "Onions, diced"]
+        );
+    }
+}
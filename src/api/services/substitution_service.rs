@@ -0,0 +1,287 @@
+use super::{pantry_service::names_match, unit_service::contains_words};
+use crate::model::{
+    pantry::PantryItemMongoDb,
+    recipe::Recipe,
+    substitution::{SubstitutionMongoDb, SubstitutionReason, SuggestedSubstitution},
+};
+
+// Ingredients containing an allergen, unless qualified by one of the exclusions
+const ALLERGEN_KEYWORDS: [(&str, &[&str], &[&str]); 7] = [
+    (
+        "gluten",
+        &[
+            "wheat",
+            "flour",
+            "bread",
+            "breadcrumb",
+            "pasta",
+            "noodle",
+            "seitan",
+            "barley",
+            "rye",
+            "spelt",
+            "couscous",
+            "soy sauce",
+        ],
+        &["gluten-free", "rice", "almond", "coconut", "chickpea"],
+    ),
+    (
+        "soy",
+        &["soy", "tofu", "tempeh", "edamame", "miso", "tamari"],
+        &[],
+    ),
+    ("peanuts", &["peanut"], &[]),
+    (
+        "tree nuts",
+        &[
+            "almond",
+            "cashew",
+            "walnut",
+            "pecan",
+            "hazelnut",
+            "pistachio",
+            "macadamia",
+        ],
+        &[],
+    ),
+    ("sesame", &["sesame", "tahini"], &[]),
+    ("egg", &["egg", "mayonnaise"], &["flax", "vegan"]),
+    (
+        "dairy",
+        &[
+            "milk", "butter", "cheese", "cream", "yogurt", "yoghurt", "ghee",
+        ],
+        &[
+            "oat", "soy", "almond", "coconut", "rice", "cashew", "peanut", "vegan", "plant",
+        ],
+    ),
+];
+
+pub fn conflicts_with_allergen(ingredient_name: &str, allergen: &str) -> bool {
+    let allergen = allergen.trim().to_lowercase();
+
+    match ALLERGEN_KEYWORDS
+        .iter()
+        .find(|(name, _, _)| *name == allergen)
+    {
+        Some((_, keywords, exclusions)) => {
+            keywords
+                .iter()
+                .any(|keyword| contains_words(ingredient_name, keyword))
+                && !exclusions
+                    .iter()
+                    .any(|exclusion| contains_words(ingredient_name, exclusion))
+        }
+        // Allergens without keywords are matched by name
+        None => contains_words(ingredient_name, &allergen),
+    }
+}
+
+fn is_safe_substitution(substitution: &SubstitutionMongoDb, allergens: &[String]) -> bool {
+    allergens.iter().all(|allergen| {
+        substitution
+            .free_of
+            .iter()
+            .any(|free_of| free_of.eq_ignore_ascii_case(allergen))
+            || !conflicts_with_allergen(&substitution.substitute, allergen)
+    })
+}
+
+pub fn suggest_substitutions(
+    recipe: &Recipe,
+    substitutions: &[SubstitutionMongoDb],
+    allergens: &[String],
+    pantry_items: &[PantryItemMongoDb],
+) -> Vec<SuggestedSubstitution> {
+    let mut suggestions = vec![];
+
+    for ingredient in &recipe.ingredients {
+        let conflicting_allergen = allergens
+            .iter()
+            .find(|allergen| conflicts_with_allergen(&ingredient.name, allergen));
+
+        // Without a pantry every ingredient would be missing
+        let is_missing = !pantry_items.is_empty()
+            && !pantry_items
+                .iter()
+                .any(|item| names_match(&item.name, &ingredient.name));
+
+        let reason = match (conflicting_allergen, is_missing) {
+            (Some(_), _) => SubstitutionReason::Allergen,
+            (None, true) => SubstitutionReason::MissingFromPantry,
+            (None, false) => continue,
+        };
+
+        let candidates = substitutions.iter().filter(|substitution| {
+            contains_words(&ingredient.name, &substitution.ingredient)
+                && is_safe_substitution(substitution, allergens)
+                && conflicting_allergen.is_none_or(|allergen| {
+                    substitution
+                        .free_of
+                        .iter()
+                        .any(|free_of| free_of.eq_ignore_ascii_case(allergen))
+                })
+        });
+
+        for substitution in candidates {
+            suggestions.push(SuggestedSubstitution {
+                ingredient: ingredient.name.clone(),
+                reason: reason.clone(),
+                allergen: conflicting_allergen.cloned(),
+                substitute: substitution.substitute.clone(),
+                quantity: ingredient.quantity.map(|q| q * substitution.ratio),
+                unit: ingredient.unit.clone(),
+                notes: substitution.notes.clone(),
+                substitute_in_pantry: pantry_items
+                    .iter()
+                    .any(|item| names_match(&item.name, &substitution.substitute)),
+            });
+        }
+    }
+
+    suggestions
+}
+
+#[cfg(test)]
+mod unit_tests_substitution_service {
+    use bson::{oid::ObjectId, DateTime};
+
+    use super::*;
+    use crate::{model::recipe::Ingredient, test_utils::get_random_recipe_db};
+    use pretty_assertions::assert_eq;
+
+    fn get_substitution(
+        ingredient: &str,
+        substitute: &str,
+        ratio: f64,
+        free_of: &[&str],
+    ) -> SubstitutionMongoDb {
+        SubstitutionMongoDb {
+            _id: ObjectId::new(),
+            ingredient: ingredient.into(),
+            substitute: substitute.into(),
+            ratio,
+            notes: None,
+            free_of: free_of.iter().map(|a| a.to_string()).collect(),
+            created_at: DateTime::now(),
+            modified_at: DateTime::now(),
+        }
+    }
+
+    #[test]
+    fn checks_allergen_conflicts() {
+        struct TestCase {
+            title: String,
+            ingredient_name: String,
+            allergen: String,
+            expected_conflict: bool,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Detects allergen by keyword".into(),
+                ingredient_name: "Low sodium soy sauce".into(),
+                allergen: "Gluten".into(),
+                expected_conflict: true,
+            },
+            TestCase {
+                title: "Ignores excluded qualifiers".into(),
+                ingredient_name: "Oat milk".into(),
+                allergen: "dairy".into(),
+                expected_conflict: false,
+            },
+            TestCase {
+                title: "Does not match partial words".into(),
+                ingredient_name: "Eggplant".into(),
+                allergen: "egg".into(),
+                expected_conflict: false,
+            },
+            TestCase {
+                title: "Matches unknown allergens by name".into(),
+                ingredient_name: "Button mushrooms".into(),
+                allergen: "mushroom".into(),
+                expected_conflict: true,
+            },
+        ];
+
+        for t in test_cases {
+            assert_eq!(
+                conflicts_with_allergen(&t.ingredient_name, &t.allergen),
+                t.expected_conflict,
+                "{}",
+                t.title
+            );
+        }
+    }
+
+    #[test]
+    fn suggests_substitutions() {
+        let mut recipe_db = get_random_recipe_db(None, None);
+        recipe_db.ingredients = vec![
+            Ingredient {
+                name: "Soy sauce".into(),
+                quantity: Some(2.0),
+                unit: Some("tbsp".into()),
+                note: None,
+            },
+            Ingredient {
+                name: "Aquafaba".into(),
+                quantity: Some(120.0),
+                unit: Some("ml".into()),
+                note: None,
+            },
+        ];
+        let recipe: Recipe = recipe_db.into();
+
+        let substitutions = vec![
+            get_substitution("soy sauce", "Tamari", 1.0, &["gluten"]),
+            get_substitution("soy sauce", "Coconut aminos", 1.0, &["gluten", "soy"]),
+            get_substitution("aquafaba", "Coconut cream", 1.0, &[]),
+        ];
+
+        let pantry_items = vec![PantryItemMongoDb {
+            _id: ObjectId::new(),
+            name: "Coconut cream".into(),
+            quantity: None,
+            unit: None,
+            expires_at: None,
+        }];
+
+        let suggestions =
+            suggest_substitutions(&recipe, &substitutions, &["gluten".into()], &pantry_items);
+
+        assert_eq!(
+            suggestions
+                .iter()
+                .map(|s| (
+                    s.substitute.as_str(),
+                    s.reason.clone(),
+                    s.substitute_in_pantry
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                ("Tamari", SubstitutionReason::Allergen, false),
+                ("Coconut aminos", SubstitutionReason::Allergen, false),
+                ("Coconut cream", SubstitutionReason::MissingFromPantry, true),
+            ]
+        );
+        assert_eq!(suggestions[0].allergen, Some("gluten".into()));
+        assert_eq!(suggestions[0].quantity, Some(2.0));
+
+        let suggestions = suggest_substitutions(
+            &recipe,
+            &substitutions,
+            &["gluten".into(), "soy".into()],
+            &[],
+        );
+
+        assert_eq!(
+            suggestions
+                .iter()
+                .map(|s| s.substitute.as_str())
+                .collect::<Vec<_>>(),
+            vec!["Coconut aminos"],
+            "Skips substitutes conflicting with other allergens"
+        );
+    }
+}
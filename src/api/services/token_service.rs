@@ -1,6 +1,8 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use axum::http::{header::AUTHORIZATION, HeaderMap};
 use chrono::{Duration, Utc};
 use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use rand::{distributions::Alphanumeric, Rng};
 use serde::{Deserialize, Serialize};
 
 use crate::model::user::{Role, UserAuthInfo};
@@ -8,6 +10,8 @@ use crate::model::user::{Role, UserAuthInfo};
 #[derive(Serialize, Deserialize)]
 pub struct Claims {
     pub sub: String,
+    /// Required, tokens issued without it fail to decode and their users log in again
+    pub user_id: String,
     pub role: Role,
     pub exp: i64,
 }
@@ -19,6 +23,7 @@ pub fn generate_jwt(
 ) -> Result<String> {
     let claims = Claims {
         sub: auth_info.email.to_string(),
+        user_id: auth_info._id.to_hex(),
         role: auth_info.role,
         exp: (Utc::now() + Duration::hours(token_validity_duration_h)).timestamp(),
     };
@@ -46,10 +51,39 @@ pub fn decode_jwt(token: &str, token_key: &str) -> Result<Claims> {
     Ok(decoded_claims)
 }
 
+pub fn generate_share_token() -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(24)
+        .map(char::from)
+        .collect()
+}
+
+pub fn get_token_from_headers(headers: &HeaderMap) -> Result<&str> {
+    let header_value = match headers.get(AUTHORIZATION) {
+        Some(value) => value.to_str()?,
+        None => return Err(anyhow!("Missing access token")),
+    };
+
+    match header_value.strip_prefix("Bearer ") {
+        Some(token) => Ok(token.trim()),
+        None => Err(anyhow!("Authorization header is not a Bearer token")),
+    }
+}
+
+pub fn get_claims_from_headers(headers: &HeaderMap, token_key: &str) -> Result<Claims> {
+    let token = get_token_from_headers(headers)?;
+
+    decode_jwt(token, token_key)
+}
+
 #[cfg(test)]
 mod unit_tests_token_service {
     use pretty_assertions::assert_eq;
 
+    use axum::http::HeaderValue;
+    use bson::oid::ObjectId;
+
     use crate::test_utils::assert_date_in_range;
 
     use super::*;
@@ -58,6 +92,7 @@ mod unit_tests_token_service {
     fn generates_jwt() -> Result<()> {
         let secret = "testSecret";
         let user_auth_info = UserAuthInfo {
+            _id: ObjectId::new(),
             email: "test@email.com".into(),
             password_hash: "test".into(),
             role: Role::User,
@@ -74,6 +109,7 @@ mod unit_tests_token_service {
         let decoded_claims = decoded_token.claims;
 
         assert_eq!(decoded_claims.sub, user_auth_info.email.to_string());
+        assert_eq!(decoded_claims.user_id, user_auth_info._id.to_hex());
         assert_eq!(decoded_claims.role, user_auth_info.role);
 
         Ok(())
@@ -87,6 +123,7 @@ mod unit_tests_token_service {
 
         let claims = Claims {
             sub: email.into(),
+            user_id: ObjectId::new().to_hex(),
             role,
             exp: (Utc::now() + Duration::hours(token_validity_duration_h)).timestamp(),
         };
@@ -118,4 +155,82 @@ mod unit_tests_token_service {
 
         Ok(())
     }
+
+    #[test]
+    fn rejects_tokens_without_user_id() -> Result<()> {
+        let secret = "testSecret";
+        let legacy_claims = serde_json::json!({
+            "sub": "test@email.com",
+            "role": "User",
+            "exp": (Utc::now() + Duration::hours(1)).timestamp(),
+        });
+
+        let token = encode(
+            &Header::default(),
+            &legacy_claims,
+            &EncodingKey::from_secret(secret.as_bytes()),
+        )?;
+
+        assert!(decode_jwt(&token, secret).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn gets_claims_from_headers() -> Result<()> {
+        struct TestCase {
+            title: String,
+            authorization: Option<String>,
+            is_success: bool,
+        }
+
+        let secret = "testSecret";
+        let user_auth_info = UserAuthInfo {
+            _id: ObjectId::new(),
+            email: "test@email.com".into(),
+            password_hash: "test".into(),
+            role: Role::User,
+            is_activated: true,
+        };
+        let token = generate_jwt(&user_auth_info, 1, secret)?;
+
+        let test_cases = vec![
+            TestCase {
+                title: "Successfully gets claims from bearer token".into(),
+                authorization: Some(format!("Bearer {token}")),
+                is_success: true,
+            },
+            TestCase {
+                title: "Fails without authorization header".into(),
+                authorization: None,
+                is_success: false,
+            },
+            TestCase {
+                title: "Fails with non-bearer authorization header".into(),
+                authorization: Some(format!("Basic {token}")),
+                is_success: false,
+            },
+            TestCase {
+                title: "Fails with invalid token".into(),
+                authorization: Some("Bearer invalid".into()),
+                is_success: false,
+            },
+        ];
+
+        for t in test_cases {
+            let mut headers = HeaderMap::new();
+            if let Some(authorization) = &t.authorization {
+                headers.insert(AUTHORIZATION, HeaderValue::from_str(authorization)?);
+            }
+
+            let claims_result = get_claims_from_headers(&headers, secret);
+
+            assert_eq!(claims_result.is_ok(), t.is_success, "{}", t.title);
+            if let Ok(claims) = claims_result {
+                assert_eq!(claims.user_id, user_auth_info._id.to_hex(), "{}", t.title);
+            }
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,257 @@
+use std::cmp::Reverse;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Dimension {
+    Mass,
+    Volume,
+}
+
+// Approximate densities in g/ml, liquids first so "rice milk" is milk
+const DENSITIES: [(&str, f64); 17] = [
+    ("brown sugar", 0.93),
+    ("powdered sugar", 0.56),
+    ("vinegar", 1.01),
+    ("oil", 0.92),
+    ("syrup", 1.33),
+    ("honey", 1.42),
+    ("milk", 1.03),
+    ("water", 1.0),
+    ("butter", 0.96),
+    ("margarine", 0.96),
+    ("sugar", 0.85),
+    ("flour", 0.53),
+    ("cocoa", 0.42),
+    ("oats", 0.38),
+    ("rice", 0.78),
+    ("lentils", 0.82),
+    ("salt", 1.2),
+];
+
+pub fn normalize_ingredient_name(name: &str) -> String {
+    name.split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+pub fn get_name_words(name: &str) -> Vec<String> {
+    normalize_ingredient_name(name)
+        .split(' ')
+        .map(|word| word.trim_end_matches('s').to_string())
+        .collect()
+}
+
+// "Red onions" contains "onion", but "soil" does not contain "oil"
+pub fn contains_words(name: &str, keyword: &str) -> bool {
+    let name_words = get_name_words(name);
+
+    get_name_words(keyword)
+        .iter()
+        .all(|word| name_words.contains(word))
+}
+
+/// Finds the value of the longest keyword contained in the name, earlier keywords
+/// win between keywords of the same length
+pub fn find_by_keyword<'a, T>(
+    name: &str,
+    keywords: impl IntoIterator<Item = (&'a str, T)>,
+) -> Option<T> {
+    keywords
+        .into_iter()
+        .filter(|(keyword, _)| contains_words(name, keyword))
+        .min_by_key(|(keyword, _)| Reverse(keyword.split(' ').count()))
+        .map(|(_, value)| value)
+}
+
+/// Returns the dimension of a unit and its factor to the base unit (g or ml)
+pub fn parse_unit(unit: &str) -> Option<(Dimension, f64)> {
+    let unit = unit.trim().trim_end_matches('.').to_lowercase();
+
+    let parsed = match unit.as_str() {
+        "mg" | "milligram" | "milligrams" => (Dimension::Mass, 0.001),
+        "g" | "gram" | "grams" => (Dimension::Mass, 1.0),
+        "kg" | "kilogram" | "kilograms" => (Dimension::Mass, 1000.0),
+        "oz" | "ounce" | "ounces" => (Dimension::Mass, 28.3495),
+        "lb" | "lbs" | "pound" | "pounds" => (Dimension::Mass, 453.592),
+        "ml" | "milliliter" | "milliliters" | "millilitre" | "millilitres" => {
+            (Dimension::Volume, 1.0)
+        }
+        "cl" => (Dimension::Volume, 10.0),
+        "dl" => (Dimension::Volume, 100.0),
+        "l" | "liter" | "liters" | "litre" | "litres" => (Dimension::Volume, 1000.0),
+        "tsp" | "teaspoon" | "teaspoons" => (Dimension::Volume, 4.92892),
+        "tbsp" | "tablespoon" | "tablespoons" => (Dimension::Volume, 14.7868),
+        "fl oz" | "fluid ounce" | "fluid ounces" => (Dimension::Volume, 29.5735),
+        "cup" | "cups" => (Dimension::Volume, 236.588),
+        "pint" | "pints" => (Dimension::Volume, 473.176),
+        "quart" | "quarts" => (Dimension::Volume, 946.353),
+        _ => return None,
+    };
+
+    Some(parsed)
+}
+
+pub fn get_density(ingredient_name: &str) -> Option<f64> {
+    find_by_keyword(ingredient_name, DENSITIES)
+}
+
+pub fn to_base_unit(quantity: f64, unit: &str) -> Option<(Dimension, f64)> {
+    parse_unit(unit).map(|(dimension, factor)| (dimension, quantity * factor))
+}
+
+/// Converts between units, crossing mass and volume if the ingredient density is known
+pub fn convert(
+    quantity: f64,
+    from_unit: &str,
+    to_unit: &str,
+    ingredient_name: &str,
+) -> Option<f64> {
+    let (from_dimension, from_factor) = parse_unit(from_unit)?;
+    let (to_dimension, to_factor) = parse_unit(to_unit)?;
+
+    let base_quantity = quantity * from_factor;
+
+    let base_quantity = match (from_dimension, to_dimension) {
+        (Dimension::Mass, Dimension::Volume) => base_quantity / get_density(ingredient_name)?,
+        (Dimension::Volume, Dimension::Mass) => base_quantity * get_density(ingredient_name)?,
+        _ => base_quantity,
+    };
+
+    Some(base_quantity / to_factor)
+}
+
+/// Picks a readable unit for a quantity in base units
+pub fn format_base_quantity(dimension: Dimension, quantity: f64) -> (f64, &'static str) {
+    let (quantity, unit) = match dimension {
+        Dimension::Mass if quantity >= 1000.0 => (quantity / 1000.0, "kg"),
+        Dimension::Mass => (quantity, "g"),
+        Dimension::Volume if quantity >= 1000.0 => (quantity / 1000.0, "l"),
+        Dimension::Volume => (quantity, "ml"),
+    };
+
+    ((quantity * 100.0).round() / 100.0, unit)
+}
+
+#[cfg(test)]
+mod unit_tests_unit_service {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn converts_units() {
+        struct TestCase {
+            title: String,
+            quantity: f64,
+            from_unit: String,
+            to_unit: String,
+            ingredient_name: String,
+            expected_quantity: Option<f64>,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Converts within mass".into(),
+                quantity: 1.5,
+                from_unit: "kg".into(),
+                to_unit: "g".into(),
+                ingredient_name: "potatoes".into(),
+                expected_quantity: Some(1500.0),
+            },
+            TestCase {
+                title: "Converts within volume".into(),
+                quantity: 3.0,
+                from_unit: "Tsp.".into(),
+                to_unit: "tbsp".into(),
+                ingredient_name: "vinegar".into(),
+                expected_quantity: Some(1.0),
+            },
+            TestCase {
+                title: "Converts volume to mass with known density".into(),
+                quantity: 1.0,
+                from_unit: "cup".into(),
+                to_unit: "g".into(),
+                ingredient_name: "All-purpose Flour".into(),
+                expected_quantity: Some(125.0),
+            },
+            TestCase {
+                title: "Fails to convert volume to mass with unknown density".into(),
+                quantity: 1.0,
+                from_unit: "cup".into(),
+                to_unit: "g".into(),
+                ingredient_name: "spinach".into(),
+                expected_quantity: None,
+            },
+            TestCase {
+                title: "Fails to convert unknown units".into(),
+                quantity: 2.0,
+                from_unit: "clove".into(),
+                to_unit: "g".into(),
+                ingredient_name: "garlic".into(),
+                expected_quantity: None,
+            },
+        ];
+
+        for t in test_cases {
+            let quantity = convert(t.quantity, &t.from_unit, &t.to_unit, &t.ingredient_name)
+                .map(|q| q.round());
+
+            assert_eq!(
+                quantity,
+                t.expected_quantity.map(|q: f64| q.round()),
+                "{}",
+                t.title
+            );
+        }
+    }
+
+    #[test]
+    fn gets_density() {
+        struct TestCase {
+            title: String,
+            ingredient_name: String,
+            expected_density: Option<f64>,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Gets density of known ingredients".into(),
+                ingredient_name: "Basmati rice".into(),
+                expected_density: Some(0.78),
+            },
+            TestCase {
+                title: "Prefers longer names".into(),
+                ingredient_name: "Light brown sugar".into(),
+                expected_density: Some(0.93),
+            },
+            TestCase {
+                title: "Prefers liquids".into(),
+                ingredient_name: "Rice vinegar".into(),
+                expected_density: Some(1.01),
+            },
+            TestCase {
+                title: "Does not match names inside words".into(),
+                ingredient_name: "Licorice".into(),
+                expected_density: None,
+            },
+        ];
+
+        for t in test_cases {
+            assert_eq!(
+                get_density(&t.ingredient_name),
+                t.expected_density,
+                "{}",
+                t.title
+            );
+        }
+    }
+
+    #[test]
+    fn formats_base_quantity() {
+        assert_eq!(format_base_quantity(Dimension::Mass, 450.0), (450.0, "g"));
+        assert_eq!(format_base_quantity(Dimension::Mass, 1250.0), (1.25, "kg"));
+        assert_eq!(
+            format_base_quantity(Dimension::Volume, 236.588),
+            (236.59, "ml")
+        );
+    }
+}
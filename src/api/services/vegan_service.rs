@@ -0,0 +1,230 @@
+use super::unit_service::get_name_words;
+use crate::model::recipe::{Ingredient, VeganCheck};
+
+// Animal products, unless qualified by one of the exclusions next to them
+const NON_VEGAN_KEYWORDS: [&str; 51] = [
+    "beef",
+    "pork",
+    "chicken",
+    "turkey",
+    "lamb",
+    "veal",
+    "duck",
+    "goose",
+    "venison",
+    "bacon",
+    "ham",
+    "sausage",
+    "prosciutto",
+    "salami",
+    "chorizo",
+    "pancetta",
+    "lard",
+    "gelatin",
+    "gelatine",
+    "fish",
+    "anchovy",
+    "anchovie",
+    "salmon",
+    "tuna",
+    "cod",
+    "shrimp",
+    "prawn",
+    "crab",
+    "lobster",
+    "scallop",
+    "oyster",
+    "mussel",
+    "clam",
+    "squid",
+    "worcestershire",
+    "milk",
+    "buttermilk",
+    "butter",
+    "cheese",
+    "parmesan",
+    "mozzarella",
+    "feta",
+    "ricotta",
+    "cream",
+    "yogurt",
+    "yoghurt",
+    "ghee",
+    "whey",
+    "egg",
+    "mayonnaise",
+    "honey",
+];
+
+const VEGAN_EXCLUSIONS: [&str; 21] = [
+    "vegan",
+    "plant",
+    "plant-based",
+    "dairy-free",
+    "egg-free",
+    "meatless",
+    "oat",
+    "soy",
+    "soybean",
+    "almond",
+    "coconut",
+    "rice",
+    "cashew",
+    "peanut",
+    "flax",
+    "cocoa",
+    "shea",
+    "tartar",
+    "bean",
+    "mushroom",
+    "jackfruit",
+];
+
+fn is_any_word(word: &str, keywords: &[&str]) -> bool {
+    keywords
+        .iter()
+        .any(|keyword| get_name_words(keyword) == [word])
+}
+
+// Neighbours are the words before and after, also across an "of" like in "cream of tartar"
+fn get_neighbours(words: &[String], idx: usize) -> Vec<usize> {
+    let mut neighbours = vec![];
+
+    if idx >= 1 {
+        neighbours.push(idx - 1);
+    }
+    if idx >= 2 && words[idx - 1] == "of" {
+        neighbours.push(idx - 2);
+    }
+    if idx + 1 < words.len() {
+        neighbours.push(idx + 1);
+    }
+    if idx + 2 < words.len() && words[idx + 1] == "of" {
+        neighbours.push(idx + 2);
+    }
+
+    neighbours
+}
+
+/// Exclusions only qualify the animal products next to them, so "oat milk" and
+/// "vegan parmesan cheese" are vegan, but "gelatin jelly beans" is not
+pub fn is_vegan_ingredient(ingredient_name: &str) -> bool {
+    let words = get_name_words(ingredient_name);
+    let mut is_qualified = words
+        .iter()
+        .map(|word| is_any_word(word, &VEGAN_EXCLUSIONS))
+        .collect::<Vec<bool>>();
+
+    // Qualified animal products qualify their neighbours in turn
+    let mut has_changed = true;
+    while has_changed {
+        has_changed = false;
+
+        for idx in 0..words.len() {
+            if !is_qualified[idx]
+                && is_any_word(&words[idx], &NON_VEGAN_KEYWORDS)
+                && get_neighbours(&words, idx)
+                    .iter()
+                    .any(|neighbour| is_qualified[*neighbour])
+            {
+                is_qualified[idx] = true;
+                has_changed = true;
+            }
+        }
+    }
+
+    words
+        .iter()
+        .zip(is_qualified)
+        .all(|(word, is_qualified)| is_qualified || !is_any_word(word, &NON_VEGAN_KEYWORDS))
+}
+
+pub fn check_vegan(ingredients: &[Ingredient]) -> VeganCheck {
+    let violations: Vec<String> = ingredients
+        .iter()
+        .filter(|ingredient| !is_vegan_ingredient(&ingredient.name))
+        .map(|ingredient| ingredient.name.clone())
+        .collect();
+
+    VeganCheck {
+        is_vegan: violations.is_empty(),
+        violations,
+    }
+}
+
+#[cfg(test)]
+mod unit_tests_vegan_service {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn checks_vegan_ingredients() {
+        struct TestCase {
+            title: String,
+            ingredient_name: String,
+            expected_vegan: bool,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Detects dairy".into(),
+                ingredient_name: "Unsalted butter".into(),
+                expected_vegan: false,
+            },
+            TestCase {
+                title: "Detects plural animal products".into(),
+                ingredient_name: "Large eggs".into(),
+                expected_vegan: false,
+            },
+            TestCase {
+                title: "Allows plant-based alternatives".into(),
+                ingredient_name: "Oat milk".into(),
+                expected_vegan: true,
+            },
+            TestCase {
+                title: "Allows plant ingredients named like animal products".into(),
+                ingredient_name: "Cream of tartar".into(),
+                expected_vegan: true,
+            },
+            TestCase {
+                title: "Allows chains of qualified animal products".into(),
+                ingredient_name: "Vegan parmesan cheese".into(),
+                expected_vegan: true,
+            },
+            TestCase {
+                title: "Does not match partial words".into(),
+                ingredient_name: "Butternut squash".into(),
+                expected_vegan: true,
+            },
+            TestCase {
+                title: "Does not match exclusions inside words".into(),
+                ingredient_name: "Honey licorice".into(),
+                expected_vegan: false,
+            },
+            TestCase {
+                title: "Allows compound plant names".into(),
+                ingredient_name: "Soybean butter".into(),
+                expected_vegan: true,
+            },
+            TestCase {
+                title: "Only qualifies neighbouring animal products".into(),
+                ingredient_name: "Gelatin jelly beans".into(),
+                expected_vegan: false,
+            },
+            TestCase {
+                title: "Does not qualify animal products by other ingredients".into(),
+                ingredient_name: "Chicken fried rice".into(),
+                expected_vegan: false,
+            },
+        ];
+
+        for t in test_cases {
+            assert_eq!(
+                is_vegan_ingredient(&t.ingredient_name),
+                t.expected_vegan,
+                "{}",
+                t.title
+            );
+        }
+    }
+}
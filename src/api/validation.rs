@@ -0,0 +1,508 @@
+use std::{error::Error, fmt::Display, sync::LazyLock};
+
+use anyhow::anyhow;
+use axum::{
+    body::Bytes,
+    extract::{
+        self,
+        rejection::{PathRejection, QueryRejection},
+        FromRequest, FromRequestParts, Request,
+    },
+    http::{header::CONTENT_TYPE, request::Parts, HeaderMap},
+};
+use regex::Regex;
+use serde::de::DeserializeOwned;
+use serde_json::error::Category;
+use tracing::info;
+
+use super::{api_response::FieldError, app_error::AppError, messages::Message};
+
+static EMAIL_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[^@\s]+@[^@\s]+\.[^@\s.]+$").expect("Valid email regex"));
+
+/// Request payloads checking their fields before they reach a handler
+pub trait Validate {
+    fn validate(&self) -> Result<(), Vec<FieldError>>;
+}
+
+/// Collects the field errors of a payload rule by rule
+///
+/// Fields are named like in the request body, nested ones like "ingredients[0].name".
+#[derive(Default)]
+pub struct Validator {
+    errors: Vec<FieldError>,
+}
+
+impl Validator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an error with the message unless the check holds
+    pub fn check(mut self, field: &str, is_valid: bool, message: Message) -> Self {
+        if !is_valid {
+            self.errors.push(FieldError {
+                field: field.into(),
+                message: message.to_string(),
+            });
+        }
+
+        self
+    }
+
+    /// Number of characters without surrounding whitespace
+    pub fn length(self, field: &str, value: &str, min_length: usize, max_length: usize) -> Self {
+        let length = value.trim().chars().count();
+
+        if length == 0 && min_length > 0 {
+            return self.check(field, false, Message::FieldEmpty);
+        }
+
+        self.check(
+            field,
+            length >= min_length,
+            Message::FieldTooShort {
+                min_length: &min_length,
+            },
+        )
+        .check(
+            field,
+            length <= max_length,
+            Message::FieldTooLong {
+                max_length: &max_length,
+            },
+        )
+    }
+
+    pub fn count<T>(self, field: &str, values: &[T], min_count: usize, max_count: usize) -> Self {
+        self.check(
+            field,
+            values.len() >= min_count,
+            Message::TooFewItems {
+                min_count: &min_count,
+            },
+        )
+        .check(
+            field,
+            values.len() <= max_count,
+            Message::TooManyItems {
+                max_count: &max_count,
+            },
+        )
+    }
+
+    pub fn range<N: PartialOrd + Display>(self, field: &str, value: N, min: N, max: N) -> Self {
+        let is_valid = value >= min && value <= max;
+
+        self.check(
+            field,
+            is_valid,
+            Message::OutOfRange {
+                min: &min,
+                max: &max,
+            },
+        )
+    }
+
+    pub fn regex(self, field: &str, value: &str, regex: &Regex) -> Self {
+        self.check(field, regex.is_match(value), Message::InvalidFormat)
+    }
+
+    pub fn email(self, field: &str, value: &str) -> Self {
+        self.check(field, EMAIL_REGEX.is_match(value), Message::InvalidEmail)
+    }
+
+    /// Applies the rules to values that were given, leaving out the others
+    pub fn optional<T>(self, value: Option<T>, rules: impl FnOnce(Self, T) -> Self) -> Self {
+        match value {
+            Some(value) => rules(self, value),
+            None => self,
+        }
+    }
+
+    /// Applies the rules to every value, passing on the field of the value
+    pub fn each<T>(
+        self,
+        field: &str,
+        values: &[T],
+        mut rules: impl FnMut(Self, &str, &T) -> Self,
+    ) -> Self {
+        values
+            .iter()
+            .enumerate()
+            .fold(self, |validator, (index, value)| {
+                rules(validator, &format!("{field}[{index}]"), value)
+            })
+    }
+
+    /// Validates a nested payload, prefixing its fields with the field
+    pub fn nested<T: Validate>(mut self, field: &str, value: &T) -> Self {
+        if let Err(errors) = value.validate() {
+            self.errors
+                .extend(errors.into_iter().map(|error| FieldError {
+                    field: format!("{field}.{}", error.field),
+                    message: error.message,
+                }));
+        }
+
+        self
+    }
+
+    pub fn nested_each<T: Validate>(self, field: &str, values: &[T]) -> Self {
+        self.each(field, values, |validator, field, value| {
+            validator.nested(field, value)
+        })
+    }
+
+    pub fn finish(self) -> Result<(), Vec<FieldError>> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(self.errors)
+        }
+    }
+}
+
+fn has_json_content_type(headers: &HeaderMap) -> bool {
+    headers
+        .get(CONTENT_TYPE)
+        .and_then(|content_type| content_type.to_str().ok())
+        .and_then(|content_type| content_type.split(';').next())
+        .map(|media_type| media_type.trim().to_lowercase())
+        .is_some_and(|media_type| {
+            media_type == "application/json"
+                || (media_type.starts_with("application/") && media_type.ends_with("+json"))
+        })
+}
+
+/// Parses a JSON body, fields of the wrong type are reported by their path
+fn parse_json<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, AppError> {
+    let deserializer = &mut serde_json::Deserializer::from_slice(bytes);
+
+    serde_path_to_error::deserialize(deserializer).map_err(|err| {
+        let field = err.path().to_string();
+        let err = err.into_inner();
+
+        // Missing fields are reported by the object holding them
+        if err.classify() == Category::Data && field != "." {
+            AppError::InvalidFields(vec![FieldError {
+                field,
+                message: Message::InvalidFieldValue { err: &err }.to_string(),
+            }])
+        } else {
+            AppError::Validation(Message::InvalidJsonBody { err: &err }.to_string())
+        }
+    })
+}
+
+/// JSON extractor answering with an ApiResponse instead of plain text for bodies
+/// that can't be parsed or break the rules of the payload
+pub struct ValidatedJson<T>(pub T);
+
+impl<T, S> FromRequest<S> for ValidatedJson<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+        if !has_json_content_type(request.headers()) {
+            return Err(AppError::Validation(
+                Message::JsonContentTypeRequired.to_string(),
+            ));
+        }
+
+        let bytes = Bytes::from_request(request, state).await.map_err(|err| {
+            let err_msg = Message::ReadBodyFailed.to_string();
+            info!("{err_msg}: {err}");
+
+            AppError::Validation(err_msg)
+        })?;
+
+        let payload: T = parse_json(&bytes)?;
+        payload.validate().map_err(AppError::InvalidFields)?;
+
+        Ok(Self(payload))
+    }
+}
+
+/// Path extractor answering with an ApiResponse instead of plain text for parameters
+/// that can't be parsed
+pub struct Path<T>(pub T);
+
+impl<T, S> FromRequestParts<S> for Path<T>
+where
+    T: DeserializeOwned + Send,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        match extract::Path::<T>::from_request_parts(parts, state).await {
+            Ok(extract::Path(params)) => Ok(Self(params)),
+            Err(PathRejection::FailedToDeserializePathParams(err)) => Err(AppError::Validation(
+                Message::InvalidPathParams { err: err.kind() }.to_string(),
+            )),
+            Err(err) => Err(anyhow!("{}", err.body_text()).into()),
+        }
+    }
+}
+
+/// Query extractor answering with an ApiResponse instead of plain text for query strings
+/// that can't be parsed
+pub struct Query<T>(pub T);
+
+impl<T, S> FromRequestParts<S> for Query<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        match extract::Query::<T>::from_request_parts(parts, state).await {
+            Ok(extract::Query(query)) => Ok(Self(query)),
+            Err(QueryRejection::FailedToDeserializeQueryString(err)) => {
+                let err = err.source().map_or(err.body_text(), ToString::to_string);
+
+                Err(AppError::Validation(
+                    Message::InvalidQueryParams { err: &err }.to_string(),
+                ))
+            }
+            Err(err) => Err(anyhow!("{}", err.body_text()).into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_tests_validation {
+    use axum::{body::Body, http::StatusCode};
+    use serde::Deserialize;
+
+    use super::*;
+    use crate::api::api_response::ErrorCode;
+    use pretty_assertions::assert_eq;
+
+    #[derive(Deserialize)]
+    struct Tag {
+        name: String,
+    }
+
+    impl Validate for Tag {
+        fn validate(&self) -> Result<(), Vec<FieldError>> {
+            Validator::new().length("name", &self.name, 1, 5).finish()
+        }
+    }
+
+    #[derive(Deserialize)]
+    struct Payload {
+        email: String,
+        code: Option<String>,
+        rating: u8,
+        tags: Vec<Tag>,
+    }
+
+    impl Validate for Payload {
+        fn validate(&self) -> Result<(), Vec<FieldError>> {
+            let code_regex = Regex::new("^[A-Z]{3}$").expect("Valid regex");
+
+            Validator::new()
+                .email("email", &self.email)
+                .optional(self.code.as_deref(), |v, code| {
+                    v.regex("code", code, &code_regex)
+                })
+                .range("rating", self.rating, 1, 5)
+                .count("tags", &self.tags, 0, 2)
+                .nested_each("tags", &self.tags)
+                .finish()
+        }
+    }
+
+    fn field_error(field: &str, message: Message) -> FieldError {
+        FieldError {
+            field: field.into(),
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn validates_payload() {
+        struct TestCase {
+            title: String,
+            payload: serde_json::Value,
+            expected_errors: Vec<FieldError>,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Accepts a valid payload".into(),
+                payload: serde_json::json!({
+                    "email": "jane@example.com",
+                    "code": "ABC",
+                    "rating": 5,
+                    "tags": [{"name": "vegan"}],
+                }),
+                expected_errors: vec![],
+            },
+            TestCase {
+                title: "Leaves out rules of missing optional fields".into(),
+                payload: serde_json::json!({
+                    "email": "jane@example.com",
+                    "rating": 1,
+                    "tags": [],
+                }),
+                expected_errors: vec![],
+            },
+            TestCase {
+                title: "Collects the errors of every rule".into(),
+                payload: serde_json::json!({
+                    "email": "jane@",
+                    "code": "abc",
+                    "rating": 6,
+                    "tags": [{"name": "vegan"}, {"name": " "}, {"name": "breakfast"}],
+                }),
+                expected_errors: vec![
+                    field_error("email", Message::InvalidEmail),
+                    field_error("code", Message::InvalidFormat),
+                    field_error("rating", Message::OutOfRange { min: &1, max: &5 }),
+                    field_error("tags", Message::TooManyItems { max_count: &2 }),
+                    field_error("tags[1].name", Message::FieldEmpty),
+                    field_error("tags[2].name", Message::FieldTooLong { max_length: &5 }),
+                ],
+            },
+        ];
+
+        for t in test_cases {
+            let payload: Payload = serde_json::from_value(t.payload).expect("Valid payload");
+
+            assert_eq!(
+                payload.validate().err().unwrap_or_default(),
+                t.expected_errors,
+                "{}",
+                t.title
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn extracts_validated_json() {
+        struct TestCase {
+            title: String,
+            content_type: Option<String>,
+            body: String,
+            expected_status_code: StatusCode,
+            expected_details: Vec<FieldError>,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Extracts a valid body".into(),
+                content_type: Some("application/json; charset=utf-8".into()),
+                body: r#"{"email": "jane@example.com", "rating": 3, "tags": []}"#.into(),
+                expected_status_code: StatusCode::OK,
+                expected_details: vec![],
+            },
+            TestCase {
+                title: "Rejects bodies without JSON content type".into(),
+                content_type: Some("text/plain".into()),
+                body: r#"{"email": "jane@example.com", "rating": 3, "tags": []}"#.into(),
+                expected_status_code: StatusCode::BAD_REQUEST,
+                expected_details: vec![],
+            },
+            TestCase {
+                title: "Rejects malformed JSON".into(),
+                content_type: Some("application/json".into()),
+                body: r#"{"email": "#.into(),
+                expected_status_code: StatusCode::BAD_REQUEST,
+                expected_details: vec![],
+            },
+            TestCase {
+                title: "Reports the path of fields with the wrong type".into(),
+                content_type: Some("application/json".into()),
+                body: r#"{"email": "jane@example.com", "rating": 3, "tags": [{"name": 42}]}"#
+                    .into(),
+                expected_status_code: StatusCode::BAD_REQUEST,
+                expected_details: vec![FieldError {
+                    field: "tags[0].name".into(),
+                    message: Message::InvalidFieldValue {
+                        err: &"invalid type: integer `42`, expected a string at line 1 column 63",
+                    }
+                    .to_string(),
+                }],
+            },
+            TestCase {
+                title: "Rejects bodies breaking the rules".into(),
+                content_type: Some("application/json".into()),
+                body: r#"{"email": "jane", "rating": 3, "tags": []}"#.into(),
+                expected_status_code: StatusCode::BAD_REQUEST,
+                expected_details: vec![field_error("email", Message::InvalidEmail)],
+            },
+        ];
+
+        for t in test_cases {
+            let mut request = Request::builder().method("POST").uri("/");
+            if let Some(content_type) = &t.content_type {
+                request = request.header(CONTENT_TYPE, content_type);
+            }
+            let request = request.body(Body::from(t.body)).expect("Valid request");
+
+            match ValidatedJson::<Payload>::from_request(request, &()).await {
+                Ok(_) => assert_eq!(StatusCode::OK, t.expected_status_code, "{}", t.title),
+                Err(app_error) => {
+                    assert_eq!(
+                        app_error.status_code(),
+                        t.expected_status_code,
+                        "{}",
+                        t.title
+                    );
+                    assert_eq!(app_error.code(), ErrorCode::ValidationFailed, "{}", t.title);
+
+                    let details = match app_error {
+                        AppError::InvalidFields(details) => details,
+                        _ => vec![],
+                    };
+                    assert_eq!(details, t.expected_details, "{}", t.title);
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn extracts_query() {
+        #[derive(Deserialize, PartialEq, Debug)]
+        struct PageQuery {
+            page: u32,
+        }
+
+        let (mut parts, _) = Request::builder()
+            .uri("/recipes?page=2")
+            .body(())
+            .expect("Valid request")
+            .into_parts();
+        let Query(query) = Query::<PageQuery>::from_request_parts(&mut parts, &())
+            .await
+            .expect("Valid query");
+        assert_eq!(query, PageQuery { page: 2 });
+
+        let (mut parts, _) = Request::builder()
+            .uri("/recipes?page=second")
+            .body(())
+            .expect("Valid request")
+            .into_parts();
+        match Query::<PageQuery>::from_request_parts(&mut parts, &()).await {
+            Ok(_) => panic!("Accepted an invalid query"),
+            Err(app_error) => {
+                assert_eq!(app_error.status_code(), StatusCode::BAD_REQUEST);
+                assert_eq!(app_error.code(), ErrorCode::ValidationFailed);
+                assert_eq!(
+                    app_error.to_string(),
+                    Message::InvalidQueryParams {
+                        err: &"page: invalid digit found in string"
+                    }
+                    .to_string()
+                );
+            }
+        }
+    }
+}
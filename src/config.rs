@@ -2,6 +2,8 @@ use anyhow::Result;
 use std::env;
 use tracing::info;
 
+pub const DEFAULT_MAIL_FROM: &str = "noreply@wegonice.com";
+
 #[derive(Debug)]
 pub struct Config {
     pub db_name: String,
@@ -10,6 +12,7 @@ pub struct Config {
     pub db_host: String,
     pub server_host: String,
     pub jwt_secret: String,
+    pub mail_from: String,
 }
 
 impl Config {
@@ -29,6 +32,7 @@ impl Config {
         let db_host = env::var("MONGO_WEGONICE_HOST")?;
         let server_host = env::var("SERVER_HOST")?;
         let jwt_secret = env::var("JWT_SECRET")?;
+        let mail_from = env::var("MAIL_FROM").unwrap_or(DEFAULT_MAIL_FROM.into());
 
         Ok(Self {
             db_name,
@@ -37,6 +41,7 @@ impl Config {
             db_host,
             server_host,
             jwt_secret,
+            mail_from,
         })
     }
 }
@@ -66,7 +71,8 @@ pub mod unit_tests_config {
                     db_user_password: "nicePassword".into(),
                     db_host: "127.0.0.1:27017".into(),
                     server_host: "127.0.0.1:3000".into(),
-                    jwt_secret: "test".into()
+                    jwt_secret: "test".into(),
+                    mail_from: DEFAULT_MAIL_FROM.into(),
                 }),
                 env_file_path: "src/test-env".into(),
                 setup_env_file: Some(
@@ -0,0 +1,200 @@
+use super::{
+    generic_handler::{parse_object_id, GenericHandler},
+    mongo_db_handler::MongoDbHandler,
+};
+
+use crate::{
+    api::app_error::AppError,
+    model::{
+        comment::{build_comment_threads, Comment, CommentMongoDb, CommentStatus},
+        pagination::{Page, Pagination},
+    },
+};
+use anyhow::{anyhow, Result};
+use bson::{doc, oid::ObjectId, to_bson, Bson, DateTime};
+use futures_util::TryStreamExt;
+
+pub trait CommentHandler {
+    async fn create_comment(
+        &self,
+        recipe_id: &str,
+        author_id: &str,
+        text: String,
+        parent: Option<&CommentMongoDb>,
+    ) -> Result<String, AppError>;
+    async fn get_comment_threads(
+        &self,
+        recipe_id: &str,
+        pagination: &Pagination,
+    ) -> Result<Page<Comment>, AppError>;
+    async fn patch_comment_text(&self, id: &str, text: String) -> Result<(), AppError>;
+    async fn set_comment_status(&self, id: &str, status: CommentStatus) -> Result<(), AppError>;
+}
+
+impl CommentHandler for MongoDbHandler {
+    async fn create_comment(
+        &self,
+        recipe_id: &str,
+        author_id: &str,
+        text: String,
+        parent: Option<&CommentMongoDb>,
+    ) -> Result<String, AppError> {
+        let _id = ObjectId::new();
+
+        let comment_db = CommentMongoDb {
+            _id,
+            recipe_id: parse_object_id(recipe_id)?,
+            thread_id: parent.map(|p| p.thread_id).unwrap_or(_id),
+            parent_id: parent.map(|p| p._id),
+            author_id: parse_object_id(author_id)?,
+            text,
+            status: CommentStatus::Visible,
+            created_at: DateTime::now(),
+            modified_at: DateTime::now(),
+        };
+
+        let insert_result = self.comments_collection.insert_one(&comment_db).await?;
+
+        match insert_result.inserted_id {
+            Bson::ObjectId(object_id) => Ok(object_id.to_hex()),
+            _ => Err(anyhow!(
+                "Failed to convert inserted Id to string, {}",
+                insert_result.inserted_id
+            )
+            .into()),
+        }
+    }
+
+    async fn get_comment_threads(
+        &self,
+        recipe_id: &str,
+        pagination: &Pagination,
+    ) -> Result<Page<Comment>, AppError> {
+        let recipe_object_id = parse_object_id(recipe_id)?;
+
+        let roots_page = self
+            .get_multiple_paginated::<CommentMongoDb, CommentMongoDb>(
+                "comments",
+                doc! {"recipe_id": recipe_object_id, "parent_id": Bson::Null},
+                doc! {"created_at": 1},
+                pagination,
+            )
+            .await?;
+
+        let thread_ids = roots_page
+            .items
+            .iter()
+            .map(|root| root._id)
+            .collect::<Vec<ObjectId>>();
+
+        let cursor = self
+            .comments_collection
+            .find(doc! {
+                "thread_id": { "$in": thread_ids },
+                "parent_id": { "$ne": Bson::Null },
+            })
+            .sort(doc! {"created_at": 1})
+            .await?;
+        let replies_db = cursor.try_collect::<Vec<CommentMongoDb>>().await?;
+
+        let threads = build_comment_threads(
+            roots_page.items.into_iter().map(Into::into).collect(),
+            replies_db.into_iter().map(Into::into).collect(),
+        );
+
+        Ok(Page {
+            items: threads,
+            page: roots_page.page,
+            page_size: roots_page.page_size,
+            total: roots_page.total,
+        })
+    }
+
+    async fn patch_comment_text(&self, id: &str, text: String) -> Result<(), AppError> {
+        let filter = doc! {"_id": parse_object_id(id)?};
+        let update = doc! {
+            "$set": {
+                "text": text,
+                "modified_at": DateTime::now(),
+            }
+        };
+
+        self.comments_collection.update_one(filter, update).await?;
+
+        Ok(())
+    }
+
+    async fn set_comment_status(&self, id: &str, status: CommentStatus) -> Result<(), AppError> {
+        let filter = doc! {"_id": parse_object_id(id)?};
+        let update = doc! {
+            "$set": {
+                "status": to_bson(&status)?,
+                "modified_at": DateTime::now(),
+            }
+        };
+
+        self.comments_collection.update_one(filter, update).await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub mod unit_tests_comment_handler {
+    use crate::test_utils::{db_clean_up, get_db_config};
+
+    use super::*;
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn get_comment_threads() -> Result<()> {
+        let (db_name, db_user_name, db_user_password, db_host) = get_db_config(Some(".env"))?;
+        let db_handler =
+            MongoDbHandler::new(&db_user_name, &db_user_password, &db_name, &db_host).await?;
+
+        let recipe_id = ObjectId::new().to_hex();
+        let author_id = ObjectId::new().to_hex();
+
+        let root_id = db_handler
+            .create_comment(&recipe_id, &author_id, "First question".into(), None)
+            .await?;
+        db_handler
+            .create_comment(&recipe_id, &author_id, "Second question".into(), None)
+            .await?;
+
+        let root = db_handler
+            .get_by_id::<CommentMongoDb, CommentMongoDb>(&root_id, "comments")
+            .await?;
+        let reply_id = db_handler
+            .create_comment(&recipe_id, &author_id, "An answer".into(), Some(&root))
+            .await?;
+        db_handler
+            .set_comment_status(&reply_id, CommentStatus::Deleted)
+            .await?;
+
+        let page = db_handler
+            .get_comment_threads(
+                &recipe_id,
+                &Pagination {
+                    page: 1,
+                    page_size: 1,
+                },
+            )
+            .await?;
+
+        assert_eq!(page.total, 2, "Counts root comments only");
+        assert_eq!(page.items.len(), 1, "Returns a single page");
+        assert_eq!(page.items[0].id, root_id, "Returns oldest root first");
+        assert_eq!(page.items[0].replies.len(), 1, "Keeps deleted reply");
+        assert_eq!(
+            page.items[0].replies[0].status,
+            CommentStatus::Deleted,
+            "Returns reply as tombstone"
+        );
+
+        db_clean_up().await?;
+
+        Ok(())
+    }
+}
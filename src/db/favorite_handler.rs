@@ -0,0 +1,441 @@
+use super::{
+    generic_handler::parse_object_id, mongo_db_handler::MongoDbHandler,
+    recipe_handler::RecipeHandler,
+};
+
+use crate::{
+    api::{app_error::AppError, services::token_service::generate_share_token},
+    model::{
+        favorite::{
+            CollectionVisibility, FavoritesMongoDb, RecipeCollection, RecipeCollectionCreate,
+            RecipeCollectionMongoDb, RecipeCollectionPatch, SharedRecipeCollection,
+        },
+        recipe::Recipe,
+    },
+};
+use anyhow::Result;
+use bson::{doc, oid::ObjectId, to_bson, DateTime, Document};
+
+pub trait FavoriteHandler {
+    async fn add_favorite(&self, user_id: &str, recipe_id: &str) -> Result<bool, AppError>;
+    async fn remove_favorite(&self, user_id: &str, recipe_id: &str) -> Result<bool, AppError>;
+    async fn get_favorite_recipes(&self, user_id: &str) -> Result<Vec<Recipe>, AppError>;
+    async fn get_recipe_collections(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<RecipeCollection>, AppError>;
+    async fn create_recipe_collection(
+        &self,
+        user_id: &str,
+        collection: RecipeCollectionCreate,
+    ) -> Result<String, AppError>;
+    async fn patch_recipe_collection(
+        &self,
+        user_id: &str,
+        collection_id: &str,
+        collection_patch: RecipeCollectionPatch,
+    ) -> Result<u64, AppError>;
+    async fn delete_recipe_collection(
+        &self,
+        user_id: &str,
+        collection_id: &str,
+    ) -> Result<u64, AppError>;
+    async fn add_recipe_to_collection(
+        &self,
+        user_id: &str,
+        collection_id: &str,
+        recipe_id: &str,
+    ) -> Result<u64, AppError>;
+    async fn remove_recipe_from_collection(
+        &self,
+        user_id: &str,
+        collection_id: &str,
+        recipe_id: &str,
+    ) -> Result<u64, AppError>;
+    async fn get_shared_recipe_collection(
+        &self,
+        share_token: &str,
+    ) -> Result<Option<SharedRecipeCollection>, AppError>;
+}
+
+impl MongoDbHandler {
+    async fn get_favorites(
+        &self,
+        user_id: &ObjectId,
+    ) -> Result<Option<FavoritesMongoDb>, AppError> {
+        let favorites = self
+            .favorites_collection
+            .find_one(doc! {"user_id": user_id})
+            .await?;
+
+        Ok(favorites)
+    }
+
+    async fn upsert_favorites(
+        &self,
+        user_id: &ObjectId,
+        mut update: Document,
+    ) -> Result<(), AppError> {
+        update.insert(
+            "$setOnInsert",
+            doc! {
+                "_id": ObjectId::new(),
+                "created_at": DateTime::now(),
+            },
+        );
+        update.insert("$set", doc! {"modified_at": DateTime::now()});
+
+        self.favorites_collection
+            .update_one(doc! {"user_id": user_id}, update)
+            .upsert(true)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl FavoriteHandler for MongoDbHandler {
+    async fn add_favorite(&self, user_id: &str, recipe_id: &str) -> Result<bool, AppError> {
+        let user_object_id = parse_object_id(user_id)?;
+        let recipe_object_id = parse_object_id(recipe_id)?;
+
+        let favorites = self.get_favorites(&user_object_id).await?;
+        let is_favorite = favorites
+            .map(|f| f.recipe_ids.contains(&recipe_object_id))
+            .unwrap_or(false);
+
+        if is_favorite {
+            return Ok(false);
+        }
+
+        self.upsert_favorites(
+            &user_object_id,
+            doc! {
+                "$addToSet": { "recipe_ids": recipe_object_id },
+            },
+        )
+        .await?;
+
+        self.recipes_collection
+            .update_one(
+                doc! {"_id": recipe_object_id},
+                doc! {"$inc": {"favorite_count": 1}},
+            )
+            .await?;
+
+        Ok(true)
+    }
+
+    async fn remove_favorite(&self, user_id: &str, recipe_id: &str) -> Result<bool, AppError> {
+        let user_object_id = parse_object_id(user_id)?;
+        let recipe_object_id = parse_object_id(recipe_id)?;
+
+        let update_result = self
+            .favorites_collection
+            .update_one(
+                doc! {"user_id": user_object_id, "recipe_ids": recipe_object_id},
+                doc! {
+                    "$pull": { "recipe_ids": recipe_object_id },
+                    "$set": { "modified_at": DateTime::now() },
+                },
+            )
+            .await?;
+
+        if update_result.modified_count == 0 {
+            return Ok(false);
+        }
+
+        self.recipes_collection
+            .update_one(
+                doc! {"_id": recipe_object_id, "favorite_count": { "$gt": 0 }},
+                doc! {"$inc": {"favorite_count": -1}},
+            )
+            .await?;
+
+        Ok(true)
+    }
+
+    async fn get_favorite_recipes(&self, user_id: &str) -> Result<Vec<Recipe>, AppError> {
+        let user_object_id = parse_object_id(user_id)?;
+        let favorites = self.get_favorites(&user_object_id).await?;
+
+        match favorites {
+            Some(favorites) => {
+                self.get_recipes_by_ids(&favorites.recipe_ids, &[user_object_id])
+                    .await
+            }
+            None => Ok(vec![]),
+        }
+    }
+
+    async fn get_recipe_collections(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<RecipeCollection>, AppError> {
+        let favorites = self.get_favorites(&parse_object_id(user_id)?).await?;
+
+        Ok(favorites
+            .map(|f| f.collections.into_iter().map(Into::into).collect())
+            .unwrap_or_default())
+    }
+
+    async fn create_recipe_collection(
+        &self,
+        user_id: &str,
+        collection: RecipeCollectionCreate,
+    ) -> Result<String, AppError> {
+        let collection_db = RecipeCollectionMongoDb {
+            _id: ObjectId::new(),
+            name: collection.name,
+            recipe_ids: vec![],
+            visibility: collection
+                .visibility
+                .unwrap_or(CollectionVisibility::Private),
+            share_token: generate_share_token(),
+            created_at: DateTime::now(),
+            modified_at: DateTime::now(),
+        };
+
+        self.upsert_favorites(
+            &parse_object_id(user_id)?,
+            doc! {
+                "$push": { "collections": to_bson(&collection_db)? },
+            },
+        )
+        .await?;
+
+        Ok(collection_db._id.to_hex())
+    }
+
+    async fn patch_recipe_collection(
+        &self,
+        user_id: &str,
+        collection_id: &str,
+        collection_patch: RecipeCollectionPatch,
+    ) -> Result<u64, AppError> {
+        let mut update_doc = doc! {};
+
+        if let Some(name) = collection_patch.name {
+            update_doc.insert("collections.$.name", name);
+        }
+
+        if let Some(visibility) = collection_patch.visibility {
+            update_doc.insert("collections.$.visibility", to_bson(&visibility)?);
+        }
+
+        update_doc.insert("collections.$.modified_at", DateTime::now());
+
+        let filter = doc! {
+            "user_id": parse_object_id(user_id)?,
+            "collections._id": parse_object_id(collection_id)?,
+        };
+
+        let update_result = self
+            .favorites_collection
+            .update_one(filter, doc! {"$set": update_doc})
+            .await?;
+
+        Ok(update_result.matched_count)
+    }
+
+    async fn delete_recipe_collection(
+        &self,
+        user_id: &str,
+        collection_id: &str,
+    ) -> Result<u64, AppError> {
+        let update_result = self
+            .favorites_collection
+            .update_one(
+                doc! {"user_id": parse_object_id(user_id)?},
+                doc! {
+                    "$pull": { "collections": { "_id": parse_object_id(collection_id)? } },
+                },
+            )
+            .await?;
+
+        Ok(update_result.modified_count)
+    }
+
+    async fn add_recipe_to_collection(
+        &self,
+        user_id: &str,
+        collection_id: &str,
+        recipe_id: &str,
+    ) -> Result<u64, AppError> {
+        let filter = doc! {
+            "user_id": parse_object_id(user_id)?,
+            "collections._id": parse_object_id(collection_id)?,
+        };
+        let update = doc! {
+            "$addToSet": { "collections.$.recipe_ids": parse_object_id(recipe_id)? },
+            "$set": { "collections.$.modified_at": DateTime::now() },
+        };
+
+        let update_result = self.favorites_collection.update_one(filter, update).await?;
+
+        Ok(update_result.matched_count)
+    }
+
+    async fn remove_recipe_from_collection(
+        &self,
+        user_id: &str,
+        collection_id: &str,
+        recipe_id: &str,
+    ) -> Result<u64, AppError> {
+        let filter = doc! {
+            "user_id": parse_object_id(user_id)?,
+            "collections._id": parse_object_id(collection_id)?,
+        };
+        let update = doc! {
+            "$pull": { "collections.$.recipe_ids": parse_object_id(recipe_id)? },
+            "$set": { "collections.$.modified_at": DateTime::now() },
+        };
+
+        let update_result = self.favorites_collection.update_one(filter, update).await?;
+
+        Ok(update_result.matched_count)
+    }
+
+    async fn get_shared_recipe_collection(
+        &self,
+        share_token: &str,
+    ) -> Result<Option<SharedRecipeCollection>, AppError> {
+        let favorites = self
+            .favorites_collection
+            .find_one(doc! {"collections.share_token": share_token})
+            .await?;
+
+        let collection = favorites.and_then(|f| {
+            f.collections.into_iter().find(|c| {
+                c.share_token == share_token && c.visibility == CollectionVisibility::Shared
+            })
+        });
+
+        match collection {
+            Some(collection) => Ok(Some(SharedRecipeCollection {
+                name: collection.name,
+                recipes: self.get_recipes_by_ids(&collection.recipe_ids, &[]).await?,
+            })),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod unit_tests_favorite_handler {
+    use crate::{
+        db::generic_handler::GenericHandler,
+        model::recipe::RecipeMongoDb,
+        test_utils::{db_clean_up, get_db_config, get_db_connection, get_random_recipe_db},
+    };
+
+    use super::*;
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn maintains_favorite_count() -> Result<()> {
+        let (db_name, db_user_name, db_user_password, db_host) = get_db_config(Some(".env"))?;
+        let db_handler =
+            MongoDbHandler::new(&db_user_name, &db_user_password, &db_name, &db_host).await?;
+
+        let recipe_db = get_random_recipe_db(None, None);
+        get_db_connection()
+            .await?
+            .collection::<RecipeMongoDb>("recipes")
+            .insert_one(&recipe_db)
+            .await?;
+
+        let recipe_id = recipe_db._id.to_hex();
+        let user_id = ObjectId::new().to_hex();
+
+        assert!(db_handler.add_favorite(&user_id, &recipe_id).await?);
+        assert!(
+            !db_handler.add_favorite(&user_id, &recipe_id).await?,
+            "Does not add a favorite twice"
+        );
+
+        let recipe = db_handler
+            .get_by_id::<RecipeMongoDb, Recipe>(&recipe_id, "recipes")
+            .await?;
+        assert_eq!(recipe.favorite_count, 1, "Counts favorite once");
+
+        let favorites = db_handler.get_favorite_recipes(&user_id).await?;
+        assert_eq!(favorites.len(), 1, "Lists favorite recipe");
+
+        assert!(db_handler.remove_favorite(&user_id, &recipe_id).await?);
+
+        let recipe = db_handler
+            .get_by_id::<RecipeMongoDb, Recipe>(&recipe_id, "recipes")
+            .await?;
+        assert_eq!(recipe.favorite_count, 0, "Decrements favorite count");
+
+        db_clean_up().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn shares_recipe_collection() -> Result<()> {
+        let (db_name, db_user_name, db_user_password, db_host) = get_db_config(Some(".env"))?;
+        let db_handler =
+            MongoDbHandler::new(&db_user_name, &db_user_password, &db_name, &db_host).await?;
+
+        let recipe_db = get_random_recipe_db(None, None);
+        get_db_connection()
+            .await?
+            .collection::<RecipeMongoDb>("recipes")
+            .insert_one(&recipe_db)
+            .await?;
+
+        let user_id = ObjectId::new().to_hex();
+        let collection_id = db_handler
+            .create_recipe_collection(
+                &user_id,
+                RecipeCollectionCreate {
+                    name: "Holiday baking".into(),
+                    visibility: None,
+                },
+            )
+            .await?;
+        db_handler
+            .add_recipe_to_collection(&user_id, &collection_id, &recipe_db._id.to_hex())
+            .await?;
+
+        let favorites = db_handler
+            .get_favorites(&ObjectId::parse_str(&user_id)?)
+            .await?
+            .expect("Favorites should exist after creating a collection");
+        let share_token = favorites.collections[0].share_token.clone();
+
+        assert!(
+            db_handler
+                .get_shared_recipe_collection(&share_token)
+                .await?
+                .is_none(),
+            "Does not expose private collections"
+        );
+
+        db_handler
+            .patch_recipe_collection(
+                &user_id,
+                &collection_id,
+                RecipeCollectionPatch {
+                    visibility: Some(CollectionVisibility::Shared),
+                    ..Default::default()
+                },
+            )
+            .await?;
+
+        let shared_collection = db_handler
+            .get_shared_recipe_collection(&share_token)
+            .await?
+            .expect("Shared collection should be found by its token");
+        assert_eq!(shared_collection.name, "Holiday baking");
+        assert_eq!(shared_collection.recipes.len(), 1);
+
+        db_clean_up().await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,308 @@
+use super::{
+    generic_handler::parse_object_id, mongo_db_handler::MongoDbHandler,
+    recipe_handler::get_visibility_filter,
+};
+
+use crate::{
+    api::{app_error::AppError, messages::Message},
+    model::{
+        feed::{FeedCursor, FeedItem, FeedItemKind, FeedPage, FeedQuery},
+        follow::{FollowMongoDb, PublicProfile},
+        recipe::{RecipeMongoDb, RecipeStatus},
+        review::ReviewMongoDb,
+    },
+};
+use anyhow::Result;
+use bson::{doc, oid::ObjectId, to_bson, DateTime, Document};
+use futures_util::TryStreamExt;
+
+pub trait FollowHandler {
+    async fn follow_user(&self, follower_id: &str, followee_id: &str) -> Result<bool, AppError>;
+    async fn unfollow_user(&self, follower_id: &str, followee_id: &str) -> Result<bool, AppError>;
+    async fn get_public_profile(&self, user_id: &str) -> Result<PublicProfile, AppError>;
+    async fn get_feed(&self, user_id: &str, query: &FeedQuery) -> Result<FeedPage, AppError>;
+}
+
+// Matches documents after the cursor when sorted by the given time field and id, newest first
+fn get_cursor_filter(cursor: Option<FeedCursor>, time_field: &str) -> Document {
+    match cursor {
+        Some(cursor) => doc! {
+            "$or": [
+                { time_field: { "$lt": cursor.occurred_at } },
+                { time_field: cursor.occurred_at, "_id": { "$lt": cursor.id } },
+            ]
+        },
+        None => doc! {},
+    }
+}
+
+impl FollowHandler for MongoDbHandler {
+    async fn follow_user(&self, follower_id: &str, followee_id: &str) -> Result<bool, AppError> {
+        let follower_object_id = parse_object_id(follower_id)?;
+        let followee_object_id = parse_object_id(followee_id)?;
+
+        let update_result = self
+            .follows_collection
+            .update_one(
+                doc! {"follower_id": follower_object_id, "followee_id": followee_object_id},
+                doc! {"$setOnInsert": {
+                    "_id": ObjectId::new(),
+                    "created_at": DateTime::now(),
+                }},
+            )
+            .upsert(true)
+            .await?;
+
+        Ok(update_result.upserted_id.is_some())
+    }
+
+    async fn unfollow_user(&self, follower_id: &str, followee_id: &str) -> Result<bool, AppError> {
+        let delete_result = self
+            .follows_collection
+            .delete_one(doc! {
+                "follower_id": parse_object_id(follower_id)?,
+                "followee_id": parse_object_id(followee_id)?,
+            })
+            .await?;
+
+        Ok(delete_result.deleted_count > 0)
+    }
+
+    async fn get_public_profile(&self, user_id: &str) -> Result<PublicProfile, AppError> {
+        let object_id = parse_object_id(user_id)?;
+
+        let user = self
+            .users_collection
+            .find_one(doc! {"_id": object_id})
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(
+                    Message::DocumentNotFound {
+                        collection: &"users",
+                        id: &user_id,
+                    }
+                    .to_string(),
+                )
+            })?;
+
+        let follower_count = self
+            .follows_collection
+            .count_documents(doc! {"followee_id": object_id})
+            .await?;
+        let following_count = self
+            .follows_collection
+            .count_documents(doc! {"follower_id": object_id})
+            .await?;
+
+        let mut recipe_filter = doc! {"author_id": object_id};
+        recipe_filter.extend(get_visibility_filter(None, false)?);
+        let recipe_count = self
+            .recipes_collection
+            .count_documents(recipe_filter)
+            .await?;
+
+        Ok(PublicProfile {
+            id: user._id.to_hex(),
+            follower_count,
+            following_count,
+            recipe_count,
+            created_at: user.created_at,
+        })
+    }
+
+    async fn get_feed(&self, user_id: &str, query: &FeedQuery) -> Result<FeedPage, AppError> {
+        let followee_ids = self
+            .follows_collection
+            .find(doc! {"follower_id": parse_object_id(user_id)?})
+            .await?
+            .try_collect::<Vec<FollowMongoDb>>()
+            .await?
+            .into_iter()
+            .map(|follow| follow.followee_id)
+            .collect::<Vec<_>>();
+
+        if followee_ids.is_empty() {
+            return Ok(FeedPage {
+                items: vec![],
+                next_cursor: None,
+            });
+        }
+
+        let cursor = query
+            .cursor
+            .as_deref()
+            .map(str::parse::<FeedCursor>)
+            .transpose()?;
+        let limit = query.limit();
+
+        // Each source fetches one extra item to tell whether there is a next page
+        let mut recipe_filter = doc! {
+            "author_id": { "$in": &followee_ids },
+            "status": to_bson(&RecipeStatus::Published)?,
+        };
+        recipe_filter.extend(get_cursor_filter(cursor, "published_at"));
+
+        let recipes = self
+            .recipes_collection
+            .find(recipe_filter)
+            .sort(doc! {"published_at": -1, "_id": -1})
+            .limit(limit as i64 + 1)
+            .await?
+            .try_collect::<Vec<RecipeMongoDb>>()
+            .await?;
+
+        let mut review_filter = doc! {"user_id": { "$in": &followee_ids }};
+        review_filter.extend(get_cursor_filter(cursor, "created_at"));
+
+        let reviews = self
+            .reviews_collection
+            .find(review_filter)
+            .sort(doc! {"created_at": -1, "_id": -1})
+            .limit(limit as i64 + 1)
+            .await?
+            .try_collect::<Vec<ReviewMongoDb>>()
+            .await?;
+
+        let mut items = recipes
+            .into_iter()
+            .map(|recipe| FeedItem {
+                id: recipe._id.to_hex(),
+                kind: match recipe.forked_from {
+                    Some(_) => FeedItemKind::RecipeForked,
+                    None => FeedItemKind::RecipePublished,
+                },
+                actor_id: recipe.author_id.to_hex(),
+                recipe_id: recipe._id.to_hex(),
+                title: Some(recipe.title),
+                rating: None,
+                occurred_at: recipe.published_at.unwrap_or(recipe.created_at),
+            })
+            .chain(reviews.into_iter().map(|review| FeedItem {
+                id: review._id.to_hex(),
+                kind: FeedItemKind::ReviewCreated,
+                actor_id: review.user_id.to_hex(),
+                recipe_id: review.recipe_id.to_hex(),
+                title: None,
+                rating: Some(review.rating),
+                occurred_at: review.created_at,
+            }))
+            .collect::<Vec<_>>();
+
+        // Hex ids sort like the ObjectIds the cursor filter compares
+        items.sort_by(|a, b| (b.occurred_at, &b.id).cmp(&(a.occurred_at, &a.id)));
+
+        let next_cursor = if items.len() as u64 > limit {
+            items.truncate(limit as usize);
+
+            match items.last() {
+                Some(last) => Some(
+                    FeedCursor {
+                        occurred_at: last.occurred_at,
+                        id: parse_object_id(&last.id)?,
+                    }
+                    .to_string(),
+                ),
+                None => None,
+            }
+        } else {
+            None
+        };
+
+        Ok(FeedPage { items, next_cursor })
+    }
+}
+
+#[cfg(test)]
+pub mod unit_tests_follow_handler {
+    use crate::test_utils::{
+        db_clean_up, get_db_config, get_db_connection, get_random_recipe_db, get_random_user_db,
+    };
+
+    use super::*;
+    use crate::model::user::UserMongoDb;
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn follows_users_and_pages_feed() -> Result<()> {
+        let (db_name, db_user_name, db_user_password, db_host) = get_db_config(Some(".env"))?;
+        let db_handler =
+            MongoDbHandler::new(&db_user_name, &db_user_password, &db_name, &db_host).await?;
+
+        let follower = get_random_user_db(None);
+        let followee = get_random_user_db(None);
+        let connection = get_db_connection().await?;
+        connection
+            .collection::<UserMongoDb>("users")
+            .insert_many(vec![&follower, &followee])
+            .await?;
+
+        let follower_id = follower._id.to_hex();
+        let followee_id = followee._id.to_hex();
+
+        assert!(db_handler.follow_user(&follower_id, &followee_id).await?);
+        assert!(
+            !db_handler.follow_user(&follower_id, &followee_id).await?,
+            "Follows only once"
+        );
+
+        let profile = db_handler.get_public_profile(&followee_id).await?;
+        assert_eq!(profile.follower_count, 1);
+        assert_eq!(profile.following_count, 0);
+
+        let recipes = (0..3)
+            .map(|i| {
+                let mut recipe = get_random_recipe_db(Some(ObjectId::new()), Some(followee._id));
+                recipe.published_at = Some(DateTime::from_millis(1_000 * i));
+                recipe
+            })
+            .collect::<Vec<_>>();
+        connection
+            .collection::<RecipeMongoDb>("recipes")
+            .insert_many(&recipes)
+            .await?;
+
+        let first_page = db_handler
+            .get_feed(
+                &follower_id,
+                &FeedQuery {
+                    cursor: None,
+                    limit: Some(2),
+                },
+            )
+            .await?;
+        assert_eq!(
+            first_page
+                .items
+                .iter()
+                .map(|item| item.recipe_id.clone())
+                .collect::<Vec<_>>(),
+            vec![recipes[2]._id.to_hex(), recipes[1]._id.to_hex()],
+            "Lists newest first"
+        );
+
+        let second_page = db_handler
+            .get_feed(
+                &follower_id,
+                &FeedQuery {
+                    cursor: first_page.next_cursor,
+                    limit: Some(2),
+                },
+            )
+            .await?;
+        assert_eq!(second_page.items.len(), 1);
+        assert_eq!(second_page.items[0].recipe_id, recipes[0]._id.to_hex());
+        assert_eq!(second_page.next_cursor, None);
+
+        assert!(db_handler.unfollow_user(&follower_id, &followee_id).await?);
+        assert!(db_handler
+            .get_feed(&follower_id, &FeedQuery::default())
+            .await?
+            .items
+            .is_empty());
+
+        db_clean_up().await?;
+
+        Ok(())
+    }
+}
@@ -1,21 +1,40 @@
 use super::mongo_db_handler::MongoDbHandler;
-use anyhow::{anyhow, Result};
-use bson::{doc, oid::ObjectId};
+use crate::{
+    api::{app_error::AppError, messages::Message},
+    model::pagination::{Page, Pagination},
+};
+use anyhow::Result;
+use bson::{doc, oid::ObjectId, Document};
 use futures_util::TryStreamExt;
 use serde::de::DeserializeOwned;
 use std::{convert::Into, marker::Sync};
 
+/// Ids that are no ObjectId can't match any document, so they are rejected as invalid
+pub fn parse_object_id(id: &str) -> Result<ObjectId, AppError> {
+    ObjectId::parse_str(id)
+        .map_err(|_| AppError::Validation(Message::InvalidId { id: &id }.to_string()))
+}
+
 pub trait GenericHandler {
-    async fn get_multiple<T, S>(&self, collection_name: &str) -> Result<Vec<S>>
+    async fn get_multiple<T, S>(&self, collection_name: &str) -> Result<Vec<S>, AppError>
     where
         T: Sync + Send + DeserializeOwned + Into<S> + Clone;
-    async fn get_by_id<T, S>(&self, id: &str, collection_name: &str) -> Result<S>
+    async fn get_by_id<T, S>(&self, id: &str, collection_name: &str) -> Result<S, AppError>
+    where
+        T: Sync + Send + DeserializeOwned + Into<S>;
+    async fn get_multiple_paginated<T, S>(
+        &self,
+        collection_name: &str,
+        filter: Document,
+        sort: Document,
+        pagination: &Pagination,
+    ) -> Result<Page<S>, AppError>
     where
         T: Sync + Send + DeserializeOwned + Into<S>;
 }
 
 impl GenericHandler for MongoDbHandler {
-    async fn get_multiple<T, S>(&self, collection_name: &str) -> Result<Vec<S>>
+    async fn get_multiple<T, S>(&self, collection_name: &str) -> Result<Vec<S>, AppError>
     where
         T: Sync + Send + DeserializeOwned + Into<S> + Clone,
     {
@@ -35,11 +54,11 @@ impl GenericHandler for MongoDbHandler {
         Ok(documents)
     }
 
-    async fn get_by_id<T, S>(&self, id: &str, collection_name: &str) -> Result<S>
+    async fn get_by_id<T, S>(&self, id: &str, collection_name: &str) -> Result<S, AppError>
     where
         T: Sync + Send + DeserializeOwned + Into<S>,
     {
-        let object_id = ObjectId::parse_str(id)?;
+        let object_id = parse_object_id(id)?;
 
         let find_result = self
             .db
@@ -49,11 +68,46 @@ impl GenericHandler for MongoDbHandler {
 
         match find_result {
             Some(document) => Ok(document.into()),
-            None => Err(anyhow!(
-                "Failed to find document in {collection_name} collection with id {id}"
+            None => Err(AppError::NotFound(
+                Message::DocumentNotFound {
+                    collection: &collection_name,
+                    id: &id,
+                }
+                .to_string(),
             )),
         }
     }
+
+    async fn get_multiple_paginated<T, S>(
+        &self,
+        collection_name: &str,
+        filter: Document,
+        sort: Document,
+        pagination: &Pagination,
+    ) -> Result<Page<S>, AppError>
+    where
+        T: Sync + Send + DeserializeOwned + Into<S>,
+    {
+        let collection = self.db.collection::<T>(collection_name);
+
+        let total = collection.count_documents(filter.clone()).await?;
+
+        let cursor = collection
+            .find(filter)
+            .sort(sort)
+            .skip(pagination.skip())
+            .limit(pagination.limit() as i64)
+            .await?;
+
+        let db_documents = cursor.try_collect::<Vec<T>>().await?;
+
+        Ok(Page {
+            items: db_documents.into_iter().map(Into::into).collect(),
+            page: pagination.page.max(1),
+            page_size: pagination.limit(),
+            total,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -66,7 +120,8 @@ pub mod unit_tests_generic_handler {
             get_db_connection, get_random_user_db, print_assert_failed,
         },
     };
-    use anyhow::Result;
+    use anyhow::{anyhow, Result};
+    use axum::http::StatusCode;
     use tokio::test;
 
     #[test]
@@ -132,7 +187,7 @@ pub mod unit_tests_generic_handler {
             title: String,
             test_users: Vec<UserMongoDb>,
             test_id: Option<String>,
-            is_success: bool,
+            expected_status_code: Option<StatusCode>,
         }
 
         let test_cases = vec![
@@ -140,13 +195,19 @@ pub mod unit_tests_generic_handler {
                 title: "Successfully get a user by id".into(),
                 test_users: vec![get_random_user_db(None), get_random_user_db(None)],
                 test_id: None,
-                is_success: true,
+                expected_status_code: None,
             },
             TestCase {
-                title: "Fails to get a user by id with non-existing id".into(),
+                title: "Fails to get a user by id with invalid id".into(),
                 test_users: vec![get_random_user_db(None), get_random_user_db(None)],
                 test_id: Some("non-existent".into()),
-                is_success: false,
+                expected_status_code: Some(StatusCode::BAD_REQUEST),
+            },
+            TestCase {
+                title: "Fails to get a user by id with non-existing id".into(),
+                test_users: vec![get_random_user_db(None), get_random_user_db(None)],
+                test_id: Some(ObjectId::new().to_hex()),
+                expected_status_code: Some(StatusCode::NOT_FOUND),
             },
         ];
 
@@ -177,7 +238,14 @@ pub mod unit_tests_generic_handler {
                 .get_by_id::<UserMongoDb, User>(&id, "users")
                 .await;
 
-            if t.is_success {
+            if let Some(expected_status_code) = t.expected_status_code {
+                assert_eq!(
+                    get_result.err().map(|err| err.status_code()),
+                    Some(expected_status_code),
+                    "{}",
+                    t.title
+                );
+            } else {
                 let got_user = get_result?;
 
                 assert_eq!(
@@ -190,8 +258,6 @@ pub mod unit_tests_generic_handler {
 
                 assert_date_is_current(got_user.created_at, &t.title)?;
                 assert_date_is_current(got_user.modified_at, &t.title)?;
-            } else {
-                assert!(get_result.is_err());
             }
 
             db_clean_up().await?;
@@ -199,4 +265,78 @@ pub mod unit_tests_generic_handler {
 
         Ok(())
     }
+
+    #[test]
+    async fn get_multiple_users_paginated() -> Result<()> {
+        struct TestCase {
+            title: String,
+            pagination: Pagination,
+            expected_emails_idx: Vec<usize>,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Successfully gets first page of users".into(),
+                pagination: Pagination {
+                    page: 1,
+                    page_size: 2,
+                },
+                expected_emails_idx: vec![0, 1],
+            },
+            TestCase {
+                title: "Successfully gets last page of users".into(),
+                pagination: Pagination {
+                    page: 2,
+                    page_size: 2,
+                },
+                expected_emails_idx: vec![2],
+            },
+        ];
+
+        let (db_name, db_user_name, db_user_password, db_host) = get_db_config(Some(".env"))?;
+        let db_handler =
+            MongoDbHandler::new(&db_user_name, &db_user_password, &db_name, &db_host).await?;
+
+        for t in test_cases {
+            let mut test_users = vec![
+                get_random_user_db(None),
+                get_random_user_db(None),
+                get_random_user_db(None),
+            ];
+            test_users.sort_by_key(|user| user.email.clone());
+
+            get_db_connection()
+                .await?
+                .collection::<UserMongoDb>("users")
+                .insert_many(&test_users)
+                .await?;
+
+            let page = db_handler
+                .get_multiple_paginated::<UserMongoDb, User>(
+                    "users",
+                    doc! {},
+                    doc! {"email": 1},
+                    &t.pagination,
+                )
+                .await?;
+
+            assert_eq!(page.total, 3, "{}", t.title);
+            assert_eq!(
+                page.items
+                    .iter()
+                    .map(|u| u.email.clone())
+                    .collect::<Vec<_>>(),
+                t.expected_emails_idx
+                    .iter()
+                    .map(|idx| test_users[*idx].email.clone())
+                    .collect::<Vec<_>>(),
+                "{}",
+                t.title
+            );
+
+            db_clean_up().await?;
+        }
+
+        Ok(())
+    }
 }
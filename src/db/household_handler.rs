@@ -0,0 +1,468 @@
+use super::{
+    generic_handler::parse_object_id,
+    mongo_db_handler::{MongoDbHandler, HOUSEHOLD_MEMBER_INDEX},
+};
+
+use crate::{
+    api::{
+        app_error::{is_duplicate_key_on, AppError},
+        messages::Message,
+    },
+    model::household::{
+        HouseholdCreate, HouseholdMemberMongoDb, HouseholdMongoDb, HouseholdRole, Owner,
+        INVITE_CODE_LENGTH,
+    },
+};
+use anyhow::Result;
+use bson::{doc, oid::ObjectId, to_bson, Bson, DateTime, Document};
+use rand::{distributions::Alphanumeric, Rng};
+
+pub trait HouseholdHandler {
+    async fn create_household(
+        &self,
+        user_id: &str,
+        household: HouseholdCreate,
+    ) -> Result<String, AppError>;
+    async fn get_household_of_user(
+        &self,
+        user_id: &str,
+    ) -> Result<Option<HouseholdMongoDb>, AppError>;
+    async fn get_household_by_invite_code(
+        &self,
+        invite_code: &str,
+    ) -> Result<Option<HouseholdMongoDb>, AppError>;
+    async fn rename_household(&self, id: &str, name: &str) -> Result<u64, AppError>;
+    async fn regenerate_invite_code(&self, id: &str) -> Result<String, AppError>;
+    async fn add_household_member(&self, id: &str, user_id: &str) -> Result<u64, AppError>;
+    async fn set_household_member_role(
+        &self,
+        id: &str,
+        user_id: &str,
+        role: HouseholdRole,
+    ) -> Result<u64, AppError>;
+    async fn remove_household_member(&self, id: &str, user_id: &str) -> Result<u64, AppError>;
+    async fn delete_household(&self, id: &str) -> Result<u64, AppError>;
+    async fn get_owner_member_ids(&self, owner: &Owner) -> Result<Vec<ObjectId>, AppError>;
+}
+
+/// Matches the meal plans and shopping lists of the owner, shared ones if it acts for a household
+pub fn get_owner_filter(owner: &Owner) -> Result<Document, AppError> {
+    match &owner.household_id {
+        Some(household_id) => Ok(doc! {"household_id": parse_object_id(household_id)?}),
+        // Documents from before households existed have no household_id at all, null matches both
+        None => Ok(doc! {"user_id": parse_object_id(&owner.user_id)?, "household_id": Bson::Null}),
+    }
+}
+
+fn generate_invite_code() -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(INVITE_CODE_LENGTH)
+        .map(|c| char::from(c).to_ascii_uppercase())
+        .collect()
+}
+
+// Only the member index tells that the user joined another household in the meantime,
+// other duplicates like colliding invite codes are failures of the server
+fn map_membership_conflict(err: mongodb::error::Error) -> AppError {
+    match is_duplicate_key_on(&err, HOUSEHOLD_MEMBER_INDEX) {
+        true => AppError::Conflict(Message::AlreadyInHousehold.to_string()),
+        false => anyhow::Error::from(err).into(),
+    }
+}
+
+impl HouseholdHandler for MongoDbHandler {
+    async fn create_household(
+        &self,
+        user_id: &str,
+        household: HouseholdCreate,
+    ) -> Result<String, AppError> {
+        let household_db = HouseholdMongoDb {
+            _id: ObjectId::new(),
+            name: household.name.trim().into(),
+            invite_code: generate_invite_code(),
+            members: vec![HouseholdMemberMongoDb {
+                user_id: parse_object_id(user_id)?,
+                role: HouseholdRole::Owner,
+                joined_at: DateTime::now(),
+            }],
+            created_at: DateTime::now(),
+            modified_at: DateTime::now(),
+        };
+
+        self.households_collection
+            .insert_one(&household_db)
+            .await
+            .map_err(map_membership_conflict)?;
+
+        Ok(household_db._id.to_hex())
+    }
+
+    async fn get_household_of_user(
+        &self,
+        user_id: &str,
+    ) -> Result<Option<HouseholdMongoDb>, AppError> {
+        Ok(self
+            .households_collection
+            .find_one(doc! {"members.user_id": parse_object_id(user_id)?})
+            .await?)
+    }
+
+    async fn get_household_by_invite_code(
+        &self,
+        invite_code: &str,
+    ) -> Result<Option<HouseholdMongoDb>, AppError> {
+        Ok(self
+            .households_collection
+            .find_one(doc! {"invite_code": invite_code.trim().to_ascii_uppercase()})
+            .await?)
+    }
+
+    async fn rename_household(&self, id: &str, name: &str) -> Result<u64, AppError> {
+        let update_result = self
+            .households_collection
+            .update_one(
+                doc! {"_id": parse_object_id(id)?},
+                doc! {"$set": {"name": name.trim(), "modified_at": DateTime::now()}},
+            )
+            .await?;
+
+        Ok(update_result.matched_count)
+    }
+
+    async fn regenerate_invite_code(&self, id: &str) -> Result<String, AppError> {
+        let invite_code = generate_invite_code();
+
+        self.households_collection
+            .update_one(
+                doc! {"_id": parse_object_id(id)?},
+                doc! {"$set": {"invite_code": &invite_code, "modified_at": DateTime::now()}},
+            )
+            .await?;
+
+        Ok(invite_code)
+    }
+
+    async fn add_household_member(&self, id: &str, user_id: &str) -> Result<u64, AppError> {
+        let user_object_id = parse_object_id(user_id)?;
+        let member = HouseholdMemberMongoDb {
+            user_id: user_object_id,
+            role: HouseholdRole::Member,
+            joined_at: DateTime::now(),
+        };
+
+        let update_result = self
+            .households_collection
+            .update_one(
+                doc! {
+                    "_id": parse_object_id(id)?,
+                    "members.user_id": { "$ne": user_object_id },
+                },
+                doc! {
+                    "$push": { "members": to_bson(&member)? },
+                    "$set": { "modified_at": DateTime::now() },
+                },
+            )
+            .await
+            .map_err(map_membership_conflict)?;
+
+        Ok(update_result.modified_count)
+    }
+
+    async fn set_household_member_role(
+        &self,
+        id: &str,
+        user_id: &str,
+        role: HouseholdRole,
+    ) -> Result<u64, AppError> {
+        let update_result = self
+            .households_collection
+            .update_one(
+                doc! {
+                    "_id": parse_object_id(id)?,
+                    "members.user_id": parse_object_id(user_id)?,
+                },
+                doc! {"$set": {
+                    "members.$.role": to_bson(&role)?,
+                    "modified_at": DateTime::now(),
+                }},
+            )
+            .await?;
+
+        Ok(update_result.matched_count)
+    }
+
+    async fn remove_household_member(&self, id: &str, user_id: &str) -> Result<u64, AppError> {
+        let update_result = self
+            .households_collection
+            .update_one(
+                doc! {"_id": parse_object_id(id)?},
+                doc! {
+                    "$pull": { "members": { "user_id": parse_object_id(user_id)? } },
+                    "$set": { "modified_at": DateTime::now() },
+                },
+            )
+            .await?;
+
+        Ok(update_result.modified_count)
+    }
+
+    async fn delete_household(&self, id: &str) -> Result<u64, AppError> {
+        let object_id = parse_object_id(id)?;
+
+        let delete_result = self
+            .households_collection
+            .delete_one(doc! {"_id": object_id})
+            .await?;
+        self.meal_plans_collection
+            .delete_many(doc! {"household_id": object_id})
+            .await?;
+        self.shopping_lists_collection
+            .delete_many(doc! {"household_id": object_id})
+            .await?;
+
+        Ok(delete_result.deleted_count)
+    }
+
+    // Shared meal plans and shopping lists may hold the own recipes of every member
+    async fn get_owner_member_ids(&self, owner: &Owner) -> Result<Vec<ObjectId>, AppError> {
+        let user_id = parse_object_id(&owner.user_id)?;
+        let mut member_ids = vec![user_id];
+
+        if let Some(household_id) = &owner.household_id {
+            let household = self
+                .households_collection
+                .find_one(doc! {"_id": parse_object_id(household_id)?})
+                .await?;
+
+            member_ids.extend(
+                household
+                    .iter()
+                    .flat_map(|household| &household.members)
+                    .map(|member| member.user_id)
+                    .filter(|member_id| *member_id != user_id),
+            );
+        }
+
+        Ok(member_ids)
+    }
+}
+
+#[cfg(test)]
+pub mod unit_tests_household_handler {
+    use crate::{
+        db::{
+            meal_plan_handler::MealPlanHandler, shopping_list_handler::ShoppingListHandler,
+            user_handler::UserHandler,
+        },
+        model::{
+            household::HouseholdRole,
+            meal_plan::{DayOfWeek, IsoWeek, MealPlanEntryCreate, MealSlot},
+            recipe::{RecipeMongoDb, RecipeStatus},
+        },
+        test_utils::{db_clean_up, get_db_config, get_db_connection, get_random_recipe_db},
+    };
+
+    use super::*;
+    use anyhow::{anyhow, Result};
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn shares_meal_plans_and_shopping_lists() -> Result<()> {
+        let (db_name, db_user_name, db_user_password, db_host) = get_db_config(Some(".env"))?;
+        let db_handler =
+            MongoDbHandler::new(&db_user_name, &db_user_password, &db_name, &db_host).await?;
+
+        let owner_id = ObjectId::new().to_hex();
+        let member_id = ObjectId::new().to_hex();
+
+        let household_id = db_handler
+            .create_household(
+                &owner_id,
+                HouseholdCreate {
+                    name: "Flat share".into(),
+                },
+            )
+            .await?;
+
+        let household = db_handler
+            .get_household_of_user(&owner_id)
+            .await?
+            .ok_or(anyhow!("Failed to find household of owner"))?;
+        let joined_household = db_handler
+            .get_household_by_invite_code(&household.invite_code.to_lowercase())
+            .await?
+            .ok_or(anyhow!("Failed to find household by invite code"))?;
+        assert_eq!(joined_household._id.to_hex(), household_id);
+
+        assert_eq!(
+            db_handler
+                .add_household_member(&household_id, &member_id)
+                .await?,
+            1
+        );
+        assert_eq!(
+            db_handler
+                .add_household_member(&household_id, &member_id)
+                .await?,
+            0,
+            "Joins only once"
+        );
+
+        let week = "2026-W42".parse::<IsoWeek>()?;
+        let owner = Owner {
+            user_id: owner_id.clone(),
+            household_id: Some(household_id.clone()),
+        };
+        let member = Owner {
+            user_id: member_id.clone(),
+            household_id: Some(household_id.clone()),
+        };
+
+        let draft = RecipeMongoDb {
+            status: RecipeStatus::Draft,
+            ..get_random_recipe_db(None, Some(parse_object_id(&owner_id)?))
+        };
+        get_db_connection()
+            .await?
+            .collection::<RecipeMongoDb>("recipes")
+            .insert_one(&draft)
+            .await?;
+
+        db_handler
+            .add_meal_plan_entry(
+                &owner,
+                &week,
+                MealPlanEntryCreate {
+                    day: DayOfWeek::Monday,
+                    slot: MealSlot::Dinner,
+                    recipe_id: draft._id.to_hex(),
+                    servings: 2,
+                },
+            )
+            .await?;
+        let shared_entries = db_handler.get_meal_plan(&member, &week).await?.entries;
+        assert_eq!(shared_entries.len(), 1, "Shares the meal plan with members");
+        assert_eq!(
+            shared_entries[0].recipe_title,
+            Some(draft.title.clone()),
+            "Resolves drafts of other members"
+        );
+        assert!(
+            db_handler
+                .get_meal_plan(&Owner::user(&owner_id), &week)
+                .await?
+                .entries
+                .is_empty(),
+            "Keeps personal meal plans apart"
+        );
+
+        let list_id = db_handler
+            .create_shopping_list(&member, "Groceries", vec![])
+            .await?;
+        assert!(db_handler
+            .get_shopping_list(&owner, &list_id)
+            .await?
+            .is_some());
+
+        assert_eq!(
+            db_handler
+                .set_household_member_role(&household_id, &member_id, HouseholdRole::Owner)
+                .await?,
+            1
+        );
+        assert_eq!(
+            db_handler
+                .remove_household_member(&household_id, &owner_id)
+                .await?,
+            1
+        );
+        assert!(db_handler.get_household_of_user(&owner_id).await?.is_none());
+
+        assert_eq!(db_handler.delete_household(&household_id).await?, 1);
+        assert!(
+            db_handler
+                .get_shopping_list(&member, &list_id)
+                .await?
+                .is_none(),
+            "Deletes shared shopping lists with the household"
+        );
+
+        db_clean_up().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn hands_over_households_of_deleted_users() -> Result<()> {
+        let (db_name, db_user_name, db_user_password, db_host) = get_db_config(Some(".env"))?;
+        let db_handler =
+            MongoDbHandler::new(&db_user_name, &db_user_password, &db_name, &db_host).await?;
+
+        let owner_id = ObjectId::new().to_hex();
+        let member_id = ObjectId::new().to_hex();
+
+        let household_id = db_handler
+            .create_household(
+                &owner_id,
+                HouseholdCreate {
+                    name: "Flat share".into(),
+                },
+            )
+            .await?;
+        db_handler
+            .add_household_member(&household_id, &member_id)
+            .await?;
+
+        assert!(
+            matches!(
+                db_handler
+                    .create_household(
+                        &member_id,
+                        HouseholdCreate {
+                            name: "Second flat".into(),
+                        },
+                    )
+                    .await,
+                Err(AppError::Conflict(_))
+            ),
+            "Rejects a second household of a member"
+        );
+
+        db_handler.delete_user_by_id(&owner_id).await?;
+        let household = db_handler
+            .get_household_of_user(&member_id)
+            .await?
+            .ok_or(anyhow!("Failed to find household of member"))?;
+        assert_eq!(
+            household.get_role(&parse_object_id(&member_id)?),
+            Some(HouseholdRole::Owner),
+            "Makes the remaining member the owner"
+        );
+
+        let member = Owner {
+            user_id: member_id.clone(),
+            household_id: Some(household_id.clone()),
+        };
+        let list_id = db_handler
+            .create_shopping_list(&member, "Groceries", vec![])
+            .await?;
+
+        db_handler.delete_user_by_id(&member_id).await?;
+        assert!(db_handler
+            .get_household_of_user(&member_id)
+            .await?
+            .is_none());
+        assert!(
+            db_handler
+                .get_shopping_list(&member, &list_id)
+                .await?
+                .is_none(),
+            "Deletes the household of the last member with its shared data"
+        );
+
+        db_clean_up().await?;
+
+        Ok(())
+    }
+}
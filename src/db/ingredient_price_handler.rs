@@ -0,0 +1,233 @@
+use super::{generic_handler::parse_object_id, mongo_db_handler::MongoDbHandler};
+
+use crate::{
+    api::{app_error::AppError, services::pantry_service::names_match},
+    model::ingredient_price::{
+        IngredientPriceCreate, IngredientPriceMongoDb, IngredientPricePatch, IngredientPriceQuery,
+    },
+};
+use anyhow::{anyhow, Result};
+use bson::{doc, oid::ObjectId, Bson, DateTime};
+use futures_util::TryStreamExt;
+
+pub trait IngredientPriceHandler {
+    async fn create_ingredient_price(
+        &self,
+        price: IngredientPriceCreate,
+    ) -> Result<String, AppError>;
+    async fn get_ingredient_prices(
+        &self,
+        query: &IngredientPriceQuery,
+    ) -> Result<Vec<IngredientPriceMongoDb>, AppError>;
+    async fn patch_ingredient_price(
+        &self,
+        id: &str,
+        price_patch: IngredientPricePatch,
+    ) -> Result<u64, AppError>;
+    async fn delete_ingredient_price(&self, id: &str) -> Result<u64, AppError>;
+}
+
+fn normalize_currency(currency: &str) -> String {
+    currency.trim().to_uppercase()
+}
+
+fn normalize_region(region: &str) -> Option<String> {
+    let region = region.trim().to_lowercase();
+
+    (!region.is_empty()).then_some(region)
+}
+
+impl IngredientPriceHandler for MongoDbHandler {
+    async fn create_ingredient_price(
+        &self,
+        price: IngredientPriceCreate,
+    ) -> Result<String, AppError> {
+        let price_db = IngredientPriceMongoDb {
+            _id: ObjectId::new(),
+            ingredient: price.ingredient.trim().into(),
+            unit: price.unit,
+            price: price.price,
+            currency: normalize_currency(&price.currency),
+            region: price.region.as_deref().and_then(normalize_region),
+            created_at: DateTime::now(),
+            modified_at: DateTime::now(),
+        };
+
+        let insert_result = self
+            .ingredient_prices_collection
+            .insert_one(&price_db)
+            .await?;
+
+        match insert_result.inserted_id.as_object_id() {
+            Some(id) => Ok(id.to_hex()),
+            None => Err(anyhow!("Failed to get id of inserted ingredient price").into()),
+        }
+    }
+
+    async fn get_ingredient_prices(
+        &self,
+        query: &IngredientPriceQuery,
+    ) -> Result<Vec<IngredientPriceMongoDb>, AppError> {
+        let mut filter = doc! {};
+
+        if let Some(currency) = &query.currency {
+            filter.insert("currency", normalize_currency(currency));
+        }
+
+        if let Some(region) = query.region.as_deref().and_then(normalize_region) {
+            filter.insert("region", doc! {"$in": [region, Bson::Null]});
+        }
+
+        let cursor = self
+            .ingredient_prices_collection
+            .find(filter)
+            .sort(doc! {"ingredient": 1, "currency": 1, "region": 1})
+            .await?;
+        let prices = cursor.try_collect::<Vec<IngredientPriceMongoDb>>().await?;
+
+        // Like substitutions the price table is curated, so names are matched in memory
+        Ok(match &query.ingredient {
+            Some(ingredient) => prices
+                .into_iter()
+                .filter(|price| names_match(&price.ingredient, ingredient))
+                .collect(),
+            None => prices,
+        })
+    }
+
+    async fn patch_ingredient_price(
+        &self,
+        id: &str,
+        price_patch: IngredientPricePatch,
+    ) -> Result<u64, AppError> {
+        let mut update_doc = doc! {};
+
+        if let Some(ingredient) = price_patch.ingredient {
+            update_doc.insert("ingredient", ingredient.trim());
+        }
+
+        if let Some(unit) = price_patch.unit {
+            update_doc.insert("unit", unit);
+        }
+
+        if let Some(price) = price_patch.price {
+            update_doc.insert("price", price);
+        }
+
+        if let Some(currency) = price_patch.currency {
+            update_doc.insert("currency", normalize_currency(&currency));
+        }
+
+        if let Some(region) = price_patch.region {
+            update_doc.insert("region", normalize_region(&region));
+        }
+
+        update_doc.insert("modified_at", DateTime::now());
+
+        let update_result = self
+            .ingredient_prices_collection
+            .update_one(
+                doc! {"_id": parse_object_id(id)?},
+                doc! {"$set": update_doc},
+            )
+            .await?;
+
+        Ok(update_result.matched_count)
+    }
+
+    async fn delete_ingredient_price(&self, id: &str) -> Result<u64, AppError> {
+        let delete_result = self
+            .ingredient_prices_collection
+            .delete_one(doc! {"_id": parse_object_id(id)?})
+            .await?;
+
+        Ok(delete_result.deleted_count)
+    }
+}
+
+#[cfg(test)]
+pub mod unit_tests_ingredient_price_handler {
+    use crate::test_utils::{db_clean_up, get_db_config};
+
+    use super::*;
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn manages_ingredient_prices() -> Result<()> {
+        let (db_name, db_user_name, db_user_password, db_host) = get_db_config(Some(".env"))?;
+        let db_handler =
+            MongoDbHandler::new(&db_user_name, &db_user_password, &db_name, &db_host).await?;
+
+        let id = db_handler
+            .create_ingredient_price(IngredientPriceCreate {
+                ingredient: "Flour".into(),
+                unit: Some("kg".into()),
+                price: 0.79,
+                currency: "eur".into(),
+                region: None,
+            })
+            .await?;
+        db_handler
+            .create_ingredient_price(IngredientPriceCreate {
+                ingredient: "Flour".into(),
+                unit: Some("kg".into()),
+                price: 0.99,
+                currency: "EUR".into(),
+                region: Some(" AT ".into()),
+            })
+            .await?;
+        db_handler
+            .create_ingredient_price(IngredientPriceCreate {
+                ingredient: "Flour".into(),
+                unit: Some("lb".into()),
+                price: 0.45,
+                currency: "USD".into(),
+                region: Some("us".into()),
+            })
+            .await?;
+
+        let prices = db_handler
+            .get_ingredient_prices(&IngredientPriceQuery {
+                ingredient: Some("flour".into()),
+                currency: Some("EUR".into()),
+                region: Some("at".into()),
+            })
+            .await?;
+        assert_eq!(prices.len(), 2, "Includes prices without region");
+
+        let prices = db_handler
+            .get_ingredient_prices(&IngredientPriceQuery {
+                currency: Some("eur".into()),
+                region: Some("de".into()),
+                ..Default::default()
+            })
+            .await?;
+        assert_eq!(prices.len(), 1);
+        assert_eq!(prices[0].currency, "EUR");
+
+        let matched_count = db_handler
+            .patch_ingredient_price(
+                &id,
+                IngredientPricePatch {
+                    price: Some(0.89),
+                    ..Default::default()
+                },
+            )
+            .await?;
+        assert_eq!(matched_count, 1);
+
+        assert_eq!(db_handler.delete_ingredient_price(&id).await?, 1);
+        assert_eq!(
+            db_handler
+                .get_ingredient_prices(&IngredientPriceQuery::default())
+                .await?
+                .len(),
+            2
+        );
+
+        db_clean_up().await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,289 @@
+use super::{
+    generic_handler::parse_object_id,
+    household_handler::{get_owner_filter, HouseholdHandler},
+    mongo_db_handler::MongoDbHandler,
+    recipe_handler::RecipeHandler,
+};
+
+use crate::{
+    api::app_error::AppError,
+    model::{
+        household::Owner,
+        meal_plan::{
+            build_meal_plan, IsoWeek, MealPlan, MealPlanEntryCreate, MealPlanEntryMongoDb,
+            MealPlanEntryPatch,
+        },
+    },
+};
+use anyhow::Result;
+use bson::{doc, oid::ObjectId, to_bson, DateTime, Document};
+
+pub trait MealPlanHandler {
+    async fn get_meal_plan(&self, owner: &Owner, week: &IsoWeek) -> Result<MealPlan, AppError>;
+    async fn add_meal_plan_entry(
+        &self,
+        owner: &Owner,
+        week: &IsoWeek,
+        entry: MealPlanEntryCreate,
+    ) -> Result<String, AppError>;
+    async fn patch_meal_plan_entry(
+        &self,
+        owner: &Owner,
+        week: &IsoWeek,
+        entry_id: &str,
+        entry_patch: MealPlanEntryPatch,
+    ) -> Result<u64, AppError>;
+    async fn delete_meal_plan_entry(
+        &self,
+        owner: &Owner,
+        week: &IsoWeek,
+        entry_id: &str,
+    ) -> Result<u64, AppError>;
+    async fn copy_meal_plan(
+        &self,
+        owner: &Owner,
+        from_week: &IsoWeek,
+        to_week: &IsoWeek,
+    ) -> Result<usize, AppError>;
+}
+
+// Fields of a new meal plan that the owner filter of an upsert does not set already
+fn get_upsert_fields(owner: &Owner) -> Result<Document, AppError> {
+    let mut fields = doc! {
+        "_id": ObjectId::new(),
+        "created_at": DateTime::now(),
+    };
+
+    if owner.household_id.is_some() {
+        fields.insert("user_id", parse_object_id(&owner.user_id)?);
+    }
+
+    Ok(fields)
+}
+
+impl MealPlanHandler for MongoDbHandler {
+    async fn get_meal_plan(&self, owner: &Owner, week: &IsoWeek) -> Result<MealPlan, AppError> {
+        let mut filter = get_owner_filter(owner)?;
+        filter.insert("week", week.to_string());
+
+        let entries = match self.meal_plans_collection.find_one(filter).await? {
+            Some(meal_plan) => meal_plan.entries,
+            None => vec![],
+        };
+
+        let recipe_ids = entries
+            .iter()
+            .map(|entry| entry.recipe_id)
+            .collect::<Vec<ObjectId>>();
+        let viewer_ids = self.get_owner_member_ids(owner).await?;
+        let recipes = self.get_recipes_by_ids(&recipe_ids, &viewer_ids).await?;
+
+        Ok(build_meal_plan(week, entries, &recipes))
+    }
+
+    async fn add_meal_plan_entry(
+        &self,
+        owner: &Owner,
+        week: &IsoWeek,
+        entry: MealPlanEntryCreate,
+    ) -> Result<String, AppError> {
+        let entry_db = MealPlanEntryMongoDb {
+            _id: ObjectId::new(),
+            day: entry.day,
+            slot: entry.slot,
+            recipe_id: parse_object_id(&entry.recipe_id)?,
+            servings: entry.servings,
+        };
+
+        let mut filter = get_owner_filter(owner)?;
+        filter.insert("week", week.to_string());
+        let update = doc! {
+            "$push": { "entries": to_bson(&entry_db)? },
+            "$set": { "modified_at": DateTime::now() },
+            "$setOnInsert": get_upsert_fields(owner)?,
+        };
+
+        self.meal_plans_collection
+            .update_one(filter, update)
+            .upsert(true)
+            .await?;
+
+        Ok(entry_db._id.to_hex())
+    }
+
+    async fn patch_meal_plan_entry(
+        &self,
+        owner: &Owner,
+        week: &IsoWeek,
+        entry_id: &str,
+        entry_patch: MealPlanEntryPatch,
+    ) -> Result<u64, AppError> {
+        let mut update_doc = doc! {};
+
+        if let Some(day) = entry_patch.day {
+            update_doc.insert("entries.$.day", to_bson(&day)?);
+        }
+
+        if let Some(slot) = entry_patch.slot {
+            update_doc.insert("entries.$.slot", to_bson(&slot)?);
+        }
+
+        if let Some(servings) = entry_patch.servings {
+            update_doc.insert("entries.$.servings", servings);
+        }
+
+        update_doc.insert("modified_at", DateTime::now());
+
+        let mut filter = get_owner_filter(owner)?;
+        filter.insert("week", week.to_string());
+        filter.insert("entries._id", parse_object_id(entry_id)?);
+
+        let update_result = self
+            .meal_plans_collection
+            .update_one(filter, doc! {"$set": update_doc})
+            .await?;
+
+        Ok(update_result.matched_count)
+    }
+
+    async fn delete_meal_plan_entry(
+        &self,
+        owner: &Owner,
+        week: &IsoWeek,
+        entry_id: &str,
+    ) -> Result<u64, AppError> {
+        let mut filter = get_owner_filter(owner)?;
+        filter.insert("week", week.to_string());
+        let update = doc! {
+            "$pull": { "entries": { "_id": parse_object_id(entry_id)? } },
+            "$set": { "modified_at": DateTime::now() },
+        };
+
+        let update_result = self
+            .meal_plans_collection
+            .update_one(filter, update)
+            .await?;
+
+        Ok(update_result.modified_count)
+    }
+
+    async fn copy_meal_plan(
+        &self,
+        owner: &Owner,
+        from_week: &IsoWeek,
+        to_week: &IsoWeek,
+    ) -> Result<usize, AppError> {
+        let mut source_filter = get_owner_filter(owner)?;
+        source_filter.insert("week", from_week.to_string());
+
+        let source = self.meal_plans_collection.find_one(source_filter).await?;
+
+        let entries = source
+            .map(|meal_plan| meal_plan.entries)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|entry| MealPlanEntryMongoDb {
+                _id: ObjectId::new(),
+                ..entry
+            })
+            .collect::<Vec<MealPlanEntryMongoDb>>();
+
+        let mut filter = get_owner_filter(owner)?;
+        filter.insert("week", to_week.to_string());
+        let update = doc! {
+            "$set": {
+                "entries": to_bson(&entries)?,
+                "modified_at": DateTime::now(),
+            },
+            "$setOnInsert": get_upsert_fields(owner)?,
+        };
+
+        self.meal_plans_collection
+            .update_one(filter, update)
+            .upsert(true)
+            .await?;
+
+        Ok(entries.len())
+    }
+}
+
+#[cfg(test)]
+pub mod unit_tests_meal_plan_handler {
+    use crate::{
+        model::{
+            meal_plan::{DayOfWeek, MealSlot},
+            recipe::{Nutrition, RecipeMongoDb},
+        },
+        test_utils::{db_clean_up, get_db_config, get_db_connection, get_random_recipe_db},
+    };
+
+    use super::*;
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn plans_and_copies_week() -> Result<()> {
+        let (db_name, db_user_name, db_user_password, db_host) = get_db_config(Some(".env"))?;
+        let db_handler =
+            MongoDbHandler::new(&db_user_name, &db_user_password, &db_name, &db_host).await?;
+
+        let mut recipe_db = get_random_recipe_db(None, None);
+        recipe_db.nutrition = Some(Nutrition {
+            calories: 300.0,
+            ..Default::default()
+        });
+        get_db_connection()
+            .await?
+            .collection::<RecipeMongoDb>("recipes")
+            .insert_one(&recipe_db)
+            .await?;
+
+        let owner = Owner::user(&ObjectId::new().to_hex());
+        let previous_week: IsoWeek = "2026-W41".parse()?;
+        let week: IsoWeek = "2026-W42".parse()?;
+
+        let entry_id = db_handler
+            .add_meal_plan_entry(
+                &owner,
+                &previous_week,
+                MealPlanEntryCreate {
+                    day: DayOfWeek::Friday,
+                    slot: MealSlot::Dinner,
+                    recipe_id: recipe_db._id.to_hex(),
+                    servings: 1,
+                },
+            )
+            .await?;
+        db_handler
+            .patch_meal_plan_entry(
+                &owner,
+                &previous_week,
+                &entry_id,
+                MealPlanEntryPatch {
+                    servings: Some(3),
+                    ..Default::default()
+                },
+            )
+            .await?;
+
+        let copied_count = db_handler
+            .copy_meal_plan(&owner, &previous_week, &week)
+            .await?;
+        assert_eq!(copied_count, 1, "Copies all entries");
+
+        let meal_plan = db_handler.get_meal_plan(&owner, &week).await?;
+        assert_eq!(meal_plan.entries.len(), 1);
+        assert_eq!(meal_plan.entries[0].servings, 3);
+        assert_ne!(meal_plan.entries[0].id, entry_id, "Creates new entry ids");
+        assert_eq!(meal_plan.daily_nutrition[4].nutrition.calories, 900.0);
+
+        let delete_count = db_handler
+            .delete_meal_plan_entry(&owner, &previous_week, &entry_id)
+            .await?;
+        assert_eq!(delete_count, 1);
+
+        db_clean_up().await?;
+
+        Ok(())
+    }
+}
@@ -1,3 +1,17 @@
+pub mod comment_handler;
+pub mod favorite_handler;
+pub mod follow_handler;
 pub mod generic_handler;
+pub mod household_handler;
+pub mod ingredient_price_handler;
+pub mod meal_plan_handler;
 pub mod mongo_db_handler;
+pub mod notification_handler;
+pub mod pantry_handler;
+pub mod recipe_handler;
+pub mod recipe_revision_handler;
+pub mod report_handler;
+pub mod review_handler;
+pub mod shopping_list_handler;
+pub mod substitution_handler;
 pub mod user_handler;
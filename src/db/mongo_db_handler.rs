@@ -1,13 +1,53 @@
-use crate::model::user::UserMongoDb;
+use crate::model::{
+    comment::CommentMongoDb,
+    favorite::FavoritesMongoDb,
+    follow::FollowMongoDb,
+    household::HouseholdMongoDb,
+    ingredient_price::IngredientPriceMongoDb,
+    meal_plan::MealPlanMongoDb,
+    notification::NotificationMongoDb,
+    pantry::PantryMongoDb,
+    recipe::RecipeMongoDb,
+    recipe_revision::RecipeRevisionMongoDb,
+    report::{ModerationLogMongoDb, ReportMongoDb},
+    review::ReviewMongoDb,
+    shopping_list::ShoppingListMongoDb,
+    substitution::SubstitutionMongoDb,
+    user::UserMongoDb,
+};
 use anyhow::Result;
 
 use bson::doc;
-use mongodb::{options::ClientOptions, Client, Collection, Database};
+use mongodb::{
+    options::{ClientOptions, IndexOptions},
+    Client, Collection, Database, IndexModel,
+};
 use tracing::info;
 
+/// Name of the unique index keeping emails of users apart
+pub const USER_EMAIL_INDEX: &str = "email_1";
+
+/// Name of the unique index keeping users in at most one household
+pub const HOUSEHOLD_MEMBER_INDEX: &str = "members.user_id_1";
+
 #[derive(Clone)]
 pub struct MongoDbHandler {
     pub users_collection: Collection<UserMongoDb>,
+    pub recipes_collection: Collection<RecipeMongoDb>,
+    pub recipe_revisions_collection: Collection<RecipeRevisionMongoDb>,
+    pub reviews_collection: Collection<ReviewMongoDb>,
+    pub comments_collection: Collection<CommentMongoDb>,
+    pub favorites_collection: Collection<FavoritesMongoDb>,
+    pub meal_plans_collection: Collection<MealPlanMongoDb>,
+    pub shopping_lists_collection: Collection<ShoppingListMongoDb>,
+    pub pantries_collection: Collection<PantryMongoDb>,
+    pub substitutions_collection: Collection<SubstitutionMongoDb>,
+    pub reports_collection: Collection<ReportMongoDb>,
+    pub moderation_log_collection: Collection<ModerationLogMongoDb>,
+    pub follows_collection: Collection<FollowMongoDb>,
+    pub notifications_collection: Collection<NotificationMongoDb>,
+    pub households_collection: Collection<HouseholdMongoDb>,
+    pub ingredient_prices_collection: Collection<IngredientPriceMongoDb>,
     pub db: Database,
 }
 
@@ -27,14 +67,162 @@ impl MongoDbHandler {
             .await?;
         info!("Connected to {connect_info}");
 
-        let db = client.database(db_name);
+        let db_handler = Self::from_database(client.database(db_name));
+
+        db_handler.create_indexes().await?;
+
+        Ok(db_handler)
+    }
+
+    /// Collections of the database, without connecting or creating indexes
+    pub fn from_database(db: Database) -> Self {
         let users_collection = db.collection("users");
+        let recipes_collection = db.collection("recipes");
+        let recipe_revisions_collection = db.collection("recipe_revisions");
+        let reviews_collection = db.collection("reviews");
+        let comments_collection = db.collection("comments");
+        let favorites_collection = db.collection("favorites");
+        let meal_plans_collection = db.collection("meal_plans");
+        let shopping_lists_collection = db.collection("shopping_lists");
+        let pantries_collection = db.collection("pantries");
+        let substitutions_collection = db.collection("substitutions");
+        let reports_collection = db.collection("reports");
+        let moderation_log_collection = db.collection("moderation_log");
+        let follows_collection = db.collection("follows");
+        let notifications_collection = db.collection("notifications");
+        let households_collection = db.collection("households");
+        let ingredient_prices_collection = db.collection("ingredient_prices");
 
-        let db_handler = MongoDbHandler {
+        MongoDbHandler {
             users_collection,
+            recipes_collection,
+            recipe_revisions_collection,
+            reviews_collection,
+            comments_collection,
+            favorites_collection,
+            meal_plans_collection,
+            shopping_lists_collection,
+            pantries_collection,
+            substitutions_collection,
+            reports_collection,
+            moderation_log_collection,
+            follows_collection,
+            notifications_collection,
+            households_collection,
+            ingredient_prices_collection,
             db,
-        };
+        }
+    }
 
-        Ok(db_handler)
+    async fn create_indexes(&self) -> Result<()> {
+        let unique_user_email_index = IndexModel::builder()
+            .keys(doc! { "email": 1 })
+            .options(
+                IndexOptions::builder()
+                    .name(String::from(USER_EMAIL_INDEX))
+                    .unique(true)
+                    .build(),
+            )
+            .build();
+
+        self.users_collection
+            .create_index(unique_user_email_index)
+            .await?;
+
+        let unique_review_index = IndexModel::builder()
+            .keys(doc! { "recipe_id": 1, "user_id": 1 })
+            .options(IndexOptions::builder().unique(true).build())
+            .build();
+
+        self.reviews_collection
+            .create_index(unique_review_index)
+            .await?;
+
+        let unique_recipe_revision_index = IndexModel::builder()
+            .keys(doc! { "recipe_id": 1, "revision": 1 })
+            .options(IndexOptions::builder().unique(true).build())
+            .build();
+
+        self.recipe_revisions_collection
+            .create_index(unique_recipe_revision_index)
+            .await?;
+
+        let unique_favorites_index = IndexModel::builder()
+            .keys(doc! { "user_id": 1 })
+            .options(IndexOptions::builder().unique(true).build())
+            .build();
+
+        self.favorites_collection
+            .create_index(unique_favorites_index)
+            .await?;
+
+        // Members keep their personal meal plans next to the household ones
+        let unique_meal_plan_index = IndexModel::builder()
+            .keys(doc! { "user_id": 1, "household_id": 1, "week": 1 })
+            .options(IndexOptions::builder().unique(true).build())
+            .build();
+
+        self.meal_plans_collection
+            .create_index(unique_meal_plan_index)
+            .await?;
+
+        let unique_household_meal_plan_index = IndexModel::builder()
+            .keys(doc! { "household_id": 1, "week": 1 })
+            .options(
+                IndexOptions::builder()
+                    .unique(true)
+                    .partial_filter_expression(doc! { "household_id": { "$type": "objectId" } })
+                    .build(),
+            )
+            .build();
+
+        self.meal_plans_collection
+            .create_index(unique_household_meal_plan_index)
+            .await?;
+
+        let unique_pantry_index = IndexModel::builder()
+            .keys(doc! { "user_id": 1 })
+            .options(IndexOptions::builder().unique(true).build())
+            .build();
+
+        self.pantries_collection
+            .create_index(unique_pantry_index)
+            .await?;
+
+        let unique_follow_index = IndexModel::builder()
+            .keys(doc! { "follower_id": 1, "followee_id": 1 })
+            .options(IndexOptions::builder().unique(true).build())
+            .build();
+
+        self.follows_collection
+            .create_index(unique_follow_index)
+            .await?;
+
+        let unique_invite_code_index = IndexModel::builder()
+            .keys(doc! { "invite_code": 1 })
+            .options(IndexOptions::builder().unique(true).build())
+            .build();
+
+        self.households_collection
+            .create_index(unique_invite_code_index)
+            .await?;
+
+        // Users belong to at most one household, also when joins race each other
+        let unique_household_member_index = IndexModel::builder()
+            .keys(doc! { "members.user_id": 1 })
+            .options(
+                IndexOptions::builder()
+                    .name(String::from(HOUSEHOLD_MEMBER_INDEX))
+                    .unique(true)
+                    .partial_filter_expression(doc! { "members.user_id": { "$exists": true } })
+                    .build(),
+            )
+            .build();
+
+        self.households_collection
+            .create_index(unique_household_member_index)
+            .await?;
+
+        Ok(())
     }
 }
@@ -0,0 +1,301 @@
+use super::{
+    generic_handler::{parse_object_id, GenericHandler},
+    mongo_db_handler::MongoDbHandler,
+};
+
+use crate::{
+    api::{app_error::AppError, messages::Message},
+    model::{
+        notification::{Notification, NotificationCreate, NotificationMongoDb, NotificationQuery},
+        pagination::{Page, Pagination},
+    },
+};
+use anyhow::Result;
+use bson::{doc, oid::ObjectId, DateTime};
+use futures_util::TryStreamExt;
+
+pub trait NotificationHandler {
+    async fn create_notification(
+        &self,
+        user_id: &str,
+        notification: NotificationCreate,
+    ) -> Result<Option<NotificationMongoDb>, AppError>;
+    async fn get_notifications(
+        &self,
+        user_id: &str,
+        query: &NotificationQuery,
+        pagination: &Pagination,
+    ) -> Result<Page<Notification>, AppError>;
+    async fn mark_notification_read(&self, user_id: &str, id: &str) -> Result<u64, AppError>;
+    async fn mark_all_notifications_read(&self, user_id: &str) -> Result<u64, AppError>;
+    async fn get_pending_email_notifications(&self) -> Result<Vec<NotificationMongoDb>, AppError>;
+    async fn mark_notifications_emailed(&self, ids: &[ObjectId]) -> Result<u64, AppError>;
+}
+
+impl NotificationHandler for MongoDbHandler {
+    async fn create_notification(
+        &self,
+        user_id: &str,
+        notification: NotificationCreate,
+    ) -> Result<Option<NotificationMongoDb>, AppError> {
+        let user_object_id = parse_object_id(user_id)?;
+
+        let user = self
+            .users_collection
+            .find_one(doc! {"_id": user_object_id})
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(
+                    Message::DocumentNotFound {
+                        collection: &"users",
+                        id: &user_id,
+                    }
+                    .to_string(),
+                )
+            })?;
+
+        let setting = user.preferences.notifications.get(notification.kind);
+        if !setting.in_app && !setting.email_digest {
+            return Ok(None);
+        }
+
+        let notification_db = NotificationMongoDb {
+            _id: ObjectId::new(),
+            user_id: user_object_id,
+            kind: notification.kind,
+            actor_id: notification
+                .actor_id
+                .as_deref()
+                .map(parse_object_id)
+                .transpose()?,
+            recipe_id: notification
+                .recipe_id
+                .as_deref()
+                .map(parse_object_id)
+                .transpose()?,
+            report_id: notification
+                .report_id
+                .as_deref()
+                .map(parse_object_id)
+                .transpose()?,
+            is_in_app: setting.in_app,
+            is_read: false,
+            is_email_pending: setting.email_digest,
+            created_at: DateTime::now(),
+        };
+
+        self.notifications_collection
+            .insert_one(&notification_db)
+            .await?;
+
+        Ok(Some(notification_db))
+    }
+
+    async fn get_notifications(
+        &self,
+        user_id: &str,
+        query: &NotificationQuery,
+        pagination: &Pagination,
+    ) -> Result<Page<Notification>, AppError> {
+        let mut filter = doc! {"user_id": parse_object_id(user_id)?, "is_in_app": true};
+
+        if query.unread_only {
+            filter.insert("is_read", false);
+        }
+
+        self.get_multiple_paginated::<NotificationMongoDb, Notification>(
+            "notifications",
+            filter,
+            doc! {"created_at": -1},
+            pagination,
+        )
+        .await
+    }
+
+    async fn mark_notification_read(&self, user_id: &str, id: &str) -> Result<u64, AppError> {
+        let update_result = self
+            .notifications_collection
+            .update_one(
+                doc! {
+                    "_id": parse_object_id(id)?,
+                    "user_id": parse_object_id(user_id)?,
+                    "is_in_app": true,
+                },
+                doc! {"$set": {"is_read": true}},
+            )
+            .await?;
+
+        Ok(update_result.matched_count)
+    }
+
+    async fn mark_all_notifications_read(&self, user_id: &str) -> Result<u64, AppError> {
+        let update_result = self
+            .notifications_collection
+            .update_many(
+                doc! {
+                    "user_id": parse_object_id(user_id)?,
+                    "is_in_app": true,
+                    "is_read": false,
+                },
+                doc! {"$set": {"is_read": true}},
+            )
+            .await?;
+
+        Ok(update_result.modified_count)
+    }
+
+    async fn get_pending_email_notifications(&self) -> Result<Vec<NotificationMongoDb>, AppError> {
+        let cursor = self
+            .notifications_collection
+            .find(doc! {"is_email_pending": true})
+            .sort(doc! {"user_id": 1, "created_at": 1})
+            .await?;
+
+        Ok(cursor.try_collect().await?)
+    }
+
+    async fn mark_notifications_emailed(&self, ids: &[ObjectId]) -> Result<u64, AppError> {
+        let update_result = self
+            .notifications_collection
+            .update_many(
+                doc! {"_id": {"$in": ids}},
+                doc! {"$set": {"is_email_pending": false}},
+            )
+            .await?;
+
+        Ok(update_result.modified_count)
+    }
+}
+
+#[cfg(test)]
+pub mod unit_tests_notification_handler {
+    use crate::{
+        api::services::{mail_service::Mailer, notification_service::send_email_digests},
+        model::{
+            notification::{NotificationKind, NotificationSetting},
+            user::UserMongoDb,
+        },
+        test_utils::{db_clean_up, get_db_config, get_db_connection, get_random_user_db},
+    };
+
+    use super::*;
+    use anyhow::{anyhow, Result};
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn respects_notification_preferences() -> Result<()> {
+        let (db_name, db_user_name, db_user_password, db_host) = get_db_config(Some(".env"))?;
+        let db_handler =
+            MongoDbHandler::new(&db_user_name, &db_user_password, &db_name, &db_host).await?;
+
+        let mut user = get_random_user_db(None);
+        user.preferences.notifications.new_follower = NotificationSetting {
+            in_app: false,
+            email_digest: true,
+        };
+        user.preferences.notifications.recipe_forked = NotificationSetting {
+            in_app: false,
+            email_digest: false,
+        };
+        get_db_connection()
+            .await?
+            .collection::<UserMongoDb>("users")
+            .insert_one(&user)
+            .await?;
+
+        let user_id = user._id.to_hex();
+        let notify = |kind| NotificationCreate {
+            kind,
+            actor_id: Some(ObjectId::new().to_hex()),
+            recipe_id: None,
+            report_id: None,
+        };
+
+        assert!(db_handler
+            .create_notification(&user_id, notify(NotificationKind::ReviewCreated))
+            .await?
+            .is_some());
+        assert!(db_handler
+            .create_notification(&user_id, notify(NotificationKind::NewFollower))
+            .await?
+            .is_some());
+        assert!(
+            db_handler
+                .create_notification(&user_id, notify(NotificationKind::RecipeForked))
+                .await?
+                .is_none(),
+            "Skips disabled kinds"
+        );
+
+        let unread_query = NotificationQuery { unread_only: true };
+        let notifications = db_handler
+            .get_notifications(&user_id, &unread_query, &Pagination::default())
+            .await?;
+        assert_eq!(notifications.total, 1, "Lists only in-app notifications");
+        assert_eq!(notifications.items[0].kind, NotificationKind::ReviewCreated);
+
+        let pending = db_handler.get_pending_email_notifications().await?;
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].kind, NotificationKind::NewFollower);
+
+        assert_eq!(db_handler.mark_all_notifications_read(&user_id).await?, 1);
+        assert_eq!(
+            db_handler
+                .get_notifications(&user_id, &unread_query, &Pagination::default())
+                .await?
+                .total,
+            0
+        );
+
+        db_clean_up().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn keeps_email_notifications_pending_without_transport() -> Result<()> {
+        let (db_name, db_user_name, db_user_password, db_host) = get_db_config(Some(".env"))?;
+        let db_handler =
+            MongoDbHandler::new(&db_user_name, &db_user_password, &db_name, &db_host).await?;
+
+        let mut user = get_random_user_db(None);
+        user.preferences.notifications.new_follower = NotificationSetting {
+            in_app: false,
+            email_digest: true,
+        };
+        get_db_connection()
+            .await?
+            .collection::<UserMongoDb>("users")
+            .insert_one(&user)
+            .await?;
+
+        let notification = db_handler
+            .create_notification(
+                &user._id.to_hex(),
+                NotificationCreate {
+                    kind: NotificationKind::NewFollower,
+                    actor_id: Some(ObjectId::new().to_hex()),
+                    recipe_id: None,
+                    report_id: None,
+                },
+            )
+            .await?
+            .ok_or(anyhow!("Notification not created"))?;
+
+        let sent_count =
+            send_email_digests(&db_handler, &Mailer::new("noreply@example.com")).await?;
+        assert_eq!(sent_count, 0);
+
+        let pending_ids = db_handler
+            .get_pending_email_notifications()
+            .await?
+            .into_iter()
+            .map(|notification| notification._id)
+            .collect::<Vec<_>>();
+        assert!(pending_ids.contains(&notification._id));
+
+        db_clean_up().await?;
+
+        Ok(())
+    }
+}
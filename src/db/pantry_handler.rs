@@ -0,0 +1,253 @@
+use super::{
+    generic_handler::parse_object_id, mongo_db_handler::MongoDbHandler,
+    recipe_handler::get_visibility_filter,
+};
+
+use crate::{
+    api::app_error::AppError,
+    model::{
+        pantry::{parse_expiry_date, PantryItemCreate, PantryItemMongoDb, PantryItemPatch},
+        recipe::RecipeMongoDb,
+    },
+};
+use anyhow::Result;
+use bson::{doc, oid::ObjectId, to_bson, Bson, DateTime};
+use futures_util::TryStreamExt;
+
+pub trait PantryHandler {
+    async fn get_pantry_items(&self, user_id: &str) -> Result<Vec<PantryItemMongoDb>, AppError>;
+    async fn add_pantry_item(
+        &self,
+        user_id: &str,
+        item: PantryItemCreate,
+    ) -> Result<String, AppError>;
+    async fn patch_pantry_item(
+        &self,
+        user_id: &str,
+        item_id: &str,
+        item_patch: PantryItemPatch,
+    ) -> Result<u64, AppError>;
+    async fn delete_pantry_item(&self, user_id: &str, item_id: &str) -> Result<u64, AppError>;
+    async fn get_matchable_recipes(&self, user_id: &str) -> Result<Vec<RecipeMongoDb>, AppError>;
+}
+
+impl PantryHandler for MongoDbHandler {
+    async fn get_pantry_items(&self, user_id: &str) -> Result<Vec<PantryItemMongoDb>, AppError> {
+        let pantry = self
+            .pantries_collection
+            .find_one(doc! {"user_id": parse_object_id(user_id)?})
+            .await?;
+
+        let mut items = pantry.map(|p| p.items).unwrap_or_default();
+        items.sort_by_key(|item| item.name.to_lowercase());
+
+        Ok(items)
+    }
+
+    async fn add_pantry_item(
+        &self,
+        user_id: &str,
+        item: PantryItemCreate,
+    ) -> Result<String, AppError> {
+        let item_db = PantryItemMongoDb {
+            _id: ObjectId::new(),
+            name: item.name,
+            quantity: item.quantity,
+            unit: item.unit,
+            expires_at: item
+                .expires_on
+                .as_deref()
+                .map(parse_expiry_date)
+                .transpose()?,
+        };
+
+        let update = doc! {
+            "$push": { "items": to_bson(&item_db)? },
+            "$set": { "modified_at": DateTime::now() },
+            "$setOnInsert": {
+                "_id": ObjectId::new(),
+                "created_at": DateTime::now(),
+            },
+        };
+
+        self.pantries_collection
+            .update_one(doc! {"user_id": parse_object_id(user_id)?}, update)
+            .upsert(true)
+            .await?;
+
+        Ok(item_db._id.to_hex())
+    }
+
+    async fn patch_pantry_item(
+        &self,
+        user_id: &str,
+        item_id: &str,
+        item_patch: PantryItemPatch,
+    ) -> Result<u64, AppError> {
+        let mut update_doc = doc! {};
+
+        if let Some(name) = item_patch.name {
+            update_doc.insert("items.$.name", name);
+        }
+
+        if let Some(quantity) = item_patch.quantity {
+            update_doc.insert("items.$.quantity", quantity);
+        }
+
+        if let Some(unit) = item_patch.unit {
+            update_doc.insert("items.$.unit", unit);
+        }
+
+        if let Some(expires_on) = item_patch.expires_on {
+            // An empty date removes the expiry date
+            let expires_at = match expires_on.is_empty() {
+                true => Bson::Null,
+                false => Bson::DateTime(parse_expiry_date(&expires_on)?),
+            };
+            update_doc.insert("items.$.expires_at", expires_at);
+        }
+
+        update_doc.insert("modified_at", DateTime::now());
+
+        let filter = doc! {
+            "user_id": parse_object_id(user_id)?,
+            "items._id": parse_object_id(item_id)?,
+        };
+
+        let update_result = self
+            .pantries_collection
+            .update_one(filter, doc! {"$set": update_doc})
+            .await?;
+
+        Ok(update_result.matched_count)
+    }
+
+    async fn delete_pantry_item(&self, user_id: &str, item_id: &str) -> Result<u64, AppError> {
+        let update = doc! {
+            "$pull": { "items": { "_id": parse_object_id(item_id)? } },
+            "$set": { "modified_at": DateTime::now() },
+        };
+
+        let update_result = self
+            .pantries_collection
+            .update_one(doc! {"user_id": parse_object_id(user_id)?}, update)
+            .await?;
+
+        Ok(update_result.modified_count)
+    }
+
+    // Only listed recipes and the user's own are suggested
+    async fn get_matchable_recipes(&self, user_id: &str) -> Result<Vec<RecipeMongoDb>, AppError> {
+        let cursor = self
+            .recipes_collection
+            .find(get_visibility_filter(Some(user_id), false)?)
+            .await?;
+
+        Ok(cursor.try_collect().await?)
+    }
+}
+
+#[cfg(test)]
+pub mod unit_tests_pantry_handler {
+    use crate::{
+        model::recipe::RecipeStatus,
+        test_utils::{db_clean_up, get_db_config, get_random_recipe_db},
+    };
+
+    use super::*;
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn manages_pantry_items() -> Result<()> {
+        let (db_name, db_user_name, db_user_password, db_host) = get_db_config(Some(".env"))?;
+        let db_handler =
+            MongoDbHandler::new(&db_user_name, &db_user_password, &db_name, &db_host).await?;
+
+        let user_id = ObjectId::new().to_hex();
+
+        let item_id = db_handler
+            .add_pantry_item(
+                &user_id,
+                PantryItemCreate {
+                    name: "Spinach".into(),
+                    quantity: Some(300.0),
+                    unit: Some("g".into()),
+                    expires_on: Some("2026-10-20".into()),
+                },
+            )
+            .await?;
+
+        let matched_count = db_handler
+            .patch_pantry_item(
+                &user_id,
+                &item_id,
+                PantryItemPatch {
+                    quantity: Some(150.0),
+                    expires_on: Some("".into()),
+                    ..Default::default()
+                },
+            )
+            .await?;
+        assert_eq!(matched_count, 1);
+
+        let items = db_handler.get_pantry_items(&user_id).await?;
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].quantity, Some(150.0));
+        assert!(items[0].expires_at.is_none(), "Removes expiry date");
+
+        let delete_count = db_handler.delete_pantry_item(&user_id, &item_id).await?;
+        assert_eq!(delete_count, 1);
+        assert!(db_handler.get_pantry_items(&user_id).await?.is_empty());
+
+        db_clean_up().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn matches_only_visible_recipes() -> Result<()> {
+        let (db_name, db_user_name, db_user_password, db_host) = get_db_config(Some(".env"))?;
+        let db_handler =
+            MongoDbHandler::new(&db_user_name, &db_user_password, &db_name, &db_host).await?;
+
+        let user_id = ObjectId::new();
+        let published_recipe = get_random_recipe_db(None, None);
+        let own_draft = RecipeMongoDb {
+            status: RecipeStatus::Draft,
+            ..get_random_recipe_db(None, Some(user_id))
+        };
+        let other_draft = RecipeMongoDb {
+            status: RecipeStatus::Draft,
+            ..get_random_recipe_db(None, None)
+        };
+        let unlisted_recipe = RecipeMongoDb {
+            status: RecipeStatus::Unlisted,
+            ..get_random_recipe_db(None, None)
+        };
+        db_handler
+            .recipes_collection
+            .insert_many([
+                &published_recipe,
+                &own_draft,
+                &other_draft,
+                &unlisted_recipe,
+            ])
+            .await?;
+
+        let mut recipe_ids = db_handler
+            .get_matchable_recipes(&user_id.to_hex())
+            .await?
+            .into_iter()
+            .map(|recipe| recipe._id)
+            .collect::<Vec<_>>();
+        recipe_ids.sort();
+        let mut expected_ids = vec![published_recipe._id, own_draft._id];
+        expected_ids.sort();
+        assert_eq!(recipe_ids, expected_ids);
+
+        db_clean_up().await?;
+
+        Ok(())
+    }
+}
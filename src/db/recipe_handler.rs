@@ -0,0 +1,882 @@
+use super::{
+    generic_handler::{parse_object_id, GenericHandler},
+    mongo_db_handler::MongoDbHandler,
+};
+
+use crate::{
+    api::{
+        app_error::AppError,
+        messages::Message,
+        services::{locale_service::normalize_locale, token_service::Claims},
+    },
+    model::{
+        pagination::{Page, Pagination},
+        recipe::{
+            Recipe, RecipeAttributionMongoDb, RecipeCreate, RecipeMongoDb, RecipePatch,
+            RecipeQuery, RecipeSort, RecipeStatus, RecipeTranslation,
+        },
+        user::Role,
+    },
+};
+use anyhow::{anyhow, Result};
+use bson::{doc, oid::ObjectId, to_bson, Bson, DateTime, Document};
+use futures_util::TryStreamExt;
+use mongodb::Cursor;
+use std::collections::BTreeMap;
+
+pub trait RecipeHandler {
+    async fn create_recipe(
+        &self,
+        author_id: &str,
+        recipe: RecipeCreate,
+    ) -> Result<String, AppError>;
+    async fn get_recipes(
+        &self,
+        query: &RecipeQuery,
+        viewer_id: Option<&str>,
+        pagination: &Pagination,
+    ) -> Result<Page<Recipe>, AppError>;
+    async fn get_recipe_candidates(
+        &self,
+        query: &RecipeQuery,
+        viewer_id: Option<&str>,
+    ) -> Result<Cursor<RecipeMongoDb>, AppError>;
+    async fn get_recipes_by_ids(
+        &self,
+        ids: &[ObjectId],
+        viewer_ids: &[ObjectId],
+    ) -> Result<Vec<Recipe>, AppError>;
+    async fn get_visible_recipe(
+        &self,
+        id: &str,
+        claims: Option<&Claims>,
+    ) -> Result<Recipe, AppError>;
+    async fn fork_recipe(&self, id: &str, author_id: &str) -> Result<String, AppError>;
+    async fn get_forks(&self, id: &str) -> Result<Vec<Recipe>, AppError>;
+    async fn patch_recipe_by_id(&self, id: &str, recipe_patch: RecipePatch)
+        -> Result<(), AppError>;
+    async fn set_recipe_translation(
+        &self,
+        id: &str,
+        locale: &str,
+        translation: RecipeTranslation,
+    ) -> Result<u64, AppError>;
+    async fn delete_recipe_translation(&self, id: &str, locale: &str) -> Result<u64, AppError>;
+    async fn publish_recipe(&self, id: &str) -> Result<u64, AppError>;
+    async fn unpublish_recipe(&self, id: &str) -> Result<u64, AppError>;
+    async fn delete_recipe_by_id(&self, id: &str) -> Result<u64, AppError>;
+}
+
+/// Matches the recipes a viewer can read, unlisted ones only if they are linked directly
+pub fn get_visibility_filter(
+    viewer_id: Option<&str>,
+    include_unlisted: bool,
+) -> Result<Document, AppError> {
+    let viewer_id = viewer_id.map(parse_object_id).transpose()?;
+
+    get_shared_visibility_filter(viewer_id.as_slice(), include_unlisted)
+}
+
+/// Matches the recipes any of the viewers can read, e.g. the members of a household
+pub fn get_shared_visibility_filter(
+    viewer_ids: &[ObjectId],
+    include_unlisted: bool,
+) -> Result<Document, AppError> {
+    // Recipes created before the lifecycle have no status and are published
+    let mut statuses = vec![to_bson(&RecipeStatus::Published)?, Bson::Null];
+    if include_unlisted {
+        statuses.push(to_bson(&RecipeStatus::Unlisted)?);
+    }
+
+    let status_filter = doc! { "status": { "$in": statuses } };
+
+    Ok(match viewer_ids.is_empty() {
+        true => status_filter,
+        false => doc! {
+            "$or": [status_filter, { "author_id": { "$in": viewer_ids } }],
+        },
+    })
+}
+
+fn get_recipes_sort(query: &RecipeQuery) -> Document {
+    match query.sort {
+        RecipeSort::Newest => doc! { "created_at": -1 },
+        RecipeSort::Rating => doc! { "rating_average": -1, "rating_count": -1 },
+    }
+}
+
+// Costs and seasons are computed from the ingredients later, so only recipes that can't
+// match at all are left out here
+fn get_recipes_filter(query: &RecipeQuery, viewer_id: Option<&str>) -> Result<Document, AppError> {
+    let mut filter = get_visibility_filter(viewer_id, false)?;
+
+    if query.max_cost_per_serving.is_some() {
+        filter.insert(
+            "ingredients",
+            doc! { "$elemMatch": { "quantity": { "$ne": Bson::Null } } },
+        );
+    } else if query.in_season == Some(true) {
+        filter.insert("ingredients.0", doc! { "$exists": true });
+    }
+
+    Ok(filter)
+}
+
+// Only listed forks count, the same ones get_forks lists
+async fn update_fork_count(
+    db_handler: &MongoDbHandler,
+    original_id: ObjectId,
+) -> Result<(), AppError> {
+    let mut filter = doc! {"forked_from": original_id};
+    filter.extend(get_visibility_filter(None, false)?);
+
+    let fork_count = db_handler
+        .recipes_collection
+        .count_documents(filter)
+        .await?;
+
+    db_handler
+        .recipes_collection
+        .update_one(
+            doc! {"_id": original_id},
+            doc! {"$set": {"fork_count": fork_count as i64}},
+        )
+        .await?;
+
+    Ok(())
+}
+
+// Called after the status of a recipe changed, which is only relevant for forks
+async fn update_fork_count_of_original(
+    db_handler: &MongoDbHandler,
+    id: ObjectId,
+) -> Result<(), AppError> {
+    let forked_from = db_handler
+        .recipes_collection
+        .find_one(doc! {"_id": id})
+        .await?
+        .and_then(|recipe| recipe.forked_from);
+
+    match forked_from {
+        Some(original_id) => update_fork_count(db_handler, original_id).await,
+        None => Ok(()),
+    }
+}
+
+impl RecipeHandler for MongoDbHandler {
+    async fn create_recipe(
+        &self,
+        author_id: &str,
+        recipe: RecipeCreate,
+    ) -> Result<String, AppError> {
+        let recipe_db = RecipeMongoDb {
+            _id: ObjectId::new(),
+            title: recipe.title,
+            description: recipe.description,
+            ingredients: recipe.ingredients,
+            steps: recipe.steps,
+            servings: recipe.servings,
+            prep_time_minutes: recipe.prep_time_minutes,
+            cook_time_minutes: recipe.cook_time_minutes,
+            nutrition: recipe.nutrition,
+            author_id: parse_object_id(author_id)?,
+            status: recipe.status,
+            published_at: match recipe.status {
+                RecipeStatus::Published => Some(DateTime::now()),
+                _ => None,
+            },
+            forked_from: None,
+            attribution: vec![],
+            fork_count: 0,
+            rating_average: 0.0,
+            rating_count: 0,
+            favorite_count: 0,
+            locale: recipe.locale.as_deref().map(normalize_locale),
+            translations: BTreeMap::new(),
+            created_at: DateTime::now(),
+            modified_at: DateTime::now(),
+        };
+
+        let insert_result = self.recipes_collection.insert_one(&recipe_db).await?;
+
+        match insert_result.inserted_id {
+            Bson::ObjectId(object_id) => Ok(object_id.to_hex()),
+            _ => Err(anyhow!(
+                "Failed to convert inserted Id to string, {}",
+                insert_result.inserted_id
+            )
+            .into()),
+        }
+    }
+
+    async fn get_recipes(
+        &self,
+        query: &RecipeQuery,
+        viewer_id: Option<&str>,
+        pagination: &Pagination,
+    ) -> Result<Page<Recipe>, AppError> {
+        self.get_multiple_paginated::<RecipeMongoDb, Recipe>(
+            "recipes",
+            get_recipes_filter(query, viewer_id)?,
+            get_recipes_sort(query),
+            pagination,
+        )
+        .await
+    }
+
+    async fn get_recipe_candidates(
+        &self,
+        query: &RecipeQuery,
+        viewer_id: Option<&str>,
+    ) -> Result<Cursor<RecipeMongoDb>, AppError> {
+        Ok(self
+            .recipes_collection
+            .find(get_recipes_filter(query, viewer_id)?)
+            .sort(get_recipes_sort(query))
+            .await?)
+    }
+
+    async fn get_recipes_by_ids(
+        &self,
+        ids: &[ObjectId],
+        viewer_ids: &[ObjectId],
+    ) -> Result<Vec<Recipe>, AppError> {
+        let mut filter = doc! {"_id": { "$in": ids }};
+        filter.extend(get_shared_visibility_filter(viewer_ids, true)?);
+
+        let cursor = self.recipes_collection.find(filter).await?;
+        let recipes_db = cursor.try_collect::<Vec<RecipeMongoDb>>().await?;
+
+        Ok(recipes_db.into_iter().map(Into::into).collect())
+    }
+
+    async fn get_visible_recipe(
+        &self,
+        id: &str,
+        claims: Option<&Claims>,
+    ) -> Result<Recipe, AppError> {
+        let recipe = self
+            .get_by_id::<RecipeMongoDb, Recipe>(id, "recipes")
+            .await?;
+
+        let is_visible = recipe.status.is_public()
+            || claims.is_some_and(|claims| {
+                recipe.author_id == claims.user_id || claims.role == Role::Admin
+            });
+
+        // Hidden recipes are indistinguishable from missing ones
+        if !is_visible {
+            return Err(AppError::NotFound(
+                Message::FindRecipeFailed { recipe_id: &id }.to_string(),
+            ));
+        }
+
+        Ok(recipe)
+    }
+
+    async fn fork_recipe(&self, id: &str, author_id: &str) -> Result<String, AppError> {
+        let object_id = parse_object_id(id)?;
+
+        let original = self
+            .recipes_collection
+            .find_one(doc! {"_id": object_id})
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(Message::FindRecipeFailed { recipe_id: &id }.to_string())
+            })?;
+
+        let mut attribution = original.attribution;
+        attribution.push(RecipeAttributionMongoDb {
+            recipe_id: original._id,
+            author_id: original.author_id,
+            title: original.title.clone(),
+        });
+
+        // Forks start as drafts so the new author can make it their own before publishing
+        let fork_db = RecipeMongoDb {
+            _id: ObjectId::new(),
+            title: original.title,
+            description: original.description,
+            ingredients: original.ingredients,
+            steps: original.steps,
+            servings: original.servings,
+            prep_time_minutes: original.prep_time_minutes,
+            cook_time_minutes: original.cook_time_minutes,
+            nutrition: original.nutrition,
+            author_id: parse_object_id(author_id)?,
+            status: RecipeStatus::Draft,
+            published_at: None,
+            forked_from: Some(object_id),
+            attribution,
+            fork_count: 0,
+            rating_average: 0.0,
+            rating_count: 0,
+            favorite_count: 0,
+            locale: original.locale,
+            translations: original.translations,
+            created_at: DateTime::now(),
+            modified_at: DateTime::now(),
+        };
+
+        // Drafts are not counted as forks until they are published
+        self.recipes_collection.insert_one(&fork_db).await?;
+
+        Ok(fork_db._id.to_hex())
+    }
+
+    async fn get_forks(&self, id: &str) -> Result<Vec<Recipe>, AppError> {
+        let mut filter = doc! {"forked_from": parse_object_id(id)?};
+        filter.extend(get_visibility_filter(None, false)?);
+
+        let cursor = self
+            .recipes_collection
+            .find(filter)
+            .sort(doc! {"created_at": -1})
+            .await?;
+        let recipes_db = cursor.try_collect::<Vec<RecipeMongoDb>>().await?;
+
+        Ok(recipes_db.into_iter().map(Into::into).collect())
+    }
+
+    async fn patch_recipe_by_id(
+        &self,
+        id: &str,
+        recipe_patch: RecipePatch,
+    ) -> Result<(), AppError> {
+        let mut update_doc = doc! {};
+
+        if let Some(title) = recipe_patch.title {
+            update_doc.insert("title", title);
+        }
+
+        if let Some(description) = recipe_patch.description {
+            update_doc.insert("description", description);
+        }
+
+        if let Some(ingredients) = recipe_patch.ingredients {
+            update_doc.insert("ingredients", to_bson(&ingredients)?);
+        }
+
+        if let Some(steps) = recipe_patch.steps {
+            update_doc.insert("steps", steps);
+        }
+
+        if let Some(servings) = recipe_patch.servings {
+            update_doc.insert("servings", servings);
+        }
+
+        if let Some(prep_time_minutes) = recipe_patch.prep_time_minutes {
+            update_doc.insert("prep_time_minutes", prep_time_minutes);
+        }
+
+        if let Some(cook_time_minutes) = recipe_patch.cook_time_minutes {
+            update_doc.insert("cook_time_minutes", cook_time_minutes);
+        }
+
+        if let Some(nutrition) = recipe_patch.nutrition {
+            update_doc.insert("nutrition", to_bson(&nutrition)?);
+        }
+
+        let is_status_patched = recipe_patch.status.is_some();
+        if let Some(status) = recipe_patch.status {
+            update_doc.insert("status", to_bson(&status)?);
+        }
+
+        if let Some(locale) = recipe_patch.locale {
+            update_doc.insert("locale", normalize_locale(&locale));
+        }
+
+        let object_id = parse_object_id(id)?;
+
+        if update_doc.is_empty() {
+            return Ok(());
+        }
+
+        update_doc.insert("modified_at", DateTime::now());
+
+        let filter = doc! {"_id": object_id};
+        let update = doc! { "$set": update_doc };
+
+        self.recipes_collection.update_one(filter, update).await?;
+
+        if is_status_patched {
+            update_fork_count_of_original(self, object_id).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn set_recipe_translation(
+        &self,
+        id: &str,
+        locale: &str,
+        translation: RecipeTranslation,
+    ) -> Result<u64, AppError> {
+        let update_result = self
+            .recipes_collection
+            .update_one(
+                doc! {"_id": parse_object_id(id)?},
+                doc! {"$set": {
+                    format!("translations.{}", normalize_locale(locale)): to_bson(&translation)?,
+                    "modified_at": DateTime::now(),
+                }},
+            )
+            .await?;
+
+        Ok(update_result.matched_count)
+    }
+
+    async fn delete_recipe_translation(&self, id: &str, locale: &str) -> Result<u64, AppError> {
+        let translation_key = format!("translations.{}", normalize_locale(locale));
+
+        let update_result = self
+            .recipes_collection
+            .update_one(
+                doc! {
+                    "_id": parse_object_id(id)?,
+                    &translation_key: {"$exists": true},
+                },
+                doc! {"$unset": {&translation_key: ""}},
+            )
+            .await?;
+
+        Ok(update_result.matched_count)
+    }
+
+    async fn publish_recipe(&self, id: &str) -> Result<u64, AppError> {
+        let object_id = parse_object_id(id)?;
+
+        let update_result = self
+            .recipes_collection
+            .update_one(
+                doc! {"_id": object_id},
+                doc! {"$set": {
+                    "status": to_bson(&RecipeStatus::Published)?,
+                    "published_at": DateTime::now(),
+                    "modified_at": DateTime::now(),
+                }},
+            )
+            .await?;
+
+        update_fork_count_of_original(self, object_id).await?;
+
+        Ok(update_result.matched_count)
+    }
+
+    async fn unpublish_recipe(&self, id: &str) -> Result<u64, AppError> {
+        let object_id = parse_object_id(id)?;
+
+        let update_result = self
+            .recipes_collection
+            .update_one(
+                doc! {"_id": object_id},
+                doc! {
+                    "$set": {
+                        "status": to_bson(&RecipeStatus::Draft)?,
+                        "modified_at": DateTime::now(),
+                    },
+                    "$unset": { "published_at": "" },
+                },
+            )
+            .await?;
+
+        update_fork_count_of_original(self, object_id).await?;
+
+        Ok(update_result.matched_count)
+    }
+
+    async fn delete_recipe_by_id(&self, id: &str) -> Result<u64, AppError> {
+        let object_id = parse_object_id(id)?;
+        let filter = doc! {"_id": object_id};
+
+        let Some(deleted) = self.recipes_collection.find_one_and_delete(filter).await? else {
+            return Ok(0);
+        };
+
+        if let Some(forked_from) = deleted.forked_from {
+            update_fork_count(self, forked_from).await?;
+        }
+
+        self.reviews_collection
+            .delete_many(doc! {"recipe_id": object_id})
+            .await?;
+        self.recipe_revisions_collection
+            .delete_many(doc! {"recipe_id": object_id})
+            .await?;
+        self.comments_collection
+            .delete_many(doc! {"recipe_id": object_id})
+            .await?;
+        self.favorites_collection
+            .update_many(
+                doc! {},
+                doc! {
+                    "$pull": {
+                        "recipe_ids": object_id,
+                        "collections.$[].recipe_ids": object_id,
+                    }
+                },
+            )
+            .await?;
+
+        Ok(1)
+    }
+}
+
+#[cfg(test)]
+pub mod unit_tests_recipe_handler {
+    use crate::test_utils::{
+        assert_date_is_current, db_clean_up, get_db_config, get_db_connection,
+        get_random_recipe_db, print_assert_failed,
+    };
+
+    use super::*;
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn create_recipe() -> Result<()> {
+        struct TestCase {
+            title: String,
+            author_id: String,
+            is_success: bool,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Successfully creates recipe".into(),
+                author_id: ObjectId::new().to_hex(),
+                is_success: true,
+            },
+            TestCase {
+                title: "Fails to create recipe with invalid author id".into(),
+                author_id: "invalidId".into(),
+                is_success: false,
+            },
+        ];
+
+        let (db_name, db_user_name, db_user_password, db_host) = get_db_config(Some(".env"))?;
+        let db_handler =
+            MongoDbHandler::new(&db_user_name, &db_user_password, &db_name, &db_host).await?;
+
+        for t in test_cases {
+            let recipe_create = RecipeCreate {
+                title: "Chili sin carne".into(),
+                description: "Hearty and spicy".into(),
+                ingredients: vec![],
+                steps: vec!["Cook everything".into()],
+                servings: 4,
+                prep_time_minutes: Some(15),
+                cook_time_minutes: Some(45),
+                nutrition: None,
+                status: RecipeStatus::Published,
+                locale: None,
+            };
+
+            let insert_result = db_handler.create_recipe(&t.author_id, recipe_create).await;
+
+            assert_eq!(
+                insert_result.is_ok(),
+                t.is_success,
+                "{}",
+                print_assert_failed(
+                    &t.title,
+                    &format!("{:?}", t.is_success),
+                    &format!("{:?}", insert_result)
+                )
+            );
+
+            if t.is_success {
+                let recipe = db_handler
+                    .get_by_id::<RecipeMongoDb, Recipe>(&insert_result?, "recipes")
+                    .await?;
+
+                assert_eq!(recipe.author_id, t.author_id, "{}", t.title);
+                assert_eq!(recipe.rating_count, 0, "{}", t.title);
+                assert_date_is_current(recipe.created_at, &t.title)?;
+            }
+
+            db_clean_up().await?;
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_recipes() -> Result<()> {
+        struct TestCase {
+            title: String,
+            sort: RecipeSort,
+            expected_first_rating: f64,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Sorts recipes by rating".into(),
+                sort: RecipeSort::Rating,
+                expected_first_rating: 4.5,
+            },
+            TestCase {
+                title: "Sorts recipes by newest".into(),
+                sort: RecipeSort::Newest,
+                expected_first_rating: 2.0,
+            },
+        ];
+
+        let (db_name, db_user_name, db_user_password, db_host) = get_db_config(Some(".env"))?;
+        let db_handler =
+            MongoDbHandler::new(&db_user_name, &db_user_password, &db_name, &db_host).await?;
+
+        for t in test_cases {
+            let mut top_rated = get_random_recipe_db(None, None);
+            top_rated.rating_average = 4.5;
+            top_rated.created_at = DateTime::from_millis(0);
+
+            let mut newest = get_random_recipe_db(None, None);
+            newest.rating_average = 2.0;
+
+            get_db_connection()
+                .await?
+                .collection::<RecipeMongoDb>("recipes")
+                .insert_many(vec![top_rated, newest])
+                .await?;
+
+            let recipes = db_handler
+                .get_recipes(
+                    &RecipeQuery {
+                        sort: t.sort,
+                        ..Default::default()
+                    },
+                    None,
+                    &Pagination::default(),
+                )
+                .await?
+                .items;
+
+            assert_eq!(recipes.len(), 2, "{}", t.title);
+            assert_eq!(
+                recipes[0].rating_average, t.expected_first_rating,
+                "{}",
+                t.title
+            );
+
+            db_clean_up().await?;
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn patch_recipe_by_id() -> Result<()> {
+        let (db_name, db_user_name, db_user_password, db_host) = get_db_config(Some(".env"))?;
+        let db_handler =
+            MongoDbHandler::new(&db_user_name, &db_user_password, &db_name, &db_host).await?;
+
+        let recipe_db = get_random_recipe_db(None, None);
+        get_db_connection()
+            .await?
+            .collection::<RecipeMongoDb>("recipes")
+            .insert_one(&recipe_db)
+            .await?;
+
+        let id = recipe_db._id.to_hex();
+        db_handler
+            .patch_recipe_by_id(
+                &id,
+                RecipePatch {
+                    title: Some("Updated title".into()),
+                    ..Default::default()
+                },
+            )
+            .await?;
+
+        let recipe = db_handler
+            .get_by_id::<RecipeMongoDb, Recipe>(&id, "recipes")
+            .await?;
+
+        assert_eq!(recipe.title, "Updated title");
+        assert_eq!(recipe.description, recipe_db.description);
+
+        db_clean_up().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn manages_recipe_translations() -> Result<()> {
+        let (db_name, db_user_name, db_user_password, db_host) = get_db_config(Some(".env"))?;
+        let db_handler =
+            MongoDbHandler::new(&db_user_name, &db_user_password, &db_name, &db_host).await?;
+
+        let recipe_db = get_random_recipe_db(None, None);
+        get_db_connection()
+            .await?
+            .collection::<RecipeMongoDb>("recipes")
+            .insert_one(&recipe_db)
+            .await?;
+
+        let id = recipe_db._id.to_hex();
+        let translation = RecipeTranslation {
+            title: Some("Linsensuppe".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            db_handler
+                .set_recipe_translation(&id, "de_DE", translation.clone())
+                .await?,
+            1
+        );
+
+        let recipe = db_handler
+            .get_by_id::<RecipeMongoDb, Recipe>(&id, "recipes")
+            .await?;
+        assert_eq!(recipe.available_locales, vec!["en", "de-de"]);
+        assert_eq!(recipe.translations.get("de-de"), Some(&translation));
+
+        assert_eq!(db_handler.delete_recipe_translation(&id, "de-DE").await?, 1);
+        assert_eq!(
+            db_handler.delete_recipe_translation(&id, "de-DE").await?,
+            0,
+            "Finds no translation to delete twice"
+        );
+
+        db_clean_up().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn enforces_recipe_visibility() -> Result<()> {
+        let (db_name, db_user_name, db_user_password, db_host) = get_db_config(Some(".env"))?;
+        let db_handler =
+            MongoDbHandler::new(&db_user_name, &db_user_password, &db_name, &db_host).await?;
+
+        let author_id = ObjectId::new();
+        let published = get_random_recipe_db(None, Some(author_id));
+        let mut unlisted = get_random_recipe_db(None, Some(author_id));
+        unlisted.status = RecipeStatus::Unlisted;
+        let mut draft = get_random_recipe_db(None, Some(author_id));
+        draft.status = RecipeStatus::Draft;
+
+        get_db_connection()
+            .await?
+            .collection::<RecipeMongoDb>("recipes")
+            .insert_many(vec![&published, &unlisted, &draft])
+            .await?;
+
+        let anonymous_recipes = db_handler
+            .get_recipes(&RecipeQuery::default(), None, &Pagination::default())
+            .await?
+            .items;
+        assert_eq!(anonymous_recipes.len(), 1, "Lists only published recipes");
+
+        let author_recipes = db_handler
+            .get_recipes(
+                &RecipeQuery::default(),
+                Some(&author_id.to_hex()),
+                &Pagination::default(),
+            )
+            .await?
+            .items;
+        assert_eq!(author_recipes.len(), 3, "Lists all recipes of the author");
+
+        let linked_recipes = db_handler
+            .get_recipes_by_ids(&[unlisted._id, draft._id], &[])
+            .await?;
+        assert_eq!(linked_recipes.len(), 1, "Reads unlisted recipes by id");
+
+        let author_claims = Claims {
+            sub: "author@example.com".into(),
+            user_id: author_id.to_hex(),
+            role: Role::User,
+            exp: 0,
+        };
+        let draft_id = draft._id.to_hex();
+        assert!(db_handler
+            .get_visible_recipe(&draft_id, None)
+            .await
+            .is_err());
+        assert!(db_handler
+            .get_visible_recipe(&draft_id, Some(&author_claims))
+            .await
+            .is_ok());
+
+        assert_eq!(db_handler.publish_recipe(&draft_id).await?, 1);
+        let recipe = db_handler.get_visible_recipe(&draft_id, None).await?;
+        assert_eq!(recipe.status, RecipeStatus::Published);
+        assert!(recipe.published_at.is_some());
+
+        db_clean_up().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn forks_recipe_with_attribution() -> Result<()> {
+        let (db_name, db_user_name, db_user_password, db_host) = get_db_config(Some(".env"))?;
+        let db_handler =
+            MongoDbHandler::new(&db_user_name, &db_user_password, &db_name, &db_host).await?;
+
+        let original = get_random_recipe_db(Some(ObjectId::new()), Some(ObjectId::new()));
+        get_db_connection()
+            .await?
+            .collection::<RecipeMongoDb>("recipes")
+            .insert_one(&original)
+            .await?;
+
+        let original_id = original._id.to_hex();
+        let fork_author_id = ObjectId::new().to_hex();
+        let fork_id = db_handler
+            .fork_recipe(&original_id, &fork_author_id)
+            .await?;
+
+        let fork = db_handler
+            .get_by_id::<RecipeMongoDb, Recipe>(&fork_id, "recipes")
+            .await?;
+        assert_eq!(fork.author_id, fork_author_id);
+        assert_eq!(fork.status, RecipeStatus::Draft);
+        assert_eq!(fork.forked_from, Some(original_id.clone()));
+        assert_eq!(fork.ingredients, original.ingredients);
+
+        let remix_id = db_handler
+            .fork_recipe(&fork_id, &ObjectId::new().to_hex())
+            .await?;
+        let remix = db_handler
+            .get_by_id::<RecipeMongoDb, Recipe>(&remix_id, "recipes")
+            .await?;
+        assert_eq!(
+            remix
+                .attribution
+                .iter()
+                .map(|a| a.recipe_id.clone())
+                .collect::<Vec<_>>(),
+            vec![original_id.clone(), fork_id.clone()],
+            "Keeps the attribution chain, the original first"
+        );
+
+        let recipe = db_handler
+            .get_by_id::<RecipeMongoDb, Recipe>(&original_id, "recipes")
+            .await?;
+        assert_eq!(recipe.fork_count, 0, "Does not count drafts");
+        assert!(
+            db_handler.get_forks(&original_id).await?.is_empty(),
+            "Lists only public forks"
+        );
+
+        db_handler.publish_recipe(&fork_id).await?;
+        assert_eq!(db_handler.get_forks(&original_id).await?.len(), 1);
+        let recipe = db_handler
+            .get_by_id::<RecipeMongoDb, Recipe>(&original_id, "recipes")
+            .await?;
+        assert_eq!(recipe.fork_count, 1, "Counts published forks");
+
+        db_handler.delete_recipe_by_id(&fork_id).await?;
+        let recipe = db_handler
+            .get_by_id::<RecipeMongoDb, Recipe>(&original_id, "recipes")
+            .await?;
+        assert_eq!(recipe.fork_count, 0, "Stops counting deleted forks");
+
+        db_clean_up().await?;
+
+        Ok(())
+    }
+}
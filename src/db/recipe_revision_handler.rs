@@ -0,0 +1,203 @@
+use super::{generic_handler::parse_object_id, mongo_db_handler::MongoDbHandler};
+
+use crate::{
+    api::app_error::AppError,
+    model::recipe_revision::{RecipeRevisionMongoDb, RecipeSnapshot},
+};
+use anyhow::Result;
+use bson::{doc, oid::ObjectId, to_bson, DateTime};
+use futures_util::TryStreamExt;
+
+pub trait RecipeRevisionHandler {
+    async fn create_recipe_revision(
+        &self,
+        recipe_id: &str,
+        author_id: &str,
+        snapshot: RecipeSnapshot,
+    ) -> Result<u32, AppError>;
+    async fn get_recipe_revisions(
+        &self,
+        recipe_id: &str,
+    ) -> Result<Vec<RecipeRevisionMongoDb>, AppError>;
+    async fn get_recipe_revision(
+        &self,
+        recipe_id: &str,
+        revision: u32,
+    ) -> Result<Option<RecipeRevisionMongoDb>, AppError>;
+    async fn restore_recipe_snapshot(
+        &self,
+        recipe_id: &str,
+        snapshot: RecipeSnapshot,
+    ) -> Result<u64, AppError>;
+}
+
+impl RecipeRevisionHandler for MongoDbHandler {
+    async fn create_recipe_revision(
+        &self,
+        recipe_id: &str,
+        author_id: &str,
+        snapshot: RecipeSnapshot,
+    ) -> Result<u32, AppError> {
+        let recipe_object_id = parse_object_id(recipe_id)?;
+
+        let latest_revision = self
+            .recipe_revisions_collection
+            .find_one(doc! {"recipe_id": recipe_object_id})
+            .sort(doc! {"revision": -1})
+            .await?
+            .map(|revision| revision.revision)
+            .unwrap_or(0);
+
+        let revision_db = RecipeRevisionMongoDb {
+            _id: ObjectId::new(),
+            recipe_id: recipe_object_id,
+            revision: latest_revision + 1,
+            author_id: parse_object_id(author_id)?,
+            snapshot,
+            created_at: DateTime::now(),
+        };
+
+        // The unique index rejects concurrent patches claiming the same revision
+        self.recipe_revisions_collection
+            .insert_one(&revision_db)
+            .await?;
+
+        Ok(revision_db.revision)
+    }
+
+    async fn get_recipe_revisions(
+        &self,
+        recipe_id: &str,
+    ) -> Result<Vec<RecipeRevisionMongoDb>, AppError> {
+        let cursor = self
+            .recipe_revisions_collection
+            .find(doc! {"recipe_id": parse_object_id(recipe_id)?})
+            .sort(doc! {"revision": -1})
+            .await?;
+
+        Ok(cursor.try_collect().await?)
+    }
+
+    async fn get_recipe_revision(
+        &self,
+        recipe_id: &str,
+        revision: u32,
+    ) -> Result<Option<RecipeRevisionMongoDb>, AppError> {
+        Ok(self
+            .recipe_revisions_collection
+            .find_one(doc! {
+                "recipe_id": parse_object_id(recipe_id)?,
+                "revision": revision,
+            })
+            .await?)
+    }
+
+    async fn restore_recipe_snapshot(
+        &self,
+        recipe_id: &str,
+        snapshot: RecipeSnapshot,
+    ) -> Result<u64, AppError> {
+        // Unlike a patch, a restore also clears optional fields missing from the snapshot
+        let update_doc = doc! {
+            "title": snapshot.title,
+            "description": snapshot.description,
+            "ingredients": to_bson(&snapshot.ingredients)?,
+            "steps": snapshot.steps,
+            "servings": snapshot.servings,
+            "prep_time_minutes": snapshot.prep_time_minutes,
+            "cook_time_minutes": snapshot.cook_time_minutes,
+            "nutrition": to_bson(&snapshot.nutrition)?,
+            "modified_at": DateTime::now(),
+        };
+
+        let update_result = self
+            .recipes_collection
+            .update_one(
+                doc! {"_id": parse_object_id(recipe_id)?},
+                doc! {"$set": update_doc},
+            )
+            .await?;
+
+        Ok(update_result.matched_count)
+    }
+}
+
+#[cfg(test)]
+pub mod unit_tests_recipe_revision_handler {
+    use crate::{
+        db::{generic_handler::GenericHandler, recipe_handler::RecipeHandler},
+        model::recipe::{Recipe, RecipeMongoDb, RecipePatch},
+        test_utils::{db_clean_up, get_db_config, get_db_connection, get_random_recipe_db},
+    };
+
+    use super::*;
+    use anyhow::{anyhow, Result};
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn manages_recipe_revisions() -> Result<()> {
+        let (db_name, db_user_name, db_user_password, db_host) = get_db_config(Some(".env"))?;
+        let db_handler =
+            MongoDbHandler::new(&db_user_name, &db_user_password, &db_name, &db_host).await?;
+
+        let mut recipe_db = get_random_recipe_db(None, None);
+        recipe_db.prep_time_minutes = None;
+        get_db_connection()
+            .await?
+            .collection::<RecipeMongoDb>("recipes")
+            .insert_one(&recipe_db)
+            .await?;
+
+        let recipe_id = recipe_db._id.to_hex();
+        let author_id = recipe_db.author_id.to_hex();
+        let original: Recipe = recipe_db.into();
+
+        let revision = db_handler
+            .create_recipe_revision(&recipe_id, &author_id, (&original).into())
+            .await?;
+        assert_eq!(revision, 1);
+
+        db_handler
+            .patch_recipe_by_id(
+                &recipe_id,
+                RecipePatch {
+                    title: Some("Updated title".into()),
+                    prep_time_minutes: Some(20),
+                    ..Default::default()
+                },
+            )
+            .await?;
+
+        let revision = db_handler
+            .create_recipe_revision(&recipe_id, &author_id, (&original).into())
+            .await?;
+        assert_eq!(revision, 2);
+
+        let revisions = db_handler.get_recipe_revisions(&recipe_id).await?;
+        assert_eq!(
+            revisions.iter().map(|r| r.revision).collect::<Vec<_>>(),
+            vec![2, 1]
+        );
+
+        let revision = db_handler
+            .get_recipe_revision(&recipe_id, 1)
+            .await?
+            .ok_or(anyhow!("Revision 1 is missing"))?;
+        assert_eq!(
+            db_handler
+                .restore_recipe_snapshot(&recipe_id, revision.snapshot)
+                .await?,
+            1
+        );
+
+        let restored = db_handler
+            .get_by_id::<RecipeMongoDb, Recipe>(&recipe_id, "recipes")
+            .await?;
+        assert_eq!(restored.title, original.title);
+        assert_eq!(restored.prep_time_minutes, None);
+
+        db_clean_up().await?;
+
+        Ok(())
+    }
+}
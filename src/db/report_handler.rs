@@ -0,0 +1,223 @@
+use super::{
+    generic_handler::{parse_object_id, GenericHandler},
+    mongo_db_handler::MongoDbHandler,
+};
+
+use crate::{
+    api::app_error::AppError,
+    model::{
+        pagination::{Page, Pagination},
+        report::{
+            ModerationLogEntry, ModerationLogMongoDb, Report, ReportCreate, ReportMongoDb,
+            ReportQuery, ReportResolution, ReportStatus,
+        },
+    },
+};
+use anyhow::Result;
+use bson::{doc, oid::ObjectId, to_bson, DateTime};
+
+pub trait ReportHandler {
+    async fn create_report(
+        &self,
+        reporter_id: &str,
+        report: ReportCreate,
+    ) -> Result<String, AppError>;
+    async fn get_reports(
+        &self,
+        query: &ReportQuery,
+        pagination: &Pagination,
+    ) -> Result<Page<Report>, AppError>;
+    async fn resolve_report(
+        &self,
+        report: &ReportMongoDb,
+        moderator_id: &str,
+        resolution: ReportResolution,
+    ) -> Result<u64, AppError>;
+    async fn get_moderation_log(
+        &self,
+        pagination: &Pagination,
+    ) -> Result<Page<ModerationLogEntry>, AppError>;
+}
+
+impl ReportHandler for MongoDbHandler {
+    async fn create_report(
+        &self,
+        reporter_id: &str,
+        report: ReportCreate,
+    ) -> Result<String, AppError> {
+        let report_db = ReportMongoDb {
+            _id: ObjectId::new(),
+            target_type: report.target_type,
+            target_id: parse_object_id(&report.target_id)?,
+            reporter_id: parse_object_id(reporter_id)?,
+            reason: report.reason.trim().into(),
+            status: ReportStatus::Open,
+            action: None,
+            resolved_by: None,
+            resolved_at: None,
+            created_at: DateTime::now(),
+        };
+
+        self.reports_collection.insert_one(&report_db).await?;
+
+        Ok(report_db._id.to_hex())
+    }
+
+    async fn get_reports(
+        &self,
+        query: &ReportQuery,
+        pagination: &Pagination,
+    ) -> Result<Page<Report>, AppError> {
+        let mut filter = doc! {};
+
+        if let Some(status) = query.status {
+            filter.insert("status", to_bson(&status)?);
+        }
+
+        if let Some(target_type) = query.target_type {
+            filter.insert("target_type", to_bson(&target_type)?);
+        }
+
+        if let Some(reporter_id) = &query.reporter_id {
+            filter.insert("reporter_id", parse_object_id(reporter_id)?);
+        }
+
+        self.get_multiple_paginated::<ReportMongoDb, Report>(
+            "reports",
+            filter,
+            doc! {"created_at": 1},
+            pagination,
+        )
+        .await
+    }
+
+    async fn resolve_report(
+        &self,
+        report: &ReportMongoDb,
+        moderator_id: &str,
+        resolution: ReportResolution,
+    ) -> Result<u64, AppError> {
+        let moderator_object_id = parse_object_id(moderator_id)?;
+
+        // Only open reports match, so an action is never applied twice
+        let update_result = self
+            .reports_collection
+            .update_one(
+                doc! {"_id": report._id, "status": to_bson(&ReportStatus::Open)?},
+                doc! {"$set": {
+                    "status": to_bson(&ReportStatus::Resolved)?,
+                    "action": to_bson(&resolution.action)?,
+                    "resolved_by": moderator_object_id,
+                    "resolved_at": DateTime::now(),
+                }},
+            )
+            .await?;
+
+        if update_result.matched_count == 0 {
+            return Ok(0);
+        }
+
+        let log_db = ModerationLogMongoDb {
+            _id: ObjectId::new(),
+            moderator_id: moderator_object_id,
+            report_id: report._id,
+            action: resolution.action,
+            target_type: report.target_type,
+            target_id: report.target_id,
+            note: resolution.note,
+            created_at: DateTime::now(),
+        };
+
+        self.moderation_log_collection.insert_one(&log_db).await?;
+
+        Ok(update_result.matched_count)
+    }
+
+    async fn get_moderation_log(
+        &self,
+        pagination: &Pagination,
+    ) -> Result<Page<ModerationLogEntry>, AppError> {
+        self.get_multiple_paginated::<ModerationLogMongoDb, ModerationLogEntry>(
+            "moderation_log",
+            doc! {},
+            doc! {"created_at": -1},
+            pagination,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+pub mod unit_tests_report_handler {
+    use crate::{
+        model::report::{ModerationAction, ReportTarget},
+        test_utils::{db_clean_up, get_db_config},
+    };
+
+    use super::*;
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn resolves_report_once() -> Result<()> {
+        let (db_name, db_user_name, db_user_password, db_host) = get_db_config(Some(".env"))?;
+        let db_handler =
+            MongoDbHandler::new(&db_user_name, &db_user_password, &db_name, &db_host).await?;
+
+        let report_id = db_handler
+            .create_report(
+                &ObjectId::new().to_hex(),
+                ReportCreate {
+                    target_type: ReportTarget::Comment,
+                    target_id: ObjectId::new().to_hex(),
+                    reason: " Harassment ".into(),
+                },
+            )
+            .await?;
+
+        let open_query = ReportQuery {
+            status: Some(ReportStatus::Open),
+            ..Default::default()
+        };
+        let open_reports = db_handler
+            .get_reports(&open_query, &Pagination::default())
+            .await?;
+        assert_eq!(open_reports.total, 1);
+        assert_eq!(open_reports.items[0].reason, "Harassment");
+
+        let report = db_handler
+            .get_by_id::<ReportMongoDb, ReportMongoDb>(&report_id, "reports")
+            .await?;
+        let moderator_id = ObjectId::new().to_hex();
+
+        for expected_count in [1, 0] {
+            let resolution = ReportResolution {
+                action: ModerationAction::HideContent,
+                note: Some("Removed the comment".into()),
+            };
+
+            assert_eq!(
+                db_handler
+                    .resolve_report(&report, &moderator_id, resolution)
+                    .await?,
+                expected_count
+            );
+        }
+
+        let open_reports = db_handler
+            .get_reports(&open_query, &Pagination::default())
+            .await?;
+        assert_eq!(open_reports.total, 0);
+
+        let log = db_handler
+            .get_moderation_log(&Pagination::default())
+            .await?;
+        assert_eq!(log.total, 1, "Records the action once");
+        assert_eq!(log.items[0].moderator_id, moderator_id);
+        assert_eq!(log.items[0].report_id, report_id);
+
+        db_clean_up().await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,269 @@
+use super::{generic_handler::parse_object_id, mongo_db_handler::MongoDbHandler};
+
+use crate::{
+    api::app_error::AppError,
+    model::review::{RecipeRating, Review, ReviewMongoDb, ReviewPayload},
+};
+use anyhow::{anyhow, Result};
+use bson::{doc, oid::ObjectId, Bson, DateTime};
+use futures_util::{StreamExt, TryStreamExt};
+
+pub trait ReviewHandler {
+    async fn create_review(
+        &self,
+        recipe_id: &str,
+        user_id: &str,
+        review: ReviewPayload,
+    ) -> Result<String, AppError>;
+    async fn get_reviews_by_recipe_id(&self, recipe_id: &str) -> Result<Vec<Review>, AppError>;
+    async fn get_review(&self, recipe_id: &str, user_id: &str) -> Result<Option<Review>, AppError>;
+    async fn patch_review(
+        &self,
+        recipe_id: &str,
+        user_id: &str,
+        review: ReviewPayload,
+    ) -> Result<u64, AppError>;
+    async fn delete_review(&self, recipe_id: &str, user_id: &str) -> Result<u64, AppError>;
+    async fn update_recipe_rating(&self, recipe_id: &ObjectId) -> Result<RecipeRating, AppError>;
+}
+
+impl ReviewHandler for MongoDbHandler {
+    async fn create_review(
+        &self,
+        recipe_id: &str,
+        user_id: &str,
+        review: ReviewPayload,
+    ) -> Result<String, AppError> {
+        let recipe_object_id = parse_object_id(recipe_id)?;
+
+        let review_db = ReviewMongoDb {
+            _id: ObjectId::new(),
+            recipe_id: recipe_object_id,
+            user_id: parse_object_id(user_id)?,
+            rating: review.rating,
+            text: review.text,
+            created_at: DateTime::now(),
+            modified_at: DateTime::now(),
+        };
+
+        let insert_result = self.reviews_collection.insert_one(&review_db).await?;
+        self.update_recipe_rating(&recipe_object_id).await?;
+
+        match insert_result.inserted_id {
+            Bson::ObjectId(object_id) => Ok(object_id.to_hex()),
+            _ => Err(anyhow!(
+                "Failed to convert inserted Id to string, {}",
+                insert_result.inserted_id
+            )
+            .into()),
+        }
+    }
+
+    async fn get_reviews_by_recipe_id(&self, recipe_id: &str) -> Result<Vec<Review>, AppError> {
+        let filter = doc! {"recipe_id": parse_object_id(recipe_id)?};
+
+        let cursor = self
+            .reviews_collection
+            .find(filter)
+            .sort(doc! {"created_at": -1})
+            .await?;
+        let reviews_db = cursor.try_collect::<Vec<ReviewMongoDb>>().await?;
+
+        Ok(reviews_db.into_iter().map(Into::into).collect())
+    }
+
+    async fn get_review(&self, recipe_id: &str, user_id: &str) -> Result<Option<Review>, AppError> {
+        let filter = doc! {
+            "recipe_id": parse_object_id(recipe_id)?,
+            "user_id": parse_object_id(user_id)?,
+        };
+
+        let find_result = self.reviews_collection.find_one(filter).await?;
+
+        Ok(find_result.map(Into::into))
+    }
+
+    async fn patch_review(
+        &self,
+        recipe_id: &str,
+        user_id: &str,
+        review: ReviewPayload,
+    ) -> Result<u64, AppError> {
+        let recipe_object_id = parse_object_id(recipe_id)?;
+
+        let filter = doc! {
+            "recipe_id": recipe_object_id,
+            "user_id": parse_object_id(user_id)?,
+        };
+        let update = doc! {
+            "$set": {
+                "rating": review.rating as i32,
+                "text": review.text,
+                "modified_at": DateTime::now(),
+            }
+        };
+
+        let update_result = self.reviews_collection.update_one(filter, update).await?;
+        self.update_recipe_rating(&recipe_object_id).await?;
+
+        Ok(update_result.matched_count)
+    }
+
+    async fn delete_review(&self, recipe_id: &str, user_id: &str) -> Result<u64, AppError> {
+        let recipe_object_id = parse_object_id(recipe_id)?;
+
+        let filter = doc! {
+            "recipe_id": recipe_object_id,
+            "user_id": parse_object_id(user_id)?,
+        };
+
+        let delete_result = self.reviews_collection.delete_one(filter).await?;
+        self.update_recipe_rating(&recipe_object_id).await?;
+
+        Ok(delete_result.deleted_count)
+    }
+
+    async fn update_recipe_rating(&self, recipe_id: &ObjectId) -> Result<RecipeRating, AppError> {
+        let stage_match_recipe = doc! {
+            "$match": { "recipe_id": recipe_id }
+        };
+
+        let stage_group = doc! {
+            "$group": {
+                "_id": "$recipe_id",
+                "average": { "$avg": "$rating" },
+                "count": { "$sum": 1 },
+            }
+        };
+
+        let pipeline = vec![stage_match_recipe, stage_group];
+
+        let mut results = self.reviews_collection.aggregate(pipeline).await?;
+
+        let rating = match results.next().await {
+            Some(document) => bson::from_document::<RecipeRating>(document?)?,
+            None => RecipeRating {
+                average: 0.0,
+                count: 0,
+            },
+        };
+
+        self.recipes_collection
+            .update_one(
+                doc! {"_id": recipe_id},
+                doc! {
+                    "$set": {
+                        "rating_average": rating.average,
+                        "rating_count": rating.count,
+                    }
+                },
+            )
+            .await?;
+
+        Ok(rating)
+    }
+}
+
+#[cfg(test)]
+pub mod unit_tests_review_handler {
+    use crate::{
+        db::generic_handler::GenericHandler,
+        model::recipe::{Recipe, RecipeMongoDb},
+        test_utils::{db_clean_up, get_db_config, get_db_connection, get_random_recipe_db},
+    };
+
+    use super::*;
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn maintains_recipe_rating() -> Result<()> {
+        let (db_name, db_user_name, db_user_password, db_host) = get_db_config(Some(".env"))?;
+        let db_handler =
+            MongoDbHandler::new(&db_user_name, &db_user_password, &db_name, &db_host).await?;
+
+        let recipe_db = get_random_recipe_db(None, None);
+        get_db_connection()
+            .await?
+            .collection::<RecipeMongoDb>("recipes")
+            .insert_one(&recipe_db)
+            .await?;
+
+        let recipe_id = recipe_db._id.to_hex();
+        let first_user_id = ObjectId::new().to_hex();
+        let second_user_id = ObjectId::new().to_hex();
+
+        db_handler
+            .create_review(
+                &recipe_id,
+                &first_user_id,
+                ReviewPayload {
+                    rating: 5,
+                    text: "Amazing".into(),
+                },
+            )
+            .await?;
+        db_handler
+            .create_review(
+                &recipe_id,
+                &second_user_id,
+                ReviewPayload {
+                    rating: 2,
+                    text: "Too salty".into(),
+                },
+            )
+            .await?;
+
+        let recipe = db_handler
+            .get_by_id::<RecipeMongoDb, Recipe>(&recipe_id, "recipes")
+            .await?;
+        assert_eq!(recipe.rating_count, 2, "Counts both reviews");
+        assert_eq!(recipe.rating_average, 3.5, "Averages both reviews");
+
+        let duplicate_result = db_handler
+            .create_review(
+                &recipe_id,
+                &first_user_id,
+                ReviewPayload {
+                    rating: 1,
+                    text: "Changed my mind".into(),
+                },
+            )
+            .await;
+        assert!(
+            duplicate_result.is_err(),
+            "Rejects a second review by the same user"
+        );
+
+        db_handler
+            .patch_review(
+                &recipe_id,
+                &second_user_id,
+                ReviewPayload {
+                    rating: 4,
+                    text: "Better with less salt".into(),
+                },
+            )
+            .await?;
+
+        let recipe = db_handler
+            .get_by_id::<RecipeMongoDb, Recipe>(&recipe_id, "recipes")
+            .await?;
+        assert_eq!(recipe.rating_average, 4.5, "Recomputes average on edit");
+
+        db_handler.delete_review(&recipe_id, &first_user_id).await?;
+        db_handler
+            .delete_review(&recipe_id, &second_user_id)
+            .await?;
+
+        let recipe = db_handler
+            .get_by_id::<RecipeMongoDb, Recipe>(&recipe_id, "recipes")
+            .await?;
+        assert_eq!(recipe.rating_count, 0, "Resets count without reviews");
+        assert_eq!(recipe.rating_average, 0.0, "Resets average without reviews");
+
+        db_clean_up().await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,306 @@
+use super::{
+    generic_handler::parse_object_id,
+    household_handler::{get_owner_filter, HouseholdHandler},
+    mongo_db_handler::MongoDbHandler,
+    recipe_handler::get_shared_visibility_filter,
+};
+
+use crate::{
+    api::app_error::AppError,
+    model::{
+        household::Owner,
+        meal_plan::IsoWeek,
+        recipe::RecipeMongoDb,
+        shopping_list::{
+            ShoppingList, ShoppingListItemMongoDb, ShoppingListItemPatch, ShoppingListMongoDb,
+        },
+    },
+};
+use anyhow::{anyhow, Result};
+use bson::{doc, oid::ObjectId, DateTime};
+use futures_util::TryStreamExt;
+
+pub trait ShoppingListHandler {
+    async fn get_shopping_list_recipes(
+        &self,
+        owner: &Owner,
+        recipe_ids: &[String],
+        meal_plan_week: Option<&IsoWeek>,
+    ) -> Result<Vec<(RecipeMongoDb, f64)>, AppError>;
+    async fn create_shopping_list(
+        &self,
+        owner: &Owner,
+        name: &str,
+        items: Vec<ShoppingListItemMongoDb>,
+    ) -> Result<String, AppError>;
+    async fn get_shopping_lists(&self, owner: &Owner) -> Result<Vec<ShoppingList>, AppError>;
+    async fn get_shopping_list(
+        &self,
+        owner: &Owner,
+        list_id: &str,
+    ) -> Result<Option<ShoppingList>, AppError>;
+    async fn patch_shopping_list_item(
+        &self,
+        owner: &Owner,
+        list_id: &str,
+        item_id: &str,
+        item_patch: ShoppingListItemPatch,
+    ) -> Result<u64, AppError>;
+    async fn delete_shopping_list(&self, owner: &Owner, list_id: &str) -> Result<u64, AppError>;
+}
+
+impl ShoppingListHandler for MongoDbHandler {
+    async fn get_shopping_list_recipes(
+        &self,
+        owner: &Owner,
+        recipe_ids: &[String],
+        meal_plan_week: Option<&IsoWeek>,
+    ) -> Result<Vec<(RecipeMongoDb, f64)>, AppError> {
+        // Recipes are listed once for their own servings, meal plan entries for the planned servings
+        let mut planned_servings: Vec<(ObjectId, Option<u32>)> = recipe_ids
+            .iter()
+            .map(|id| parse_object_id(id).map(|object_id| (object_id, None)))
+            .collect::<Result<_, _>>()?;
+
+        if let Some(week) = meal_plan_week {
+            let mut meal_plan_filter = get_owner_filter(owner)?;
+            meal_plan_filter.insert("week", week.to_string());
+
+            let meal_plan = self
+                .meal_plans_collection
+                .find_one(meal_plan_filter)
+                .await?;
+
+            if let Some(meal_plan) = meal_plan {
+                planned_servings.extend(
+                    meal_plan
+                        .entries
+                        .iter()
+                        .map(|entry| (entry.recipe_id, Some(entry.servings))),
+                );
+            }
+        }
+
+        let ids = planned_servings
+            .iter()
+            .map(|(id, _)| *id)
+            .collect::<Vec<ObjectId>>();
+        let mut filter = doc! {"_id": { "$in": ids }};
+        filter.extend(get_shared_visibility_filter(
+            &self.get_owner_member_ids(owner).await?,
+            true,
+        )?);
+
+        let recipes = self
+            .recipes_collection
+            .find(filter)
+            .await?
+            .try_collect::<Vec<RecipeMongoDb>>()
+            .await?;
+
+        // Recipes deleted or hidden since they were planned are left out
+        Ok(planned_servings
+            .into_iter()
+            .filter_map(|(id, servings)| {
+                let recipe = recipes.iter().find(|recipe| recipe._id == id)?;
+
+                let factor = match servings {
+                    Some(servings) => servings as f64 / recipe.servings.max(1) as f64,
+                    None => 1.0,
+                };
+
+                Some((recipe.clone(), factor))
+            })
+            .collect())
+    }
+
+    async fn create_shopping_list(
+        &self,
+        owner: &Owner,
+        name: &str,
+        items: Vec<ShoppingListItemMongoDb>,
+    ) -> Result<String, AppError> {
+        let shopping_list_db = ShoppingListMongoDb {
+            _id: ObjectId::new(),
+            user_id: parse_object_id(&owner.user_id)?,
+            household_id: owner
+                .household_id
+                .as_deref()
+                .map(parse_object_id)
+                .transpose()?,
+            name: name.into(),
+            items,
+            created_at: DateTime::now(),
+            modified_at: DateTime::now(),
+        };
+
+        let insert_result = self
+            .shopping_lists_collection
+            .insert_one(&shopping_list_db)
+            .await?;
+
+        match insert_result.inserted_id.as_object_id() {
+            Some(id) => Ok(id.to_hex()),
+            None => Err(anyhow!("Failed to get id of inserted shopping list").into()),
+        }
+    }
+
+    async fn get_shopping_lists(&self, owner: &Owner) -> Result<Vec<ShoppingList>, AppError> {
+        let cursor = self
+            .shopping_lists_collection
+            .find(get_owner_filter(owner)?)
+            .sort(doc! {"created_at": -1})
+            .await?;
+        let shopping_lists_db = cursor.try_collect::<Vec<ShoppingListMongoDb>>().await?;
+
+        Ok(shopping_lists_db.into_iter().map(Into::into).collect())
+    }
+
+    async fn get_shopping_list(
+        &self,
+        owner: &Owner,
+        list_id: &str,
+    ) -> Result<Option<ShoppingList>, AppError> {
+        let mut filter = get_owner_filter(owner)?;
+        filter.insert("_id", parse_object_id(list_id)?);
+
+        let shopping_list_db = self.shopping_lists_collection.find_one(filter).await?;
+
+        Ok(shopping_list_db.map(Into::into))
+    }
+
+    async fn patch_shopping_list_item(
+        &self,
+        owner: &Owner,
+        list_id: &str,
+        item_id: &str,
+        item_patch: ShoppingListItemPatch,
+    ) -> Result<u64, AppError> {
+        let mut update_doc = doc! {};
+
+        if let Some(checked) = item_patch.checked {
+            update_doc.insert("items.$.checked", checked);
+        }
+
+        update_doc.insert("modified_at", DateTime::now());
+
+        let mut filter = get_owner_filter(owner)?;
+        filter.insert("_id", parse_object_id(list_id)?);
+        filter.insert("items._id", parse_object_id(item_id)?);
+
+        let update_result = self
+            .shopping_lists_collection
+            .update_one(filter, doc! {"$set": update_doc})
+            .await?;
+
+        Ok(update_result.matched_count)
+    }
+
+    async fn delete_shopping_list(&self, owner: &Owner, list_id: &str) -> Result<u64, AppError> {
+        let mut filter = get_owner_filter(owner)?;
+        filter.insert("_id", parse_object_id(list_id)?);
+
+        let delete_result = self.shopping_lists_collection.delete_one(filter).await?;
+
+        Ok(delete_result.deleted_count)
+    }
+}
+
+#[cfg(test)]
+pub mod unit_tests_shopping_list_handler {
+    use crate::{
+        api::services::shopping_list_service::merge_ingredients,
+        db::meal_plan_handler::MealPlanHandler,
+        model::{
+            meal_plan::{DayOfWeek, MealPlanEntryCreate, MealSlot},
+            recipe::RecipeStatus,
+        },
+        test_utils::{db_clean_up, get_db_config, get_db_connection, get_random_recipe_db},
+    };
+
+    use super::*;
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn creates_shopping_list_from_meal_plan() -> Result<()> {
+        let (db_name, db_user_name, db_user_password, db_host) = get_db_config(Some(".env"))?;
+        let db_handler =
+            MongoDbHandler::new(&db_user_name, &db_user_password, &db_name, &db_host).await?;
+
+        let recipe_db = get_random_recipe_db(None, None);
+        let hidden_recipe_db = RecipeMongoDb {
+            status: RecipeStatus::Draft,
+            ..get_random_recipe_db(None, None)
+        };
+        get_db_connection()
+            .await?
+            .collection::<RecipeMongoDb>("recipes")
+            .insert_many([&recipe_db, &hidden_recipe_db])
+            .await?;
+
+        let owner = Owner::user(&ObjectId::new().to_hex());
+        let week: IsoWeek = "2026-W42".parse()?;
+        db_handler
+            .add_meal_plan_entry(
+                &owner,
+                &week,
+                MealPlanEntryCreate {
+                    day: DayOfWeek::Monday,
+                    slot: MealSlot::Dinner,
+                    recipe_id: recipe_db._id.to_hex(),
+                    servings: 4,
+                },
+            )
+            .await?;
+
+        let recipes = db_handler
+            .get_shopping_list_recipes(
+                &owner,
+                &[
+                    recipe_db._id.to_hex(),
+                    hidden_recipe_db._id.to_hex(),
+                    ObjectId::new().to_hex(),
+                ],
+                Some(&week),
+            )
+            .await?;
+        assert_eq!(recipes.len(), 2, "Leaves out hidden and missing recipes");
+        assert_eq!(recipes[1].1, 2.0, "Scales to planned servings");
+
+        let list_id = db_handler
+            .create_shopping_list(&owner, "Week 42", merge_ingredients(&recipes))
+            .await?;
+
+        let shopping_list = db_handler
+            .get_shopping_list(&owner, &list_id)
+            .await?
+            .ok_or(anyhow!("Shopping list not found"))?;
+        assert_eq!(shopping_list.items.len(), 1);
+        assert_eq!(shopping_list.items[0].quantity, Some(600.0));
+
+        let matched_count = db_handler
+            .patch_shopping_list_item(
+                &owner,
+                &list_id,
+                &shopping_list.items[0].id,
+                ShoppingListItemPatch {
+                    checked: Some(true),
+                },
+            )
+            .await?;
+        assert_eq!(matched_count, 1);
+
+        let other_owner = Owner::user(&ObjectId::new().to_hex());
+        assert!(db_handler
+            .get_shopping_list(&other_owner, &list_id)
+            .await?
+            .is_none());
+
+        assert_eq!(db_handler.delete_shopping_list(&owner, &list_id).await?, 1);
+
+        db_clean_up().await?;
+
+        Ok(())
+    }
+}
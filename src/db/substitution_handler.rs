@@ -0,0 +1,201 @@
+use super::{generic_handler::parse_object_id, mongo_db_handler::MongoDbHandler};
+
+use crate::{
+    api::{app_error::AppError, services::pantry_service::names_match},
+    model::substitution::{
+        SubstitutionCreate, SubstitutionMongoDb, SubstitutionPatch, SubstitutionQuery,
+    },
+};
+use anyhow::{anyhow, Result};
+use bson::{doc, oid::ObjectId, DateTime};
+use futures_util::TryStreamExt;
+
+pub trait SubstitutionHandler {
+    async fn create_substitution(
+        &self,
+        substitution: SubstitutionCreate,
+    ) -> Result<String, AppError>;
+    async fn get_substitutions(
+        &self,
+        query: &SubstitutionQuery,
+    ) -> Result<Vec<SubstitutionMongoDb>, AppError>;
+    async fn patch_substitution(
+        &self,
+        id: &str,
+        substitution_patch: SubstitutionPatch,
+    ) -> Result<u64, AppError>;
+    async fn delete_substitution(&self, id: &str) -> Result<u64, AppError>;
+}
+
+fn normalize_allergens(allergens: Vec<String>) -> Vec<String> {
+    allergens
+        .iter()
+        .map(|allergen| allergen.trim().to_lowercase())
+        .filter(|allergen| !allergen.is_empty())
+        .collect()
+}
+
+impl SubstitutionHandler for MongoDbHandler {
+    async fn create_substitution(
+        &self,
+        substitution: SubstitutionCreate,
+    ) -> Result<String, AppError> {
+        let substitution_db = SubstitutionMongoDb {
+            _id: ObjectId::new(),
+            ingredient: substitution.ingredient,
+            substitute: substitution.substitute,
+            ratio: substitution.ratio,
+            notes: substitution.notes,
+            free_of: normalize_allergens(substitution.free_of),
+            created_at: DateTime::now(),
+            modified_at: DateTime::now(),
+        };
+
+        let insert_result = self
+            .substitutions_collection
+            .insert_one(&substitution_db)
+            .await?;
+
+        match insert_result.inserted_id.as_object_id() {
+            Some(id) => Ok(id.to_hex()),
+            None => Err(anyhow!("Failed to get id of inserted substitution").into()),
+        }
+    }
+
+    async fn get_substitutions(
+        &self,
+        query: &SubstitutionQuery,
+    ) -> Result<Vec<SubstitutionMongoDb>, AppError> {
+        let cursor = self
+            .substitutions_collection
+            .find(doc! {})
+            .sort(doc! {"ingredient": 1, "substitute": 1})
+            .await?;
+        let substitutions = cursor.try_collect::<Vec<SubstitutionMongoDb>>().await?;
+
+        // The knowledge base is small and curated, so names are matched in memory
+        Ok(match &query.ingredient {
+            Some(ingredient) => substitutions
+                .into_iter()
+                .filter(|substitution| names_match(&substitution.ingredient, ingredient))
+                .collect(),
+            None => substitutions,
+        })
+    }
+
+    async fn patch_substitution(
+        &self,
+        id: &str,
+        substitution_patch: SubstitutionPatch,
+    ) -> Result<u64, AppError> {
+        let mut update_doc = doc! {};
+
+        if let Some(ingredient) = substitution_patch.ingredient {
+            update_doc.insert("ingredient", ingredient);
+        }
+
+        if let Some(substitute) = substitution_patch.substitute {
+            update_doc.insert("substitute", substitute);
+        }
+
+        if let Some(ratio) = substitution_patch.ratio {
+            update_doc.insert("ratio", ratio);
+        }
+
+        if let Some(notes) = substitution_patch.notes {
+            update_doc.insert("notes", notes);
+        }
+
+        if let Some(free_of) = substitution_patch.free_of {
+            update_doc.insert("free_of", normalize_allergens(free_of));
+        }
+
+        update_doc.insert("modified_at", DateTime::now());
+
+        let update_result = self
+            .substitutions_collection
+            .update_one(
+                doc! {"_id": parse_object_id(id)?},
+                doc! {"$set": update_doc},
+            )
+            .await?;
+
+        Ok(update_result.matched_count)
+    }
+
+    async fn delete_substitution(&self, id: &str) -> Result<u64, AppError> {
+        let delete_result = self
+            .substitutions_collection
+            .delete_one(doc! {"_id": parse_object_id(id)?})
+            .await?;
+
+        Ok(delete_result.deleted_count)
+    }
+}
+
+#[cfg(test)]
+pub mod unit_tests_substitution_handler {
+    use crate::test_utils::{db_clean_up, get_db_config};
+
+    use super::*;
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn manages_substitutions() -> Result<()> {
+        let (db_name, db_user_name, db_user_password, db_host) = get_db_config(Some(".env"))?;
+        let db_handler =
+            MongoDbHandler::new(&db_user_name, &db_user_password, &db_name, &db_host).await?;
+
+        let id = db_handler
+            .create_substitution(SubstitutionCreate {
+                ingredient: "Egg".into(),
+                substitute: "Flax egg".into(),
+                ratio: 1.0,
+                notes: Some("1 tbsp ground flaxseed with 3 tbsp water".into()),
+                free_of: vec![" Egg ".into()],
+            })
+            .await?;
+        db_handler
+            .create_substitution(SubstitutionCreate {
+                ingredient: "Soy sauce".into(),
+                substitute: "Tamari".into(),
+                ratio: 1.0,
+                notes: None,
+                free_of: vec!["gluten".into()],
+            })
+            .await?;
+
+        let substitutions = db_handler
+            .get_substitutions(&SubstitutionQuery {
+                ingredient: Some("eggs".into()),
+            })
+            .await?;
+        assert_eq!(substitutions.len(), 1);
+        assert_eq!(substitutions[0].free_of, vec!["egg".to_string()]);
+
+        let matched_count = db_handler
+            .patch_substitution(
+                &id,
+                SubstitutionPatch {
+                    ratio: Some(2.0),
+                    ..Default::default()
+                },
+            )
+            .await?;
+        assert_eq!(matched_count, 1);
+
+        assert_eq!(db_handler.delete_substitution(&id).await?, 1);
+        assert_eq!(
+            db_handler
+                .get_substitutions(&SubstitutionQuery::default())
+                .await?
+                .len(),
+            1
+        );
+
+        db_clean_up().await?;
+
+        Ok(())
+    }
+}
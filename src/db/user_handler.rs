@@ -1,53 +1,100 @@
-use super::mongo_db_handler::MongoDbHandler;
-
-use crate::model::user::{Role, User, UserAuthInfo, UserCreate, UserMongoDb, UserPatch};
+use super::{
+    generic_handler::parse_object_id,
+    household_handler::{get_owner_filter, HouseholdHandler},
+    mongo_db_handler::{MongoDbHandler, USER_EMAIL_INDEX},
+    recipe_handler::RecipeHandler,
+    review_handler::ReviewHandler,
+};
+
+use crate::{
+    api::{
+        app_error::{is_duplicate_key_on, AppError},
+        messages::Message,
+        services::locale_service::normalize_locale,
+        validation::Validate,
+    },
+    model::{
+        comment::CommentStatus,
+        household::{HouseholdRole, Owner},
+        user::{
+            Role, User, UserAuthInfo, UserCreate, UserMongoDb, UserPatch, UserPreferences,
+            UserPreferencesPatch,
+        },
+    },
+};
 use anyhow::{anyhow, Result};
 use bson::{doc, oid::ObjectId, to_bson, Bson, DateTime};
 use futures_util::StreamExt;
 
 pub trait UserHandler {
-    async fn create_user(&self, user: UserCreate) -> Result<String>;
-    async fn get_user_by_email(&self, email: &str) -> Result<User>;
-    async fn patch_user_by_id(&self, id: &str, user_patch: UserPatch) -> Result<()>;
-    async fn delete_user_by_id(&self, id: &str) -> Result<u64>;
-    async fn get_user_auth_info(&self, email: &str) -> Result<UserAuthInfo>;
+    async fn create_user(&self, user: UserCreate) -> Result<String, AppError>;
+    async fn get_user_by_email(&self, email: &str) -> Result<User, AppError>;
+    async fn patch_user_by_id(&self, id: &str, user_patch: UserPatch) -> Result<(), AppError>;
+    async fn delete_user_by_id(&self, id: &str) -> Result<u64, AppError>;
+    async fn get_user_auth_info(&self, email: &str) -> Result<UserAuthInfo, AppError>;
+    async fn get_user_preferences(&self, id: &str) -> Result<Option<UserPreferences>, AppError>;
+    async fn patch_user_preferences(
+        &self,
+        id: &str,
+        preferences_patch: UserPreferencesPatch,
+    ) -> Result<u64, AppError>;
 }
 
 impl UserHandler for MongoDbHandler {
-    async fn create_user(&self, user: UserCreate) -> Result<String> {
+    async fn create_user(&self, user: UserCreate) -> Result<String, AppError> {
         let user_db = UserMongoDb {
             _id: ObjectId::new(),
             email: user.email,
             password_hash: user.password_hash,
             role: Role::User,
             is_activated: false,
+            preferences: UserPreferences::default(),
             created_at: DateTime::now(),
             modified_at: DateTime::now(),
         };
 
-        let insert_result = self.users_collection.insert_one(&user_db).await?;
+        // The unique index also rejects signups racing each other
+        let insert_result = self
+            .users_collection
+            .insert_one(&user_db)
+            .await
+            .map_err(|err| match is_duplicate_key_on(&err, USER_EMAIL_INDEX) {
+                true => AppError::Conflict(
+                    Message::EmailTaken {
+                        email: &user_db.email,
+                    }
+                    .to_string(),
+                ),
+                false => err.into(),
+            })?;
 
         match insert_result.inserted_id {
             Bson::ObjectId(object_id) => Ok(object_id.to_hex()),
             _ => Err(anyhow!(
                 "Failed to convert inserted Id to string, {}",
-                insert_result.inserted_id.to_string()
-            )),
+                insert_result.inserted_id
+            )
+            .into()),
         }
     }
 
-    async fn get_user_by_email(&self, email: &str) -> Result<User> {
+    async fn get_user_by_email(&self, email: &str) -> Result<User, AppError> {
         let filter = doc! {"email": email};
 
         let find_result = self.users_collection.find_one(filter).await?;
 
         match find_result {
             Some(user) => Ok(user.into()),
-            None => Err(anyhow!("Failed to find user with email {}", email)),
+            None => Err(AppError::NotFound(
+                Message::UserWithEmailNotFound { email: &email }.to_string(),
+            )),
         }
     }
 
-    async fn patch_user_by_id(&self, id: &str, user_patch: UserPatch) -> Result<()> {
+    async fn patch_user_by_id(&self, id: &str, user_patch: UserPatch) -> Result<(), AppError> {
+        // Patches are built by handlers, so they are checked before they are written
+        user_patch.validate().map_err(AppError::InvalidFields)?;
+
         let mut update_doc = doc! {};
 
         if let Some(email) = user_patch.email {
@@ -66,7 +113,7 @@ impl UserHandler for MongoDbHandler {
             update_doc.insert("is_activated", is_activated);
         }
 
-        let object_id = ObjectId::parse_str(id)?;
+        let object_id = parse_object_id(id)?;
 
         if update_doc.is_empty() {
             return Ok(());
@@ -75,21 +122,126 @@ impl UserHandler for MongoDbHandler {
         let filter = doc! {"_id": object_id};
         let update = doc! { "$set": update_doc };
 
-        self.users_collection.update_one(filter, update).await?;
+        let update_result = self.users_collection.update_one(filter, update).await?;
+
+        if update_result.matched_count == 0 {
+            return Err(AppError::NotFound(
+                Message::DocumentNotFound {
+                    collection: &"users",
+                    id: &id,
+                }
+                .to_string(),
+            ));
+        }
 
         Ok(())
     }
 
-    async fn delete_user_by_id(&self, id: &str) -> Result<u64> {
-        let object_id = ObjectId::parse_str(id)?;
+    async fn delete_user_by_id(&self, id: &str) -> Result<u64, AppError> {
+        let object_id = parse_object_id(id)?;
         let filter = doc! {"_id": object_id};
 
         let delete_result = self.users_collection.delete_one(filter).await?;
+        self.follows_collection
+            .delete_many(doc! {"$or": [{"follower_id": object_id}, {"followee_id": object_id}]})
+            .await?;
+        self.notifications_collection
+            .delete_many(doc! {"user_id": object_id})
+            .await?;
+
+        // Deleting a recipe also takes its reviews, comments and revisions along
+        let recipe_ids = self
+            .recipes_collection
+            .distinct("_id", doc! {"author_id": object_id})
+            .await?;
+        for recipe_id in recipe_ids.iter().filter_map(Bson::as_object_id) {
+            self.delete_recipe_by_id(&recipe_id.to_hex()).await?;
+        }
+
+        let reviewed_recipe_ids = self
+            .reviews_collection
+            .distinct("recipe_id", doc! {"user_id": object_id})
+            .await?;
+        self.reviews_collection
+            .delete_many(doc! {"user_id": object_id})
+            .await?;
+        for recipe_id in reviewed_recipe_ids.iter().filter_map(Bson::as_object_id) {
+            self.update_recipe_rating(&recipe_id).await?;
+        }
+
+        // Comments stay as tombstones to keep the replies of others attached
+        self.comments_collection
+            .update_many(
+                doc! {"author_id": object_id},
+                doc! {"$set": {
+                    "text": "",
+                    "status": to_bson(&CommentStatus::Deleted)?,
+                    "modified_at": DateTime::now(),
+                }},
+            )
+            .await?;
+
+        // Collections are stored along with the favorites
+        if let Some(favorites) = self
+            .favorites_collection
+            .find_one_and_delete(doc! {"user_id": object_id})
+            .await?
+        {
+            self.recipes_collection
+                .update_many(
+                    doc! {"_id": { "$in": favorites.recipe_ids }, "favorite_count": { "$gt": 0 }},
+                    doc! {"$inc": {"favorite_count": -1}},
+                )
+                .await?;
+        }
+
+        self.pantries_collection
+            .delete_many(doc! {"user_id": object_id})
+            .await?;
+
+        // Shared meal plans and shopping lists stay with the household
+        let personal_filter = get_owner_filter(&Owner::user(id))?;
+        self.meal_plans_collection
+            .delete_many(personal_filter.clone())
+            .await?;
+        self.shopping_lists_collection
+            .delete_many(personal_filter)
+            .await?;
+
+        // Households of the last member go with their shared data, others keep an owner
+        if let Some(household) = self.get_household_of_user(id).await? {
+            let household_id = household._id.to_hex();
+            let is_last_owner = household.get_role(&object_id) == Some(HouseholdRole::Owner)
+                && household.owner_count() == 1;
+            let successor = household
+                .members
+                .iter()
+                .filter(|member| member.user_id != object_id)
+                .min_by_key(|member| member.joined_at);
+
+            match successor {
+                Some(successor) => {
+                    if is_last_owner {
+                        self.set_household_member_role(
+                            &household_id,
+                            &successor.user_id.to_hex(),
+                            HouseholdRole::Owner,
+                        )
+                        .await?;
+                    }
+
+                    self.remove_household_member(&household_id, id).await?;
+                }
+                None => {
+                    self.delete_household(&household_id).await?;
+                }
+            }
+        }
 
         Ok(delete_result.deleted_count)
     }
 
-    async fn get_user_auth_info(&self, email: &str) -> Result<UserAuthInfo> {
+    async fn get_user_auth_info(&self, email: &str) -> Result<UserAuthInfo, AppError> {
         let stage_match_email = doc! {
             "$match": {
                 "email": email,
@@ -110,20 +262,74 @@ impl UserHandler for MongoDbHandler {
                     let auth_info: UserAuthInfo = bson::from_document(document)?;
                     Ok(auth_info)
                 }
-                Err(err) => Err(anyhow!("Failed to get user auth info: {err}")),
+                Err(err) => Err(err.into()),
             },
-            None => Err(anyhow!("Failed to find any matching documents")),
+            None => Err(AppError::NotFound(
+                Message::UserWithEmailNotFound { email: &email }.to_string(),
+            )),
         }
     }
+
+    async fn get_user_preferences(&self, id: &str) -> Result<Option<UserPreferences>, AppError> {
+        let object_id = parse_object_id(id)?;
+
+        let find_result = self
+            .users_collection
+            .find_one(doc! {"_id": object_id})
+            .await?;
+
+        Ok(find_result.map(|user| user.preferences))
+    }
+
+    async fn patch_user_preferences(
+        &self,
+        id: &str,
+        preferences_patch: UserPreferencesPatch,
+    ) -> Result<u64, AppError> {
+        let mut update_doc = doc! {};
+
+        if let Some(allergens) = preferences_patch.allergens {
+            let allergens = allergens
+                .iter()
+                .map(|allergen| allergen.trim().to_lowercase())
+                .filter(|allergen| !allergen.is_empty())
+                .collect::<Vec<String>>();
+            update_doc.insert("preferences.allergens", allergens);
+        }
+
+        if let Some(notifications) = preferences_patch.notifications {
+            update_doc.insert("preferences.notifications", to_bson(&notifications)?);
+        }
+
+        if let Some(locale) = preferences_patch.locale {
+            update_doc.insert("preferences.locale", normalize_locale(&locale));
+        }
+
+        update_doc.insert("modified_at", DateTime::now());
+
+        let object_id = parse_object_id(id)?;
+
+        let update_result = self
+            .users_collection
+            .update_one(doc! {"_id": object_id}, doc! {"$set": update_doc})
+            .await?;
+
+        Ok(update_result.matched_count)
+    }
 }
 
 #[cfg(test)]
 pub mod unit_tests_users_handler {
     use crate::{
-        model::user::UserMongoDb,
+        db::{favorite_handler::FavoriteHandler, generic_handler::GenericHandler},
+        model::{
+            recipe::{Recipe, RecipeMongoDb},
+            review::ReviewPayload,
+            user::UserMongoDb,
+        },
         test_utils::{
             assert_date_is_current, db_clean_up, get_db_config, get_db_connection,
-            get_random_user_db, print_assert_failed,
+            get_random_recipe_db, get_random_user_db, print_assert_failed,
         },
     };
 
@@ -465,6 +671,63 @@ pub mod unit_tests_users_handler {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn deletes_data_of_deleted_users() -> Result<()> {
+        let (db_name, db_user_name, db_user_password, db_host) = get_db_config(Some(".env"))?;
+        let db_handler =
+            MongoDbHandler::new(&db_user_name, &db_user_password, &db_name, &db_host).await?;
+
+        let user = get_random_user_db(None);
+        let user_id = user._id.to_hex();
+        let own_recipe = get_random_recipe_db(None, Some(user._id));
+        let other_recipe = get_random_recipe_db(None, Some(ObjectId::new()));
+        let other_recipe_id = other_recipe._id.to_hex();
+
+        let db = get_db_connection().await?;
+        db.collection::<UserMongoDb>("users")
+            .insert_one(&user)
+            .await?;
+        db.collection::<RecipeMongoDb>("recipes")
+            .insert_many([&own_recipe, &other_recipe])
+            .await?;
+
+        db_handler
+            .create_review(
+                &other_recipe_id,
+                &user_id,
+                ReviewPayload {
+                    rating: 5,
+                    text: "Great".into(),
+                },
+            )
+            .await?;
+        db_handler.add_favorite(&user_id, &other_recipe_id).await?;
+
+        assert_eq!(db_handler.delete_user_by_id(&user_id).await?, 1);
+
+        assert!(
+            db_handler
+                .get_by_id::<RecipeMongoDb, Recipe>(&own_recipe._id.to_hex(), "recipes")
+                .await
+                .is_err(),
+            "Deletes the recipes of the user"
+        );
+
+        let other_recipe = db_handler
+            .get_by_id::<RecipeMongoDb, Recipe>(&other_recipe_id, "recipes")
+            .await?;
+        assert_eq!(other_recipe.rating_count, 0, "Recomputes the rating");
+        assert_eq!(other_recipe.favorite_count, 0, "Releases the favorites");
+        assert!(db_handler
+            .get_review(&other_recipe_id, &user_id)
+            .await?
+            .is_none());
+
+        db_clean_up().await?;
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn get_user_auth_info() -> Result<()> {
         struct TestCase {
@@ -531,4 +794,40 @@ pub mod unit_tests_users_handler {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn patch_user_preferences() -> Result<()> {
+        let (db_name, db_user_name, db_user_password, db_host) = get_db_config(Some(".env"))?;
+        let db_handler =
+            MongoDbHandler::new(&db_user_name, &db_user_password, &db_name, &db_host).await?;
+
+        let user = get_random_user_db(None);
+        get_db_connection()
+            .await?
+            .collection::<UserMongoDb>("users")
+            .insert_one(&user)
+            .await?;
+
+        let matched_count = db_handler
+            .patch_user_preferences(
+                &user._id.to_hex(),
+                UserPreferencesPatch {
+                    allergens: Some(vec![" Gluten".into(), "".into(), "SOY".into()]),
+                    notifications: None,
+                    locale: None,
+                },
+            )
+            .await?;
+        assert_eq!(matched_count, 1);
+
+        let preferences = db_handler.get_user_preferences(&user._id.to_hex()).await?;
+        assert_eq!(
+            preferences.map(|p| p.allergens),
+            Some(vec!["gluten".to_string(), "soy".to_string()])
+        );
+
+        db_clean_up().await?;
+
+        Ok(())
+    }
 }
@@ -9,10 +9,21 @@ mod test_utils;
 use anyhow::{Error, Result};
 use api::{
     routers::{
-        auth_router::AuthRouter, heart_beat_router::HeartBeatRouter, recipes_router::RecipesRouter,
+        auth_router::AuthRouter, comments_router::CommentsRouter, events_router::EventsRouter,
+        favorites_router::FavoritesRouter, follows_router::FollowsRouter,
+        heart_beat_router::HeartBeatRouter, households_router::HouseholdsRouter,
+        ingredient_prices_router::IngredientPricesRouter, meal_plans_router::MealPlansRouter,
+        notifications_router::NotificationsRouter, pantry_router::PantryRouter,
+        preferences_router::PreferencesRouter, recipe_revisions_router::RecipeRevisionsRouter,
+        recipes_router::RecipesRouter, reports_router::ReportsRouter,
+        reviews_router::ReviewsRouter, seasons_router::SeasonsRouter,
+        shopping_lists_router::ShoppingListsRouter, substitutions_router::SubstitutionsRouter,
         users_router::UsersRouter,
     },
     server::Server,
+    services::{
+        event_service::EventBus, mail_service::Mailer, notification_service::run_email_digests,
+    },
 };
 use config::Config;
 use db::mongo_db_handler::MongoDbHandler;
@@ -31,11 +42,34 @@ async fn main() -> Result<(), Error> {
     )
     .await?;
 
+    tokio::spawn(run_email_digests(
+        db_handler.clone(),
+        Mailer::new(&config.mail_from),
+    ));
+
+    let event_bus = EventBus::default();
+
     let routers = vec![
         HeartBeatRouter::new().router,
         AuthRouter::new(db_handler.clone(), &config.jwt_secret).router,
         UsersRouter::new(db_handler.clone()).router,
-        RecipesRouter::new(db_handler.clone(), &config.jwt_secret).router,
+        RecipesRouter::new(db_handler.clone(), &config.jwt_secret, event_bus.clone()).router,
+        ReviewsRouter::new(db_handler.clone(), &config.jwt_secret, event_bus.clone()).router,
+        CommentsRouter::new(db_handler.clone(), &config.jwt_secret, event_bus.clone()).router,
+        FavoritesRouter::new(db_handler.clone(), &config.jwt_secret).router,
+        MealPlansRouter::new(db_handler.clone(), &config.jwt_secret).router,
+        ShoppingListsRouter::new(db_handler.clone(), &config.jwt_secret, event_bus.clone()).router,
+        PantryRouter::new(db_handler.clone(), &config.jwt_secret).router,
+        SubstitutionsRouter::new(db_handler.clone(), &config.jwt_secret).router,
+        IngredientPricesRouter::new(db_handler.clone(), &config.jwt_secret).router,
+        SeasonsRouter::new().router,
+        PreferencesRouter::new(db_handler.clone(), &config.jwt_secret).router,
+        RecipeRevisionsRouter::new(db_handler.clone(), &config.jwt_secret).router,
+        ReportsRouter::new(db_handler.clone(), &config.jwt_secret, event_bus.clone()).router,
+        FollowsRouter::new(db_handler.clone(), &config.jwt_secret, event_bus.clone()).router,
+        NotificationsRouter::new(db_handler.clone(), &config.jwt_secret).router,
+        HouseholdsRouter::new(db_handler.clone(), &config.jwt_secret).router,
+        EventsRouter::new(db_handler.clone(), &config.jwt_secret, event_bus).router,
     ];
 
     let _ = Server::new(&config.server_host, routers).await?;
@@ -0,0 +1,230 @@
+use std::collections::HashMap;
+
+use bson::{oid::ObjectId, DateTime};
+use serde::{Deserialize, Serialize};
+
+use super::serialize_datetime;
+use crate::api::{
+    api_response::FieldError,
+    validation::{Validate, Validator},
+};
+
+const MAX_TEXT_LENGTH: usize = 5000;
+
+#[derive(Serialize, Deserialize, Copy, Clone, PartialEq, Debug)]
+pub enum CommentStatus {
+    Visible,
+    Deleted,
+    Removed,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct CommentMongoDb {
+    pub _id: ObjectId,
+    pub recipe_id: ObjectId,
+    pub thread_id: ObjectId,
+    pub parent_id: Option<ObjectId>,
+    pub author_id: ObjectId,
+    pub text: String,
+    pub status: CommentStatus,
+    pub created_at: DateTime,
+    pub modified_at: DateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Comment {
+    pub id: String,
+    pub recipe_id: String,
+    pub parent_id: Option<String>,
+    pub author_id: Option<String>,
+    pub text: String,
+    pub status: CommentStatus,
+    #[serde(serialize_with = "serialize_datetime")]
+    pub created_at: DateTime,
+    #[serde(serialize_with = "serialize_datetime")]
+    pub modified_at: DateTime,
+    pub replies: Vec<Comment>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentCreate {
+    pub text: String,
+    pub parent_id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct CommentPatch {
+    pub text: String,
+}
+
+impl Validate for CommentCreate {
+    fn validate(&self) -> Result<(), Vec<FieldError>> {
+        Validator::new()
+            .length("text", &self.text, 1, MAX_TEXT_LENGTH)
+            .finish()
+    }
+}
+
+impl Validate for CommentPatch {
+    fn validate(&self) -> Result<(), Vec<FieldError>> {
+        Validator::new()
+            .length("text", &self.text, 1, MAX_TEXT_LENGTH)
+            .finish()
+    }
+}
+
+// Deleted and removed comments stay as tombstones to keep replies attached
+impl From<CommentMongoDb> for Comment {
+    fn from(comment_mongo_db: CommentMongoDb) -> Self {
+        let is_visible = comment_mongo_db.status == CommentStatus::Visible;
+
+        Self {
+            id: comment_mongo_db._id.to_hex(),
+            recipe_id: comment_mongo_db.recipe_id.to_hex(),
+            parent_id: comment_mongo_db.parent_id.map(|id| id.to_hex()),
+            author_id: is_visible.then(|| comment_mongo_db.author_id.to_hex()),
+            text: if is_visible {
+                comment_mongo_db.text
+            } else {
+                String::new()
+            },
+            status: comment_mongo_db.status,
+            created_at: comment_mongo_db.created_at,
+            modified_at: comment_mongo_db.modified_at,
+            replies: vec![],
+        }
+    }
+}
+
+pub fn build_comment_threads(roots: Vec<Comment>, replies: Vec<Comment>) -> Vec<Comment> {
+    let mut replies_by_parent: HashMap<String, Vec<Comment>> = HashMap::new();
+
+    for reply in replies {
+        if let Some(parent_id) = &reply.parent_id {
+            replies_by_parent
+                .entry(parent_id.clone())
+                .or_default()
+                .push(reply);
+        }
+    }
+
+    fn attach_replies(
+        mut comment: Comment,
+        replies_by_parent: &mut HashMap<String, Vec<Comment>>,
+    ) -> Comment {
+        if let Some(replies) = replies_by_parent.remove(&comment.id) {
+            comment.replies = replies
+                .into_iter()
+                .map(|reply| attach_replies(reply, replies_by_parent))
+                .collect();
+        }
+
+        comment
+    }
+
+    roots
+        .into_iter()
+        .map(|root| attach_replies(root, &mut replies_by_parent))
+        .collect()
+}
+
+#[cfg(test)]
+mod unit_tests_comment_model {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn get_comment_db(parent: Option<&CommentMongoDb>) -> CommentMongoDb {
+        let _id = ObjectId::new();
+
+        CommentMongoDb {
+            _id,
+            recipe_id: ObjectId::new(),
+            thread_id: parent.map(|p| p.thread_id).unwrap_or(_id),
+            parent_id: parent.map(|p| p._id),
+            author_id: ObjectId::new(),
+            text: "Can I swap cashews for sunflower seeds?".into(),
+            status: CommentStatus::Visible,
+            created_at: DateTime::now(),
+            modified_at: DateTime::now(),
+        }
+    }
+
+    #[test]
+    fn comment_mongo_db_into_comment() {
+        struct TestCase {
+            title: String,
+            status: CommentStatus,
+            is_content_visible: bool,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Keeps content of visible comments".into(),
+                status: CommentStatus::Visible,
+                is_content_visible: true,
+            },
+            TestCase {
+                title: "Hides content of deleted comments".into(),
+                status: CommentStatus::Deleted,
+                is_content_visible: false,
+            },
+            TestCase {
+                title: "Hides content of removed comments".into(),
+                status: CommentStatus::Removed,
+                is_content_visible: false,
+            },
+        ];
+
+        for t in test_cases {
+            let mut comment_db = get_comment_db(None);
+            comment_db.status = t.status;
+
+            let comment: Comment = comment_db.clone().into();
+
+            assert_eq!(comment.id, comment_db._id.to_hex(), "{}", t.title);
+            assert_eq!(comment.status, t.status, "{}", t.title);
+            assert_eq!(
+                comment.author_id.is_some(),
+                t.is_content_visible,
+                "{}",
+                t.title
+            );
+            assert_eq!(
+                !comment.text.is_empty(),
+                t.is_content_visible,
+                "{}",
+                t.title
+            );
+        }
+    }
+
+    #[test]
+    fn builds_comment_threads() {
+        let root = get_comment_db(None);
+        let mut deleted_reply = get_comment_db(Some(&root));
+        deleted_reply.status = CommentStatus::Deleted;
+        let nested_reply = get_comment_db(Some(&deleted_reply));
+        let other_root = get_comment_db(None);
+
+        let threads = build_comment_threads(
+            vec![root.clone().into(), other_root.clone().into()],
+            vec![deleted_reply.clone().into(), nested_reply.clone().into()],
+        );
+
+        assert_eq!(threads.len(), 2, "Keeps all root comments");
+        assert_eq!(threads[0].replies.len(), 1, "Attaches reply to its root");
+        assert_eq!(
+            threads[0].replies[0].id,
+            deleted_reply._id.to_hex(),
+            "Keeps deleted reply as tombstone"
+        );
+        assert_eq!(
+            threads[0].replies[0].replies[0].id,
+            nested_reply._id.to_hex(),
+            "Attaches nested reply below the tombstone"
+        );
+        assert!(threads[1].replies.is_empty(), "Keeps other root unchanged");
+    }
+}
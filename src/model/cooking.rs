@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+use super::recipe::Ingredient;
+
+#[derive(Serialize, Deserialize, Copy, Clone, PartialEq, Debug)]
+pub enum TemperatureUnit {
+    Celsius,
+    Fahrenheit,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct StepTimer {
+    /// Part of the step the duration was detected in, e.g. "15 minutes"
+    pub text: String,
+    pub seconds: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct StepTemperature {
+    pub text: String,
+    pub unit: TemperatureUnit,
+    pub celsius: i32,
+    pub fahrenheit: i32,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CookingStep {
+    pub number: usize,
+    pub text: String,
+    pub timers: Vec<StepTimer>,
+    pub temperatures: Vec<StepTemperature>,
+    pub ingredients: Vec<Ingredient>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CookingMode {
+    pub recipe_id: String,
+    pub title: String,
+    pub servings: u32,
+    pub ingredients: Vec<Ingredient>,
+    pub steps: Vec<CookingStep>,
+    pub total_timer_seconds: u32,
+}
@@ -0,0 +1,121 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Serialize, Copy, Clone, PartialEq, Debug)]
+pub enum EventKind {
+    Notification,
+    CommentCreated,
+    ShoppingListUpdated,
+    ShoppingListDeleted,
+}
+
+impl EventKind {
+    /// Name of the server-sent event, so clients can listen per kind
+    pub fn name(&self) -> &'static str {
+        match self {
+            EventKind::Notification => "notification",
+            EventKind::CommentCreated => "comment_created",
+            EventKind::ShoppingListUpdated => "shopping_list_updated",
+            EventKind::ShoppingListDeleted => "shopping_list_deleted",
+        }
+    }
+}
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Event {
+    pub kind: EventKind,
+    /// Users the event is addressed to, events without any go to everyone watching the recipe
+    #[serde(skip)]
+    pub user_ids: Vec<String>,
+    pub recipe_id: Option<String>,
+    pub data: Value,
+}
+
+#[derive(Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct EventsQuery {
+    pub recipe_id: Option<String>,
+}
+
+impl Event {
+    pub fn is_for(&self, user_id: &str, watched_recipe_id: Option<&str>) -> bool {
+        if !self.user_ids.is_empty() {
+            return self.user_ids.iter().any(|id| id == user_id);
+        }
+
+        self.recipe_id.is_some() && self.recipe_id.as_deref() == watched_recipe_id
+    }
+}
+
+#[cfg(test)]
+mod unit_tests_event_model {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn matches_event_audience() {
+        struct TestCase {
+            title: String,
+            user_ids: Vec<String>,
+            recipe_id: Option<String>,
+            watched_recipe_id: Option<String>,
+            is_for_user: bool,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Delivers events addressed to the user".into(),
+                user_ids: vec!["other".into(), "user".into()],
+                recipe_id: None,
+                watched_recipe_id: None,
+                is_for_user: true,
+            },
+            TestCase {
+                title: "Skips events addressed to other users".into(),
+                user_ids: vec!["other".into()],
+                recipe_id: Some("recipe".into()),
+                watched_recipe_id: Some("recipe".into()),
+                is_for_user: false,
+            },
+            TestCase {
+                title: "Delivers recipe events to watchers".into(),
+                user_ids: vec![],
+                recipe_id: Some("recipe".into()),
+                watched_recipe_id: Some("recipe".into()),
+                is_for_user: true,
+            },
+            TestCase {
+                title: "Skips recipe events of other recipes".into(),
+                user_ids: vec![],
+                recipe_id: Some("recipe".into()),
+                watched_recipe_id: Some("other".into()),
+                is_for_user: false,
+            },
+            TestCase {
+                title: "Skips events without an audience".into(),
+                user_ids: vec![],
+                recipe_id: None,
+                watched_recipe_id: None,
+                is_for_user: false,
+            },
+        ];
+
+        for t in test_cases {
+            let event = Event {
+                kind: EventKind::CommentCreated,
+                user_ids: t.user_ids,
+                recipe_id: t.recipe_id,
+                data: json!({}),
+            };
+
+            assert_eq!(
+                event.is_for("user", t.watched_recipe_id.as_deref()),
+                t.is_for_user,
+                "{}",
+                t.title
+            );
+        }
+    }
+}
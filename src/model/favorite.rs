@@ -0,0 +1,166 @@
+use bson::{oid::ObjectId, DateTime};
+use serde::{Deserialize, Serialize};
+
+use super::{recipe::Recipe, serialize_datetime};
+use crate::api::{
+    api_response::FieldError,
+    validation::{Validate, Validator},
+};
+
+const MAX_NAME_LENGTH: usize = 100;
+
+#[derive(Serialize, Deserialize, Copy, Clone, PartialEq, Debug)]
+pub enum CollectionVisibility {
+    Private,
+    Shared,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct RecipeCollectionMongoDb {
+    pub _id: ObjectId,
+    pub name: String,
+    pub recipe_ids: Vec<ObjectId>,
+    pub visibility: CollectionVisibility,
+    pub share_token: String,
+    pub created_at: DateTime,
+    pub modified_at: DateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct FavoritesMongoDb {
+    pub _id: ObjectId,
+    pub user_id: ObjectId,
+    #[serde(default)]
+    pub recipe_ids: Vec<ObjectId>,
+    #[serde(default)]
+    pub collections: Vec<RecipeCollectionMongoDb>,
+    pub created_at: DateTime,
+    pub modified_at: DateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeCollection {
+    pub id: String,
+    pub name: String,
+    pub recipe_ids: Vec<String>,
+    pub visibility: CollectionVisibility,
+    pub share_token: Option<String>,
+    #[serde(serialize_with = "serialize_datetime")]
+    pub created_at: DateTime,
+    #[serde(serialize_with = "serialize_datetime")]
+    pub modified_at: DateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SharedRecipeCollection {
+    pub name: String,
+    pub recipes: Vec<Recipe>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct RecipeCollectionCreate {
+    pub name: String,
+    pub visibility: Option<CollectionVisibility>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct RecipeCollectionPatch {
+    pub name: Option<String>,
+    pub visibility: Option<CollectionVisibility>,
+}
+
+impl Validate for RecipeCollectionCreate {
+    fn validate(&self) -> Result<(), Vec<FieldError>> {
+        Validator::new()
+            .length("name", &self.name, 1, MAX_NAME_LENGTH)
+            .finish()
+    }
+}
+
+impl Validate for RecipeCollectionPatch {
+    fn validate(&self) -> Result<(), Vec<FieldError>> {
+        Validator::new()
+            .optional(self.name.as_deref(), |v, name| {
+                v.length("name", name, 1, MAX_NAME_LENGTH)
+            })
+            .finish()
+    }
+}
+
+impl From<RecipeCollectionMongoDb> for RecipeCollection {
+    fn from(collection_mongo_db: RecipeCollectionMongoDb) -> Self {
+        let is_shared = collection_mongo_db.visibility == CollectionVisibility::Shared;
+
+        Self {
+            id: collection_mongo_db._id.to_hex(),
+            name: collection_mongo_db.name,
+            recipe_ids: collection_mongo_db
+                .recipe_ids
+                .iter()
+                .map(|id| id.to_hex())
+                .collect(),
+            visibility: collection_mongo_db.visibility,
+            share_token: is_shared.then_some(collection_mongo_db.share_token),
+            created_at: collection_mongo_db.created_at,
+            modified_at: collection_mongo_db.modified_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_tests_favorite_model {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn recipe_collection_mongo_db_into_recipe_collection() {
+        struct TestCase {
+            title: String,
+            visibility: CollectionVisibility,
+            expected_share_token: Option<String>,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Exposes share token of shared collections".into(),
+                visibility: CollectionVisibility::Shared,
+                expected_share_token: Some("shareToken".into()),
+            },
+            TestCase {
+                title: "Hides share token of private collections".into(),
+                visibility: CollectionVisibility::Private,
+                expected_share_token: None,
+            },
+        ];
+
+        for t in test_cases {
+            let recipe_id = ObjectId::new();
+            let collection_db = RecipeCollectionMongoDb {
+                _id: ObjectId::new(),
+                name: "Weeknight dinners".into(),
+                recipe_ids: vec![recipe_id],
+                visibility: t.visibility,
+                share_token: "shareToken".into(),
+                created_at: DateTime::now(),
+                modified_at: DateTime::now(),
+            };
+
+            let collection: RecipeCollection = collection_db.clone().into();
+
+            assert_eq!(collection.id, collection_db._id.to_hex(), "{}", t.title);
+            assert_eq!(
+                collection.recipe_ids,
+                vec![recipe_id.to_hex()],
+                "{}",
+                t.title
+            );
+            assert_eq!(
+                collection.share_token, t.expected_share_token,
+                "{}",
+                t.title
+            );
+        }
+    }
+}
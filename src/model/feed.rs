@@ -0,0 +1,123 @@
+use std::{fmt, str::FromStr};
+
+use anyhow::{anyhow, Error, Result};
+use bson::{oid::ObjectId, DateTime};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    pagination::{DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE},
+    serialize_datetime,
+};
+use crate::api::messages::Message;
+
+#[derive(Serialize, Deserialize, Copy, Clone, PartialEq, Debug)]
+pub enum FeedItemKind {
+    RecipePublished,
+    RecipeForked,
+    ReviewCreated,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FeedItem {
+    pub id: String,
+    pub kind: FeedItemKind,
+    pub actor_id: String,
+    pub recipe_id: String,
+    pub title: Option<String>,
+    pub rating: Option<u8>,
+    #[serde(serialize_with = "serialize_datetime")]
+    pub occurred_at: DateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FeedPage {
+    pub items: Vec<FeedItem>,
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FeedQuery {
+    pub cursor: Option<String>,
+    pub limit: Option<u64>,
+}
+
+impl FeedQuery {
+    pub fn limit(&self) -> u64 {
+        self.limit
+            .unwrap_or(DEFAULT_PAGE_SIZE)
+            .clamp(1, MAX_PAGE_SIZE)
+    }
+}
+
+/// Position after the last item of a feed page, opaque to clients
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct FeedCursor {
+    pub occurred_at: DateTime,
+    pub id: ObjectId,
+}
+
+impl fmt::Display for FeedCursor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}_{}",
+            self.occurred_at.timestamp_millis(),
+            self.id.to_hex()
+        )
+    }
+}
+
+impl FromStr for FeedCursor {
+    type Err = Error;
+
+    fn from_str(cursor: &str) -> Result<Self> {
+        let (millis, id) = cursor
+            .split_once('_')
+            .ok_or_else(|| anyhow!(Message::InvalidFeedCursor { cursor: &cursor }.to_string()))?;
+
+        Ok(Self {
+            occurred_at: DateTime::from_millis(millis.parse()?),
+            id: ObjectId::parse_str(id)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod unit_tests_feed_model {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parses_feed_cursor() -> Result<()> {
+        let cursor = FeedCursor {
+            occurred_at: DateTime::from_millis(1_700_000_000_000),
+            id: ObjectId::new(),
+        };
+
+        assert_eq!(cursor.to_string().parse::<FeedCursor>()?, cursor);
+
+        for invalid in ["", "1700000000000", "soon_abc", "1700000000000_abc"] {
+            assert!(
+                invalid.parse::<FeedCursor>().is_err(),
+                "Rejects '{invalid}'"
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn clamps_feed_limit() {
+        let query = |limit| FeedQuery {
+            cursor: None,
+            limit,
+        };
+
+        assert_eq!(query(None).limit(), DEFAULT_PAGE_SIZE);
+        assert_eq!(query(Some(0)).limit(), 1);
+        assert_eq!(query(Some(1000)).limit(), MAX_PAGE_SIZE);
+    }
+}
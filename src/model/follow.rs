@@ -0,0 +1,24 @@
+use bson::{oid::ObjectId, DateTime};
+use serde::{Deserialize, Serialize};
+
+use super::serialize_datetime;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct FollowMongoDb {
+    pub _id: ObjectId,
+    pub follower_id: ObjectId,
+    pub followee_id: ObjectId,
+    pub created_at: DateTime,
+}
+
+/// What anyone can see about a user, without the email address
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PublicProfile {
+    pub id: String,
+    pub follower_count: u64,
+    pub following_count: u64,
+    pub recipe_count: u64,
+    #[serde(serialize_with = "serialize_datetime")]
+    pub created_at: DateTime,
+}
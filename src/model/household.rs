@@ -0,0 +1,271 @@
+use bson::{oid::ObjectId, DateTime};
+use serde::{Deserialize, Serialize};
+
+use super::{serialize_datetime, user::Role};
+use crate::api::{
+    api_response::FieldError,
+    validation::{Validate, Validator},
+};
+
+pub const INVITE_CODE_LENGTH: usize = 8;
+
+pub const MAX_NAME_LENGTH: usize = 100;
+
+/// Owners manage the household, all members edit its meal plans and shopping lists
+#[derive(Serialize, Deserialize, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum HouseholdRole {
+    Member,
+    Owner,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct HouseholdMemberMongoDb {
+    pub user_id: ObjectId,
+    pub role: HouseholdRole,
+    pub joined_at: DateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct HouseholdMongoDb {
+    pub _id: ObjectId,
+    pub name: String,
+    pub invite_code: String,
+    pub members: Vec<HouseholdMemberMongoDb>,
+    pub created_at: DateTime,
+    pub modified_at: DateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HouseholdMember {
+    pub user_id: String,
+    pub role: HouseholdRole,
+    #[serde(serialize_with = "serialize_datetime")]
+    pub joined_at: DateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Household {
+    pub id: String,
+    pub name: String,
+    pub invite_code: String,
+    pub members: Vec<HouseholdMember>,
+    #[serde(serialize_with = "serialize_datetime")]
+    pub created_at: DateTime,
+    #[serde(serialize_with = "serialize_datetime")]
+    pub modified_at: DateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct HouseholdCreate {
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HouseholdJoin {
+    pub invite_code: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct HouseholdMemberPatch {
+    pub role: HouseholdRole,
+}
+
+/// Selects the household whose meal plans and shopping lists a request works on
+#[derive(Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct HouseholdScopeQuery {
+    pub household_id: Option<String>,
+}
+
+/// Whose meal plans and shopping lists are used, the user acts on behalf of the household if set
+#[derive(Clone, PartialEq, Debug)]
+pub struct Owner {
+    pub user_id: String,
+    pub household_id: Option<String>,
+}
+
+impl Owner {
+    pub fn user(user_id: &str) -> Self {
+        Self {
+            user_id: String::from(user_id),
+            household_id: None,
+        }
+    }
+}
+
+impl HouseholdMongoDb {
+    pub fn get_role(&self, user_id: &ObjectId) -> Option<HouseholdRole> {
+        self.members
+            .iter()
+            .find(|member| member.user_id == *user_id)
+            .map(|member| member.role)
+    }
+
+    /// Admins act as owners of every household
+    pub fn has_role(&self, user_id: &ObjectId, role: &Role, required: HouseholdRole) -> bool {
+        *role == Role::Admin || self.get_role(user_id).is_some_and(|r| r >= required)
+    }
+
+    pub fn owner_count(&self) -> usize {
+        self.members
+            .iter()
+            .filter(|member| member.role == HouseholdRole::Owner)
+            .count()
+    }
+}
+
+impl Validate for HouseholdCreate {
+    fn validate(&self) -> Result<(), Vec<FieldError>> {
+        Validator::new()
+            .length("name", &self.name, 1, MAX_NAME_LENGTH)
+            .finish()
+    }
+}
+
+impl Validate for HouseholdJoin {
+    fn validate(&self) -> Result<(), Vec<FieldError>> {
+        Validator::new()
+            .length(
+                "inviteCode",
+                &self.invite_code,
+                INVITE_CODE_LENGTH,
+                INVITE_CODE_LENGTH,
+            )
+            .finish()
+    }
+}
+
+// The role is one of the enum variants, which serde already checks
+impl Validate for HouseholdMemberPatch {
+    fn validate(&self) -> Result<(), Vec<FieldError>> {
+        Ok(())
+    }
+}
+
+impl From<HouseholdMemberMongoDb> for HouseholdMember {
+    fn from(member_mongo_db: HouseholdMemberMongoDb) -> Self {
+        Self {
+            user_id: member_mongo_db.user_id.to_hex(),
+            role: member_mongo_db.role,
+            joined_at: member_mongo_db.joined_at,
+        }
+    }
+}
+
+impl From<HouseholdMongoDb> for Household {
+    fn from(household_mongo_db: HouseholdMongoDb) -> Self {
+        Self {
+            id: household_mongo_db._id.to_hex(),
+            name: household_mongo_db.name,
+            invite_code: household_mongo_db.invite_code,
+            members: household_mongo_db
+                .members
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            created_at: household_mongo_db.created_at,
+            modified_at: household_mongo_db.modified_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_tests_household_model {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn checks_household_roles() {
+        let owner_id = ObjectId::new();
+        let member_id = ObjectId::new();
+        let stranger_id = ObjectId::new();
+
+        let household = HouseholdMongoDb {
+            _id: ObjectId::new(),
+            name: "Flat share".into(),
+            invite_code: "ABCD1234".into(),
+            members: vec![
+                HouseholdMemberMongoDb {
+                    user_id: owner_id,
+                    role: HouseholdRole::Owner,
+                    joined_at: DateTime::now(),
+                },
+                HouseholdMemberMongoDb {
+                    user_id: member_id,
+                    role: HouseholdRole::Member,
+                    joined_at: DateTime::now(),
+                },
+            ],
+            created_at: DateTime::now(),
+            modified_at: DateTime::now(),
+        };
+
+        struct TestCase {
+            title: String,
+            user_id: ObjectId,
+            role: Role,
+            required: HouseholdRole,
+            has_role: bool,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Owners are members".into(),
+                user_id: owner_id,
+                role: Role::User,
+                required: HouseholdRole::Member,
+                has_role: true,
+            },
+            TestCase {
+                title: "Members are no owners".into(),
+                user_id: member_id,
+                role: Role::User,
+                required: HouseholdRole::Owner,
+                has_role: false,
+            },
+            TestCase {
+                title: "Strangers are no members".into(),
+                user_id: stranger_id,
+                role: Role::User,
+                required: HouseholdRole::Member,
+                has_role: false,
+            },
+            TestCase {
+                title: "Admins act as owners".into(),
+                user_id: stranger_id,
+                role: Role::Admin,
+                required: HouseholdRole::Owner,
+                has_role: true,
+            },
+        ];
+
+        for t in test_cases {
+            assert_eq!(
+                household.has_role(&t.user_id, &t.role, t.required),
+                t.has_role,
+                "{}",
+                t.title
+            );
+        }
+
+        assert_eq!(household.owner_count(), 1);
+    }
+
+    #[test]
+    fn validates_invite_codes() {
+        let is_valid = |invite_code: &str| {
+            HouseholdJoin {
+                invite_code: invite_code.into(),
+            }
+            .validate()
+            .is_ok()
+        };
+
+        assert!(is_valid("AB12CD34"), "Accepts generated codes");
+        assert!(!is_valid("AB12"), "Rejects short codes");
+        assert!(!is_valid(""), "Rejects empty codes");
+    }
+}
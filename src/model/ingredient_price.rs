@@ -0,0 +1,155 @@
+use bson::{oid::ObjectId, DateTime};
+use serde::{Deserialize, Serialize};
+
+use super::serialize_datetime;
+use crate::api::{
+    api_response::FieldError,
+    messages::Message,
+    validation::{Validate, Validator},
+};
+
+pub const DEFAULT_CURRENCY: &str = "EUR";
+
+const MAX_INGREDIENT_LENGTH: usize = 100;
+const MAX_CURRENCY_LENGTH: usize = 3;
+
+/// Price of one unit of an ingredient, or of one piece if there is no unit
+#[derive(Serialize, Deserialize, Clone)]
+pub struct IngredientPriceMongoDb {
+    pub _id: ObjectId,
+    pub ingredient: String,
+    pub unit: Option<String>,
+    pub price: f64,
+    pub currency: String,
+    /// Prices without region apply everywhere
+    pub region: Option<String>,
+    pub created_at: DateTime,
+    pub modified_at: DateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IngredientPrice {
+    pub id: String,
+    pub ingredient: String,
+    pub unit: Option<String>,
+    pub price: f64,
+    pub currency: String,
+    pub region: Option<String>,
+    #[serde(serialize_with = "serialize_datetime")]
+    pub created_at: DateTime,
+    #[serde(serialize_with = "serialize_datetime")]
+    pub modified_at: DateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IngredientPriceCreate {
+    pub ingredient: String,
+    pub unit: Option<String>,
+    pub price: f64,
+    pub currency: String,
+    pub region: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct IngredientPricePatch {
+    pub ingredient: Option<String>,
+    pub unit: Option<String>,
+    pub price: Option<f64>,
+    pub currency: Option<String>,
+    pub region: Option<String>,
+}
+
+/// Filters prices, a region also matches the prices that apply everywhere
+#[derive(Deserialize, Clone, Default)]
+pub struct IngredientPriceQuery {
+    pub ingredient: Option<String>,
+    pub currency: Option<String>,
+    pub region: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeCost {
+    pub currency: String,
+    pub total: f64,
+    pub per_serving: f64,
+    /// Ingredients with a quantity but without a matching price, the estimate is too low if any
+    pub missing_ingredients: Vec<String>,
+}
+
+/// Currency and region to estimate recipe costs in
+#[derive(Deserialize, Clone, Default)]
+pub struct CostQuery {
+    pub currency: Option<String>,
+    pub region: Option<String>,
+}
+
+impl CostQuery {
+    pub fn currency(&self) -> String {
+        self.currency
+            .as_deref()
+            .unwrap_or(DEFAULT_CURRENCY)
+            .trim()
+            .to_uppercase()
+    }
+
+    pub fn region(&self) -> Option<String> {
+        let region = self.region.as_deref()?.trim().to_lowercase();
+
+        (!region.is_empty()).then_some(region)
+    }
+}
+
+impl RecipeCost {
+    pub fn is_complete(&self) -> bool {
+        self.missing_ingredients.is_empty()
+    }
+}
+
+fn is_valid_price(price: f64) -> bool {
+    price.is_finite() && price >= 0.0
+}
+
+impl Validate for IngredientPriceCreate {
+    fn validate(&self) -> Result<(), Vec<FieldError>> {
+        Validator::new()
+            .length("ingredient", &self.ingredient, 1, MAX_INGREDIENT_LENGTH)
+            .length("currency", &self.currency, 1, MAX_CURRENCY_LENGTH)
+            .check("price", is_valid_price(self.price), Message::NegativePrice)
+            .finish()
+    }
+}
+
+impl Validate for IngredientPricePatch {
+    fn validate(&self) -> Result<(), Vec<FieldError>> {
+        Validator::new()
+            .optional(self.ingredient.as_deref(), |v, ingredient| {
+                v.length("ingredient", ingredient, 1, MAX_INGREDIENT_LENGTH)
+            })
+            .optional(self.currency.as_deref(), |v, currency| {
+                v.length("currency", currency, 1, MAX_CURRENCY_LENGTH)
+            })
+            .optional(self.price, |v, price| {
+                v.check("price", is_valid_price(price), Message::NegativePrice)
+            })
+            .finish()
+    }
+}
+
+impl From<IngredientPriceMongoDb> for IngredientPrice {
+    fn from(price_mongo_db: IngredientPriceMongoDb) -> Self {
+        Self {
+            id: price_mongo_db._id.to_hex(),
+            ingredient: price_mongo_db.ingredient,
+            unit: price_mongo_db.unit,
+            price: price_mongo_db.price,
+            currency: price_mongo_db.currency,
+            region: price_mongo_db.region,
+            created_at: price_mongo_db.created_at,
+            modified_at: price_mongo_db.modified_at,
+        }
+    }
+}
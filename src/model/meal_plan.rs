@@ -0,0 +1,385 @@
+use std::{collections::HashMap, fmt, str::FromStr};
+
+use anyhow::{anyhow, Error, Result};
+use bson::{oid::ObjectId, DateTime};
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+
+use super::recipe::{Nutrition, Recipe};
+use crate::api::{
+    api_response::FieldError,
+    messages::Message,
+    validation::{Validate, Validator},
+};
+
+const MAX_SERVINGS: u32 = 100;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct IsoWeek {
+    pub year: i32,
+    pub week: u32,
+}
+
+impl IsoWeek {
+    pub fn first_day(&self) -> NaiveDate {
+        // Weeks are validated when parsed
+        NaiveDate::from_isoywd_opt(self.year, self.week, Weekday::Mon).unwrap_or_default()
+    }
+
+    pub fn previous(&self) -> Self {
+        let iso_week = (self.first_day() - Duration::weeks(1)).iso_week();
+
+        Self {
+            year: iso_week.year(),
+            week: iso_week.week(),
+        }
+    }
+}
+
+impl FromStr for IsoWeek {
+    type Err = Error;
+
+    fn from_str(week: &str) -> Result<Self> {
+        let (year, week_number) = week
+            .split_once("-W")
+            .ok_or_else(|| anyhow!(Message::InvalidWeekFormat { week: &week }.to_string()))?;
+
+        let year = year.parse::<i32>()?;
+        let week_number = week_number.parse::<u32>()?;
+
+        match NaiveDate::from_isoywd_opt(year, week_number, Weekday::Mon) {
+            Some(_) => Ok(Self {
+                year,
+                week: week_number,
+            }),
+            None => Err(anyhow!(
+                Message::WeekDoesNotExist { week: &week }.to_string()
+            )),
+        }
+    }
+}
+
+impl fmt::Display for IsoWeek {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-W{:02}", self.year, self.week)
+    }
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub enum DayOfWeek {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl DayOfWeek {
+    pub const ALL: [DayOfWeek; 7] = [
+        DayOfWeek::Monday,
+        DayOfWeek::Tuesday,
+        DayOfWeek::Wednesday,
+        DayOfWeek::Thursday,
+        DayOfWeek::Friday,
+        DayOfWeek::Saturday,
+        DayOfWeek::Sunday,
+    ];
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum MealSlot {
+    Breakfast,
+    Lunch,
+    Dinner,
+    Snack,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct MealPlanEntryMongoDb {
+    pub _id: ObjectId,
+    pub day: DayOfWeek,
+    pub slot: MealSlot,
+    pub recipe_id: ObjectId,
+    pub servings: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct MealPlanMongoDb {
+    pub _id: ObjectId,
+    /// Creator of the plan, shared plans belong to the household
+    pub user_id: ObjectId,
+    #[serde(default)]
+    pub household_id: Option<ObjectId>,
+    pub week: String,
+    #[serde(default)]
+    pub entries: Vec<MealPlanEntryMongoDb>,
+    pub created_at: DateTime,
+    pub modified_at: DateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MealPlanEntry {
+    pub id: String,
+    pub day: DayOfWeek,
+    pub slot: MealSlot,
+    pub recipe_id: String,
+    pub recipe_title: Option<String>,
+    pub servings: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct DailyNutrition {
+    pub day: DayOfWeek,
+    pub date: String,
+    pub nutrition: Nutrition,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MealPlan {
+    pub week: String,
+    pub entries: Vec<MealPlanEntry>,
+    pub daily_nutrition: Vec<DailyNutrition>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MealPlanEntryCreate {
+    pub day: DayOfWeek,
+    pub slot: MealSlot,
+    pub recipe_id: String,
+    pub servings: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct MealPlanEntryPatch {
+    pub day: Option<DayOfWeek>,
+    pub slot: Option<MealSlot>,
+    pub servings: Option<u32>,
+}
+
+impl Validate for MealPlanEntryCreate {
+    fn validate(&self) -> Result<(), Vec<FieldError>> {
+        Validator::new()
+            .range("servings", self.servings, 1, MAX_SERVINGS)
+            .finish()
+    }
+}
+
+impl Validate for MealPlanEntryPatch {
+    fn validate(&self) -> Result<(), Vec<FieldError>> {
+        Validator::new()
+            .optional(self.servings, |v, servings| {
+                v.range("servings", servings, 1, MAX_SERVINGS)
+            })
+            .finish()
+    }
+}
+
+#[derive(Deserialize, Clone, Default)]
+pub struct MealPlanCopyQuery {
+    pub from: Option<String>,
+}
+
+pub fn build_meal_plan(
+    week: &IsoWeek,
+    entries: Vec<MealPlanEntryMongoDb>,
+    recipes: &[Recipe],
+) -> MealPlan {
+    let recipes_by_id = recipes
+        .iter()
+        .map(|recipe| (recipe.id.clone(), recipe))
+        .collect::<HashMap<String, &Recipe>>();
+
+    let mut nutrition_by_day: HashMap<DayOfWeek, Nutrition> = HashMap::new();
+
+    let mut entries = entries
+        .into_iter()
+        .map(|entry| {
+            let recipe_id = entry.recipe_id.to_hex();
+            let recipe = recipes_by_id.get(&recipe_id);
+
+            // Recipe nutrition is stored per serving
+            if let Some(nutrition) = recipe.and_then(|r| r.nutrition) {
+                *nutrition_by_day.entry(entry.day).or_default() +=
+                    nutrition.scale(entry.servings as f64);
+            }
+
+            MealPlanEntry {
+                id: entry._id.to_hex(),
+                day: entry.day,
+                slot: entry.slot,
+                recipe_id,
+                recipe_title: recipe.map(|r| r.title.clone()),
+                servings: entry.servings,
+            }
+        })
+        .collect::<Vec<MealPlanEntry>>();
+    entries.sort_by_key(|entry| (entry.day, entry.slot));
+
+    let first_day = week.first_day();
+    let daily_nutrition = DayOfWeek::ALL
+        .iter()
+        .enumerate()
+        .map(|(offset, day)| DailyNutrition {
+            day: *day,
+            date: (first_day + Duration::days(offset as i64)).to_string(),
+            nutrition: nutrition_by_day.remove(day).unwrap_or_default(),
+        })
+        .collect();
+
+    MealPlan {
+        week: week.to_string(),
+        entries,
+        daily_nutrition,
+    }
+}
+
+#[cfg(test)]
+mod unit_tests_meal_plan_model {
+    use crate::test_utils::get_random_recipe_db;
+
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parses_iso_week() {
+        struct TestCase {
+            title: String,
+            week: String,
+            expected_week: Option<IsoWeek>,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Parses a valid week".into(),
+                week: "2026-W42".into(),
+                expected_week: Some(IsoWeek {
+                    year: 2026,
+                    week: 42,
+                }),
+            },
+            TestCase {
+                title: "Parses week 53 in a long year".into(),
+                week: "2026-W53".into(),
+                expected_week: Some(IsoWeek {
+                    year: 2026,
+                    week: 53,
+                }),
+            },
+            TestCase {
+                title: "Rejects week 53 in a short year".into(),
+                week: "2025-W53".into(),
+                expected_week: None,
+            },
+            TestCase {
+                title: "Rejects a date".into(),
+                week: "2026-10-18".into(),
+                expected_week: None,
+            },
+        ];
+
+        for t in test_cases {
+            let week = t.week.parse::<IsoWeek>().ok();
+
+            assert_eq!(week, t.expected_week, "{}", t.title);
+        }
+    }
+
+    #[test]
+    fn gets_previous_iso_week() {
+        struct TestCase {
+            title: String,
+            week: IsoWeek,
+            expected_previous: String,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Gets previous week within a year".into(),
+                week: IsoWeek {
+                    year: 2026,
+                    week: 42,
+                },
+                expected_previous: "2026-W41".into(),
+            },
+            TestCase {
+                title: "Gets previous week across years".into(),
+                week: IsoWeek {
+                    year: 2027,
+                    week: 1,
+                },
+                expected_previous: "2026-W53".into(),
+            },
+        ];
+
+        for t in test_cases {
+            assert_eq!(
+                t.week.previous().to_string(),
+                t.expected_previous,
+                "{}",
+                t.title
+            );
+        }
+    }
+
+    #[test]
+    fn builds_meal_plan_with_daily_nutrition() {
+        let mut recipe_db = get_random_recipe_db(None, None);
+        recipe_db.nutrition = Some(Nutrition {
+            calories: 400.0,
+            protein: 20.0,
+            carbohydrates: 50.0,
+            fat: 12.0,
+            fiber: 8.0,
+        });
+        let recipe: Recipe = recipe_db.clone().into();
+
+        let entries = vec![
+            MealPlanEntryMongoDb {
+                _id: ObjectId::new(),
+                day: DayOfWeek::Tuesday,
+                slot: MealSlot::Dinner,
+                recipe_id: recipe_db._id,
+                servings: 2,
+            },
+            MealPlanEntryMongoDb {
+                _id: ObjectId::new(),
+                day: DayOfWeek::Tuesday,
+                slot: MealSlot::Lunch,
+                recipe_id: recipe_db._id,
+                servings: 1,
+            },
+            MealPlanEntryMongoDb {
+                _id: ObjectId::new(),
+                day: DayOfWeek::Monday,
+                slot: MealSlot::Lunch,
+                recipe_id: ObjectId::new(),
+                servings: 1,
+            },
+        ];
+
+        let week = IsoWeek {
+            year: 2026,
+            week: 42,
+        };
+        let meal_plan = build_meal_plan(&week, entries, &[recipe]);
+
+        assert_eq!(meal_plan.week, "2026-W42");
+        assert_eq!(meal_plan.entries[0].day, DayOfWeek::Monday, "Sorts by day");
+        assert_eq!(meal_plan.entries[0].recipe_title, None, "Unknown recipe");
+        assert_eq!(meal_plan.entries[1].slot, MealSlot::Lunch, "Sorts by slot");
+        assert_eq!(meal_plan.daily_nutrition.len(), 7);
+        assert_eq!(meal_plan.daily_nutrition[0].date, "2026-10-12");
+        assert_eq!(
+            meal_plan.daily_nutrition[0].nutrition,
+            Nutrition::default(),
+            "Ignores recipes without nutrition"
+        );
+        assert_eq!(meal_plan.daily_nutrition[1].nutrition.calories, 1200.0);
+        assert_eq!(meal_plan.daily_nutrition[1].nutrition.fiber, 24.0);
+    }
+}
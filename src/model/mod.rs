@@ -1 +1,43 @@
+use bson::DateTime;
+use serde::Serializer;
+
+pub mod comment;
+pub mod cooking;
+pub mod event;
+pub mod favorite;
+pub mod feed;
+pub mod follow;
+pub mod household;
+pub mod ingredient_price;
+pub mod meal_plan;
+pub mod notification;
+pub mod pagination;
+pub mod pantry;
+pub mod recipe;
+pub mod recipe_revision;
+pub mod report;
+pub mod review;
+pub mod season;
+pub mod shopping_list;
+pub mod substitution;
 pub mod user;
+
+pub fn serialize_datetime<S>(date: &DateTime, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&date.to_string())
+}
+
+pub fn serialize_optional_datetime<S>(
+    date: &Option<DateTime>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match date {
+        Some(date) => serializer.serialize_some(&date.to_string()),
+        None => serializer.serialize_none(),
+    }
+}
@@ -0,0 +1,152 @@
+use bson::{oid::ObjectId, DateTime};
+use serde::{Deserialize, Serialize};
+
+use super::serialize_datetime;
+use crate::api::messages::Message;
+
+#[derive(Serialize, Deserialize, Copy, Clone, PartialEq, Debug)]
+pub enum NotificationKind {
+    ReviewCreated,
+    NewFollower,
+    RecipeForked,
+    ReportResolved,
+}
+
+impl NotificationKind {
+    pub fn message(&self) -> Message<'static> {
+        match self {
+            NotificationKind::ReviewCreated => Message::ReviewCreatedNotification,
+            NotificationKind::NewFollower => Message::NewFollowerNotification,
+            NotificationKind::RecipeForked => Message::RecipeForkedNotification,
+            NotificationKind::ReportResolved => Message::ReportResolvedNotification,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationSetting {
+    pub in_app: bool,
+    pub email_digest: bool,
+}
+
+impl Default for NotificationSetting {
+    fn default() -> Self {
+        Self {
+            in_app: true,
+            email_digest: false,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationPreferences {
+    #[serde(default)]
+    pub review_created: NotificationSetting,
+    #[serde(default)]
+    pub new_follower: NotificationSetting,
+    #[serde(default)]
+    pub recipe_forked: NotificationSetting,
+    #[serde(default)]
+    pub report_resolved: NotificationSetting,
+}
+
+impl NotificationPreferences {
+    pub fn get(&self, kind: NotificationKind) -> NotificationSetting {
+        match kind {
+            NotificationKind::ReviewCreated => self.review_created,
+            NotificationKind::NewFollower => self.new_follower,
+            NotificationKind::RecipeForked => self.recipe_forked,
+            NotificationKind::ReportResolved => self.report_resolved,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct NotificationMongoDb {
+    pub _id: ObjectId,
+    pub user_id: ObjectId,
+    pub kind: NotificationKind,
+    pub actor_id: Option<ObjectId>,
+    pub recipe_id: Option<ObjectId>,
+    pub report_id: Option<ObjectId>,
+    pub is_in_app: bool,
+    pub is_read: bool,
+    /// Waiting for the next email digest
+    pub is_email_pending: bool,
+    pub created_at: DateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Notification {
+    pub id: String,
+    pub kind: NotificationKind,
+    pub message: String,
+    pub actor_id: Option<String>,
+    pub recipe_id: Option<String>,
+    pub report_id: Option<String>,
+    pub is_read: bool,
+    #[serde(serialize_with = "serialize_datetime")]
+    pub created_at: DateTime,
+}
+
+#[derive(Clone, Debug)]
+pub struct NotificationCreate {
+    pub kind: NotificationKind,
+    pub actor_id: Option<String>,
+    pub recipe_id: Option<String>,
+    pub report_id: Option<String>,
+}
+
+#[derive(Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationQuery {
+    #[serde(default)]
+    pub unread_only: bool,
+}
+
+impl From<NotificationMongoDb> for Notification {
+    fn from(notification_mongo_db: NotificationMongoDb) -> Self {
+        Self {
+            id: notification_mongo_db._id.to_hex(),
+            kind: notification_mongo_db.kind,
+            // Listed by the recipient, so the locale of the request is theirs
+            message: notification_mongo_db.kind.message().to_string(),
+            actor_id: notification_mongo_db.actor_id.map(|id| id.to_hex()),
+            recipe_id: notification_mongo_db.recipe_id.map(|id| id.to_hex()),
+            report_id: notification_mongo_db.report_id.map(|id| id.to_hex()),
+            is_read: notification_mongo_db.is_read,
+            created_at: notification_mongo_db.created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_tests_notification_model {
+    use super::*;
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn deserialize_notification_preferences() -> Result<()> {
+        let preferences: NotificationPreferences =
+            serde_json::from_str(r#"{"newFollower":{"inApp":false,"emailDigest":true}}"#)?;
+
+        assert_eq!(
+            preferences.get(NotificationKind::NewFollower),
+            NotificationSetting {
+                in_app: false,
+                email_digest: true,
+            }
+        );
+        assert_eq!(
+            preferences.get(NotificationKind::ReviewCreated),
+            NotificationSetting::default(),
+            "Defaults missing kinds to in-app only"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,106 @@
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_PAGE_SIZE: u64 = 20;
+pub const MAX_PAGE_SIZE: u64 = 100;
+
+#[derive(Deserialize, Clone, Copy, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Pagination {
+    #[serde(default = "default_page")]
+    pub page: u64,
+    #[serde(default = "default_page_size")]
+    pub page_size: u64,
+}
+
+fn default_page() -> u64 {
+    1
+}
+
+fn default_page_size() -> u64 {
+    DEFAULT_PAGE_SIZE
+}
+
+impl Default for Pagination {
+    fn default() -> Self {
+        Self {
+            page: default_page(),
+            page_size: default_page_size(),
+        }
+    }
+}
+
+impl Pagination {
+    pub fn limit(&self) -> u64 {
+        self.page_size.clamp(1, MAX_PAGE_SIZE)
+    }
+
+    pub fn skip(&self) -> u64 {
+        (self.page.max(1) - 1) * self.limit()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub page: u64,
+    pub page_size: u64,
+    pub total: u64,
+}
+
+#[cfg(test)]
+mod unit_tests_pagination_model {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn computes_skip_and_limit() {
+        struct TestCase {
+            title: String,
+            pagination: Pagination,
+            expected_skip: u64,
+            expected_limit: u64,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Starts at the first page by default".into(),
+                pagination: Pagination::default(),
+                expected_skip: 0,
+                expected_limit: DEFAULT_PAGE_SIZE,
+            },
+            TestCase {
+                title: "Skips previous pages".into(),
+                pagination: Pagination {
+                    page: 3,
+                    page_size: 10,
+                },
+                expected_skip: 20,
+                expected_limit: 10,
+            },
+            TestCase {
+                title: "Treats page zero as the first page".into(),
+                pagination: Pagination {
+                    page: 0,
+                    page_size: 10,
+                },
+                expected_skip: 0,
+                expected_limit: 10,
+            },
+            TestCase {
+                title: "Caps the page size".into(),
+                pagination: Pagination {
+                    page: 1,
+                    page_size: 1000,
+                },
+                expected_skip: 0,
+                expected_limit: MAX_PAGE_SIZE,
+            },
+        ];
+
+        for t in test_cases {
+            assert_eq!(t.pagination.skip(), t.expected_skip, "{}", t.title);
+            assert_eq!(t.pagination.limit(), t.expected_limit, "{}", t.title);
+        }
+    }
+}
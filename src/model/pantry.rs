@@ -0,0 +1,238 @@
+use anyhow::Result;
+use bson::{oid::ObjectId, DateTime};
+use chrono::{NaiveDate, NaiveTime};
+use serde::{Deserialize, Serialize};
+
+use super::recipe::{Ingredient, Recipe};
+use crate::api::{
+    api_response::FieldError,
+    messages::Message,
+    validation::{Validate, Validator},
+};
+
+const MAX_NAME_LENGTH: usize = 100;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PantryItemMongoDb {
+    pub _id: ObjectId,
+    pub name: String,
+    pub quantity: Option<f64>,
+    pub unit: Option<String>,
+    pub expires_at: Option<DateTime>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PantryMongoDb {
+    pub _id: ObjectId,
+    pub user_id: ObjectId,
+    #[serde(default)]
+    pub items: Vec<PantryItemMongoDb>,
+    pub created_at: DateTime,
+    pub modified_at: DateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PantryItem {
+    pub id: String,
+    pub name: String,
+    pub quantity: Option<f64>,
+    pub unit: Option<String>,
+    pub expires_on: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PantryItemCreate {
+    pub name: String,
+    pub quantity: Option<f64>,
+    pub unit: Option<String>,
+    pub expires_on: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PantryItemPatch {
+    pub name: Option<String>,
+    pub quantity: Option<f64>,
+    pub unit: Option<String>,
+    pub expires_on: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PantryRecipeMatch {
+    pub recipe: Recipe,
+    pub coverage: f64,
+    pub missing_ingredients: Vec<Ingredient>,
+    pub expiring_ingredients: Vec<String>,
+}
+
+#[derive(Deserialize, Clone, Default)]
+pub struct PantryMatchQuery {
+    pub limit: Option<usize>,
+}
+
+pub fn parse_expiry_date(date: &str) -> Result<DateTime> {
+    let date = NaiveDate::parse_from_str(date, "%Y-%m-%d")?;
+
+    Ok(DateTime::from_chrono(
+        date.and_time(NaiveTime::MIN).and_utc(),
+    ))
+}
+
+fn check_expiry_date(validator: Validator, date: &str) -> Validator {
+    match parse_expiry_date(date) {
+        Ok(_) => validator,
+        Err(err) => validator.check("expiresOn", false, Message::InvalidExpiryDate { err: &err }),
+    }
+}
+
+impl Validate for PantryItemCreate {
+    fn validate(&self) -> Result<(), Vec<FieldError>> {
+        Validator::new()
+            .length("name", &self.name, 1, MAX_NAME_LENGTH)
+            .optional(self.expires_on.as_deref(), check_expiry_date)
+            .finish()
+    }
+}
+
+// An empty expiry date removes the one set before
+impl Validate for PantryItemPatch {
+    fn validate(&self) -> Result<(), Vec<FieldError>> {
+        Validator::new()
+            .optional(self.name.as_deref(), |v, name| {
+                v.length("name", name, 1, MAX_NAME_LENGTH)
+            })
+            .optional(
+                self.expires_on.as_deref().filter(|date| !date.is_empty()),
+                check_expiry_date,
+            )
+            .finish()
+    }
+}
+
+impl From<PantryItemMongoDb> for PantryItem {
+    fn from(item_mongo_db: PantryItemMongoDb) -> Self {
+        Self {
+            id: item_mongo_db._id.to_hex(),
+            name: item_mongo_db.name,
+            quantity: item_mongo_db.quantity,
+            unit: item_mongo_db.unit,
+            expires_on: item_mongo_db
+                .expires_at
+                .map(|date| date.to_chrono().date_naive().to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_tests_pantry_model {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn pantry_item_mongo_db_into_pantry_item() -> Result<()> {
+        struct TestCase {
+            title: String,
+            expires_on: Option<String>,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Converts item with expiry date".into(),
+                expires_on: Some("2026-10-20".into()),
+            },
+            TestCase {
+                title: "Converts item without expiry date".into(),
+                expires_on: None,
+            },
+        ];
+
+        for t in test_cases {
+            let item_db = PantryItemMongoDb {
+                _id: ObjectId::new(),
+                name: "Chickpeas".into(),
+                quantity: Some(400.0),
+                unit: Some("g".into()),
+                expires_at: t
+                    .expires_on
+                    .as_ref()
+                    .map(|date| parse_expiry_date(date))
+                    .transpose()?,
+            };
+
+            let item: PantryItem = item_db.clone().into();
+
+            assert_eq!(item.id, item_db._id.to_hex(), "{}", t.title);
+            assert_eq!(item.expires_on, t.expires_on, "{}", t.title);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn validates_pantry_item_payloads() {
+        struct TestCase {
+            title: String,
+            name: Option<String>,
+            expires_on: Option<String>,
+            expected_fields: Vec<String>,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Accepts an item with expiry date".into(),
+                name: Some("Chickpeas".into()),
+                expires_on: Some("2026-10-20".into()),
+                expected_fields: vec![],
+            },
+            TestCase {
+                title: "Rejects a blank name".into(),
+                name: Some("  ".into()),
+                expires_on: None,
+                expected_fields: vec!["name".into()],
+            },
+            TestCase {
+                title: "Rejects an invalid expiry date".into(),
+                name: Some("Chickpeas".into()),
+                expires_on: Some("20.10.2026".into()),
+                expected_fields: vec!["expiresOn".into()],
+            },
+            TestCase {
+                title: "Accepts an empty expiry date in patches only".into(),
+                name: None,
+                expires_on: Some("".into()),
+                expected_fields: vec![],
+            },
+        ];
+
+        for t in test_cases {
+            let errors = match &t.name {
+                Some(name) => PantryItemCreate {
+                    name: name.clone(),
+                    quantity: None,
+                    unit: None,
+                    expires_on: t.expires_on.clone(),
+                }
+                .validate(),
+                None => PantryItemPatch {
+                    expires_on: t.expires_on.clone(),
+                    ..Default::default()
+                }
+                .validate(),
+            }
+            .err()
+            .unwrap_or_default();
+
+            let fields: Vec<String> = errors.into_iter().map(|error| error.field).collect();
+            assert_eq!(fields, t.expected_fields, "{}", t.title);
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_expiry_date() {
+        assert!(parse_expiry_date("20.10.2026").is_err());
+        assert!(parse_expiry_date("2026-02-30").is_err());
+    }
+}
@@ -0,0 +1,996 @@
+use std::{
+    collections::BTreeMap,
+    ops::{Add, AddAssign},
+    sync::LazyLock,
+};
+
+use bson::{oid::ObjectId, DateTime};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    ingredient_price::{CostQuery, RecipeCost},
+    serialize_datetime, serialize_optional_datetime,
+    substitution::SuggestedSubstitution,
+    user::Role,
+};
+use crate::api::{
+    api_response::FieldError,
+    messages::Message,
+    services::{locale_service::is_valid_locale, season_service::IN_SEASON_MIN_SCORE},
+    validation::{Validate, Validator},
+};
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct Ingredient {
+    pub name: String,
+    pub quantity: Option<f64>,
+    pub unit: Option<String>,
+    pub note: Option<String>,
+}
+
+pub const DEFAULT_RECIPE_LOCALE: &str = "en";
+
+const MAX_TITLE_LENGTH: usize = 200;
+const MAX_DESCRIPTION_LENGTH: usize = 5000;
+const MAX_INGREDIENT_COUNT: usize = 100;
+const MAX_INGREDIENT_NAME_LENGTH: usize = 100;
+const MAX_UNIT_LENGTH: usize = 32;
+const MAX_NOTE_LENGTH: usize = 500;
+const MAX_QUANTITY: f64 = 100_000.0;
+const MAX_STEP_COUNT: usize = 100;
+const MAX_STEP_LENGTH: usize = 5000;
+const MAX_SERVINGS: u32 = 100;
+/// A week, enough for anything fermented
+const MAX_TIME_MINUTES: u32 = 10_080;
+const MAX_NUTRIENT_AMOUNT: f64 = 100_000.0;
+
+/// Units like "g", "fl oz", "Stk." or "%"
+static UNIT_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[\p{L}\p{N} ./%-]*$").expect("Valid unit regex"));
+
+/// Translated texts of a recipe, the original ones are used for everything left out
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+pub struct RecipeTranslation {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    /// Notes of the ingredients by position
+    #[serde(default)]
+    pub notes: Vec<Option<String>>,
+    #[serde(default)]
+    pub steps: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug, Default)]
+pub struct Nutrition {
+    pub calories: f64,
+    pub protein: f64,
+    pub carbohydrates: f64,
+    pub fat: f64,
+    pub fiber: f64,
+}
+
+impl Nutrition {
+    pub fn scale(&self, factor: f64) -> Self {
+        Self {
+            calories: self.calories * factor,
+            protein: self.protein * factor,
+            carbohydrates: self.carbohydrates * factor,
+            fat: self.fat * factor,
+            fiber: self.fiber * factor,
+        }
+    }
+}
+
+impl Add for Nutrition {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            calories: self.calories + other.calories,
+            protein: self.protein + other.protein,
+            carbohydrates: self.carbohydrates + other.carbohydrates,
+            fat: self.fat + other.fat,
+            fiber: self.fiber + other.fiber,
+        }
+    }
+}
+
+impl AddAssign for Nutrition {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, PartialEq, Debug, Default)]
+pub enum RecipeStatus {
+    Draft,
+    Private,
+    Unlisted,
+    #[default]
+    Published,
+    Archived,
+    /// Hidden by a moderator, only admins can change the status again
+    Removed,
+}
+
+impl RecipeStatus {
+    /// Published and unlisted recipes can be read by anyone with a link
+    pub fn is_public(&self) -> bool {
+        matches!(self, RecipeStatus::Published | RecipeStatus::Unlisted)
+    }
+
+    /// Checks a status set on create or patch, recipes are published through their own
+    /// endpoint and only admins remove them
+    pub fn check_requested(&self, role: &Role) -> Result<(), Message<'static>> {
+        match self {
+            RecipeStatus::Published => Err(Message::PublishThroughEndpoint),
+            RecipeStatus::Removed if *role != Role::Admin => Err(Message::RemoveThroughReports),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn default_create_status() -> RecipeStatus {
+    RecipeStatus::Draft
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct VeganCheck {
+    pub is_vegan: bool,
+    pub violations: Vec<String>,
+}
+
+/// A recipe a fork was derived from, copied so the credit survives the original being deleted
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct RecipeAttributionMongoDb {
+    pub recipe_id: ObjectId,
+    pub author_id: ObjectId,
+    pub title: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeAttribution {
+    pub recipe_id: String,
+    pub author_id: String,
+    pub title: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct RecipeMongoDb {
+    pub _id: ObjectId,
+    pub title: String,
+    pub description: String,
+    pub ingredients: Vec<Ingredient>,
+    pub steps: Vec<String>,
+    pub servings: u32,
+    pub prep_time_minutes: Option<u32>,
+    pub cook_time_minutes: Option<u32>,
+    #[serde(default)]
+    pub nutrition: Option<Nutrition>,
+    pub author_id: ObjectId,
+    #[serde(default)]
+    pub status: RecipeStatus,
+    #[serde(default)]
+    pub published_at: Option<DateTime>,
+    #[serde(default)]
+    pub forked_from: Option<ObjectId>,
+    /// Recipes this one was forked from, the original first
+    #[serde(default)]
+    pub attribution: Vec<RecipeAttributionMongoDb>,
+    #[serde(default)]
+    pub fork_count: u32,
+    pub rating_average: f64,
+    pub rating_count: u32,
+    #[serde(default)]
+    pub favorite_count: u32,
+    /// Locale the recipe was written in, recipes from before translations have none
+    #[serde(default)]
+    pub locale: Option<String>,
+    #[serde(default)]
+    pub translations: BTreeMap<String, RecipeTranslation>,
+    pub created_at: DateTime,
+    pub modified_at: DateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Recipe {
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    pub ingredients: Vec<Ingredient>,
+    pub steps: Vec<String>,
+    pub servings: u32,
+    pub prep_time_minutes: Option<u32>,
+    pub cook_time_minutes: Option<u32>,
+    pub nutrition: Option<Nutrition>,
+    pub author_id: String,
+    pub status: RecipeStatus,
+    #[serde(serialize_with = "serialize_optional_datetime")]
+    pub published_at: Option<DateTime>,
+    pub forked_from: Option<String>,
+    pub attribution: Vec<RecipeAttribution>,
+    pub fork_count: u32,
+    pub rating_average: f64,
+    pub rating_count: u32,
+    pub favorite_count: u32,
+    /// Locale of the texts, a translation if one matched the Accept-Language header
+    pub locale: String,
+    pub available_locales: Vec<String>,
+    #[serde(skip)]
+    pub translations: BTreeMap<String, RecipeTranslation>,
+    /// Only estimated on reads, never stored with the recipe
+    #[serde(default)]
+    pub estimated_cost: Option<RecipeCost>,
+    /// Share of the seasonal produce in season, only computed on reads as well
+    #[serde(default)]
+    pub seasonality_score: Option<f64>,
+    #[serde(serialize_with = "serialize_datetime")]
+    pub created_at: DateTime,
+    #[serde(serialize_with = "serialize_datetime")]
+    pub modified_at: DateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeDetail {
+    #[serde(flatten)]
+    pub recipe: Recipe,
+    pub suggested_substitutions: Vec<SuggestedSubstitution>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeCreate {
+    pub title: String,
+    pub description: String,
+    pub ingredients: Vec<Ingredient>,
+    pub steps: Vec<String>,
+    pub servings: u32,
+    pub prep_time_minutes: Option<u32>,
+    pub cook_time_minutes: Option<u32>,
+    pub nutrition: Option<Nutrition>,
+    /// New recipes start as drafts unless another status is requested
+    #[serde(default = "default_create_status")]
+    pub status: RecipeStatus,
+    pub locale: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RecipePatch {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub ingredients: Option<Vec<Ingredient>>,
+    pub steps: Option<Vec<String>>,
+    pub servings: Option<u32>,
+    pub prep_time_minutes: Option<u32>,
+    pub cook_time_minutes: Option<u32>,
+    pub nutrition: Option<Nutrition>,
+    pub status: Option<RecipeStatus>,
+    pub locale: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeImportReport {
+    pub recipe_id: String,
+    pub status: RecipeStatus,
+    pub unmapped_fields: Vec<String>,
+    pub vegan_check: VeganCheck,
+}
+
+#[derive(Deserialize, Copy, Clone, PartialEq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum RecipeFormat {
+    Cooklang,
+    Markdown,
+}
+
+#[derive(Deserialize, Clone, Default)]
+pub struct RecipeFormatQuery {
+    pub format: Option<RecipeFormat>,
+}
+
+#[derive(Deserialize, Copy, Clone, PartialEq, Debug, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum RecipeSort {
+    #[default]
+    Newest,
+    Rating,
+}
+
+#[derive(Deserialize, Clone, Default)]
+pub struct RecipeQuery {
+    #[serde(default)]
+    pub sort: RecipeSort,
+    /// Only keeps recipes whose complete cost estimate is within budget
+    pub max_cost_per_serving: Option<f64>,
+    pub currency: Option<String>,
+    /// Selects the price region as well as the seasonal calendar
+    pub region: Option<String>,
+    /// Only keeps recipes made mostly from produce in season this month
+    pub in_season: Option<bool>,
+}
+
+impl RecipeQuery {
+    pub fn get_cost_query(&self) -> CostQuery {
+        CostQuery {
+            currency: self.currency.clone(),
+            region: self.region.clone(),
+        }
+    }
+
+    /// Whether recipes are filtered by values computed after they were loaded
+    pub fn has_computed_filters(&self) -> bool {
+        self.max_cost_per_serving.is_some() || self.in_season == Some(true)
+    }
+
+    pub fn matches_computed_filters(&self, recipe: &Recipe) -> bool {
+        let is_in_season = self.in_season != Some(true)
+            || recipe
+                .seasonality_score
+                .is_some_and(|score| score >= IN_SEASON_MIN_SCORE);
+
+        // Recipes with missing prices can't be promised to stay within the budget
+        let is_within_budget = self
+            .max_cost_per_serving
+            .is_none_or(|max_cost_per_serving| {
+                recipe.estimated_cost.as_ref().is_some_and(|cost| {
+                    cost.is_complete() && cost.per_serving <= max_cost_per_serving
+                })
+            });
+
+        is_in_season && is_within_budget
+    }
+}
+
+impl From<RecipeAttributionMongoDb> for RecipeAttribution {
+    fn from(attribution_mongo_db: RecipeAttributionMongoDb) -> Self {
+        Self {
+            recipe_id: attribution_mongo_db.recipe_id.to_hex(),
+            author_id: attribution_mongo_db.author_id.to_hex(),
+            title: attribution_mongo_db.title,
+        }
+    }
+}
+
+impl Recipe {
+    /// Anyone with a link can fork published and unlisted recipes, except their author
+    /// who edits the recipe instead
+    pub fn check_forkable_by(&self, user_id: &str) -> Result<(), Message<'static>> {
+        if !self.status.is_public() {
+            return Err(Message::ForkUnpublishedRecipe);
+        }
+
+        if self.author_id == user_id {
+            return Err(Message::ForkOwnRecipe);
+        }
+
+        Ok(())
+    }
+}
+
+impl From<RecipeMongoDb> for Recipe {
+    fn from(recipe_mongo_db: RecipeMongoDb) -> Self {
+        let locale = recipe_mongo_db
+            .locale
+            .unwrap_or_else(|| DEFAULT_RECIPE_LOCALE.into());
+        let available_locales = std::iter::once(locale.clone())
+            .chain(recipe_mongo_db.translations.keys().cloned())
+            .collect();
+
+        Self {
+            id: recipe_mongo_db._id.to_hex(),
+            title: recipe_mongo_db.title,
+            description: recipe_mongo_db.description,
+            ingredients: recipe_mongo_db.ingredients,
+            steps: recipe_mongo_db.steps,
+            servings: recipe_mongo_db.servings,
+            prep_time_minutes: recipe_mongo_db.prep_time_minutes,
+            cook_time_minutes: recipe_mongo_db.cook_time_minutes,
+            nutrition: recipe_mongo_db.nutrition,
+            author_id: recipe_mongo_db.author_id.to_hex(),
+            status: recipe_mongo_db.status,
+            published_at: recipe_mongo_db.published_at,
+            forked_from: recipe_mongo_db.forked_from.map(|id| id.to_hex()),
+            attribution: recipe_mongo_db
+                .attribution
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            fork_count: recipe_mongo_db.fork_count,
+            rating_average: recipe_mongo_db.rating_average,
+            rating_count: recipe_mongo_db.rating_count,
+            favorite_count: recipe_mongo_db.favorite_count,
+            locale,
+            available_locales,
+            translations: recipe_mongo_db.translations,
+            estimated_cost: None,
+            seasonality_score: None,
+            created_at: recipe_mongo_db.created_at,
+            modified_at: recipe_mongo_db.modified_at,
+        }
+    }
+}
+
+impl Validate for Ingredient {
+    fn validate(&self) -> Result<(), Vec<FieldError>> {
+        Validator::new()
+            .length("name", &self.name, 1, MAX_INGREDIENT_NAME_LENGTH)
+            .optional(self.quantity, |v, quantity| {
+                v.range("quantity", quantity, 0.0, MAX_QUANTITY)
+            })
+            .optional(self.unit.as_deref(), |v, unit| {
+                v.length("unit", unit, 0, MAX_UNIT_LENGTH)
+                    .regex("unit", unit, &UNIT_REGEX)
+            })
+            .optional(self.note.as_deref(), |v, note| {
+                v.length("note", note, 0, MAX_NOTE_LENGTH)
+            })
+            .finish()
+    }
+}
+
+impl Validate for Nutrition {
+    fn validate(&self) -> Result<(), Vec<FieldError>> {
+        [
+            ("calories", self.calories),
+            ("protein", self.protein),
+            ("carbohydrates", self.carbohydrates),
+            ("fat", self.fat),
+            ("fiber", self.fiber),
+        ]
+        .into_iter()
+        .fold(Validator::new(), |v, (field, amount)| {
+            v.range(field, amount, 0.0, MAX_NUTRIENT_AMOUNT)
+        })
+        .finish()
+    }
+}
+
+impl Validate for RecipeTranslation {
+    fn validate(&self) -> Result<(), Vec<FieldError>> {
+        Validator::new()
+            .optional(self.title.as_deref(), |v, title| {
+                v.length("title", title, 1, MAX_TITLE_LENGTH)
+            })
+            .optional(self.description.as_deref(), |v, description| {
+                v.length("description", description, 0, MAX_DESCRIPTION_LENGTH)
+            })
+            .count("notes", &self.notes, 0, MAX_INGREDIENT_COUNT)
+            .each("notes", &self.notes, |v, field, note| {
+                v.optional(note.as_deref(), |v, note| {
+                    v.length(field, note, 0, MAX_NOTE_LENGTH)
+                })
+            })
+            .count("steps", &self.steps, 0, MAX_STEP_COUNT)
+            .each("steps", &self.steps, |v, field, step| {
+                v.length(field, step, 1, MAX_STEP_LENGTH)
+            })
+            .finish()
+    }
+}
+
+/// Rules shared by new and patched recipes, patches only check what they change
+struct RecipeFields<'a> {
+    title: Option<&'a str>,
+    description: Option<&'a str>,
+    ingredients: Option<&'a [Ingredient]>,
+    steps: Option<&'a [String]>,
+    servings: Option<u32>,
+    prep_time_minutes: Option<u32>,
+    cook_time_minutes: Option<u32>,
+    nutrition: Option<&'a Nutrition>,
+    locale: Option<&'a str>,
+}
+
+impl Validate for RecipeFields<'_> {
+    fn validate(&self) -> Result<(), Vec<FieldError>> {
+        Validator::new()
+            .optional(self.title, |v, title| {
+                v.length("title", title, 1, MAX_TITLE_LENGTH)
+            })
+            .optional(self.description, |v, description| {
+                v.length("description", description, 0, MAX_DESCRIPTION_LENGTH)
+            })
+            .optional(self.ingredients, |v, ingredients| {
+                v.count("ingredients", ingredients, 0, MAX_INGREDIENT_COUNT)
+                    .nested_each("ingredients", ingredients)
+            })
+            .optional(self.steps, |v, steps| {
+                v.count("steps", steps, 0, MAX_STEP_COUNT)
+                    .each("steps", steps, |v, field, step| {
+                        v.length(field, step, 1, MAX_STEP_LENGTH)
+                    })
+            })
+            .optional(self.servings, |v, servings| {
+                v.range("servings", servings, 1, MAX_SERVINGS)
+            })
+            .optional(self.prep_time_minutes, |v, minutes| {
+                v.range("prepTimeMinutes", minutes, 0, MAX_TIME_MINUTES)
+            })
+            .optional(self.cook_time_minutes, |v, minutes| {
+                v.range("cookTimeMinutes", minutes, 0, MAX_TIME_MINUTES)
+            })
+            .optional(self.nutrition, |v, nutrition| {
+                v.nested("nutrition", nutrition)
+            })
+            .optional(self.locale, |v, locale| {
+                v.check(
+                    "locale",
+                    is_valid_locale(locale),
+                    Message::InvalidLocale { locale: &locale },
+                )
+            })
+            .finish()
+    }
+}
+
+impl Validate for RecipeCreate {
+    fn validate(&self) -> Result<(), Vec<FieldError>> {
+        RecipeFields {
+            title: Some(&self.title),
+            description: Some(&self.description),
+            ingredients: Some(&self.ingredients),
+            steps: Some(&self.steps),
+            servings: Some(self.servings),
+            prep_time_minutes: self.prep_time_minutes,
+            cook_time_minutes: self.cook_time_minutes,
+            nutrition: self.nutrition.as_ref(),
+            locale: self.locale.as_deref(),
+        }
+        .validate()
+    }
+}
+
+impl Validate for RecipePatch {
+    fn validate(&self) -> Result<(), Vec<FieldError>> {
+        RecipeFields {
+            title: self.title.as_deref(),
+            description: self.description.as_deref(),
+            ingredients: self.ingredients.as_deref(),
+            steps: self.steps.as_deref(),
+            servings: self.servings,
+            prep_time_minutes: self.prep_time_minutes,
+            cook_time_minutes: self.cook_time_minutes,
+            nutrition: self.nutrition.as_ref(),
+            locale: self.locale.as_deref(),
+        }
+        .validate()
+    }
+}
+
+#[cfg(test)]
+mod unit_tests_recipe_model {
+    use crate::test_utils::get_random_recipe_db;
+
+    use super::*;
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn recipe_mongo_db_into_recipe() {
+        struct TestCase {
+            title: String,
+        }
+
+        let test_cases = vec![TestCase {
+            title: "Successfully converts a RecipeMongoDb into a Recipe".into(),
+        }];
+
+        for t in test_cases {
+            let recipe_db = get_random_recipe_db(None, None);
+            let cloned_recipe_db = recipe_db.clone();
+            let recipe: Recipe = recipe_db.into();
+
+            assert_eq!(cloned_recipe_db._id.to_hex(), recipe.id, "{}", &t.title);
+            assert_eq!(cloned_recipe_db.title, recipe.title, "{}", &t.title);
+            assert_eq!(
+                cloned_recipe_db.ingredients, recipe.ingredients,
+                "{}",
+                &t.title
+            );
+            assert_eq!(
+                cloned_recipe_db.author_id.to_hex(),
+                recipe.author_id,
+                "{}",
+                &t.title
+            );
+            assert_eq!(
+                cloned_recipe_db.rating_count, recipe.rating_count,
+                "{}",
+                &t.title
+            );
+        }
+    }
+
+    #[test]
+    fn sums_and_scales_nutrition() {
+        let nutrition = Nutrition {
+            calories: 250.0,
+            protein: 10.0,
+            carbohydrates: 30.0,
+            fat: 8.0,
+            fiber: 4.0,
+        };
+
+        let mut total = Nutrition::default();
+        total += nutrition.scale(2.0);
+        total += nutrition;
+
+        assert_eq!(
+            total,
+            Nutrition {
+                calories: 750.0,
+                protein: 30.0,
+                carbohydrates: 90.0,
+                fat: 24.0,
+                fiber: 12.0,
+            }
+        );
+    }
+
+    #[test]
+    fn deserialize_recipe_query() -> Result<()> {
+        struct TestCase {
+            title: String,
+            query: String,
+            expected_sort: RecipeSort,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Defaults to sorting by newest".into(),
+                query: "{}".into(),
+                expected_sort: RecipeSort::Newest,
+            },
+            TestCase {
+                title: "Sorts by rating".into(),
+                query: r#"{"sort":"rating"}"#.into(),
+                expected_sort: RecipeSort::Rating,
+            },
+        ];
+
+        for t in test_cases {
+            let query: RecipeQuery = serde_json::from_str(&t.query)?;
+
+            assert_eq!(query.sort, t.expected_sort, "{}", t.title);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_recipe_create_status() -> Result<()> {
+        struct TestCase {
+            title: String,
+            status: Option<String>,
+            expected_status: RecipeStatus,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Defaults new recipes to drafts".into(),
+                status: None,
+                expected_status: RecipeStatus::Draft,
+            },
+            TestCase {
+                title: "Keeps a requested status".into(),
+                status: Some("Unlisted".into()),
+                expected_status: RecipeStatus::Unlisted,
+            },
+        ];
+
+        for t in test_cases {
+            let mut payload = serde_json::json!({
+                "title": "Chickpea curry",
+                "description": "Quick and creamy",
+                "ingredients": [],
+                "steps": [],
+                "servings": 4,
+            });
+            if let Some(status) = t.status {
+                payload["status"] = status.into();
+            }
+
+            let recipe_create: RecipeCreate = serde_json::from_value(payload)?;
+
+            assert_eq!(recipe_create.status, t.expected_status, "{}", t.title);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn checks_requested_status() {
+        struct TestCase {
+            title: String,
+            status: RecipeStatus,
+            role: Role,
+            is_allowed: bool,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Allows authors to keep drafts".into(),
+                status: RecipeStatus::Draft,
+                role: Role::User,
+                is_allowed: true,
+            },
+            TestCase {
+                title: "Rejects publishing without the publish endpoint".into(),
+                status: RecipeStatus::Published,
+                role: Role::User,
+                is_allowed: false,
+            },
+            TestCase {
+                title: "Rejects publishing by admins as well".into(),
+                status: RecipeStatus::Published,
+                role: Role::Admin,
+                is_allowed: false,
+            },
+            TestCase {
+                title: "Rejects removing by authors".into(),
+                status: RecipeStatus::Removed,
+                role: Role::User,
+                is_allowed: false,
+            },
+            TestCase {
+                title: "Allows removing by admins".into(),
+                status: RecipeStatus::Removed,
+                role: Role::Admin,
+                is_allowed: true,
+            },
+        ];
+
+        for t in test_cases {
+            assert_eq!(
+                t.status.check_requested(&t.role).is_ok(),
+                t.is_allowed,
+                "{}",
+                t.title
+            );
+        }
+    }
+
+    #[test]
+    fn checks_forkable_recipes() {
+        struct TestCase {
+            title: String,
+            status: RecipeStatus,
+            is_author: bool,
+            is_allowed: bool,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Allows forking published recipes".into(),
+                status: RecipeStatus::Published,
+                is_author: false,
+                is_allowed: true,
+            },
+            TestCase {
+                title: "Allows forking unlisted recipes shared by link".into(),
+                status: RecipeStatus::Unlisted,
+                is_author: false,
+                is_allowed: true,
+            },
+            TestCase {
+                title: "Rejects forking drafts".into(),
+                status: RecipeStatus::Draft,
+                is_author: false,
+                is_allowed: false,
+            },
+            TestCase {
+                title: "Rejects forking removed recipes".into(),
+                status: RecipeStatus::Removed,
+                is_author: false,
+                is_allowed: false,
+            },
+            TestCase {
+                title: "Rejects forking own recipes".into(),
+                status: RecipeStatus::Published,
+                is_author: true,
+                is_allowed: false,
+            },
+        ];
+
+        for t in test_cases {
+            let author_id = ObjectId::new();
+            let recipe: Recipe = RecipeMongoDb {
+                status: t.status,
+                ..get_random_recipe_db(None, Some(author_id))
+            }
+            .into();
+            let user_id = if t.is_author {
+                author_id
+            } else {
+                ObjectId::new()
+            };
+
+            assert_eq!(
+                recipe.check_forkable_by(&user_id.to_hex()).is_ok(),
+                t.is_allowed,
+                "{}",
+                t.title
+            );
+        }
+    }
+
+    #[test]
+    fn validates_recipe_payloads() {
+        struct TestCase {
+            title: String,
+            errors: Option<Vec<FieldError>>,
+            expected_fields: Vec<String>,
+        }
+
+        let recipe_create = RecipeCreate {
+            title: "Chickpea curry".into(),
+            description: "Quick and creamy".into(),
+            ingredients: vec![Ingredient {
+                name: "Chickpeas".into(),
+                quantity: Some(400.0),
+                unit: Some("g".into()),
+                note: None,
+            }],
+            steps: vec!["Simmer everything for 20 minutes".into()],
+            servings: 4,
+            prep_time_minutes: Some(10),
+            cook_time_minutes: Some(20),
+            nutrition: None,
+            status: RecipeStatus::default(),
+            locale: Some("en".into()),
+        };
+
+        let test_cases = vec![
+            TestCase {
+                title: "Accepts a valid recipe".into(),
+                errors: recipe_create.validate().err(),
+                expected_fields: vec![],
+            },
+            TestCase {
+                title: "Reports nested fields by their path".into(),
+                errors: RecipeCreate {
+                    title: " ".into(),
+                    ingredients: vec![
+                        recipe_create.ingredients[0].clone(),
+                        Ingredient {
+                            name: "Coconut milk".into(),
+                            quantity: Some(-1.0),
+                            unit: Some("<ml>".into()),
+                            note: None,
+                        },
+                    ],
+                    steps: vec!["".into()],
+                    servings: 0,
+                    nutrition: Some(Nutrition {
+                        fat: -5.0,
+                        ..Default::default()
+                    }),
+                    locale: Some("not a locale".into()),
+                    ..recipe_create.clone()
+                }
+                .validate()
+                .err(),
+                expected_fields: vec![
+                    "title".into(),
+                    "ingredients[1].quantity".into(),
+                    "ingredients[1].unit".into(),
+                    "steps[0]".into(),
+                    "servings".into(),
+                    "nutrition.fat".into(),
+                    "locale".into(),
+                ],
+            },
+            TestCase {
+                title: "Only checks the fields of a patch that change".into(),
+                errors: RecipePatch {
+                    servings: Some(1000),
+                    ..Default::default()
+                }
+                .validate()
+                .err(),
+                expected_fields: vec!["servings".into()],
+            },
+        ];
+
+        for t in test_cases {
+            let fields: Vec<String> = t
+                .errors
+                .unwrap_or_default()
+                .into_iter()
+                .map(|error| error.field)
+                .collect();
+
+            assert_eq!(fields, t.expected_fields, "{}", t.title);
+        }
+    }
+
+    #[test]
+    fn matches_computed_filters() {
+        struct TestCase {
+            title: String,
+            query: RecipeQuery,
+            per_serving: f64,
+            missing_ingredients: Vec<String>,
+            seasonality_score: Option<f64>,
+            expected_match: bool,
+        }
+
+        let budget_query = RecipeQuery {
+            max_cost_per_serving: Some(3.0),
+            ..Default::default()
+        };
+        let season_query = RecipeQuery {
+            in_season: Some(true),
+            ..Default::default()
+        };
+
+        let test_cases = vec![
+            TestCase {
+                title: "Keeps recipes within budget".into(),
+                query: budget_query.clone(),
+                per_serving: 2.5,
+                missing_ingredients: vec![],
+                seasonality_score: None,
+                expected_match: true,
+            },
+            TestCase {
+                title: "Leaves out recipes over budget".into(),
+                query: budget_query.clone(),
+                per_serving: 3.5,
+                missing_ingredients: vec![],
+                seasonality_score: None,
+                expected_match: false,
+            },
+            TestCase {
+                title: "Leaves out recipes with missing prices".into(),
+                query: budget_query,
+                per_serving: 1.0,
+                missing_ingredients: vec!["saffron".into()],
+                seasonality_score: None,
+                expected_match: false,
+            },
+            TestCase {
+                title: "Keeps recipes in season".into(),
+                query: season_query.clone(),
+                per_serving: 10.0,
+                missing_ingredients: vec![],
+                seasonality_score: Some(IN_SEASON_MIN_SCORE),
+                expected_match: true,
+            },
+            TestCase {
+                title: "Leaves out recipes without produce".into(),
+                query: season_query,
+                per_serving: 10.0,
+                missing_ingredients: vec![],
+                seasonality_score: None,
+                expected_match: false,
+            },
+        ];
+
+        for t in test_cases {
+            let mut recipe: Recipe = get_random_recipe_db(None, None).into();
+            recipe.estimated_cost = Some(RecipeCost {
+                currency: "EUR".into(),
+                total: t.per_serving * recipe.servings as f64,
+                per_serving: t.per_serving,
+                missing_ingredients: t.missing_ingredients,
+            });
+            recipe.seasonality_score = t.seasonality_score;
+
+            assert!(t.query.has_computed_filters(), "{}", t.title);
+            assert_eq!(
+                t.query.matches_computed_filters(&recipe),
+                t.expected_match,
+                "{}",
+                t.title
+            );
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use bson::{oid::ObjectId, DateTime};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    recipe::{Ingredient, Nutrition, Recipe},
+    serialize_datetime,
+};
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeSnapshot {
+    pub title: String,
+    pub description: String,
+    pub ingredients: Vec<Ingredient>,
+    pub steps: Vec<String>,
+    pub servings: u32,
+    pub prep_time_minutes: Option<u32>,
+    pub cook_time_minutes: Option<u32>,
+    pub nutrition: Option<Nutrition>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct RecipeRevisionMongoDb {
+    pub _id: ObjectId,
+    pub recipe_id: ObjectId,
+    pub revision: u32,
+    pub author_id: ObjectId,
+    pub snapshot: RecipeSnapshot,
+    pub created_at: DateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeRevision {
+    pub id: String,
+    pub recipe_id: String,
+    pub revision: u32,
+    pub author_id: String,
+    pub snapshot: RecipeSnapshot,
+    #[serde(serialize_with = "serialize_datetime")]
+    pub created_at: DateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct IngredientChange {
+    pub name: String,
+    pub from: Ingredient,
+    pub to: Ingredient,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct StepChange {
+    pub position: usize,
+    pub from: Option<String>,
+    pub to: Option<String>,
+}
+
+/// Changes from a revision to the current recipe
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeDiff {
+    pub changed_fields: Vec<String>,
+    pub ingredients_added: Vec<Ingredient>,
+    pub ingredients_removed: Vec<Ingredient>,
+    pub ingredients_changed: Vec<IngredientChange>,
+    pub steps_changed: Vec<StepChange>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeRevisionDetail {
+    #[serde(flatten)]
+    pub revision: RecipeRevision,
+    pub diff: RecipeDiff,
+}
+
+impl From<&Recipe> for RecipeSnapshot {
+    fn from(recipe: &Recipe) -> Self {
+        Self {
+            title: recipe.title.clone(),
+            description: recipe.description.clone(),
+            ingredients: recipe.ingredients.clone(),
+            steps: recipe.steps.clone(),
+            servings: recipe.servings,
+            prep_time_minutes: recipe.prep_time_minutes,
+            cook_time_minutes: recipe.cook_time_minutes,
+            nutrition: recipe.nutrition,
+        }
+    }
+}
+
+impl From<RecipeRevisionMongoDb> for RecipeRevision {
+    fn from(revision_mongo_db: RecipeRevisionMongoDb) -> Self {
+        Self {
+            id: revision_mongo_db._id.to_hex(),
+            recipe_id: revision_mongo_db.recipe_id.to_hex(),
+            revision: revision_mongo_db.revision,
+            author_id: revision_mongo_db.author_id.to_hex(),
+            snapshot: revision_mongo_db.snapshot,
+            created_at: revision_mongo_db.created_at,
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use bson::{oid::ObjectId, DateTime};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use super::{serialize_datetime, serialize_optional_datetime};
+use crate::api::{
+    api_response::FieldError,
+    validation::{Validate, Validator},
+};
+
+pub const MAX_REASON_LENGTH: usize = 1000;
+const MAX_NOTE_LENGTH: usize = 1000;
+
+#[derive(Serialize, Deserialize, Copy, Clone, PartialEq, Debug)]
+pub enum ReportTarget {
+    Recipe,
+    Comment,
+    User,
+}
+
+impl fmt::Display for ReportTarget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            ReportTarget::Recipe => "Recipe",
+            ReportTarget::Comment => "Comment",
+            ReportTarget::User => "User",
+        };
+
+        write!(f, "{name}")
+    }
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, PartialEq, Debug)]
+pub enum ReportStatus {
+    Open,
+    Resolved,
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, PartialEq, Debug)]
+pub enum ModerationAction {
+    Dismiss,
+    HideContent,
+    DeactivateAuthor,
+}
+
+impl fmt::Display for ModerationAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            ModerationAction::Dismiss => "Dismiss",
+            ModerationAction::HideContent => "HideContent",
+            ModerationAction::DeactivateAuthor => "DeactivateAuthor",
+        };
+
+        write!(f, "{name}")
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ReportMongoDb {
+    pub _id: ObjectId,
+    pub target_type: ReportTarget,
+    pub target_id: ObjectId,
+    pub reporter_id: ObjectId,
+    pub reason: String,
+    pub status: ReportStatus,
+    pub action: Option<ModerationAction>,
+    pub resolved_by: Option<ObjectId>,
+    pub resolved_at: Option<DateTime>,
+    pub created_at: DateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Report {
+    pub id: String,
+    pub target_type: ReportTarget,
+    pub target_id: String,
+    pub reporter_id: String,
+    pub reason: String,
+    pub status: ReportStatus,
+    pub action: Option<ModerationAction>,
+    pub resolved_by: Option<String>,
+    #[serde(serialize_with = "serialize_optional_datetime")]
+    pub resolved_at: Option<DateTime>,
+    #[serde(serialize_with = "serialize_datetime")]
+    pub created_at: DateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportCreate {
+    pub target_type: ReportTarget,
+    pub target_id: String,
+    pub reason: String,
+}
+
+impl Validate for ReportCreate {
+    fn validate(&self) -> Result<(), Vec<FieldError>> {
+        Validator::new()
+            .length("reason", &self.reason, 1, MAX_REASON_LENGTH)
+            .finish()
+    }
+}
+
+#[derive(Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportQuery {
+    pub status: Option<ReportStatus>,
+    pub target_type: Option<ReportTarget>,
+    pub reporter_id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportResolution {
+    pub action: ModerationAction,
+    pub note: Option<String>,
+}
+
+impl Validate for ReportResolution {
+    fn validate(&self) -> Result<(), Vec<FieldError>> {
+        Validator::new()
+            .optional(self.note.as_deref(), |v, note| {
+                v.length("note", note, 0, MAX_NOTE_LENGTH)
+            })
+            .finish()
+    }
+}
+
+/// Audit entry written for every moderation action
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ModerationLogMongoDb {
+    pub _id: ObjectId,
+    pub moderator_id: ObjectId,
+    pub report_id: ObjectId,
+    pub action: ModerationAction,
+    pub target_type: ReportTarget,
+    pub target_id: ObjectId,
+    pub note: Option<String>,
+    pub created_at: DateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ModerationLogEntry {
+    pub id: String,
+    pub moderator_id: String,
+    pub report_id: String,
+    pub action: ModerationAction,
+    pub target_type: ReportTarget,
+    pub target_id: String,
+    pub note: Option<String>,
+    #[serde(serialize_with = "serialize_datetime")]
+    pub created_at: DateTime,
+}
+
+impl From<ReportMongoDb> for Report {
+    fn from(report_mongo_db: ReportMongoDb) -> Self {
+        Self {
+            id: report_mongo_db._id.to_hex(),
+            target_type: report_mongo_db.target_type,
+            target_id: report_mongo_db.target_id.to_hex(),
+            reporter_id: report_mongo_db.reporter_id.to_hex(),
+            reason: report_mongo_db.reason,
+            status: report_mongo_db.status,
+            action: report_mongo_db.action,
+            resolved_by: report_mongo_db.resolved_by.map(|id| id.to_hex()),
+            resolved_at: report_mongo_db.resolved_at,
+            created_at: report_mongo_db.created_at,
+        }
+    }
+}
+
+impl From<ModerationLogMongoDb> for ModerationLogEntry {
+    fn from(log_mongo_db: ModerationLogMongoDb) -> Self {
+        Self {
+            id: log_mongo_db._id.to_hex(),
+            moderator_id: log_mongo_db.moderator_id.to_hex(),
+            report_id: log_mongo_db.report_id.to_hex(),
+            action: log_mongo_db.action,
+            target_type: log_mongo_db.target_type,
+            target_id: log_mongo_db.target_id.to_hex(),
+            note: log_mongo_db.note,
+            created_at: log_mongo_db.created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_tests_report_model {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn validates_report_create() {
+        struct TestCase {
+            title: String,
+            reason: String,
+            is_success: bool,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Accepts a reason".into(),
+                reason: "Spam link in the description".into(),
+                is_success: true,
+            },
+            TestCase {
+                title: "Rejects a blank reason".into(),
+                reason: "   ".into(),
+                is_success: false,
+            },
+            TestCase {
+                title: "Rejects a reason above the maximum length".into(),
+                reason: "a".repeat(MAX_REASON_LENGTH + 1),
+                is_success: false,
+            },
+        ];
+
+        for t in test_cases {
+            let report_create = ReportCreate {
+                target_type: ReportTarget::Recipe,
+                target_id: ObjectId::new().to_hex(),
+                reason: t.reason,
+            };
+
+            assert_eq!(
+                report_create.validate().is_ok(),
+                t.is_success,
+                "{}",
+                t.title
+            );
+        }
+    }
+}
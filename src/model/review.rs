@@ -0,0 +1,120 @@
+use bson::{oid::ObjectId, DateTime};
+use serde::{Deserialize, Serialize};
+
+use super::serialize_datetime;
+use crate::api::{
+    api_response::FieldError,
+    validation::{Validate, Validator},
+};
+
+pub const MIN_RATING: u8 = 1;
+pub const MAX_RATING: u8 = 5;
+
+const MAX_TEXT_LENGTH: usize = 5000;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ReviewMongoDb {
+    pub _id: ObjectId,
+    pub recipe_id: ObjectId,
+    pub user_id: ObjectId,
+    pub rating: u8,
+    pub text: String,
+    pub created_at: DateTime,
+    pub modified_at: DateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Review {
+    pub id: String,
+    pub recipe_id: String,
+    pub user_id: String,
+    pub rating: u8,
+    pub text: String,
+    #[serde(serialize_with = "serialize_datetime")]
+    pub created_at: DateTime,
+    #[serde(serialize_with = "serialize_datetime")]
+    pub modified_at: DateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ReviewPayload {
+    pub rating: u8,
+    pub text: String,
+}
+
+impl Validate for ReviewPayload {
+    fn validate(&self) -> Result<(), Vec<FieldError>> {
+        Validator::new()
+            .range("rating", self.rating, MIN_RATING, MAX_RATING)
+            .length("text", &self.text, 0, MAX_TEXT_LENGTH)
+            .finish()
+    }
+}
+
+#[derive(Deserialize, Clone, PartialEq, Debug)]
+pub struct RecipeRating {
+    pub average: f64,
+    pub count: u32,
+}
+
+impl From<ReviewMongoDb> for Review {
+    fn from(review_mongo_db: ReviewMongoDb) -> Self {
+        Self {
+            id: review_mongo_db._id.to_hex(),
+            recipe_id: review_mongo_db.recipe_id.to_hex(),
+            user_id: review_mongo_db.user_id.to_hex(),
+            rating: review_mongo_db.rating,
+            text: review_mongo_db.text,
+            created_at: review_mongo_db.created_at,
+            modified_at: review_mongo_db.modified_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_tests_review_model {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn validates_review_payload() {
+        struct TestCase {
+            title: String,
+            rating: u8,
+            is_success: bool,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Accepts the lowest rating".into(),
+                rating: 1,
+                is_success: true,
+            },
+            TestCase {
+                title: "Accepts the highest rating".into(),
+                rating: 5,
+                is_success: true,
+            },
+            TestCase {
+                title: "Rejects a rating of zero".into(),
+                rating: 0,
+                is_success: false,
+            },
+            TestCase {
+                title: "Rejects a rating above the maximum".into(),
+                rating: 6,
+                is_success: false,
+            },
+        ];
+
+        for t in test_cases {
+            let payload = ReviewPayload {
+                rating: t.rating,
+                text: "Tasty".into(),
+            };
+
+            assert_eq!(payload.validate().is_ok(), t.is_success, "{}", t.title);
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SeasonalProduce {
+    pub ingredient: String,
+    /// Months of the harvest season, 1 is January
+    pub months: Vec<u32>,
+    pub in_season: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SeasonCalendar {
+    pub region: String,
+    pub month: u32,
+    pub produce: Vec<SeasonalProduce>,
+}
+
+/// Region and month to look up seasons for, the current month if there is none
+#[derive(Deserialize, Clone, Default)]
+pub struct SeasonQuery {
+    pub region: Option<String>,
+    pub month: Option<u32>,
+}
@@ -0,0 +1,240 @@
+use std::fmt;
+
+use bson::{oid::ObjectId, DateTime};
+use serde::{Deserialize, Serialize};
+
+use super::{meal_plan::IsoWeek, serialize_datetime};
+use crate::api::{
+    api_response::FieldError,
+    messages::Message,
+    validation::{Validate, Validator},
+};
+
+const MAX_NAME_LENGTH: usize = 100;
+const MAX_RECIPE_COUNT: usize = 50;
+
+#[derive(Serialize, Deserialize, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Aisle {
+    Produce,
+    Bakery,
+    Protein,
+    DairyAndAlternatives,
+    Frozen,
+    Pantry,
+    SpicesAndSeasonings,
+    Beverages,
+    Other,
+}
+
+impl fmt::Display for Aisle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Aisle::Produce => "Produce",
+            Aisle::Bakery => "Bakery",
+            Aisle::Protein => "Protein",
+            Aisle::DairyAndAlternatives => "Dairy & alternatives",
+            Aisle::Frozen => "Frozen",
+            Aisle::Pantry => "Pantry",
+            Aisle::SpicesAndSeasonings => "Spices & seasonings",
+            Aisle::Beverages => "Beverages",
+            Aisle::Other => "Other",
+        };
+
+        write!(f, "{name}")
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ShoppingListItemMongoDb {
+    pub _id: ObjectId,
+    pub name: String,
+    pub quantity: Option<f64>,
+    pub unit: Option<String>,
+    pub aisle: Aisle,
+    pub checked: bool,
+    pub recipe_ids: Vec<ObjectId>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ShoppingListMongoDb {
+    pub _id: ObjectId,
+    /// Creator of the list, shared lists belong to the household
+    pub user_id: ObjectId,
+    #[serde(default)]
+    pub household_id: Option<ObjectId>,
+    pub name: String,
+    pub items: Vec<ShoppingListItemMongoDb>,
+    pub created_at: DateTime,
+    pub modified_at: DateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ShoppingListItem {
+    pub id: String,
+    pub name: String,
+    pub quantity: Option<f64>,
+    pub unit: Option<String>,
+    pub aisle: Aisle,
+    pub checked: bool,
+    pub recipe_ids: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ShoppingList {
+    pub id: String,
+    pub household_id: Option<String>,
+    pub name: String,
+    pub items: Vec<ShoppingListItem>,
+    #[serde(serialize_with = "serialize_datetime")]
+    pub created_at: DateTime,
+    #[serde(serialize_with = "serialize_datetime")]
+    pub modified_at: DateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ShoppingListCreate {
+    pub name: Option<String>,
+    #[serde(default)]
+    pub recipe_ids: Vec<String>,
+    pub meal_plan_week: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct ShoppingListItemPatch {
+    pub checked: Option<bool>,
+}
+
+impl Validate for ShoppingListCreate {
+    fn validate(&self) -> Result<(), Vec<FieldError>> {
+        Validator::new()
+            .optional(self.name.as_deref(), |v, name| {
+                v.length("name", name, 1, MAX_NAME_LENGTH)
+            })
+            .count("recipeIds", &self.recipe_ids, 0, MAX_RECIPE_COUNT)
+            .check(
+                "recipeIds",
+                !self.recipe_ids.is_empty() || self.meal_plan_week.is_some(),
+                Message::ShoppingListSourceRequired,
+            )
+            .optional(self.meal_plan_week.as_deref(), |v, week| {
+                v.check(
+                    "mealPlanWeek",
+                    week.parse::<IsoWeek>().is_ok(),
+                    Message::InvalidWeekFormat { week: &week },
+                )
+            })
+            .finish()
+    }
+}
+
+// Only the checked flag can be patched, which serde already checks
+impl Validate for ShoppingListItemPatch {
+    fn validate(&self) -> Result<(), Vec<FieldError>> {
+        Ok(())
+    }
+}
+
+#[derive(Deserialize, Copy, Clone, PartialEq, Debug, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ShoppingListExportFormat {
+    #[default]
+    Text,
+    Markdown,
+}
+
+#[derive(Deserialize, Clone, Default)]
+pub struct ShoppingListExportQuery {
+    #[serde(default)]
+    pub format: ShoppingListExportFormat,
+}
+
+impl From<ShoppingListItemMongoDb> for ShoppingListItem {
+    fn from(item_mongo_db: ShoppingListItemMongoDb) -> Self {
+        Self {
+            id: item_mongo_db._id.to_hex(),
+            name: item_mongo_db.name,
+            quantity: item_mongo_db.quantity,
+            unit: item_mongo_db.unit,
+            aisle: item_mongo_db.aisle,
+            checked: item_mongo_db.checked,
+            recipe_ids: item_mongo_db
+                .recipe_ids
+                .iter()
+                .map(|id| id.to_hex())
+                .collect(),
+        }
+    }
+}
+
+impl From<ShoppingListMongoDb> for ShoppingList {
+    fn from(shopping_list_mongo_db: ShoppingListMongoDb) -> Self {
+        Self {
+            id: shopping_list_mongo_db._id.to_hex(),
+            household_id: shopping_list_mongo_db.household_id.map(|id| id.to_hex()),
+            name: shopping_list_mongo_db.name,
+            items: shopping_list_mongo_db
+                .items
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            created_at: shopping_list_mongo_db.created_at,
+            modified_at: shopping_list_mongo_db.modified_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_tests_shopping_list_model {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn validates_shopping_list_create() {
+        struct TestCase {
+            title: String,
+            payload: ShoppingListCreate,
+            expected_fields: Vec<String>,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Accepts a meal plan week".into(),
+                payload: ShoppingListCreate {
+                    meal_plan_week: Some("2026-W42".into()),
+                    ..Default::default()
+                },
+                expected_fields: vec![],
+            },
+            TestCase {
+                title: "Requires recipes or a meal plan week".into(),
+                payload: ShoppingListCreate::default(),
+                expected_fields: vec!["recipeIds".into()],
+            },
+            TestCase {
+                title: "Rejects invalid weeks and blank names".into(),
+                payload: ShoppingListCreate {
+                    name: Some(" ".into()),
+                    recipe_ids: vec![],
+                    meal_plan_week: Some("2026-42".into()),
+                },
+                expected_fields: vec!["name".into(), "mealPlanWeek".into()],
+            },
+        ];
+
+        for t in test_cases {
+            let fields: Vec<String> = t
+                .payload
+                .validate()
+                .err()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|error| error.field)
+                .collect();
+
+            assert_eq!(fields, t.expected_fields, "{}", t.title);
+        }
+    }
+}
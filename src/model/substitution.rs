@@ -0,0 +1,144 @@
+use bson::{oid::ObjectId, DateTime};
+use serde::{Deserialize, Serialize};
+
+use super::serialize_datetime;
+use crate::api::{
+    api_response::FieldError,
+    messages::Message,
+    validation::{Validate, Validator},
+};
+
+const MAX_NAME_LENGTH: usize = 100;
+const MAX_NOTES_LENGTH: usize = 1000;
+const MAX_FREE_OF_COUNT: usize = 20;
+const MAX_ALLERGEN_LENGTH: usize = 50;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SubstitutionMongoDb {
+    pub _id: ObjectId,
+    pub ingredient: String,
+    pub substitute: String,
+    pub ratio: f64,
+    pub notes: Option<String>,
+    pub free_of: Vec<String>,
+    pub created_at: DateTime,
+    pub modified_at: DateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Substitution {
+    pub id: String,
+    pub ingredient: String,
+    pub substitute: String,
+    pub ratio: f64,
+    pub notes: Option<String>,
+    pub free_of: Vec<String>,
+    #[serde(serialize_with = "serialize_datetime")]
+    pub created_at: DateTime,
+    #[serde(serialize_with = "serialize_datetime")]
+    pub modified_at: DateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubstitutionCreate {
+    pub ingredient: String,
+    pub substitute: String,
+    pub ratio: f64,
+    pub notes: Option<String>,
+    #[serde(default)]
+    pub free_of: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SubstitutionPatch {
+    pub ingredient: Option<String>,
+    pub substitute: Option<String>,
+    pub ratio: Option<f64>,
+    pub notes: Option<String>,
+    pub free_of: Option<Vec<String>>,
+}
+
+fn check_free_of(validator: Validator, free_of: &[String]) -> Validator {
+    validator
+        .count("freeOf", free_of, 0, MAX_FREE_OF_COUNT)
+        .each("freeOf", free_of, |v, field, allergen| {
+            v.length(field, allergen, 1, MAX_ALLERGEN_LENGTH)
+        })
+}
+
+impl Validate for SubstitutionCreate {
+    fn validate(&self) -> Result<(), Vec<FieldError>> {
+        let validator = Validator::new()
+            .length("ingredient", &self.ingredient, 1, MAX_NAME_LENGTH)
+            .length("substitute", &self.substitute, 1, MAX_NAME_LENGTH)
+            .check("ratio", self.ratio > 0.0, Message::RatioNotPositive)
+            .optional(self.notes.as_deref(), |v, notes| {
+                v.length("notes", notes, 0, MAX_NOTES_LENGTH)
+            });
+
+        check_free_of(validator, &self.free_of).finish()
+    }
+}
+
+impl Validate for SubstitutionPatch {
+    fn validate(&self) -> Result<(), Vec<FieldError>> {
+        Validator::new()
+            .optional(self.ingredient.as_deref(), |v, ingredient| {
+                v.length("ingredient", ingredient, 1, MAX_NAME_LENGTH)
+            })
+            .optional(self.substitute.as_deref(), |v, substitute| {
+                v.length("substitute", substitute, 1, MAX_NAME_LENGTH)
+            })
+            .optional(self.ratio, |v, ratio| {
+                v.check("ratio", ratio > 0.0, Message::RatioNotPositive)
+            })
+            .optional(self.notes.as_deref(), |v, notes| {
+                v.length("notes", notes, 0, MAX_NOTES_LENGTH)
+            })
+            .optional(self.free_of.as_deref(), check_free_of)
+            .finish()
+    }
+}
+
+#[derive(Deserialize, Clone, Default)]
+pub struct SubstitutionQuery {
+    pub ingredient: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum SubstitutionReason {
+    Allergen,
+    MissingFromPantry,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SuggestedSubstitution {
+    pub ingredient: String,
+    pub reason: SubstitutionReason,
+    pub allergen: Option<String>,
+    pub substitute: String,
+    pub quantity: Option<f64>,
+    pub unit: Option<String>,
+    pub notes: Option<String>,
+    pub substitute_in_pantry: bool,
+}
+
+impl From<SubstitutionMongoDb> for Substitution {
+    fn from(substitution_mongo_db: SubstitutionMongoDb) -> Self {
+        Self {
+            id: substitution_mongo_db._id.to_hex(),
+            ingredient: substitution_mongo_db.ingredient,
+            substitute: substitution_mongo_db.substitute,
+            ratio: substitution_mongo_db.ratio,
+            notes: substitution_mongo_db.notes,
+            free_of: substitution_mongo_db.free_of,
+            created_at: substitution_mongo_db.created_at,
+            modified_at: substitution_mongo_db.modified_at,
+        }
+    }
+}
@@ -1,8 +1,20 @@
 use anyhow::{anyhow, Result};
 use bson::{oid::ObjectId, DateTime};
-use serde::{Deserialize, Serialize, Serializer};
+use serde::{Deserialize, Serialize};
 
-use crate::api::services::hash_service::hash_password;
+use super::{notification::NotificationPreferences, serialize_datetime};
+use crate::api::{
+    api_response::FieldError,
+    messages::Message,
+    services::{hash_service::hash_password, locale_service::is_valid_locale},
+    validation::{Validate, Validator},
+};
+
+const MAX_EMAIL_LENGTH: usize = 254;
+/// Longer passwords only make hashing slow
+const MAX_PASSWORD_LENGTH: usize = 128;
+const MAX_ALLERGEN_COUNT: usize = 20;
+const MAX_ALLERGEN_LENGTH: usize = 50;
 
 #[derive(Serialize, Deserialize, Copy, Clone, PartialEq, Debug)]
 pub enum Role {
@@ -17,6 +29,8 @@ pub struct UserMongoDb {
     pub password_hash: String,
     pub role: Role,
     pub is_activated: bool,
+    #[serde(default)]
+    pub preferences: UserPreferences,
     pub created_at: DateTime,
     pub modified_at: DateTime,
 }
@@ -34,11 +48,45 @@ pub struct User {
     pub modified_at: DateTime,
 }
 
-fn serialize_datetime<S>(date: &DateTime, serializer: S) -> Result<S::Ok, S::Error>
-where
-    S: Serializer,
-{
-    serializer.serialize_str(&date.to_string())
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UserPreferences {
+    #[serde(default)]
+    pub allergens: Vec<String>,
+    #[serde(default)]
+    pub notifications: NotificationPreferences,
+    /// Language of messages sent outside of requests, like email digests
+    #[serde(default)]
+    pub locale: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UserPreferencesPatch {
+    pub allergens: Option<Vec<String>>,
+    pub notifications: Option<NotificationPreferences>,
+    pub locale: Option<String>,
+}
+
+impl Validate for UserPreferencesPatch {
+    fn validate(&self) -> Result<(), Vec<FieldError>> {
+        Validator::new()
+            .optional(self.allergens.as_deref(), |v, allergens| {
+                v.count("allergens", allergens, 0, MAX_ALLERGEN_COUNT).each(
+                    "allergens",
+                    allergens,
+                    |v, field, allergen| v.length(field, allergen, 1, MAX_ALLERGEN_LENGTH),
+                )
+            })
+            .optional(self.locale.as_deref(), |v, locale| {
+                v.check(
+                    "locale",
+                    is_valid_locale(locale),
+                    Message::InvalidLocale { locale: &locale },
+                )
+            })
+            .finish()
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -47,6 +95,16 @@ pub struct AuthPayload {
     pub password: String,
 }
 
+impl Validate for AuthPayload {
+    fn validate(&self) -> Result<(), Vec<FieldError>> {
+        Validator::new()
+            .length("email", &self.email, 1, MAX_EMAIL_LENGTH)
+            .email("email", &self.email)
+            .length("password", &self.password, 1, MAX_PASSWORD_LENGTH)
+            .finish()
+    }
+}
+
 impl TryFrom<AuthPayload> for UserCreate {
     type Error = anyhow::Error;
 
@@ -63,6 +121,7 @@ impl TryFrom<AuthPayload> for UserCreate {
 
 #[derive(Deserialize)]
 pub struct UserAuthInfo {
+    pub _id: ObjectId,
     pub email: String,
     pub password_hash: String,
     pub role: Role,
@@ -85,6 +144,17 @@ pub struct UserPatch {
     pub is_activated: Option<bool>,
 }
 
+impl Validate for UserPatch {
+    fn validate(&self) -> Result<(), Vec<FieldError>> {
+        Validator::new()
+            .optional(self.email.as_deref(), |v, email| {
+                v.length("email", email, 1, MAX_EMAIL_LENGTH)
+                    .email("email", email)
+            })
+            .finish()
+    }
+}
+
 impl From<UserMongoDb> for User {
     fn from(user_mongo_db: UserMongoDb) -> Self {
         Self {
@@ -159,6 +229,55 @@ mod unit_tests_user_model {
         Ok(())
     }
 
+    #[test]
+    fn validates_auth_payload() {
+        struct TestCase {
+            title: String,
+            auth_payload: AuthPayload,
+            expected_fields: Vec<String>,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                title: "Accepts a valid auth payload".into(),
+                auth_payload: AuthPayload {
+                    email: get_random_email(),
+                    password: get_random_string(10),
+                },
+                expected_fields: vec![],
+            },
+            TestCase {
+                title: "Rejects invalid emails and empty passwords".into(),
+                auth_payload: AuthPayload {
+                    email: "jane.example.com".into(),
+                    password: "".into(),
+                },
+                expected_fields: vec!["email".into(), "password".into()],
+            },
+            TestCase {
+                title: "Rejects overly long passwords".into(),
+                auth_payload: AuthPayload {
+                    email: get_random_email(),
+                    password: get_random_string(MAX_PASSWORD_LENGTH + 1),
+                },
+                expected_fields: vec!["password".into()],
+            },
+        ];
+
+        for t in test_cases {
+            let fields: Vec<String> = t
+                .auth_payload
+                .validate()
+                .err()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|error| error.field)
+                .collect();
+
+            assert_eq!(fields, t.expected_fields, "{}", t.title);
+        }
+    }
+
     #[test]
     fn serialize_user() -> Result<()> {
         struct TestCase {
@@ -191,4 +310,32 @@ mod unit_tests_user_model {
 
         Ok(())
     }
+
+    #[test]
+    fn validates_preferences_patch() {
+        let get_fields = |allergens: Vec<&str>| {
+            UserPreferencesPatch {
+                allergens: Some(allergens.into_iter().map(Into::into).collect()),
+                notifications: None,
+                locale: None,
+            }
+            .validate()
+            .err()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|error| error.field)
+            .collect::<Vec<String>>()
+        };
+
+        assert!(get_fields(vec!["gluten", "peanuts"]).is_empty());
+        assert_eq!(get_fields(vec!["gluten", " "]), vec!["allergens[1]"]);
+        assert_eq!(get_fields(vec!["soy"; 21]), vec!["allergens"]);
+
+        let locale_patch = |locale: &str| UserPreferencesPatch {
+            locale: Some(locale.into()),
+            ..Default::default()
+        };
+        assert!(locale_patch("de-AT").validate().is_ok());
+        assert!(locale_patch("de/../x").validate().is_err());
+    }
 }
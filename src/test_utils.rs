@@ -1,9 +1,28 @@
-use crate::model::user::{Role, User, UserMongoDb};
+use crate::{
+    api::services::token_service::generate_jwt,
+    db::mongo_db_handler::MongoDbHandler,
+    model::{
+        recipe::{Ingredient, RecipeMongoDb, RecipeStatus},
+        user::{Role, User, UserAuthInfo, UserMongoDb, UserPreferences},
+    },
+};
 use anyhow::{anyhow, Result};
-use bson::{doc, oid::ObjectId, DateTime};
-use mongodb::{options::ClientOptions, Client, Database};
+use axum::{
+    http::{Method, StatusCode},
+    Router,
+};
+use axum_test::TestServer;
+use bson::{doc, oid::ObjectId, DateTime, Document};
+use mongodb::{
+    options::{ClientOptions, ServerAddress},
+    Client, Database,
+};
 use rand::{distributions::Alphanumeric, Rng};
-use std::env;
+use serde_json::Value;
+use std::{collections::BTreeMap, env, time::Duration};
+
+#[cfg(test)]
+pub const TEST_JWT_SECRET: &str = "testSecret";
 
 #[cfg(test)]
 pub fn print_assert_failed(title: &str, expected: &str, got: &str) -> String {
@@ -123,21 +142,123 @@ pub async fn get_db_connection() -> Result<Database> {
     Ok(client.database(&db_name))
 }
 
+#[cfg(test)]
+pub async fn get_db_handler() -> Result<MongoDbHandler> {
+    let (db_name, db_user_name, db_user_password, db_host) = get_db_config(Some(".env"))?;
+
+    MongoDbHandler::new(&db_user_name, &db_user_password, &db_name, &db_host).await
+}
+
+/// Handler for requests that are rejected before they reach the database
+///
+/// Nothing connects until the first query, which fails fast without a running database.
+#[cfg(test)]
+pub fn get_unconnected_db_handler() -> Result<MongoDbHandler> {
+    let client_options = ClientOptions::builder()
+        .hosts(vec![ServerAddress::Tcp {
+            host: "127.0.0.1".into(),
+            port: Some(27017),
+        }])
+        .server_selection_timeout(Duration::from_millis(100))
+        .build();
+
+    let client = Client::with_options(client_options)?;
+
+    Ok(MongoDbHandler::from_database(
+        client.database("wegonice_test"),
+    ))
+}
+
+#[cfg(test)]
+pub fn get_bearer_token(user_id: ObjectId, role: Role) -> Result<String> {
+    let user_auth_info = UserAuthInfo {
+        _id: user_id,
+        email: get_random_email(),
+        password_hash: get_random_string(10),
+        role,
+        is_activated: true,
+    };
+
+    generate_jwt(&user_auth_info, 1, TEST_JWT_SECRET)
+}
+
+#[cfg(test)]
+pub struct RequestTestCase {
+    pub title: String,
+    pub method: Method,
+    pub path: String,
+    pub token: Option<String>,
+    pub body: Option<Value>,
+    pub expected_status_code: StatusCode,
+}
+
+/// Sends the request of every test case to the router and checks the status codes
+#[cfg(test)]
+pub async fn assert_status_codes(router: Router, test_cases: Vec<RequestTestCase>) -> Result<()> {
+    let server = TestServer::new(router)?;
+
+    for t in test_cases {
+        let mut request = server.method(t.method, &t.path);
+
+        if let Some(token) = t.token {
+            request = request.authorization_bearer(token);
+        }
+        if let Some(body) = t.body {
+            request = request.json(&body);
+        }
+
+        let response = request.await;
+
+        assert_eq!(
+            response.status_code(),
+            t.expected_status_code,
+            "{}",
+            print_assert_failed(
+                &t.title,
+                &t.expected_status_code.to_string(),
+                &response.text()
+            )
+        );
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 pub async fn db_clean_up() -> Result<()> {
     let database = get_db_connection().await?;
 
-    match database
-        .collection::<UserMongoDb>("users")
-        .delete_many(doc! {})
-        .await
-    {
-        Ok(_) => Ok(()),
-        Err(error) => Err(anyhow!(
-            "Failed to delete users in clean up step: {}",
-            error
-        )),
+    for collection_name in [
+        "users",
+        "recipes",
+        "recipe_revisions",
+        "reviews",
+        "comments",
+        "favorites",
+        "meal_plans",
+        "shopping_lists",
+        "pantries",
+        "substitutions",
+        "reports",
+        "moderation_log",
+        "follows",
+        "notifications",
+        "households",
+        "ingredient_prices",
+    ] {
+        if let Err(error) = database
+            .collection::<Document>(collection_name)
+            .delete_many(doc! {})
+            .await
+        {
+            return Err(anyhow!(
+                "Failed to delete {collection_name} in clean up step: {}",
+                error
+            ));
+        }
     }
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -167,6 +288,40 @@ pub fn get_random_user_db(id: Option<ObjectId>) -> UserMongoDb {
         password_hash: get_random_string(10),
         role: Role::User,
         is_activated: true,
+        preferences: UserPreferences::default(),
+        created_at: DateTime::now(),
+        modified_at: DateTime::now(),
+    }
+}
+
+#[cfg(test)]
+pub fn get_random_recipe_db(id: Option<ObjectId>, author_id: Option<ObjectId>) -> RecipeMongoDb {
+    RecipeMongoDb {
+        _id: id.unwrap_or_default(),
+        title: get_random_string(10),
+        description: get_random_string(30),
+        ingredients: vec![Ingredient {
+            name: get_random_string(8),
+            quantity: Some(200.0),
+            unit: Some("g".into()),
+            note: None,
+        }],
+        steps: vec![get_random_string(20)],
+        servings: 2,
+        prep_time_minutes: Some(10),
+        cook_time_minutes: Some(20),
+        nutrition: None,
+        author_id: author_id.unwrap_or_default(),
+        status: RecipeStatus::Published,
+        published_at: Some(DateTime::now()),
+        forked_from: None,
+        attribution: vec![],
+        fork_count: 0,
+        rating_average: 0.0,
+        rating_count: 0,
+        favorite_count: 0,
+        locale: None,
+        translations: BTreeMap::new(),
         created_at: DateTime::now(),
         modified_at: DateTime::now(),
     }